@@ -0,0 +1,6 @@
+DELETE FROM "users"
+WHERE
+    "id" IN (
+        'b5fc35ba-0990-482b-8988-6b25f25f9052',
+        '8bcf473d-3744-4ad5-b3eb-3d9c49979fd6'
+    );
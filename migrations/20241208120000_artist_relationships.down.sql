@@ -0,0 +1,9 @@
+-- Add down migration script here
+
+DROP TABLE "artist_membership";
+
+DROP TABLE "artist_alias";
+
+ALTER TABLE "artist" DROP COLUMN "artist_type";
+
+DROP TYPE "artist_type";
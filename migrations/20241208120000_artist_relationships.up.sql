@@ -0,0 +1,29 @@
+-- Add up migration script here
+
+CREATE TYPE "artist_type" AS ENUM('Person', 'Group');
+
+ALTER TABLE "artist"
+ADD COLUMN "artist_type" "artist_type" NOT NULL DEFAULT 'Person';
+
+CREATE TABLE "artist_alias" (
+    "artist_id" UUID NOT NULL,
+    "name" VARCHAR(128) NOT NULL,
+    PRIMARY KEY ("artist_id", "name"),
+    FOREIGN KEY ("artist_id") REFERENCES "artist" ("id") ON DELETE CASCADE
+);
+
+CREATE INDEX "idx_artist_alias_name" ON "artist_alias" ("name");
+
+CREATE TABLE "artist_membership" (
+    "group_id" UUID NOT NULL,
+    "member_id" UUID NOT NULL,
+    "started_at" DATE,
+    "ended_at" DATE,
+    PRIMARY KEY ("group_id", "member_id"),
+    FOREIGN KEY ("group_id") REFERENCES "artist" ("id") ON DELETE CASCADE,
+    FOREIGN KEY ("member_id") REFERENCES "artist" ("id") ON DELETE CASCADE,
+    CHECK ("group_id" <> "member_id"),
+    CHECK ("ended_at" IS NULL OR "started_at" IS NULL OR "started_at" <= "ended_at")
+);
+
+CREATE INDEX "idx_artist_membership_member_id" ON "artist_membership" ("member_id");
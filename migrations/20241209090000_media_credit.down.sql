@@ -0,0 +1,5 @@
+-- Add down migration script here
+
+DROP TABLE "media_credit";
+
+DROP TYPE "credit_role";
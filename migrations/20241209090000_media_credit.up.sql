@@ -0,0 +1,24 @@
+-- Add up migration script here
+
+CREATE TYPE "credit_role" AS ENUM(
+    'Lyricist',
+    'Producer',
+    'FeaturedArtist',
+    'Arranger',
+    'Mixing',
+    'Mastering'
+);
+
+CREATE TABLE "media_credit" (
+    "media_id" UUID NOT NULL,
+    "artist_id" UUID NOT NULL,
+    "role" "credit_role" NOT NULL,
+    "position" SMALLINT NOT NULL DEFAULT 0,
+    PRIMARY KEY ("media_id", "artist_id", "role"),
+    FOREIGN KEY ("media_id") REFERENCES "media" ("id") ON DELETE CASCADE,
+    FOREIGN KEY ("artist_id") REFERENCES "artist" ("id") ON DELETE CASCADE
+);
+
+CREATE INDEX "idx_media_credit_artist_id" ON "media_credit" ("artist_id");
+
+CREATE INDEX "idx_media_credit_role" ON "media_credit" ("role");
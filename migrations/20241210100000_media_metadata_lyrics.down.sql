@@ -0,0 +1,12 @@
+-- Add down migration script here
+
+DROP TABLE "lyrics";
+
+DROP INDEX "idx_media_isrc";
+
+ALTER TABLE "media"
+DROP COLUMN "duration",
+DROP COLUMN "isrc",
+DROP COLUMN "bpm",
+DROP COLUMN "key",
+DROP COLUMN "language";
@@ -0,0 +1,23 @@
+-- Add up migration script here
+
+ALTER TABLE "media"
+ADD COLUMN "duration" INTEGER CHECK ("duration" >= 0),
+ADD COLUMN "isrc" VARCHAR(12),
+ADD COLUMN "bpm" SMALLINT CHECK ("bpm" > 0),
+ADD COLUMN "key" VARCHAR(16),
+ADD COLUMN "language" VARCHAR(8);
+
+CREATE INDEX "idx_media_isrc" ON "media" ("isrc");
+
+CREATE TABLE "lyrics" (
+    "id" UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+    "media_id" UUID NOT NULL,
+    "version" INTEGER NOT NULL,
+    "language" VARCHAR(8),
+    "content" TEXT NOT NULL,
+    "lines" JSONB NOT NULL DEFAULT '[]',
+    "created_at" TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    "updated_at" TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    UNIQUE ("media_id", "version"),
+    FOREIGN KEY ("media_id") REFERENCES "media" ("id") ON DELETE CASCADE
+);
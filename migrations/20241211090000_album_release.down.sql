@@ -0,0 +1,15 @@
+-- Add down migration script here
+
+DROP INDEX IF EXISTS "idx_album_barcode";
+
+DROP INDEX IF EXISTS "idx_album_label";
+
+DROP INDEX IF EXISTS "idx_album_release_group_id";
+
+ALTER TABLE "album"
+DROP COLUMN "release_group_id",
+DROP COLUMN "edition",
+DROP COLUMN "release_country",
+DROP COLUMN "barcode",
+DROP COLUMN "catalog_number",
+DROP COLUMN "label";
@@ -0,0 +1,19 @@
+-- Add up migration script here
+
+ALTER TABLE "album"
+ADD COLUMN "label" VARCHAR(255),
+ADD COLUMN "catalog_number" VARCHAR(64),
+ADD COLUMN "barcode" VARCHAR(13),
+ADD COLUMN "release_country" VARCHAR(2),
+ADD COLUMN "edition" VARCHAR(64),
+ADD COLUMN "release_group_id" UUID;
+
+UPDATE "album" SET "release_group_id" = "id";
+
+ALTER TABLE "album" ALTER COLUMN "release_group_id" SET NOT NULL;
+
+CREATE INDEX "idx_album_release_group_id" ON "album" ("release_group_id");
+
+CREATE INDEX "idx_album_label" ON "album" (LOWER("label"));
+
+CREATE INDEX "idx_album_barcode" ON "album" ("barcode");
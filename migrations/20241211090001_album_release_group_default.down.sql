@@ -0,0 +1,5 @@
+-- Add down migration script here
+
+DROP TRIGGER IF EXISTS "trg_album_release_group_default" ON "album";
+
+DROP FUNCTION IF EXISTS "f_album_release_group_default";
@@ -0,0 +1,35 @@
+-- Add up migration script here
+
+-- Sem grupo informado, o álbum inaugura um novo grupo de lançamento. Uma coluna não pode
+-- usar outra como valor padrão, então o preenchimento é feito por trigger, o que também
+-- permite reaplicar as seeds escritas antes da existência da coluna.
+CREATE OR REPLACE FUNCTION "f_album_release_group_default" () RETURNS TRIGGER
+LANGUAGE plpgsql
+AS $$
+BEGIN
+    NEW."release_group_id" := COALESCE(NEW."release_group_id", NEW."id");
+    RETURN NEW;
+END;
+$$;
+
+CREATE TRIGGER "trg_album_release_group_default"
+BEFORE INSERT ON "album"
+FOR EACH ROW
+EXECUTE FUNCTION "f_album_release_group_default" ();
+
+-- Álbuns registrados em atividades anteriores à coluna passam a inaugurar o próprio grupo,
+-- como os álbuns já gravados.
+UPDATE "activity"
+SET "changes" = jsonb_set("changes", '{Album,Create,release_group_id}', "changes" #> '{Album,Create,id}')
+WHERE "changes" #> '{Album,Create}' IS NOT NULL
+  AND "changes" #> '{Album,Create,release_group_id}' IS NULL;
+
+UPDATE "activity"
+SET "changes" = jsonb_set("changes", '{Album,Delete,release_group_id}', "changes" #> '{Album,Delete,id}')
+WHERE "changes" #> '{Album,Delete}' IS NOT NULL
+  AND "changes" #> '{Album,Delete,release_group_id}' IS NULL;
+
+UPDATE "activity"
+SET "changes" = jsonb_set("changes", '{Album,Update,entity,release_group_id}', "changes" #> '{Album,Update,entity,id}')
+WHERE "changes" #> '{Album,Update,entity}' IS NOT NULL
+  AND "changes" #> '{Album,Update,entity,release_group_id}' IS NULL;
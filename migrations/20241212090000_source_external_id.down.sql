@@ -0,0 +1,5 @@
+-- Add down migration script here
+
+DROP INDEX IF EXISTS "idx_source_media_external_id";
+
+ALTER TABLE "source" DROP COLUMN "external_id";
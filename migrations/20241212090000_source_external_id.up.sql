@@ -0,0 +1,67 @@
+-- Add up migration script here
+
+ALTER TABLE "source" ADD COLUMN "external_id" VARCHAR(255);
+
+-- Extrai o identificador com as mesmas regras de `domain::source::source_url::parse`.
+UPDATE "source"
+SET
+    "external_id" = COALESCE(
+        SUBSTRING("src" FROM '(?i)^(?:https?://)?(?:www\.)?(?:m\.|music\.)?youtube(?:-nocookie)?\.com/watch/?\?(?:[^#]*&)?v=([A-Za-z0-9_-]{11})(?:[&#]|$)'),
+        SUBSTRING("src" FROM '(?i)^(?:https?://)?(?:www\.)?(?:m\.|music\.)?youtube(?:-nocookie)?\.com/(?:embed|shorts|live|v)/([A-Za-z0-9_-]{11})(?:[/?#]|$)'),
+        SUBSTRING("src" FROM '(?i)^(?:https?://)?(?:www\.)?youtu\.be/([A-Za-z0-9_-]{11})(?:[/?#]|$)')
+    )
+WHERE
+    "source_type" = 'Youtube';
+
+UPDATE "source"
+SET
+    "external_id" = LOWER(
+        SUBSTRING("src" FROM '(?i)^(?:https?://)?(?:www\.|m\.)?soundcloud\.com/([^/?#]+/[^/?#]+)/?(?:[?#]|$)')
+    )
+WHERE
+    "source_type" = 'Soundcloud';
+
+-- Segmentos que não identificam uma faixa (playlists, perfis, etc).
+UPDATE "source"
+SET
+    "external_id" = NULL
+WHERE
+    "source_type" = 'Soundcloud'
+    AND (
+        SPLIT_PART("external_id", '/', 1) IN ('sets', 'likes', 'tracks', 'albums', 'reposts', 'followers', 'following', 'discover')
+        OR SPLIT_PART("external_id", '/', 2) IN ('sets', 'likes', 'tracks', 'albums', 'reposts', 'followers', 'following', 'discover')
+    );
+
+-- O tipo Vimeo ainda é gravado como "Video" neste ponto do histórico.
+UPDATE "source"
+SET
+    "external_id" = COALESCE(
+        SUBSTRING("src" FROM '(?i)^(?:https?://)?(?:www\.)?vimeo\.com/(?:[^?#]*/)?([0-9]+)(?:/[^?#]*)?(?:[?#]|$)'),
+        SUBSTRING("src" FROM '(?i)^(?:https?://)?player\.vimeo\.com/video/([0-9]+)(?:[/?#]|$)')
+    )
+WHERE
+    "source_type" IN ('Video', 'Vimeo');
+
+-- Só a fonte mais antiga de cada identificador por media fica com ele; as
+-- duplicadas são mantidas sem identificador, já que nulos não colidem no índice.
+UPDATE "source" AS "duplicate"
+SET
+    "external_id" = NULL
+FROM "source" AS "original"
+WHERE
+    "duplicate"."media_id" = "original"."media_id"
+    AND "duplicate"."source_type" = "original"."source_type"
+    AND "duplicate"."external_id" = "original"."external_id"
+    AND (
+        "duplicate"."created_at",
+        "duplicate"."id"
+    ) > (
+        "original"."created_at",
+        "original"."id"
+    );
+
+CREATE UNIQUE INDEX "idx_source_media_external_id" ON "source" (
+    "media_id",
+    "source_type",
+    "external_id"
+);
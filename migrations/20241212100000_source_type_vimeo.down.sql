@@ -0,0 +1,3 @@
+-- Add down migration script here
+
+UPDATE "source" SET "source_type" = 'Video' WHERE "source_type" = 'Vimeo';
@@ -0,0 +1,3 @@
+-- Add up migration script here
+
+UPDATE "source" SET "source_type" = 'Vimeo' WHERE "source_type" = 'Video';
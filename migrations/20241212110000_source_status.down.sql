@@ -0,0 +1,9 @@
+-- Add down migration script here
+
+DROP INDEX IF EXISTS "idx_source_status";
+
+ALTER TABLE "source"
+DROP COLUMN "duration",
+DROP COLUMN "start_offset",
+DROP COLUMN "last_checked_at",
+DROP COLUMN "status";
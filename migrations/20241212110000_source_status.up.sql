@@ -0,0 +1,9 @@
+-- Add up migration script here
+
+ALTER TABLE "source"
+ADD COLUMN "status" VARCHAR(32) NOT NULL DEFAULT 'Active',
+ADD COLUMN "last_checked_at" TIMESTAMP,
+ADD COLUMN "start_offset" INTEGER CHECK ("start_offset" >= 0),
+ADD COLUMN "duration" INTEGER CHECK ("duration" >= 0);
+
+CREATE INDEX "idx_source_status" ON "source" ("status");
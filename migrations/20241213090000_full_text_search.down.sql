@@ -0,0 +1,29 @@
+-- Add down migration script here
+
+DROP INDEX IF EXISTS "idx_genre_name_trgm";
+
+DROP INDEX IF EXISTS "idx_media_name_trgm";
+
+DROP INDEX IF EXISTS "idx_album_name_trgm";
+
+DROP INDEX IF EXISTS "idx_artist_alias_name_trgm";
+
+DROP INDEX IF EXISTS "idx_artist_name_trgm";
+
+DROP INDEX IF EXISTS "idx_genre_search_vector";
+
+DROP INDEX IF EXISTS "idx_media_search_vector";
+
+DROP INDEX IF EXISTS "idx_album_search_vector";
+
+DROP INDEX IF EXISTS "idx_artist_search_vector";
+
+ALTER TABLE "genre" DROP COLUMN "search_vector";
+
+ALTER TABLE "media" DROP COLUMN "search_vector";
+
+ALTER TABLE "album" DROP COLUMN "search_vector";
+
+ALTER TABLE "artist" DROP COLUMN "search_vector";
+
+DROP FUNCTION IF EXISTS "f_unaccent" (TEXT);
@@ -0,0 +1,53 @@
+-- Add up migration script here
+
+CREATE EXTENSION IF NOT EXISTS "unaccent";
+
+CREATE EXTENSION IF NOT EXISTS "pg_trgm";
+
+-- unaccent() é STABLE; índices e colunas geradas exigem uma função IMMUTABLE.
+CREATE OR REPLACE FUNCTION "f_unaccent" (TEXT) RETURNS TEXT
+LANGUAGE SQL IMMUTABLE PARALLEL SAFE STRICT
+AS $$ SELECT public.unaccent('public.unaccent'::regdictionary, $1) $$;
+
+ALTER TABLE "artist"
+ADD COLUMN "search_vector" TSVECTOR GENERATED ALWAYS AS (
+    setweight(to_tsvector('simple', "f_unaccent"("name")), 'A') ||
+    setweight(to_tsvector('simple', replace("slug", '-', ' ')), 'B')
+) STORED;
+
+ALTER TABLE "album"
+ADD COLUMN "search_vector" TSVECTOR GENERATED ALWAYS AS (
+    setweight(to_tsvector('simple', "f_unaccent"("name")), 'A') ||
+    setweight(to_tsvector('simple', replace("slug", '-', ' ')), 'B') ||
+    setweight(to_tsvector('simple', "f_unaccent"(coalesce("edition", '') || ' ' || coalesce("label", ''))), 'C')
+) STORED;
+
+ALTER TABLE "media"
+ADD COLUMN "search_vector" TSVECTOR GENERATED ALWAYS AS (
+    setweight(to_tsvector('simple', "f_unaccent"("name")), 'A') ||
+    setweight(to_tsvector('simple', replace("slug", '-', ' ')), 'B')
+) STORED;
+
+ALTER TABLE "genre"
+ADD COLUMN "search_vector" TSVECTOR GENERATED ALWAYS AS (
+    setweight(to_tsvector('simple', "f_unaccent"("name")), 'A') ||
+    setweight(to_tsvector('simple', replace("slug", '-', ' ')), 'B')
+) STORED;
+
+CREATE INDEX "idx_artist_search_vector" ON "artist" USING GIN ("search_vector");
+
+CREATE INDEX "idx_album_search_vector" ON "album" USING GIN ("search_vector");
+
+CREATE INDEX "idx_media_search_vector" ON "media" USING GIN ("search_vector");
+
+CREATE INDEX "idx_genre_search_vector" ON "genre" USING GIN ("search_vector");
+
+CREATE INDEX "idx_artist_name_trgm" ON "artist" USING GIN ("f_unaccent"(lower("name")) gin_trgm_ops);
+
+CREATE INDEX "idx_artist_alias_name_trgm" ON "artist_alias" USING GIN ("f_unaccent"(lower("name")) gin_trgm_ops);
+
+CREATE INDEX "idx_album_name_trgm" ON "album" USING GIN ("f_unaccent"(lower("name")) gin_trgm_ops);
+
+CREATE INDEX "idx_media_name_trgm" ON "media" USING GIN ("f_unaccent"(lower("name")) gin_trgm_ops);
+
+CREATE INDEX "idx_genre_name_trgm" ON "genre" USING GIN ("f_unaccent"(lower("name")) gin_trgm_ops);
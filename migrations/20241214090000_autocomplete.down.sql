@@ -0,0 +1,9 @@
+-- Add down migration script here
+
+DROP INDEX IF EXISTS "idx_genre_name_prefix";
+
+DROP INDEX IF EXISTS "idx_media_name_prefix";
+
+DROP INDEX IF EXISTS "idx_album_name_prefix";
+
+DROP INDEX IF EXISTS "idx_artist_name_prefix";
@@ -0,0 +1,10 @@
+-- Add up migration script here
+
+-- Índices para buscas por prefixo (LIKE 'termo%') usadas pelo autocomplete.
+CREATE INDEX "idx_artist_name_prefix" ON "artist" ("f_unaccent"(lower("name")) text_pattern_ops);
+
+CREATE INDEX "idx_album_name_prefix" ON "album" ("f_unaccent"(lower("name")) text_pattern_ops);
+
+CREATE INDEX "idx_media_name_prefix" ON "media" ("f_unaccent"(lower("name")) text_pattern_ops);
+
+CREATE INDEX "idx_genre_name_prefix" ON "genre" ("f_unaccent"(lower("name")) text_pattern_ops);
@@ -0,0 +1,7 @@
+-- Add down migration script here
+
+DROP INDEX IF EXISTS "idx_activity_user_id_created_at_id";
+
+DROP INDEX IF EXISTS "idx_activity_created_at_id";
+
+DROP INDEX IF EXISTS "idx_media_created_at_id";
@@ -0,0 +1,8 @@
+-- Add up migration script here
+
+-- Índices da paginação por cursor, que ordena por ("created_at", "id").
+CREATE INDEX "idx_media_created_at_id" ON "media" ("created_at", "id");
+
+CREATE INDEX "idx_activity_created_at_id" ON "activity" ("created_at", "id");
+
+CREATE INDEX "idx_activity_user_id_created_at_id" ON "activity" ("user_id", "created_at", "id");
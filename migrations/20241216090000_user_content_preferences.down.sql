@@ -0,0 +1,5 @@
+-- Add down migration script here
+
+ALTER TABLE "users"
+DROP COLUMN IF EXISTS "birth_date",
+DROP COLUMN IF EXISTS "max_parental_rating";
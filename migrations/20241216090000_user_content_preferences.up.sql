@@ -0,0 +1,8 @@
+-- Add up migration script here
+
+-- Preferências de conteúdo do usuário: classificação indicativa máxima e data de nascimento.
+ALTER TABLE "users"
+ADD COLUMN "max_parental_rating" SMALLINT NULL CHECK (
+    "max_parental_rating" BETWEEN 0 AND 18
+),
+ADD COLUMN "birth_date" DATE NULL;
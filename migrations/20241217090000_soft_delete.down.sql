@@ -0,0 +1,11 @@
+-- Add down migration script here
+
+ALTER TABLE "source" DROP COLUMN IF EXISTS "deleted_at";
+
+ALTER TABLE "genre" DROP COLUMN IF EXISTS "deleted_at";
+
+ALTER TABLE "media" DROP COLUMN IF EXISTS "deleted_at";
+
+ALTER TABLE "album" DROP COLUMN IF EXISTS "deleted_at";
+
+ALTER TABLE "artist" DROP COLUMN IF EXISTS "deleted_at";
@@ -0,0 +1,13 @@
+-- Add up migration script here
+
+-- Exclusão lógica: as entidades do catálogo deixam de ser apagadas e passam a ser
+-- marcadas com a data de exclusão, podendo ser restauradas depois.
+ALTER TABLE "artist" ADD COLUMN "deleted_at" TIMESTAMP NULL;
+
+ALTER TABLE "album" ADD COLUMN "deleted_at" TIMESTAMP NULL;
+
+ALTER TABLE "media" ADD COLUMN "deleted_at" TIMESTAMP NULL;
+
+ALTER TABLE "genre" ADD COLUMN "deleted_at" TIMESTAMP NULL;
+
+ALTER TABLE "source" ADD COLUMN "deleted_at" TIMESTAMP NULL;
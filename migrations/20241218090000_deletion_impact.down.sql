@@ -0,0 +1,7 @@
+-- Add down migration script here
+
+ALTER TABLE "activity"
+DROP COLUMN IF EXISTS "impact";
+
+ALTER TABLE "users"
+DROP COLUMN IF EXISTS "is_admin";
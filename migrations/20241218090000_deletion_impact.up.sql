@@ -0,0 +1,9 @@
+-- Add up migration script here
+
+-- Administradores podem aprovar exclusões com impacto acima do limite configurado.
+ALTER TABLE "users"
+ADD COLUMN "is_admin" BOOLEAN NOT NULL DEFAULT false;
+
+-- Resumo do que depende da entidade cuja exclusão foi proposta.
+ALTER TABLE "activity"
+ADD COLUMN "impact" JSONB NULL;
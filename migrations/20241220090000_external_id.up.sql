@@ -0,0 +1,15 @@
+-- Add up migration script here
+
+-- Identificadores das entidades do catálogo em bases externas, como o MusicBrainz,
+-- para que uma nova importação atualize a entidade em vez de duplicá-la.
+CREATE TABLE "external_id" (
+  "origin" VARCHAR(32) NOT NULL,
+  "entity_name" VARCHAR(32) NOT NULL,
+  "external_id" VARCHAR(255) NOT NULL,
+  "entity_id" UUID NOT NULL,
+  "created_at" TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  "updated_at" TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  PRIMARY KEY ("origin", "entity_name", "external_id")
+);
+
+CREATE INDEX "external_id_entity_id_idx" ON "external_id" ("entity_id");
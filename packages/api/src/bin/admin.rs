@@ -0,0 +1,718 @@
+use api::{
+  di, domain,
+  domain::{source::repository::SourceRepository, user::UserRepository},
+  infra::{self, actix::errors::ErrorResponse},
+  shared::{self, vo::UUID4},
+  AppState,
+};
+
+const USAGE: &str = "Uso: admin <comando>
+
+Comandos:
+  user create <username> <email> [display_name]
+  user promote <username> [--admin]
+  activity approve --as <curator> <activity_id>...
+  activity reject --as <curator> --reason <reason> <activity_id>...
+  import <file> --as <user> [--format jsonl|csv] [--dry-run | --apply]
+  musicbrainz import <dump dir> --as <user> [--dry-run | --apply]
+  export --as <user> [--types <Genre,Artist,...>] [--since <YYYY-MM-DDTHH:MM:SS>] [--output <file>]
+  sources check [<source_id>... | --limit N]
+  search reindex
+  migrate [--down N]
+  seed
+
+A senha de `user create` é lida da variável ADMIN_USER_PASSWORD, de um prompt
+sem eco quando o terminal é interativo, ou da primeira linha da entrada padrão.";
+
+/**
+ * Tarefas operacionais executadas pela linha de comando, sempre pelas mesmas
+ * histórias usadas pela API.
+ */
+enum Command {
+  CreateUser {
+    username: String,
+    email: String,
+    display_name: Option<String>,
+  },
+  Promote(domain::user::stories::promote::Input),
+  Approve {
+    curator: String,
+    activity_ids: Vec<UUID4>,
+  },
+  Reject {
+    curator: String,
+    reason: String,
+    activity_ids: Vec<UUID4>,
+  },
+  Import {
+    path: String,
+    user: String,
+    format: infra::catalog::Format,
+    mode: domain::catalog::stories::import::Mode,
+  },
+  ImportMusicBrainz {
+    path: String,
+    user: String,
+    mode: domain::catalog::stories::import::Mode,
+  },
+  Export {
+    user: String,
+    entity_names: Vec<shared::vo::CollaborativeEntityName>,
+    since: Option<chrono::NaiveDateTime>,
+    output: Option<String>,
+  },
+  CheckSources {
+    source_ids: Vec<UUID4>,
+    limit: usize,
+  },
+  Reindex,
+  Migrate,
+  MigrateDown(usize),
+  Seed,
+}
+
+fn parse_ids(ids: &[&str]) -> Option<Vec<UUID4>> {
+  if ids.is_empty() {
+    return None;
+  }
+
+  ids.iter().map(|id| UUID4::new(*id).ok()).collect()
+}
+
+/**
+ * Lê as opções do comando de importação. Sem `--format`, o formato é deduzido da
+ * extensão do arquivo; sem `--dry-run` ou `--apply`, as atividades ficam pendentes.
+ */
+fn parse_import(path: &str, user: &str, mut flags: &[&str]) -> Option<Command> {
+  let mut format = infra::catalog::Format::from_path(path);
+  let mut mode = domain::catalog::stories::import::Mode::Propose;
+
+  loop {
+    flags = match flags {
+      [] => break,
+      ["--format", value, rest @ ..] => {
+        format = Some(value.parse().ok()?);
+        rest
+      }
+      ["--dry-run", rest @ ..] => {
+        mode = domain::catalog::stories::import::Mode::DryRun;
+        rest
+      }
+      ["--apply", rest @ ..] => {
+        mode = domain::catalog::stories::import::Mode::Apply;
+        rest
+      }
+      _ => return None,
+    };
+  }
+
+  Some(Command::Import {
+    path: path.to_string(),
+    user: user.to_string(),
+    format: format?,
+    mode,
+  })
+}
+
+/**
+ * Lê as opções da importação de um dump do MusicBrainz, uma pasta com os arquivos
+ * `artist`, `release-group`, `release`, `recording` e `work`.
+ */
+fn parse_musicbrainz_import(path: &str, user: &str, mut flags: &[&str]) -> Option<Command> {
+  let mut mode = domain::catalog::stories::import::Mode::Propose;
+
+  loop {
+    flags = match flags {
+      [] => break,
+      ["--dry-run", rest @ ..] => {
+        mode = domain::catalog::stories::import::Mode::DryRun;
+        rest
+      }
+      ["--apply", rest @ ..] => {
+        mode = domain::catalog::stories::import::Mode::Apply;
+        rest
+      }
+      _ => return None,
+    };
+  }
+
+  Some(Command::ImportMusicBrainz {
+    path: path.to_string(),
+    user: user.to_string(),
+    mode,
+  })
+}
+
+/**
+ * Lê as opções do comando de exportação. Sem `--output`, os registros são
+ * escritos na saída padrão.
+ */
+fn parse_export(user: &str, mut flags: &[&str]) -> Option<Command> {
+  let mut entity_names = Vec::new();
+  let mut since = None;
+  let mut output = None;
+
+  loop {
+    flags = match flags {
+      [] => break,
+      ["--types", value, rest @ ..] => {
+        entity_names = value
+          .split(',')
+          .map(|name| name.trim().parse().ok())
+          .collect::<Option<_>>()?;
+        rest
+      }
+      ["--since", value, rest @ ..] => {
+        since =
+          Some(chrono::NaiveDateTime::parse_from_str(value, infra::catalog::SINCE_FORMAT).ok()?);
+        rest
+      }
+      ["--output", value, rest @ ..] => {
+        output = Some(value.to_string());
+        rest
+      }
+      _ => return None,
+    };
+  }
+
+  Some(Command::Export {
+    user: user.to_string(),
+    entity_names,
+    since,
+    output,
+  })
+}
+
+/**
+ * Quantidade de fontes verificadas quando nenhum identificador é informado.
+ */
+const DEFAULT_CHECK_LIMIT: usize = 100;
+
+/**
+ * Sem identificadores, verifica as fontes que estão há mais tempo sem verificação.
+ */
+fn parse_sources_check(args: &[&str]) -> Option<Command> {
+  match args {
+    [] => Some(Command::CheckSources {
+      source_ids: Vec::new(),
+      limit: DEFAULT_CHECK_LIMIT,
+    }),
+    ["--limit", limit] => match limit.parse::<usize>() {
+      Ok(limit) if limit > 0 => Some(Command::CheckSources {
+        source_ids: Vec::new(),
+        limit,
+      }),
+      _ => None,
+    },
+    ids => Some(Command::CheckSources {
+      source_ids: parse_ids(ids)?,
+      limit: ids.len(),
+    }),
+  }
+}
+
+const PASSWORD_VAR: &str = "ADMIN_USER_PASSWORD";
+
+/**
+ * Lê a senha sem expô-la nos argumentos do processo, que ficam visíveis no
+ * histórico do shell e na lista de processos.
+ */
+fn read_password() -> Result<String, String> {
+  use std::io::{BufRead, IsTerminal};
+
+  let password = match std::env::var(PASSWORD_VAR) {
+    Ok(password) => password,
+    Err(_) if std::io::stdin().is_terminal() => {
+      rpassword::prompt_password("Password: ").map_err(|error| error.to_string())?
+    }
+    Err(_) => {
+      let mut line = String::new();
+      std::io::stdin()
+        .lock()
+        .read_line(&mut line)
+        .map_err(|error| error.to_string())?;
+      line.trim_end_matches(['\r', '\n']).to_string()
+    }
+  };
+
+  if password.is_empty() {
+    return Err(String::from("The password must not be empty"));
+  }
+
+  Ok(password)
+}
+
+fn parse(args: &[&str]) -> Option<Command> {
+  match args {
+    ["user", "create", username, email, display_name @ ..] if display_name.len() <= 1 => {
+      Some(Command::CreateUser {
+        username: username.to_string(),
+        email: email.to_string(),
+        display_name: display_name.first().map(|name| name.to_string()),
+      })
+    }
+    ["user", "promote", username, flags @ ..] if matches!(flags, [] | ["--admin"]) => {
+      Some(Command::Promote(domain::user::stories::promote::Input {
+        username: username.to_string(),
+        is_admin: !flags.is_empty(),
+      }))
+    }
+    ["activity", "approve", "--as", curator, ids @ ..] => Some(Command::Approve {
+      curator: curator.to_string(),
+      activity_ids: parse_ids(ids)?,
+    }),
+    ["activity", "reject", "--as", curator, "--reason", reason, ids @ ..] => {
+      Some(Command::Reject {
+        curator: curator.to_string(),
+        reason: reason.to_string(),
+        activity_ids: parse_ids(ids)?,
+      })
+    }
+    ["import", path, "--as", user, flags @ ..] => parse_import(path, user, flags),
+    ["musicbrainz", "import", path, "--as", user, flags @ ..] => {
+      parse_musicbrainz_import(path, user, flags)
+    }
+    ["export", "--as", user, flags @ ..] => parse_export(user, flags),
+    ["sources", "check", args @ ..] => parse_sources_check(args),
+    ["search", "reindex"] => Some(Command::Reindex),
+    ["migrate"] => Some(Command::Migrate),
+    ["migrate", "--down", steps] => match steps.parse::<usize>() {
+      Ok(steps) if steps > 0 => Some(Command::MigrateDown(steps)),
+      _ => None,
+    },
+    ["seed"] => Some(Command::Seed),
+    _ => None,
+  }
+}
+
+async fn find_curator(app_state: &AppState, username: &str) -> Result<domain::user::User, String> {
+  let mut user_repository = di::user::repositories::UserRepository::new(app_state);
+
+  match user_repository.find_by_username(username).await {
+    Ok(Some(user)) => Ok(user),
+    Ok(None) => Err(format!("User {} not found", username)),
+    Err(error) => Err(format!("Failed to find user {}: {:?}", username, error)),
+  }
+}
+
+/**
+ * Executa a história para cada atividade, sem interromper o lote na primeira
+ * falha, e informa se todas foram processadas.
+ */
+async fn for_each_activity<F, Fut, E>(activity_ids: Vec<UUID4>, mut execute: F) -> bool
+where
+  F: FnMut(UUID4) -> Fut,
+  Fut: std::future::Future<Output = Result<domain::activity::Activity, E>>,
+  E: Into<ErrorResponse>,
+{
+  let mut succeeded = true;
+
+  for activity_id in activity_ids {
+    match execute(activity_id.clone()).await {
+      Ok(_) => println!("✅ {}", activity_id),
+      Err(error) => {
+        succeeded = false;
+        eprintln!("🔥 {}: {}", activity_id, error.into());
+      }
+    }
+  }
+
+  succeeded
+}
+
+fn print_report(path: &str, report: &domain::catalog::stories::import::Report) {
+  use domain::catalog::stories::import::Outcome;
+
+  for entry in &report.entries {
+    let activity = entry
+      .activity_id
+      .as_ref()
+      .map(|id| format!(" (activity {})", id))
+      .unwrap_or_default();
+
+    match &entry.outcome {
+      Outcome::Create => println!("➕ {} {}{}", entry.entity, entry.key, activity),
+      Outcome::Update(fields) => println!(
+        "✏️  {} {} [{}]{}",
+        entry.entity,
+        entry.key,
+        fields.join(", "),
+        activity
+      ),
+      Outcome::Unchanged => println!("➖ {} {}", entry.entity, entry.key),
+      Outcome::Delete => println!("🗑️  {} {}{}", entry.entity, entry.key, activity),
+      Outcome::Failed(error) => eprintln!(
+        "🔥 {}:{}: {} {}: {}",
+        path,
+        entry.line,
+        entry.entity,
+        entry.key,
+        ErrorResponse::from(error.clone())
+      ),
+    }
+  }
+
+  println!(
+    "{} created, {} updated, {} unchanged, {} deleted, {} failed",
+    report.created(),
+    report.updated(),
+    report.unchanged(),
+    report.deleted(),
+    report.failed()
+  );
+}
+
+async fn import(
+  app_state: &AppState,
+  path: &str,
+  user: &str,
+  mode: domain::catalog::stories::import::Mode,
+  records: Vec<(usize, domain::catalog::ImportRecord)>,
+  origin: Option<String>,
+) -> Result<(), String> {
+  let actor = find_curator(app_state, user).await?;
+
+  let report = domain::catalog::stories::import::execute(
+    &mut di::genre::repositories::GenreRepository::new(app_state),
+    &mut di::artist::repositories::ArtistRepository::new(app_state),
+    &mut di::album::repositories::AlbumRepository::new(app_state),
+    &mut di::media::repositories::MediaRepository::new(app_state),
+    &mut di::source::repositories::SourceRepository::new(app_state),
+    &mut di::lyrics::repositories::LyricsRepository::new(app_state),
+    &mut di::activity::repositories::ActivityRepository::new(app_state),
+    &mut di::catalog::repositories::CatalogRepository::new(app_state),
+    domain::catalog::stories::import::Input {
+      actor,
+      mode,
+      records,
+      deletion_impact_threshold: app_state.deletion_impact_threshold,
+      origin,
+    },
+  )
+  .await
+  .map_err(|error| ErrorResponse::from(error).to_string())?;
+
+  print_report(path, &report);
+
+  if report.failed() > 0 {
+    return Err(format!("{} records were not imported", report.failed()));
+  }
+
+  Ok(())
+}
+
+async fn run(app_state: &AppState, command: Command) -> Result<(), String> {
+  match command {
+    Command::CreateUser {
+      username,
+      email,
+      display_name,
+    } => {
+      let mut user_repository = di::user::repositories::UserRepository::new(app_state);
+      let password_hash = infra::bcrypt::BcryptPasswordHash;
+
+      let input = domain::user::stories::user_register::Input {
+        username,
+        email,
+        password: read_password()?,
+        display_name,
+      };
+
+      let user =
+        domain::user::stories::user_register::execute(&mut user_repository, &password_hash, input)
+          .await
+          .map_err(|error| ErrorResponse::from(error).to_string())?;
+
+      println!("✅ User {} created with id {}", user.username(), user.id());
+    }
+
+    Command::Promote(input) => {
+      let mut user_repository = di::user::repositories::UserRepository::new(app_state);
+
+      let user = domain::user::stories::promote::execute(&mut user_repository, input)
+        .await
+        .map_err(|error| ErrorResponse::from(error).to_string())?;
+
+      println!(
+        "✅ User {} is now a curator{}",
+        user.username(),
+        if *user.is_admin() {
+          " and an admin"
+        } else {
+          ""
+        }
+      );
+    }
+
+    Command::Approve {
+      curator,
+      activity_ids,
+    } => {
+      let actor = find_curator(app_state, &curator).await?;
+
+      let succeeded = for_each_activity(activity_ids, |activity_id| {
+        let actor = actor.clone();
+
+        async move {
+          domain::activity::stories::approve::execute(
+            &mut di::activity::repositories::ActivityRepository::new(app_state),
+            &mut di::genre::repositories::GenreRepository::new(app_state),
+            &mut di::artist::repositories::ArtistRepository::new(app_state),
+            &mut di::album::repositories::AlbumRepository::new(app_state),
+            &mut di::media::repositories::MediaRepository::new(app_state),
+            &mut di::source::repositories::SourceRepository::new(app_state),
+            &mut di::lyrics::repositories::LyricsRepository::new(app_state),
+            domain::activity::stories::approve::Input {
+              activity_id,
+              actor,
+              deletion_impact_threshold: app_state.deletion_impact_threshold,
+            },
+          )
+          .await
+        }
+      })
+      .await;
+
+      if !succeeded {
+        return Err(String::from("Some activities were not approved"));
+      }
+    }
+
+    Command::Reject {
+      curator,
+      reason,
+      activity_ids,
+    } => {
+      let user = find_curator(app_state, &curator).await?;
+
+      let succeeded = for_each_activity(activity_ids, |activity_id| {
+        let input = domain::activity::stories::reject::Input {
+          activity_id,
+          reason: reason.clone(),
+          user: user.clone(),
+        };
+
+        async move {
+          domain::activity::stories::reject::execute(
+            &mut di::activity::repositories::ActivityRepository::new(app_state),
+            input,
+          )
+          .await
+        }
+      })
+      .await;
+
+      if !succeeded {
+        return Err(String::from("Some activities were not rejected"));
+      }
+    }
+
+    Command::Import {
+      path,
+      user,
+      format,
+      mode,
+    } => {
+      let content = std::fs::read_to_string(&path)
+        .map_err(|error| format!("Failed to read {}: {}", path, error))?;
+
+      let records = infra::catalog::parse(format, &content).map_err(|errors| {
+        errors
+          .into_iter()
+          .map(|(line, error)| format!("{}:{}: {}", path, line, error))
+          .collect::<Vec<_>>()
+          .join("\n")
+      })?;
+
+      import(app_state, &path, &user, mode, records, None).await?;
+    }
+
+    Command::ImportMusicBrainz { path, user, mode } => {
+      let dump = infra::catalog::musicbrainz::Dump::read_dir(std::path::Path::new(&path))
+        .map_err(|errors| errors.join("\n"))?;
+
+      // Os registros não vêm de um único arquivo; o relatório os numera na ordem gerada.
+      let records = dump
+        .records()
+        .into_iter()
+        .enumerate()
+        .map(|(index, record)| (index + 1, record))
+        .collect();
+
+      import(
+        app_state,
+        &path,
+        &user,
+        mode,
+        records,
+        Some(String::from(infra::catalog::musicbrainz::ORIGIN)),
+      )
+      .await?;
+    }
+
+    Command::Export {
+      user,
+      entity_names,
+      since,
+      output,
+    } => {
+      use std::io::Write;
+
+      let actor = find_curator(app_state, &user).await?;
+      let started_at = shared::util::naive_now();
+
+      let mut export =
+        domain::catalog::stories::export::execute(domain::catalog::stories::export::Input {
+          actor,
+          entity_names,
+          since,
+          batch_size: infra::catalog::EXPORT_BATCH_SIZE,
+        })
+        .map_err(|error| ErrorResponse::from(error).to_string())?;
+
+      let mut writer: Box<dyn Write> = match &output {
+        Some(path) => Box::new(std::io::BufWriter::new(
+          std::fs::File::create(path)
+            .map_err(|error| format!("Failed to create {}: {}", path, error))?,
+        )),
+        None => Box::new(std::io::BufWriter::new(std::io::stdout().lock())),
+      };
+
+      let mut catalog_repository = di::catalog::repositories::CatalogRepository::new(app_state);
+      let mut exported = 0;
+
+      while let Some(records) = export
+        .next_batch(&mut catalog_repository)
+        .await
+        .map_err(|error| ErrorResponse::from(error).to_string())?
+      {
+        exported += records.len();
+
+        writer
+          .write_all(infra::catalog::to_json_lines(&records).as_bytes())
+          .and_then(|_| writer.flush())
+          .map_err(|error| format!("Failed to write the export: {}", error))?;
+      }
+
+      eprintln!(
+        "✅ {} records exported. Use --since {} for the next incremental export",
+        exported,
+        started_at.format(infra::catalog::SINCE_FORMAT)
+      );
+    }
+
+    Command::CheckSources { source_ids, limit } => {
+      let mut source_repository = di::source::repositories::SourceRepository::new(app_state);
+      let mut source_checker = di::source::checkers::SourceChecker::new().map_err(|error| {
+        ErrorResponse::from(domain::source::stories::check::Error::CheckerError(error)).to_string()
+      })?;
+
+      let source_ids = if source_ids.is_empty() {
+        source_repository
+          .find_least_recently_checked(limit)
+          .await
+          .map_err(|error| ErrorResponse::from(error).to_string())?
+          .into_iter()
+          .map(|source| source.id().clone())
+          .collect()
+      } else {
+        source_ids
+      };
+
+      let mut failed = 0;
+
+      for source_id in source_ids {
+        match domain::source::stories::check::execute(
+          &mut source_repository,
+          &mut source_checker,
+          &source_id,
+        )
+        .await
+        {
+          Ok(source) => println!("✅ {} {}", source_id, source.status()),
+          Err(error) => {
+            failed += 1;
+            eprintln!("🔥 {}: {}", source_id, ErrorResponse::from(error));
+          }
+        }
+      }
+
+      if failed > 0 {
+        return Err(format!("{} sources were not checked", failed));
+      }
+    }
+
+    Command::Reindex => {
+      let mut search_repository = di::search::repositories::SearchRepository::new(app_state);
+
+      domain::search::stories::reindex::execute(&mut search_repository)
+        .await
+        .map_err(|error| ErrorResponse::from(error).to_string())?;
+
+      println!("✅ Search indexes rebuilt");
+    }
+
+    Command::Migrate => {
+      infra::sqlx::migrations::run(&app_state.db)
+        .await
+        .map_err(|error| format!("Failed to apply migrations: {}", error))?;
+
+      println!("✅ Migrations applied successfully!");
+    }
+
+    Command::MigrateDown(steps) => {
+      let versions = infra::sqlx::migrations::undo(&app_state.db, steps)
+        .await
+        .map_err(|error| format!("Failed to revert migrations: {}", error))?;
+
+      for version in versions {
+        println!("↩️  Reverted migration {}", version);
+      }
+    }
+
+    Command::Seed => {
+      let output = domain::catalog::stories::seed::execute(
+        &mut di::user::repositories::UserRepository::new(app_state),
+        &mut di::genre::repositories::GenreRepository::new(app_state),
+        &mut di::artist::repositories::ArtistRepository::new(app_state),
+        &mut di::album::repositories::AlbumRepository::new(app_state),
+        &mut di::media::repositories::MediaRepository::new(app_state),
+        &mut di::source::repositories::SourceRepository::new(app_state),
+        domain::catalog::stories::seed::Seed::initial(),
+      )
+      .await
+      .map_err(|error| format!("Failed to seed: {:?}", error))?;
+
+      println!(
+        "🌱 {} created, {} restored, {} unchanged",
+        output.created, output.restored, output.unchanged
+      );
+    }
+  }
+
+  Ok(())
+}
+
+#[tokio::main]
+async fn main() {
+  // Load .env file
+  dotenvy::dotenv().ok();
+
+  // Initialize logger
+  env_logger::init();
+
+  let args = std::env::args().skip(1).collect::<Vec<_>>();
+  let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+
+  let Some(command) = parse(&args) else {
+    eprintln!("{}", USAGE);
+    std::process::exit(1);
+  };
+
+  let app_state = AppState::default().await;
+
+  if let Err(error) = run(&app_state, command).await {
+    eprintln!("🔥 {}", error);
+    std::process::exit(1);
+  }
+}
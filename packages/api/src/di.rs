@@ -34,8 +34,30 @@ pub mod media {
   }
 }
 
+pub mod lyrics {
+  pub mod repositories {
+    pub use crate::infra::sqlx::SqlxLyricsRepository as LyricsRepository;
+  }
+}
+
 pub mod source {
   pub mod repositories {
     pub use crate::infra::sqlx::SqlxSourceRepository as SourceRepository;
   }
+
+  pub mod checkers {
+    pub use crate::infra::http::HttpSourceChecker as SourceChecker;
+  }
+}
+
+pub mod search {
+  pub mod repositories {
+    pub use crate::infra::sqlx::SqlxSearchRepository as SearchRepository;
+  }
+}
+
+pub mod catalog {
+  pub mod repositories {
+    pub use crate::infra::sqlx::SqlxCatalogRepository as CatalogRepository;
+  }
 }
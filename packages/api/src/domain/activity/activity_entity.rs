@@ -25,6 +25,11 @@ pub struct Activity {
   revision_date: Option<chrono::NaiveDateTime>,
   contribution: shared::vo::Contribution,
 
+  /**
+   * Impacto da exclusão proposta, calculado na criação e atualizado na revisão.
+   */
+  impact: Option<super::DeletionImpact>,
+
   created_at: chrono::NaiveDateTime,
   updated_at: chrono::NaiveDateTime,
 }
@@ -59,6 +64,7 @@ impl Default for Activity {
       curator_id: None,
       contribution: crate::shared::vo::Contribution::default(),
       revision_date: None,
+      impact: None,
       created_at: now,
       updated_at: now,
     }
@@ -0,0 +1,48 @@
+/**
+ * Resumo do que depende de uma entidade cuja exclusão foi proposta, para que
+ * os curadores saibam o que deixará de ser exibido caso ela seja aprovada.
+ */
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DeletionImpact {
+  /**
+   * Medias que referenciam a entidade.
+   */
+  pub medias: u64,
+
+  /**
+   * Álbuns que referenciam a entidade.
+   */
+  pub albums: u64,
+
+  /**
+   * Fontes das medias afetadas.
+   */
+  pub sources: u64,
+
+  /**
+   * Subgêneros, diretos ou indiretos, do gênero excluído.
+   */
+  pub subgenres: u64,
+
+  /**
+   * Quantidade de registros de relacionamento por tabela.
+   */
+  pub join_rows: std::collections::BTreeMap<String, u64>,
+}
+
+impl DeletionImpact {
+  /**
+   * Quantidade de itens dependentes. Os registros de relacionamento não entram
+   * na conta, pois apenas ligam os itens já contados à entidade.
+   */
+  pub fn total(&self) -> u64 {
+    self.medias + self.albums + self.sources + self.subgenres
+  }
+
+  /**
+   * Indica se a exclusão precisa ser aprovada por um administrador.
+   */
+  pub fn requires_elevated_approval(&self, threshold: u64) -> bool {
+    self.total() > threshold
+  }
+}
@@ -1,7 +1,9 @@
 mod activity_entity;
+mod impact;
 
 pub mod error;
 pub mod repository;
 pub mod stories;
 
 pub use activity_entity::*;
+pub use impact::*;
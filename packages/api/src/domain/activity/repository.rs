@@ -1,6 +1,9 @@
 use std::future::Future;
 
-use crate::shared::vo::UUID4;
+use crate::shared::{
+  paged::{CursorPageParams, CursorPaged},
+  vo::UUID4,
+};
 
 use super::Activity;
 
@@ -10,6 +13,11 @@ pub enum Error {
   EntityNotFound,
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct FindAllQuery {
+  pub user_id: Option<UUID4>,
+}
+
 pub trait ActivityRepository {
   /**
    * Cria uma nova atividade de contribuição.
@@ -25,4 +33,13 @@ pub trait ActivityRepository {
    * Atualiza uma atividade de contribuição.
    */
   fn update(&mut self, activity: &Activity) -> impl Future<Output = Result<Activity, Error>>;
+
+  /**
+   * Busca atividades de contribuição com paginação por cursor, da mais recente para a mais antiga.
+   */
+  fn find_by_cursor(
+    &self,
+    query: &FindAllQuery,
+    page: &CursorPageParams,
+  ) -> impl Future<Output = Result<CursorPaged<Activity>, Error>>;
 }
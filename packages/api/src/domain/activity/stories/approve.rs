@@ -2,7 +2,11 @@
 pub enum Error {
   UserIsNotACurator,
   ActivityNotFound,
+  ElevatedApprovalRequired(crate::domain::activity::DeletionImpact),
   RepositoryError(crate::domain::activity::repository::Error),
+  GenreRepositoryError(crate::domain::genre::Error),
+  ArtistRepositoryError(crate::domain::artist::repository::Error),
+  AlbumRepositoryError(crate::domain::album::repository::Error),
 
   ActivityError(crate::domain::activity::Error),
 
@@ -11,14 +15,55 @@ pub enum Error {
   AlbumApplyError(crate::domain::album::stories::apply_changes::Error),
   MediaApplyError(crate::domain::media::stories::apply_changes::Error),
   SourceApplyError(crate::domain::source::stories::apply_changes::Error),
+  LyricsApplyError(crate::domain::lyrics::stories::apply_changes::Error),
 }
 
 #[derive(Debug, Clone)]
 pub struct Input {
   pub activity_id: crate::shared::vo::UUID4,
   pub actor: crate::domain::user::User,
+
+  /**
+   * Quantidade de itens dependentes a partir da qual uma exclusão só pode ser aprovada por um administrador.
+   */
+  pub deletion_impact_threshold: u64,
+}
+
+/**
+ * Recalcula o impacto das exclusões, já que o catálogo pode ter mudado desde a criação da atividade.
+ */
+async fn deletion_impact(
+  genre_repository: &mut impl crate::domain::genre::GenreRepository,
+  artist_repository: &mut impl crate::domain::artist::repository::ArtistRepository,
+  album_repository: &mut impl crate::domain::album::repository::AlbumRepository,
+  contribution: &crate::shared::vo::Contribution,
+) -> Result<Option<crate::domain::activity::DeletionImpact>, Error> {
+  let impact = match contribution {
+    crate::shared::vo::Contribution::Genre(
+      crate::domain::genre::contribution::Contribution::Delete(genre),
+    ) => genre_repository
+      .deletion_impact(genre.id())
+      .await
+      .map_err(Error::GenreRepositoryError)?,
+    crate::shared::vo::Contribution::Artist(
+      crate::domain::artist::contribution::Contribution::Delete(artist),
+    ) => artist_repository
+      .deletion_impact(artist.id())
+      .await
+      .map_err(Error::ArtistRepositoryError)?,
+    crate::shared::vo::Contribution::Album(
+      crate::domain::album::contribution::Contribution::Delete(album),
+    ) => album_repository
+      .deletion_impact(album.id())
+      .await
+      .map_err(Error::AlbumRepositoryError)?,
+    _ => return Ok(None),
+  };
+
+  Ok(Some(impact))
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn execute(
   activity_repository: &mut impl crate::domain::activity::repository::ActivityRepository,
   genre_repository: &mut impl crate::domain::genre::GenreRepository,
@@ -26,6 +71,7 @@ pub async fn execute(
   album_repository: &mut impl crate::domain::album::repository::AlbumRepository,
   media_repository: &mut impl crate::domain::media::repository::MediaRepository,
   source_repository: &mut impl crate::domain::source::repository::SourceRepository,
+  lyrics_repository: &mut impl crate::domain::lyrics::repository::LyricsRepository,
   input: Input,
 ) -> Result<crate::domain::activity::Activity, Error> {
   if !input.actor.is_curator() {
@@ -38,6 +84,31 @@ pub async fn execute(
     .map_err(Error::RepositoryError)?;
 
   if let Some(mut activity) = activity {
+    if *activity.status() == crate::domain::activity::ActivityStatus::Pending {
+      let impact = deletion_impact(
+        genre_repository,
+        artist_repository,
+        album_repository,
+        activity.contribution(),
+      )
+      .await?;
+
+      if let Some(impact) = impact {
+        let requires_elevated_approval =
+          impact.requires_elevated_approval(input.deletion_impact_threshold);
+        activity.set_impact(Some(impact.clone()));
+
+        if requires_elevated_approval && !input.actor.is_admin() {
+          activity_repository
+            .update(&activity)
+            .await
+            .map_err(Error::RepositoryError)?;
+
+          return Err(Error::ElevatedApprovalRequired(impact));
+        }
+      }
+    }
+
     activity = activity
       .set_curator_status(
         crate::domain::activity::ActivityStatus::Approved,
@@ -87,9 +158,160 @@ pub async fn execute(
         .await
         .map_err(Error::SourceApplyError)?;
       }
+      crate::shared::vo::Contribution::Lyrics(contribution) => {
+        crate::domain::lyrics::stories::apply_changes::execute(
+          lyrics_repository,
+          contribution.clone(),
+        )
+        .await
+        .map_err(Error::LyricsApplyError)?;
+      }
     }
 
+    activity_repository
+      .update(&activity)
+      .await
+      .map_err(Error::RepositoryError)?;
+
     return Ok(activity);
   }
   Err(Error::ActivityNotFound)
 }
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    domain::{
+      activity::{repository::ActivityRepository, Activity, ActivityStatus},
+      artist::{contribution::Contribution, repository::ArtistRepository, Artist},
+      media::{Media, MediaType},
+      user::User,
+    },
+    infra::in_memory::{
+      InMemoryActivityRepository, InMemoryAlbumRepository, InMemoryArtistRepository,
+      InMemoryGenreRepository, InMemoryLyricsRepository, InMemoryMediaRepository,
+      InMemorySourceRepository,
+    },
+    shared::vo::{Slug, UUID4},
+  };
+
+  async fn approve(
+    activity_repository: &mut InMemoryActivityRepository,
+    artist_repository: &mut InMemoryArtistRepository,
+    activity: &Activity,
+    actor: User,
+  ) -> Result<Activity, super::Error> {
+    super::execute(
+      activity_repository,
+      &mut InMemoryGenreRepository::default(),
+      artist_repository,
+      &mut InMemoryAlbumRepository::default(),
+      &mut InMemoryMediaRepository::default(),
+      &mut InMemorySourceRepository::default(),
+      &mut InMemoryLyricsRepository::default(),
+      super::Input {
+        activity_id: activity.id().clone(),
+        actor,
+        deletion_impact_threshold: 1,
+      },
+    )
+    .await
+  }
+
+  #[tokio::test]
+  async fn test_elevated_approval_above_threshold() {
+    let mut activity_repository = InMemoryActivityRepository::default();
+    let mut artist_repository = InMemoryArtistRepository::default();
+
+    let artist = Artist::builder()
+      .name(String::from("Teste"))
+      .slug(Slug::new("teste").unwrap())
+      .build();
+    artist_repository.create(&artist).await.unwrap();
+
+    for name in ["primeira", "segunda"] {
+      let media = Media::builder()
+        .name(String::from(name))
+        .slug(Slug::new(name).unwrap())
+        .media_type(MediaType::Song)
+        .interpreter_ids([artist.id().clone()].into())
+        .build();
+      artist_repository
+        .medias
+        .insert(media.id().to_string(), media);
+    }
+
+    let activity = Activity::builder()
+      .user_id(UUID4::generate())
+      .contribution(crate::shared::vo::Contribution::Artist(
+        Contribution::Delete(artist.clone()),
+      ))
+      .status(ActivityStatus::Pending)
+      .build();
+    activity_repository.create(&activity).await.unwrap();
+
+    let curator = User::builder()
+      .username(String::from("curator"))
+      .is_curator(true)
+      .build();
+
+    let result = approve(
+      &mut activity_repository,
+      &mut artist_repository,
+      &activity,
+      curator,
+    )
+    .await;
+
+    match result {
+      Err(super::Error::ElevatedApprovalRequired(impact)) => {
+        assert_eq!(impact.medias, 2, "O impacto deveria contar as medias");
+      }
+      other => panic!("Esperava aprovação elevada, obteve {:?}", other),
+    }
+
+    let pending = activity_repository
+      .find_by_id(activity.id())
+      .await
+      .unwrap()
+      .unwrap();
+    assert_eq!(
+      *pending.status(),
+      ActivityStatus::Pending,
+      "A atividade deveria continuar pendente"
+    );
+    assert!(
+      artist_repository
+        .artists
+        .contains_key(&artist.id().to_string()),
+      "O artista não deveria ser excluído"
+    );
+
+    let admin = User::builder()
+      .username(String::from("admin"))
+      .is_curator(true)
+      .is_admin(true)
+      .build();
+
+    let approved = approve(
+      &mut activity_repository,
+      &mut artist_repository,
+      &activity,
+      admin,
+    )
+    .await
+    .expect("Um administrador deveria aprovar a exclusão");
+
+    assert_eq!(
+      *approved.status(),
+      ActivityStatus::Approved,
+      "A atividade deveria ser aprovada"
+    );
+    assert!(
+      !artist_repository
+        .artists
+        .contains_key(&artist.id().to_string()),
+      "O artista deveria ser excluído"
+    );
+  }
+}
@@ -0,0 +1,129 @@
+use crate::shared::{
+  paged::{CursorPageParams, CursorPaged},
+  vo::UUID4,
+};
+
+pub struct Input {
+  pub actor: crate::domain::user::User,
+  pub page: CursorPageParams,
+  pub user_id: Option<UUID4>,
+}
+
+pub enum Error {
+  RepositoryError(crate::domain::activity::repository::Error),
+}
+
+/**
+ * Lista as atividades. Curadores veem as de todos os usuários; os demais, apenas
+ * as próprias, independentemente do `user_id` informado.
+ */
+pub async fn execute(
+  activity_repository: &impl crate::domain::activity::repository::ActivityRepository,
+  input: Input,
+) -> Result<CursorPaged<crate::domain::activity::Activity>, Error> {
+  let user_id = if *input.actor.is_curator() {
+    input.user_id
+  } else {
+    Some(input.actor.id().clone())
+  };
+
+  activity_repository
+    .find_by_cursor(
+      &crate::domain::activity::repository::FindAllQuery { user_id },
+      &input.page,
+    )
+    .await
+    .map_err(Error::RepositoryError)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    domain::{
+      activity::{repository::ActivityRepository, Activity, ActivityStatus},
+      artist::{contribution::Contribution, Artist},
+      user::User,
+    },
+    infra::in_memory::InMemoryActivityRepository,
+    shared::{paged::CursorPageParams, vo::UUID4},
+  };
+
+  async fn find_all(
+    activity_repository: &InMemoryActivityRepository,
+    actor: User,
+    user_id: Option<UUID4>,
+  ) -> Vec<UUID4> {
+    let result = super::execute(
+      activity_repository,
+      super::Input {
+        actor,
+        page: CursorPageParams {
+          cursor: None,
+          limit: 10,
+          include_total: false,
+        },
+        user_id,
+      },
+    )
+    .await;
+
+    match result {
+      Ok(paged) => paged
+        .items
+        .into_iter()
+        .map(|activity| activity.user_id().clone())
+        .collect(),
+      Err(_) => panic!("A listagem de atividades deveria funcionar"),
+    }
+  }
+
+  #[tokio::test]
+  async fn test_visibility_by_role() {
+    let mut activity_repository = InMemoryActivityRepository::default();
+
+    let user = User::builder().username(String::from("user")).build();
+    let other = User::builder().username(String::from("other")).build();
+
+    for author in [&user, &other] {
+      let activity = Activity::builder()
+        .user_id(author.id().clone())
+        .contribution(crate::shared::vo::Contribution::Artist(
+          Contribution::Create(Artist::builder().name(String::from("Teste")).build()),
+        ))
+        .status(ActivityStatus::Pending)
+        .build();
+      activity_repository.create(&activity).await.unwrap();
+    }
+
+    assert_eq!(
+      find_all(&activity_repository, user.clone(), None).await,
+      vec![user.id().clone()],
+      "Sem ser curador, o usuário deveria ver apenas as próprias atividades"
+    );
+
+    assert_eq!(
+      find_all(&activity_repository, user.clone(), Some(other.id().clone())).await,
+      vec![user.id().clone()],
+      "O filtro por outro usuário deveria ser ignorado para quem não é curador"
+    );
+
+    let curator = User::builder()
+      .username(String::from("curator"))
+      .is_curator(true)
+      .build();
+
+    assert_eq!(
+      find_all(&activity_repository, curator.clone(), None)
+        .await
+        .len(),
+      2,
+      "O curador deveria ver as atividades de todos os usuários"
+    );
+
+    assert_eq!(
+      find_all(&activity_repository, curator, Some(other.id().clone())).await,
+      vec![other.id().clone()],
+      "O curador deveria poder filtrar pelo usuário"
+    );
+  }
+}
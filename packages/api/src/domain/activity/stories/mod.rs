@@ -1,2 +1,3 @@
 pub mod approve;
+pub mod find_all;
 pub mod reject;
@@ -1,5 +1,3 @@
-use std::vec;
-
 #[derive(Debug, Clone, PartialEq)]
 pub struct AlbumAggregate {
   album: super::Album,
@@ -11,6 +9,10 @@ impl AlbumAggregate {
     Self { album, artists }
   }
 
+  pub fn album(&self) -> &super::Album {
+    &self.album
+  }
+
   pub fn into_parts(self) -> (super::Album, Vec<crate::domain::artist::Artist>) {
     (self.album, self.artists)
   }
@@ -2,9 +2,12 @@ use std::{fmt::Display, str::FromStr};
 
 use domain_proc_macros::Entity;
 
-use crate::shared::{util::naive_now, vo::Slug};
+use crate::shared::{
+  util::naive_now,
+  vo::{Barcode, Slug, UUID4},
+};
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum AlbumType {
   Single,
   EP,
@@ -48,6 +51,17 @@ pub struct Album {
   parental_rating: u8,
   artist_ids: std::collections::HashSet<crate::shared::vo::UUID4>,
 
+  label: Option<String>,
+  catalog_number: Option<String>,
+  barcode: Option<Barcode>,
+  release_country: Option<String>,
+
+  /**
+   * Edições (deluxe, remasterizada, regional...) de um mesmo álbum compartilham o mesmo grupo.
+   */
+  release_group_id: UUID4,
+  edition: Option<String>,
+
   created_at: chrono::NaiveDateTime,
   updated_at: chrono::NaiveDateTime,
 }
@@ -82,6 +96,30 @@ impl Album {
       self.artist_ids = value.clone();
     }
 
+    if let Some(value) = &changes.label {
+      self.label = Some(value.clone());
+    }
+
+    if let Some(value) = &changes.catalog_number {
+      self.catalog_number = Some(value.clone());
+    }
+
+    if let Some(value) = &changes.barcode {
+      self.barcode = Some(value.clone());
+    }
+
+    if let Some(value) = &changes.release_country {
+      self.release_country = Some(value.clone());
+    }
+
+    if let Some(value) = &changes.release_group_id {
+      self.release_group_id = value.clone();
+    }
+
+    if let Some(value) = &changes.edition {
+      self.edition = Some(value.clone());
+    }
+
     self.updated_at = naive_now();
   }
 }
@@ -89,9 +127,11 @@ impl Album {
 impl Default for Album {
   fn default() -> Self {
     let now = naive_now();
+    let id = UUID4::generate();
 
     Self {
-      id: crate::shared::vo::UUID4::generate(),
+      release_group_id: id.clone(),
+      id,
       name: String::new(),
       album_type: AlbumType::Album,
       cover: Some(String::new()),
@@ -99,6 +139,11 @@ impl Default for Album {
       release_date: None,
       parental_rating: 0,
       artist_ids: std::collections::HashSet::new(),
+      label: None,
+      catalog_number: None,
+      barcode: None,
+      release_country: None,
+      edition: None,
       created_at: now,
       updated_at: now,
     }
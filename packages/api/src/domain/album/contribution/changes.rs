@@ -1,4 +1,4 @@
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Changes {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub name: Option<String>,
@@ -19,4 +19,22 @@ pub struct Changes {
 
   #[serde(skip_serializing_if = "Option::is_none")]
   pub artist_ids: Option<std::collections::HashSet<crate::shared::vo::UUID4>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub label: Option<String>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub catalog_number: Option<String>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub barcode: Option<crate::shared::vo::Barcode>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub release_country: Option<String>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub release_group_id: Option<crate::shared::vo::UUID4>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub edition: Option<String>,
 }
@@ -1,5 +1,6 @@
 pub mod changes;
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum Contribution {
   Create(crate::domain::album::Album),
@@ -8,6 +9,7 @@ pub enum Contribution {
     changes: changes::Changes,
   },
   Delete(crate::domain::album::Album),
+  Restore(crate::domain::album::Album),
 }
 
 impl Default for Contribution {
@@ -1,6 +1,10 @@
 use std::future::Future;
 
-use crate::shared::vo::{Slug, UUID4};
+use crate::shared::{
+  facet::Facet,
+  filter::ListFilter,
+  vo::{Slug, UUID4},
+};
 
 use super::{album_aggregate::AlbumAggregate, Album};
 
@@ -9,20 +13,60 @@ pub enum Error {
   DatabaseError(String),
 }
 
+crate::sort_fields! {
+  /**
+   * Campos pelos quais os álbuns podem ser ordenados.
+   *
+   * A popularidade é o número de mídias do álbum.
+   */
+  pub enum SortField {
+    Name => "name",
+    ReleaseDate => "release_date",
+    CreatedAt => "created_at",
+    UpdatedAt => "updated_at",
+    Popularity => "popularity",
+  }
+}
+
+crate::facet_fields! {
+  /**
+   * Campos pelos quais os álbuns de uma listagem podem ser contados.
+   *
+   * Os gêneros de um álbum são os gêneros de suas mídias.
+   */
+  pub enum FacetField {
+    AlbumType => "album_type",
+    ParentalRating => "parental_rating",
+    ReleaseYear => "release_year",
+    ReleaseDecade => "release_decade",
+    Genre => "genre",
+  }
+}
+
 #[derive(Debug, Clone)]
 pub struct FindAllQuery {
   pub page: crate::shared::paged::RequestPageParams,
+  pub sort: Option<crate::shared::sort::Sort<SortField>>,
   pub name: Option<String>,
   pub slug: Option<Slug>,
-  pub artist_ids: Option<Vec<UUID4>>,
-  pub album_type: Option<super::AlbumType>,
+  pub artist_ids: Option<ListFilter<UUID4>>,
+  pub album_type: Option<ListFilter<super::AlbumType>>,
   pub release_date: Option<chrono::NaiveDate>,
   pub min_release_date: Option<chrono::NaiveDate>,
   pub max_release_date: Option<chrono::NaiveDate>,
-  pub parental_rating: Option<u8>,
+  pub parental_rating: Option<ListFilter<u8>>,
   pub min_parental_rating: Option<u8>,
   pub max_parental_rating: Option<u8>,
   pub search: Option<String>,
+  pub label: Option<String>,
+  pub barcode: Option<crate::shared::vo::Barcode>,
+  pub release_country: Option<String>,
+  pub release_group_id: Option<UUID4>,
+
+  /**
+   * Quando verdadeiro, retorna apenas uma edição por grupo de lançamento (a mais antiga).
+   */
+  pub group_editions: bool,
 }
 
 pub trait AlbumRepository {
@@ -42,10 +86,39 @@ pub trait AlbumRepository {
   fn find_by_id(&mut self, id: &UUID4) -> impl Future<Output = Result<Option<Album>, Error>>;
 
   /**
-   * Deleta um álbum pelo seu identificador.
+   * Busca os álbuns com os identificadores informados. Identificadores inexistentes são ignorados.
+   */
+  fn find_by_ids(
+    &mut self,
+    ids: &std::collections::HashSet<UUID4>,
+  ) -> impl Future<Output = Result<Vec<Album>, Error>>;
+
+  /**
+   * Exclui logicamente um álbum, que deixa de ser retornado pelas consultas até ser restaurado.
    */
   fn delete_by_id(&mut self, id: &UUID4) -> impl Future<Output = Result<(), Error>>;
 
+  /**
+   * Busca um álbum excluído pelo seu identificador.
+   */
+  fn find_deleted_by_id(
+    &mut self,
+    id: &UUID4,
+  ) -> impl Future<Output = Result<Option<Album>, Error>>;
+
+  /**
+   * Restaura um álbum excluído.
+   */
+  fn restore_by_id(&mut self, id: &UUID4) -> impl Future<Output = Result<(), Error>>;
+
+  /**
+   * Calcula o impacto da exclusão de um álbum: as medias do álbum, as fontes dessas medias e os relacionamentos.
+   */
+  fn deletion_impact(
+    &mut self,
+    id: &UUID4,
+  ) -> impl Future<Output = Result<crate::domain::activity::DeletionImpact, Error>>;
+
   /**
    * Busca um álbum pelo seu slug e retorna o álbum encontrado ou None caso não exista.
    */
@@ -55,8 +128,27 @@ pub trait AlbumRepository {
     artist_slug: &Slug,
   ) -> impl Future<Output = Result<Option<super::album_aggregate::AlbumAggregate>, Error>>;
 
+  /**
+   * Busca todas as edições que pertencem ao grupo de lançamento informado.
+   */
+  fn find_by_release_group_id(
+    &mut self,
+    release_group_id: &UUID4,
+  ) -> impl Future<Output = Result<Vec<Album>, Error>>;
+
   fn find_by(
     &mut self,
     query: &FindAllQuery,
   ) -> impl Future<Output = Result<crate::shared::paged::Paged<AlbumAggregate>, Error>>;
+
+  /**
+   * Conta os álbuns que atendem aos filtros da consulta agrupando-os por cada um dos campos.
+   *
+   * Os campos `page` e `sort` da consulta são ignorados.
+   */
+  fn facets(
+    &mut self,
+    query: &FindAllQuery,
+    fields: &[FacetField],
+  ) -> impl Future<Output = Result<Vec<Facet<FacetField>>, Error>>;
 }
@@ -33,6 +33,12 @@ pub async fn execute(
         .await
         .map_err(Error::RepositoryError)?;
     }
+    crate::domain::album::contribution::Contribution::Restore(album) => {
+      album_repository
+        .restore_by_id(album.id())
+        .await
+        .map_err(Error::RepositoryError)?;
+    }
   }
 
   Ok(())
@@ -1,23 +1,46 @@
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone)]
 pub struct CreateInput {
   pub name: String,
+  pub slug: Option<crate::shared::vo::Slug>,
   pub cover: Option<String>,
   pub album_type: crate::domain::album::AlbumType,
   pub release_date: Option<chrono::NaiveDate>,
   pub parental_rating: Option<u8>,
   pub artist_ids: std::collections::HashSet<crate::shared::vo::UUID4>,
+  pub label: Option<String>,
+  pub catalog_number: Option<String>,
+  pub barcode: Option<crate::shared::vo::Barcode>,
+  pub release_country: Option<String>,
+  pub release_group_id: Option<crate::shared::vo::UUID4>,
+  pub edition: Option<String>,
 }
 
 impl From<CreateInput> for crate::domain::album::Album {
   fn from(value: CreateInput) -> Self {
-    Self::builder()
+    let mut album = Self::builder()
+      .slug(
+        value
+          .slug
+          .unwrap_or_else(|| crate::shared::vo::Slug::generate(&value.name)),
+      )
       .name(value.name)
       .cover(value.cover)
       .album_type(value.album_type)
       .release_date(value.release_date)
       .parental_rating(value.parental_rating.unwrap_or_default())
       .artist_ids(value.artist_ids)
-      .build()
+      .label(value.label)
+      .catalog_number(value.catalog_number)
+      .barcode(value.barcode)
+      .release_country(value.release_country)
+      .edition(value.edition)
+      .build();
+
+    // Sem grupo informado, o álbum inaugura um novo grupo de lançamento.
+    let release_group_id = value.release_group_id.unwrap_or_else(|| album.id().clone());
+    album.set_release_group_id(release_group_id);
+
+    album
   }
 }
 
@@ -29,13 +52,17 @@ pub enum ChangeInput {
     changes: crate::domain::album::contribution::changes::Changes,
   },
   Delete(crate::shared::vo::UUID4),
+  Restore(crate::shared::vo::UUID4),
 }
 
 #[derive(Debug, Clone)]
 pub enum Error {
   AlbumRepositoryError(crate::domain::album::repository::Error),
+  ArtistRepositoryError(crate::domain::artist::repository::Error),
   ActivityRepositoryError(crate::domain::activity::repository::Error),
   AlbumNotFound,
+  ReleaseGroupNotFound(crate::shared::vo::UUID4),
+  MissingReferences(crate::shared::reference::MissingReferences),
 }
 
 #[derive(Debug, Clone)]
@@ -44,14 +71,65 @@ pub struct Input {
   pub data: ChangeInput,
 }
 
+async fn validate_release_group(
+  album_repository: &mut impl crate::domain::album::repository::AlbumRepository,
+  album: &crate::domain::album::Album,
+) -> Result<(), Error> {
+  let release_group_id = album.release_group_id();
+
+  if release_group_id == album.id() {
+    return Ok(());
+  }
+
+  let editions = album_repository
+    .find_by_release_group_id(release_group_id)
+    .await
+    .map_err(Error::AlbumRepositoryError)?;
+
+  if editions.is_empty() {
+    return Err(Error::ReleaseGroupNotFound(release_group_id.clone()));
+  }
+
+  Ok(())
+}
+
+/**
+ * Garante que todos os artistas referenciados pelo álbum existam.
+ */
+async fn validate_artists(
+  artist_repository: &mut impl crate::domain::artist::repository::ArtistRepository,
+  artist_ids: &std::collections::HashSet<crate::shared::vo::UUID4>,
+) -> Result<(), Error> {
+  if artist_ids.is_empty() {
+    return Ok(());
+  }
+
+  let existing = artist_repository
+    .find_by_ids(artist_ids)
+    .await
+    .map_err(Error::ArtistRepositoryError)?
+    .into_iter()
+    .map(|artist| artist.id().clone())
+    .collect();
+
+  let mut missing = crate::shared::reference::MissingReferences::default();
+  missing.check("artist_ids", artist_ids, &existing);
+
+  missing.into_result().map_err(Error::MissingReferences)
+}
+
 pub async fn execute(
   album_repository: &mut impl crate::domain::album::repository::AlbumRepository,
+  artist_repository: &mut impl crate::domain::artist::repository::ArtistRepository,
   activity_repository: &mut impl crate::domain::activity::repository::ActivityRepository,
   input: Input,
 ) -> Result<crate::domain::activity::Activity, Error> {
   let contribution: crate::domain::album::contribution::Contribution = match input.data {
     ChangeInput::Create(album) => {
-      crate::domain::album::contribution::Contribution::Create(album.into())
+      let album: crate::domain::album::Album = album.into();
+      validate_release_group(album_repository, &album).await?;
+      validate_artists(artist_repository, album.artist_ids()).await?;
+      crate::domain::album::contribution::Contribution::Create(album)
     }
 
     ChangeInput::Delete(id) => {
@@ -67,6 +145,19 @@ pub async fn execute(
       }
     }
 
+    ChangeInput::Restore(id) => {
+      let album = album_repository
+        .find_deleted_by_id(&id)
+        .await
+        .map_err(Error::AlbumRepositoryError)?;
+
+      if let Some(album) = album {
+        crate::domain::album::contribution::Contribution::Restore(album)
+      } else {
+        return Err(Error::AlbumNotFound);
+      }
+    }
+
     ChangeInput::Update { id, changes } => {
       let album = album_repository
         .find_by_id(&id)
@@ -75,6 +166,10 @@ pub async fn execute(
 
       if let Some(mut album) = album {
         album.apply_changes(&changes);
+        validate_release_group(album_repository, &album).await?;
+        if let Some(artist_ids) = &changes.artist_ids {
+          validate_artists(artist_repository, artist_ids).await?;
+        }
         crate::domain::album::contribution::Contribution::Update {
           entity: album,
           changes,
@@ -85,9 +180,20 @@ pub async fn execute(
     }
   };
 
+  let impact = match &contribution {
+    crate::domain::album::contribution::Contribution::Delete(album) => Some(
+      album_repository
+        .deletion_impact(album.id())
+        .await
+        .map_err(Error::AlbumRepositoryError)?,
+    ),
+    _ => None,
+  };
+
   let activity = crate::domain::activity::Activity::builder()
     .user_id(input.actor_id)
     .contribution(crate::shared::vo::Contribution::Album(contribution))
+    .impact(impact)
     .status(crate::domain::activity::ActivityStatus::Pending)
     .build();
 
@@ -98,3 +204,79 @@ pub async fn execute(
 
   Ok(activity)
 }
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    domain::{
+      album::AlbumType,
+      artist::{repository::ArtistRepository, Artist},
+    },
+    infra::in_memory::{
+      InMemoryActivityRepository, InMemoryAlbumRepository, InMemoryArtistRepository,
+    },
+    shared::vo::{Slug, UUID4},
+  };
+
+  #[tokio::test]
+  async fn test_missing_artist_references() {
+    let mut album_repository = InMemoryAlbumRepository::default();
+    let mut artist_repository = InMemoryArtistRepository::default();
+    let mut activity_repository = InMemoryActivityRepository::default();
+
+    let artist = Artist::builder()
+      .name(String::from("Teste"))
+      .slug(Slug::new("teste").unwrap())
+      .build();
+    artist_repository.create(&artist).await.unwrap();
+
+    let missing_id = UUID4::generate();
+
+    let input = |artist_ids| super::Input {
+      actor_id: UUID4::generate(),
+      data: super::ChangeInput::Create(super::CreateInput {
+        name: String::from("Teste"),
+        slug: None,
+        cover: None,
+        album_type: AlbumType::Album,
+        release_date: None,
+        parental_rating: None,
+        artist_ids,
+        label: None,
+        catalog_number: None,
+        barcode: None,
+        release_country: None,
+        release_group_id: None,
+        edition: None,
+      }),
+    };
+
+    let result = super::execute(
+      &mut album_repository,
+      &mut artist_repository,
+      &mut activity_repository,
+      input([artist.id().clone(), missing_id.clone()].into()),
+    )
+    .await;
+
+    match result {
+      Err(super::Error::MissingReferences(missing)) => {
+        assert_eq!(
+          missing.fields().get("artist_ids"),
+          Some(&vec![missing_id]),
+          "Apenas o artista inexistente deveria ser apontado"
+        );
+      }
+      other => panic!("Esperava referências ausentes, obteve {:?}", other),
+    }
+
+    super::execute(
+      &mut album_repository,
+      &mut artist_repository,
+      &mut activity_repository,
+      input([artist.id().clone()].into()),
+    )
+    .await
+    .expect("Falha ao contribuir com álbum de artista existente");
+  }
+}
@@ -0,0 +1,34 @@
+use crate::{domain::album::repository::FacetField, shared::facet::Facet};
+
+pub struct Input {
+  /**
+   * Filtros da listagem sobre a qual as facetas são calculadas; a paginação e a
+   * ordenação são ignoradas.
+   */
+  pub filter: super::find_by::Input,
+
+  /**
+   * Campos a agrupar; vazio para todos os campos disponíveis.
+   */
+  pub fields: Vec<FacetField>,
+}
+
+pub enum Error {
+  RepositoryError(crate::domain::album::repository::Error),
+}
+
+pub async fn execute(
+  album_repository: &mut impl crate::domain::album::repository::AlbumRepository,
+  input: Input,
+) -> Result<Vec<Facet<FacetField>>, Error> {
+  let fields = if input.fields.is_empty() {
+    FacetField::ALL.to_vec()
+  } else {
+    input.fields
+  };
+
+  album_repository
+    .facets(&input.filter.into(), &fields)
+    .await
+    .map_err(Error::RepositoryError)
+}
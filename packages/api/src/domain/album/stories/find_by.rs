@@ -1,33 +1,41 @@
 use crate::{
   domain::album::{repository::FindAllQuery, AlbumType},
-  shared::vo::{Slug, UUID4},
+  shared::{
+    filter::ListFilter,
+    vo::{Slug, UUID4},
+  },
 };
 
 pub enum Error {
   RepositoryError(crate::domain::album::repository::Error),
 }
+#[derive(Debug, Clone)]
 pub struct Input {
   pub page: crate::shared::paged::RequestPageParams,
+  pub sort: Option<crate::shared::sort::Sort<crate::domain::album::repository::SortField>>,
   pub name: Option<String>,
   pub slug: Option<Slug>,
-  pub artist_ids: Option<Vec<UUID4>>,
-  pub album_type: Option<AlbumType>,
+  pub artist_ids: Option<ListFilter<UUID4>>,
+  pub album_type: Option<ListFilter<AlbumType>>,
   pub release_date: Option<chrono::NaiveDate>,
   pub min_release_date: Option<chrono::NaiveDate>,
   pub max_release_date: Option<chrono::NaiveDate>,
-  pub parental_rating: Option<u8>,
+  pub parental_rating: Option<ListFilter<u8>>,
   pub min_parental_rating: Option<u8>,
   pub max_parental_rating: Option<u8>,
   pub search: Option<String>,
+  pub label: Option<String>,
+  pub barcode: Option<crate::shared::vo::Barcode>,
+  pub release_country: Option<String>,
+  pub release_group_id: Option<UUID4>,
+  pub group_editions: bool,
 }
-pub async fn execute(
-  album_repository: &mut impl crate::domain::album::repository::AlbumRepository,
-  input: Input,
-) -> Result<crate::shared::paged::Paged<crate::domain::album::album_aggregate::AlbumAggregate>, Error>
-{
-  let result = album_repository
-    .find_by(&FindAllQuery {
+
+impl From<Input> for FindAllQuery {
+  fn from(input: Input) -> Self {
+    Self {
       page: input.page,
+      sort: input.sort,
       name: input.name,
       slug: input.slug,
       artist_ids: input.artist_ids,
@@ -39,7 +47,22 @@ pub async fn execute(
       min_parental_rating: input.min_parental_rating,
       max_parental_rating: input.max_parental_rating,
       search: input.search,
-    })
+      label: input.label,
+      barcode: input.barcode,
+      release_country: input.release_country,
+      release_group_id: input.release_group_id,
+      group_editions: input.group_editions,
+    }
+  }
+}
+
+pub async fn execute(
+  album_repository: &mut impl crate::domain::album::repository::AlbumRepository,
+  input: Input,
+) -> Result<crate::shared::paged::Paged<crate::domain::album::album_aggregate::AlbumAggregate>, Error>
+{
+  let result = album_repository
+    .find_by(&input.into())
     .await
     .map_err(Error::RepositoryError)?;
 
@@ -6,12 +6,14 @@ use crate::domain::{
 pub enum Error {
   RepositoryError(domain::album::repository::Error),
   AlbumNotFound,
+  ParentalRatingRestricted,
 }
 
 pub async fn execute(
   album_repository: &mut impl AlbumRepository,
   slug: &crate::shared::vo::Slug,
   artist_slug: &crate::shared::vo::Slug,
+  parental_rating_limit: u8,
 ) -> Result<AlbumAggregate, Error> {
   let album_aggregate = album_repository
     .find_by_slug(slug, artist_slug)
@@ -19,6 +21,10 @@ pub async fn execute(
     .map_err(Error::RepositoryError)?;
 
   if let Some(album_aggregate) = album_aggregate {
+    if *album_aggregate.album().parental_rating() > parental_rating_limit {
+      return Err(Error::ParentalRatingRestricted);
+    }
+
     return Ok(album_aggregate);
   }
 
@@ -1,4 +1,5 @@
 pub mod apply_changes;
 pub mod contribute;
+pub mod facets;
 pub mod find_by;
 pub mod find_by_slug;
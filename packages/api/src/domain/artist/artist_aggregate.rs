@@ -0,0 +1,34 @@
+/**
+ * Artista relacionado por meio de uma associação a um grupo, com o período em que esteve ativo.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArtistMembership {
+  pub artist: super::Artist,
+  pub started_at: Option<chrono::NaiveDate>,
+  pub ended_at: Option<chrono::NaiveDate>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArtistAggregate {
+  artist: super::Artist,
+  members: Vec<ArtistMembership>,
+  groups: Vec<ArtistMembership>,
+}
+
+impl ArtistAggregate {
+  pub fn new(
+    artist: super::Artist,
+    members: Vec<ArtistMembership>,
+    groups: Vec<ArtistMembership>,
+  ) -> Self {
+    Self {
+      artist,
+      members,
+      groups,
+    }
+  }
+
+  pub fn into_parts(self) -> (super::Artist, Vec<ArtistMembership>, Vec<ArtistMembership>) {
+    (self.artist, self.members, self.groups)
+  }
+}
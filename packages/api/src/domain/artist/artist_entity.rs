@@ -1,13 +1,66 @@
+use std::{collections::HashSet, fmt::Display, str::FromStr};
+
 use domain_proc_macros::Entity;
 
 use crate::shared::util::naive_now;
 
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum ArtistType {
+  #[default]
+  Person,
+  Group,
+}
+
+impl Display for ArtistType {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Person => write!(f, "Person"),
+      Self::Group => write!(f, "Group"),
+    }
+  }
+}
+
+impl FromStr for ArtistType {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "Person" => Ok(Self::Person),
+      "Group" => Ok(Self::Group),
+      _ => Err(format!("Invalid ArtistType: {}", s)),
+    }
+  }
+}
+
+/**
+ * Vínculo de um artista (pessoa) como membro de um grupo, com o período em que esteve ativo.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct ArtistMember {
+  pub artist_id: crate::shared::vo::UUID4,
+  pub started_at: Option<chrono::NaiveDate>,
+  pub ended_at: Option<chrono::NaiveDate>,
+}
+
+impl ArtistMember {
+  pub fn is_active_at(&self, date: chrono::NaiveDate) -> bool {
+    self.started_at.is_none_or(|started_at| started_at <= date)
+      && self.ended_at.is_none_or(|ended_at| date <= ended_at)
+  }
+}
+
 #[derive(Debug, Clone, Entity, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Artist {
   id: crate::shared::vo::UUID4,
   slug: crate::shared::vo::Slug,
   name: String,
   country: Option<String>,
+  #[serde(default)]
+  artist_type: ArtistType,
+  #[serde(default)]
+  aliases: HashSet<String>,
+  #[serde(default)]
+  members: Vec<ArtistMember>,
   created_at: chrono::NaiveDateTime,
   updated_at: chrono::NaiveDateTime,
 }
@@ -26,8 +79,31 @@ impl Artist {
       self.country = Some(value.clone());
     }
 
+    if let Some(value) = &changes.artist_type {
+      self.artist_type = value.clone();
+    }
+
+    if let Some(value) = &changes.aliases {
+      self.aliases = value.clone();
+    }
+
+    if let Some(value) = &changes.members {
+      self.members = value.clone();
+    }
+
     self.updated_at = naive_now();
   }
+
+  /**
+   * Retorna os membros ativos do grupo na data informada.
+   */
+  pub fn active_members_at(&self, date: chrono::NaiveDate) -> Vec<&ArtistMember> {
+    self
+      .members
+      .iter()
+      .filter(|member| member.is_active_at(date))
+      .collect()
+  }
 }
 
 impl Default for Artist {
@@ -39,6 +115,9 @@ impl Default for Artist {
       slug: crate::shared::vo::Slug::default(),
       name: "".to_string(),
       country: None,
+      artist_type: ArtistType::default(),
+      aliases: HashSet::new(),
+      members: Vec::new(),
       created_at: now,
       updated_at: now,
     }
@@ -1,4 +1,4 @@
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Changes {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub name: Option<String>,
@@ -8,4 +8,13 @@ pub struct Changes {
 
   #[serde(skip_serializing_if = "Option::is_none")]
   pub slug: Option<crate::shared::vo::Slug>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub artist_type: Option<crate::domain::artist::ArtistType>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub aliases: Option<std::collections::HashSet<String>>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub members: Option<Vec<crate::domain::artist::ArtistMember>>,
 }
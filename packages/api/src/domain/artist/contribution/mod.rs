@@ -8,6 +8,7 @@ pub enum Contribution {
     changes: changes::Changes,
   },
   Delete(crate::domain::artist::Artist),
+  Restore(crate::domain::artist::Artist),
 }
 
 impl Default for Contribution {
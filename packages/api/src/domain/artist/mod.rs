@@ -1,7 +1,8 @@
+pub mod artist_aggregate;
 pub mod contribution;
 pub mod repository;
 pub mod stories;
 
 mod artist_entity;
 
-pub use artist_entity::Artist;
+pub use artist_entity::{Artist, ArtistMember, ArtistType};
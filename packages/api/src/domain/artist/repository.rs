@@ -5,7 +5,7 @@ use crate::shared::{
   vo::{Slug, UUID4},
 };
 
-use super::Artist;
+use super::{Artist, ArtistType};
 
 #[derive(Debug, Clone)]
 pub enum Error {
@@ -13,11 +13,27 @@ pub enum Error {
   DatabaseError(String),
 }
 
+crate::sort_fields! {
+  /**
+   * Campos pelos quais os artistas podem ser ordenados.
+   *
+   * A popularidade é o número de mídias interpretadas pelo artista.
+   */
+  pub enum SortField {
+    Name => "name",
+    CreatedAt => "created_at",
+    UpdatedAt => "updated_at",
+    Popularity => "popularity",
+  }
+}
+
 #[derive(Debug, Clone)]
 pub struct FindAllQuery {
   pub page: RequestPageParams,
+  pub sort: Option<crate::shared::sort::Sort<SortField>>,
   pub search: Option<String>,
   pub country: Option<String>,
+  pub artist_type: Option<ArtistType>,
 }
 
 pub trait ArtistRepository {
@@ -37,15 +53,52 @@ pub trait ArtistRepository {
   fn find_by_id(&mut self, id: &UUID4) -> impl Future<Output = Result<Option<Artist>, Error>>;
 
   /**
-   * Deleta um artista pelo seu identificador.
+   * Exclui logicamente um artista, que deixa de ser retornado pelas consultas até ser restaurado.
    */
   fn delete_by_id(&mut self, id: &UUID4) -> impl Future<Output = Result<(), Error>>;
 
+  /**
+   * Busca um artista excluído pelo seu identificador.
+   */
+  fn find_deleted_by_id(
+    &mut self,
+    id: &UUID4,
+  ) -> impl Future<Output = Result<Option<Artist>, Error>>;
+
+  /**
+   * Restaura um artista excluído.
+   */
+  fn restore_by_id(&mut self, id: &UUID4) -> impl Future<Output = Result<(), Error>>;
+
+  /**
+   * Calcula o impacto da exclusão de um artista: as medias e álbuns do artista, as fontes dessas medias e os relacionamentos.
+   */
+  fn deletion_impact(
+    &mut self,
+    id: &UUID4,
+  ) -> impl Future<Output = Result<crate::domain::activity::DeletionImpact, Error>>;
+
   /**
    * Busca um artista pelo seu slug e retorna o artista encontrado ou None caso não exista.
    */
   fn find_by_slug(&mut self, slug: &Slug) -> impl Future<Output = Result<Option<Artist>, Error>>;
 
+  /**
+   * Busca os artistas com os identificadores informados. Identificadores inexistentes são ignorados.
+   */
+  fn find_by_ids(
+    &mut self,
+    ids: &std::collections::HashSet<UUID4>,
+  ) -> impl Future<Output = Result<Vec<Artist>, Error>>;
+
+  /**
+   * Busca os grupos dos quais o artista informado é ou já foi membro.
+   */
+  fn find_groups_by_member_id(
+    &mut self,
+    member_id: &UUID4,
+  ) -> impl Future<Output = Result<Vec<Artist>, Error>>;
+
   fn find_all(
     &mut self,
     query: &FindAllQuery,
@@ -33,6 +33,12 @@ pub async fn execute(
         .await
         .map_err(Error::RepositoryError)?;
     }
+    crate::domain::artist::contribution::Contribution::Restore(artist) => {
+      artist_repository
+        .restore_by_id(artist.id())
+        .await
+        .map_err(Error::RepositoryError)?;
+    }
   }
 
   Ok(())
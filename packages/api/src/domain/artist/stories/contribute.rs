@@ -5,6 +5,9 @@ pub struct CreateArtistInput {
   pub name: String,
   pub slug: Option<Slug>,
   pub country: Option<String>,
+  pub artist_type: Option<crate::domain::artist::ArtistType>,
+  pub aliases: Option<std::collections::HashSet<String>>,
+  pub members: Option<Vec<crate::domain::artist::ArtistMember>>,
 }
 
 impl From<CreateArtistInput> for crate::domain::artist::Artist {
@@ -17,6 +20,9 @@ impl From<CreateArtistInput> for crate::domain::artist::Artist {
           .unwrap_or_else(|| crate::shared::vo::Slug::generate(&value.name)),
       )
       .country(value.country.clone())
+      .artist_type(value.artist_type.unwrap_or_default())
+      .aliases(value.aliases.unwrap_or_default())
+      .members(value.members.unwrap_or_default())
       .build()
   }
 }
@@ -29,6 +35,7 @@ pub enum ChangeInput {
     changes: crate::domain::artist::contribution::changes::Changes,
   },
   Delete(crate::shared::vo::UUID4),
+  Restore(crate::shared::vo::UUID4),
 }
 
 #[derive(Debug, Clone)]
@@ -36,6 +43,81 @@ pub enum Error {
   ArtistRepositoryError(crate::domain::artist::repository::Error),
   ActivityRepositoryError(crate::domain::activity::repository::Error),
   ArtistNotFound,
+  MembersRequireGroup,
+  InvalidMembership(String),
+  MissingReferences(crate::shared::reference::MissingReferences),
+}
+
+/**
+ * Valida os membros do artista: apenas grupos possuem membros, um grupo não pode ser membro de si
+ * mesmo, cada membro aparece uma única vez e o período de atividade deve ser coerente.
+ */
+fn validate_members(artist: &crate::domain::artist::Artist) -> Result<(), Error> {
+  if artist.members().is_empty() {
+    return Ok(());
+  }
+
+  if artist.artist_type() != &crate::domain::artist::ArtistType::Group {
+    return Err(Error::MembersRequireGroup);
+  }
+
+  let mut member_ids = std::collections::HashSet::new();
+
+  for member in artist.members() {
+    if &member.artist_id == artist.id() {
+      return Err(Error::InvalidMembership(String::from(
+        "An artist cannot be a member of itself",
+      )));
+    }
+
+    if !member_ids.insert(&member.artist_id) {
+      return Err(Error::InvalidMembership(format!(
+        "Artist {} is listed more than once",
+        member.artist_id
+      )));
+    }
+
+    if let (Some(started_at), Some(ended_at)) = (member.started_at, member.ended_at) {
+      if started_at > ended_at {
+        return Err(Error::InvalidMembership(format!(
+          "Membership of artist {} ends before it starts",
+          member.artist_id
+        )));
+      }
+    }
+  }
+
+  Ok(())
+}
+
+/**
+ * Garante que todos os artistas informados como membros existam.
+ */
+async fn validate_member_references(
+  artist_repository: &mut impl crate::domain::artist::repository::ArtistRepository,
+  members: &[crate::domain::artist::ArtistMember],
+) -> Result<(), Error> {
+  if members.is_empty() {
+    return Ok(());
+  }
+
+  let member_ids = members
+    .iter()
+    .map(|member| member.artist_id.clone())
+    .collect();
+
+  let existing = artist_repository
+    .find_by_ids(&member_ids)
+    .await
+    .map_err(Error::ArtistRepositoryError)?
+    .into_iter()
+    .map(|artist| artist.id().clone())
+    .collect();
+
+  let mut missing = crate::shared::reference::MissingReferences::default();
+  missing.check("members", &member_ids, &existing);
+
+  missing.into_result().map_err(Error::MissingReferences)
 }
 
 #[derive(Debug, Clone)]
@@ -51,7 +133,10 @@ pub async fn execute(
 ) -> Result<crate::domain::activity::Activity, Error> {
   let contribution: crate::domain::artist::contribution::Contribution = match input.data {
     ChangeInput::Create(artist) => {
-      crate::domain::artist::contribution::Contribution::Create(artist.into())
+      let artist: crate::domain::artist::Artist = artist.into();
+      validate_members(&artist)?;
+      validate_member_references(artist_repository, artist.members()).await?;
+      crate::domain::artist::contribution::Contribution::Create(artist)
     }
 
     ChangeInput::Delete(id) => {
@@ -67,6 +152,19 @@ pub async fn execute(
       }
     }
 
+    ChangeInput::Restore(id) => {
+      let artist = artist_repository
+        .find_deleted_by_id(&id)
+        .await
+        .map_err(Error::ArtistRepositoryError)?;
+
+      if let Some(artist) = artist {
+        crate::domain::artist::contribution::Contribution::Restore(artist)
+      } else {
+        return Err(Error::ArtistNotFound);
+      }
+    }
+
     ChangeInput::Update { id, changes } => {
       let artist = artist_repository
         .find_by_id(&id)
@@ -75,6 +173,10 @@ pub async fn execute(
 
       if let Some(mut artist) = artist {
         artist.apply_changes(&changes);
+        validate_members(&artist)?;
+        if let Some(members) = &changes.members {
+          validate_member_references(artist_repository, members).await?;
+        }
         crate::domain::artist::contribution::Contribution::Update {
           entity: artist,
           changes,
@@ -85,9 +187,20 @@ pub async fn execute(
     }
   };
 
+  let impact = match &contribution {
+    crate::domain::artist::contribution::Contribution::Delete(artist) => Some(
+      artist_repository
+        .deletion_impact(artist.id())
+        .await
+        .map_err(Error::ArtistRepositoryError)?,
+    ),
+    _ => None,
+  };
+
   let activity = crate::domain::activity::Activity::builder()
     .user_id(input.actor_id)
     .contribution(crate::shared::vo::Contribution::Artist(contribution))
+    .impact(impact)
     .status(crate::domain::activity::ActivityStatus::Pending)
     .build();
 
@@ -98,3 +211,73 @@ pub async fn execute(
 
   Ok(activity)
 }
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    domain::artist::{repository::ArtistRepository, Artist, ArtistMember, ArtistType},
+    infra::in_memory::{InMemoryActivityRepository, InMemoryArtistRepository},
+    shared::vo::{Slug, UUID4},
+  };
+
+  #[tokio::test]
+  async fn test_missing_member_references() {
+    let mut artist_repository = InMemoryArtistRepository::default();
+    let mut activity_repository = InMemoryActivityRepository::default();
+
+    let member = Artist::builder()
+      .name(String::from("Membro"))
+      .slug(Slug::new("membro").unwrap())
+      .build();
+    artist_repository.create(&member).await.unwrap();
+
+    let missing_id = UUID4::generate();
+
+    let input = |member_ids: Vec<UUID4>| super::Input {
+      actor_id: UUID4::generate(),
+      data: super::ChangeInput::Create(super::CreateArtistInput {
+        name: String::from("Banda"),
+        slug: None,
+        country: None,
+        artist_type: Some(ArtistType::Group),
+        aliases: None,
+        members: Some(
+          member_ids
+            .into_iter()
+            .map(|artist_id| ArtistMember {
+              artist_id,
+              started_at: None,
+              ended_at: None,
+            })
+            .collect(),
+        ),
+      }),
+    };
+
+    let result = super::execute(
+      &mut artist_repository,
+      &mut activity_repository,
+      input(vec![member.id().clone(), missing_id.clone()]),
+    )
+    .await;
+
+    match result {
+      Err(super::Error::MissingReferences(missing)) => {
+        assert_eq!(
+          missing.fields().get("members"),
+          Some(&vec![missing_id]),
+          "Apenas o membro inexistente deveria ser apontado"
+        );
+      }
+      other => panic!("Esperava referências ausentes, obteve {:?}", other),
+    }
+
+    super::execute(
+      &mut artist_repository,
+      &mut activity_repository,
+      input(vec![member.id().clone()]),
+    )
+    .await
+    .expect("Falha ao contribuir com grupo de membros existentes");
+  }
+}
@@ -5,8 +5,10 @@ pub enum Error {
 }
 pub struct Input {
   pub page: RequestPageParams,
+  pub sort: Option<crate::shared::sort::Sort<crate::domain::artist::repository::SortField>>,
   pub search: Option<String>,
   pub country: Option<String>,
+  pub artist_type: Option<crate::domain::artist::ArtistType>,
 }
 pub async fn execute(
   artist_repository: &mut impl crate::domain::artist::repository::ArtistRepository,
@@ -15,8 +17,10 @@ pub async fn execute(
   let result = artist_repository
     .find_all(&crate::domain::artist::repository::FindAllQuery {
       page: input.page,
+      sort: input.sort,
       search: input.search,
       country: input.country,
+      artist_type: input.artist_type,
     })
     .await
     .map_err(Error::RepositoryError)?;
@@ -1,7 +1,10 @@
 use crate::{
   domain::{
     self,
-    artist::{repository::ArtistRepository, Artist},
+    artist::{
+      artist_aggregate::{ArtistAggregate, ArtistMembership},
+      repository::ArtistRepository,
+    },
   },
   shared::vo::Slug,
 };
@@ -11,40 +14,139 @@ pub enum Error {
   ArtistNotFound,
 }
 
-pub async fn execute(repository: &mut impl ArtistRepository, slug: &Slug) -> Result<Artist, Error> {
+pub async fn execute(
+  repository: &mut impl ArtistRepository,
+  slug: &Slug,
+) -> Result<ArtistAggregate, Error> {
   let artist = repository
     .find_by_slug(slug)
     .await
+    .map_err(Error::RepositoryError)?
+    .ok_or(Error::ArtistNotFound)?;
+
+  let member_ids = artist
+    .members()
+    .iter()
+    .map(|member| member.artist_id.clone())
+    .collect();
+
+  let mut member_artists = repository
+    .find_by_ids(&member_ids)
+    .await
     .map_err(Error::RepositoryError)?;
 
-  if let Some(artist) = artist {
-    return Ok(artist);
-  }
+  let members = artist
+    .members()
+    .iter()
+    .filter_map(|member| {
+      let index = member_artists
+        .iter()
+        .position(|artist| artist.id() == &member.artist_id)?;
+
+      Some(ArtistMembership {
+        artist: member_artists.swap_remove(index),
+        started_at: member.started_at,
+        ended_at: member.ended_at,
+      })
+    })
+    .collect();
 
-  Err(Error::ArtistNotFound)
+  let groups = repository
+    .find_groups_by_member_id(artist.id())
+    .await
+    .map_err(Error::RepositoryError)?
+    .into_iter()
+    .filter_map(|group| {
+      let membership = group
+        .members()
+        .iter()
+        .find(|member| &member.artist_id == artist.id())
+        .cloned()?;
+
+      Some(ArtistMembership {
+        artist: group,
+        started_at: membership.started_at,
+        ended_at: membership.ended_at,
+      })
+    })
+    .collect();
+
+  Ok(ArtistAggregate::new(artist, members, groups))
 }
 
 #[cfg(test)]
 pub mod tests {
-  // use super::*;
-  // use crate::infra::artist_repository::InMemoryArtistRepository;
+  use std::collections::HashMap;
+
+  use super::*;
+  use crate::{
+    domain::artist::{Artist, ArtistMember, ArtistType},
+    infra::in_memory::InMemoryArtistRepository,
+  };
 
-  // #[tokio::test]
-  // async fn test_find_artist_by_slug() {
-  //     let artist = Artist::new("name".to_string(), "slug".to_string(), "country".to_string());
-  //     let mut repository = InMemoryArtistRepository::default();
+  #[tokio::test]
+  async fn test_find_artist_by_slug_with_members_and_groups() {
+    let person = Artist::builder()
+      .name(String::from("person"))
+      .slug(Slug::new("person").unwrap())
+      .build();
 
-  //     repository.artists.insert(artist.slug.clone(), artist.clone());
+    let membership = ArtistMember {
+      artist_id: person.id().clone(),
+      started_at: chrono::NaiveDate::from_ymd_opt(1990, 1, 1),
+      ended_at: chrono::NaiveDate::from_ymd_opt(2000, 12, 31),
+    };
 
-  //     let result = find_artist_by_slug(&repository, &artist.slug).await.unwrap();
-  //     assert_eq!(result.id, artist.id);
-  // }
+    let group = Artist::builder()
+      .name(String::from("group"))
+      .slug(Slug::new("group").unwrap())
+      .artist_type(ArtistType::Group)
+      .members(vec![membership.clone()])
+      .build();
 
-  // #[tokio::test]
-  // async fn test_find_artist_by_slug_not_found() {
-  //     let repository = InMemoryArtistRepository::default();
+    let mut repository = InMemoryArtistRepository {
+      artists: HashMap::from([
+        (person.id().to_string(), person.clone()),
+        (group.id().to_string(), group.clone()),
+      ]),
+      ..Default::default()
+    };
 
-  //     let result = find_artist_by_slug(&repository, "not_found").await;
-  //     assert!(result.is_err());
-  // }
+    let (_, members, _) = execute(&mut repository, group.slug())
+      .await
+      .unwrap_or_else(|_| panic!("Group not found"))
+      .into_parts();
+
+    assert_eq!(
+      members,
+      vec![ArtistMembership {
+        artist: person.clone(),
+        started_at: membership.started_at,
+        ended_at: membership.ended_at,
+      }]
+    );
+
+    let (_, _, groups) = execute(&mut repository, person.slug())
+      .await
+      .unwrap_or_else(|_| panic!("Person not found"))
+      .into_parts();
+
+    assert_eq!(
+      groups,
+      vec![ArtistMembership {
+        artist: group,
+        started_at: membership.started_at,
+        ended_at: membership.ended_at,
+      }]
+    );
+  }
+
+  #[tokio::test]
+  async fn test_find_artist_by_slug_not_found() {
+    let mut repository = InMemoryArtistRepository::default();
+
+    let result = execute(&mut repository, &Slug::new("not-found").unwrap()).await;
+
+    assert!(matches!(result, Err(Error::ArtistNotFound)));
+  }
 }
@@ -0,0 +1,6 @@
+mod record;
+mod repository;
+
+pub use record::*;
+pub use repository::*;
+pub mod stories;
@@ -0,0 +1,248 @@
+use std::collections::BTreeSet;
+
+use crate::shared::vo::{Barcode, CollaborativeEntityName, Isrc, Slug};
+
+/**
+ * Entrada de um arquivo de importação do catálogo.
+ *
+ * Referências a outras entidades são informadas pelo slug ou pelo `external_id` de
+ * outro registro do mesmo arquivo. Álbuns e medias são referenciados por
+ * `<slug do artista>/<slug>`, pois o slug deles só é único por artista.
+ *
+ * É também o formato da exportação, em que o `external_id` de cada registro é o
+ * identificador da entidade, e em que as exclusões são informadas por `Deleted`.
+ */
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ImportRecord {
+  Genre(GenreRecord),
+  Artist(ArtistRecord),
+  Album(AlbumRecord),
+  Media(MediaRecord),
+  Source(SourceRecord),
+  Lyrics(LyricsRecord),
+  Deleted(DeletedRecord),
+}
+
+impl ImportRecord {
+  /**
+   * Ordem de processamento: cada tipo só referencia tipos processados antes dele.
+   */
+  pub fn rank(&self) -> u8 {
+    match self {
+      Self::Genre(_) => 0,
+      Self::Artist(_) => 1,
+      Self::Album(_) => 2,
+      Self::Media(_) => 3,
+      Self::Source(_) => 4,
+      Self::Lyrics(_) => 5,
+      Self::Deleted(_) => 6,
+    }
+  }
+
+  pub fn entity_name(&self) -> &'static str {
+    match self {
+      Self::Genre(_) => "genre",
+      Self::Artist(_) => "artist",
+      Self::Album(_) => "album",
+      Self::Media(_) => "media",
+      Self::Source(_) => "source",
+      Self::Lyrics(_) => "lyrics",
+      Self::Deleted(record) => record.entity_name(),
+    }
+  }
+
+  /**
+   * Identificação do registro no relatório, montada a partir das referências como
+   * foram informadas no arquivo.
+   */
+  pub fn key(&self) -> String {
+    match self {
+      Self::Genre(record) => slug_or_generate(&record.slug, &record.name).to_string(),
+      Self::Artist(record) => slug_or_generate(&record.slug, &record.name).to_string(),
+      Self::Album(record) => format!(
+        "{}/{}",
+        record
+          .artists
+          .first()
+          .map(String::as_str)
+          .unwrap_or_default(),
+        Slug::generate(&record.name)
+      ),
+      Self::Media(record) => format!(
+        "{}/{}",
+        record
+          .interpreters
+          .first()
+          .map(String::as_str)
+          .unwrap_or_default(),
+        slug_or_generate(&record.slug, &record.name)
+      ),
+      Self::Source(record) => format!("{} {}", record.media, record.src),
+      Self::Lyrics(record) => record.media.clone(),
+      Self::Deleted(record) => record.external_id.clone(),
+    }
+  }
+}
+
+pub fn slug_or_generate(slug: &Option<Slug>, name: &str) -> Slug {
+  slug.clone().unwrap_or_else(|| Slug::generate(name))
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct GenreRecord {
+  pub external_id: Option<String>,
+  pub name: String,
+  pub slug: Option<Slug>,
+
+  /**
+   * Gênero pai.
+   */
+  pub parent: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ArtistRecord {
+  pub external_id: Option<String>,
+  pub name: String,
+  pub slug: Option<Slug>,
+  pub country: Option<String>,
+  pub artist_type: Option<crate::domain::artist::ArtistType>,
+  pub aliases: Option<BTreeSet<String>>,
+
+  /**
+   * Membros do grupo, na ordem em que o repositório os lista.
+   */
+  pub members: Option<Vec<MemberRecord>>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct MemberRecord {
+  /**
+   * Artista membro do grupo.
+   */
+  pub artist: String,
+  pub started_at: Option<chrono::NaiveDate>,
+  pub ended_at: Option<chrono::NaiveDate>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct AlbumRecord {
+  pub external_id: Option<String>,
+  pub name: String,
+  pub slug: Option<Slug>,
+  pub album_type: crate::domain::album::AlbumType,
+
+  /**
+   * Artistas do álbum. O primeiro compõe a chave usada para encontrar o álbum.
+   */
+  pub artists: Vec<String>,
+  pub cover: Option<String>,
+  pub release_date: Option<chrono::NaiveDate>,
+  pub parental_rating: Option<u8>,
+  pub label: Option<String>,
+  pub catalog_number: Option<String>,
+  pub barcode: Option<Barcode>,
+  pub release_country: Option<String>,
+  pub edition: Option<String>,
+
+  /**
+   * Álbum do mesmo grupo de lançamento, no formato `<slug do artista>/<slug>`. Ausente
+   * quando o álbum inaugura o próprio grupo.
+   */
+  pub release_group: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct MediaRecord {
+  pub external_id: Option<String>,
+  pub name: String,
+  pub slug: Option<Slug>,
+  pub media_type: crate::domain::media::MediaType,
+
+  /**
+   * Intérpretes da media. O primeiro compõe a chave usada para encontrar a media.
+   */
+  pub interpreters: Vec<String>,
+  pub composers: Option<Vec<String>>,
+  pub genres: Option<Vec<String>>,
+  pub albums: Option<Vec<String>>,
+  pub release_date: Option<chrono::NaiveDate>,
+  pub cover: Option<String>,
+  pub parental_rating: Option<u8>,
+  pub duration: Option<u32>,
+  pub isrc: Option<Isrc>,
+  pub bpm: Option<u16>,
+  pub key: Option<String>,
+  pub language: Option<String>,
+  pub is_single: Option<bool>,
+  pub credits: Option<Vec<CreditRecord>>,
+}
+
+/**
+ * Crédito de um artista na media. A posição define a ordem de exibição.
+ */
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CreditRecord {
+  pub artist: String,
+  pub role: crate::domain::media::credit::CreditRole,
+  pub position: u16,
+}
+
+/**
+ * Fonte de uma media. É encontrada pelo identificador canônico extraído da URL; o
+ * `external_id` apenas acompanha o registro.
+ */
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SourceRecord {
+  pub external_id: Option<String>,
+  pub media: String,
+  pub source_type: crate::domain::source::source_entity::SourceType,
+  pub src: String,
+  pub start_offset: Option<u32>,
+  pub duration: Option<u32>,
+}
+
+/**
+ * Versão mais recente da letra de uma media. Uma letra diferente da atual é
+ * importada como uma nova versão; o `external_id` apenas acompanha o registro.
+ */
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct LyricsRecord {
+  pub external_id: Option<String>,
+  pub media: String,
+  pub language: Option<String>,
+  pub content: String,
+
+  /**
+   * Linhas sincronizadas no formato LRC.
+   */
+  pub lrc: Option<String>,
+}
+
+/**
+ * Registro de exclusão, emitido pela exportação incremental para as entidades
+ * excluídas depois de `since`. A entidade é encontrada pelo `external_id` vinculado
+ * em uma importação anterior da mesma origem.
+ */
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DeletedRecord {
+  pub entity: CollaborativeEntityName,
+  pub external_id: String,
+}
+
+impl DeletedRecord {
+  /**
+   * Tipo da entidade excluída, como nos demais registros.
+   */
+  pub fn entity_name(&self) -> &'static str {
+    match self.entity {
+      CollaborativeEntityName::Genre => "genre",
+      CollaborativeEntityName::Artist => "artist",
+      CollaborativeEntityName::Album => "album",
+      CollaborativeEntityName::Media => "media",
+      CollaborativeEntityName::Source => "source",
+      CollaborativeEntityName::Lyrics => "lyrics",
+    }
+  }
+}
@@ -0,0 +1,60 @@
+use std::future::Future;
+
+use crate::shared::vo::{CollaborativeEntityName, UUID4};
+
+use super::ImportRecord;
+
+#[derive(Debug, Clone)]
+pub enum Error {
+  DatabaseError(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct ExportQuery {
+  pub entity_name: CollaborativeEntityName,
+
+  /**
+   * Exporta apenas as entidades alteradas depois deste momento.
+   */
+  pub since: Option<chrono::NaiveDateTime>,
+
+  /**
+   * Continua a partir da entidade com este identificador, exclusive.
+   */
+  pub after: Option<UUID4>,
+  pub limit: usize,
+}
+
+pub trait CatalogRepository {
+  /**
+   * Lista as entidades não excluídas do tipo informado em ordem de identificador,
+   * já no formato de importação, com as relações referenciadas por slug.
+   */
+  fn export(
+    &mut self,
+    query: &ExportQuery,
+  ) -> impl Future<Output = Result<Vec<(UUID4, ImportRecord)>, Error>>;
+
+  /**
+   * Busca a entidade vinculada ao identificador de uma base externa, como o MBID do
+   * MusicBrainz.
+   */
+  fn find_by_external_id(
+    &mut self,
+    origin: &str,
+    entity_name: &CollaborativeEntityName,
+    external_id: &str,
+  ) -> impl Future<Output = Result<Option<UUID4>, Error>>;
+
+  /**
+   * Vincula a entidade ao identificador da base externa, substituindo um vínculo
+   * anterior do mesmo identificador.
+   */
+  fn save_external_id(
+    &mut self,
+    origin: &str,
+    entity_name: &CollaborativeEntityName,
+    external_id: &str,
+    entity_id: &UUID4,
+  ) -> impl Future<Output = Result<(), Error>>;
+}
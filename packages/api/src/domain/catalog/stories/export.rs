@@ -0,0 +1,128 @@
+use std::collections::VecDeque;
+
+use crate::{
+  domain::catalog::{CatalogRepository, ExportQuery, ImportRecord},
+  shared::vo::{CollaborativeEntityName, UUID4},
+};
+
+/**
+ * Tipos exportáveis, na ordem em que a importação os processa.
+ */
+pub const EXPORTABLE: [CollaborativeEntityName; 6] = [
+  CollaborativeEntityName::Genre,
+  CollaborativeEntityName::Artist,
+  CollaborativeEntityName::Album,
+  CollaborativeEntityName::Media,
+  CollaborativeEntityName::Source,
+  CollaborativeEntityName::Lyrics,
+];
+
+#[derive(Debug, Clone)]
+pub enum Error {
+  UserIsNotACurator,
+  UnsupportedEntity(CollaborativeEntityName),
+  RepositoryError(crate::domain::catalog::Error),
+}
+
+#[derive(Debug, Clone)]
+pub struct Input {
+  pub actor: crate::domain::user::User,
+
+  /**
+   * Tipos a exportar. Vazio exporta todos.
+   */
+  pub entity_names: Vec<CollaborativeEntityName>,
+
+  /**
+   * Exportação incremental: apenas o que foi alterado depois deste momento. As
+   * entidades excluídas depois dele são exportadas como registros de exclusão.
+   */
+  pub since: Option<chrono::NaiveDateTime>,
+  pub batch_size: usize,
+}
+
+/**
+ * Exportação em andamento. O catálogo é lido em lotes, para que nunca precise
+ * caber inteiro na memória.
+ */
+#[derive(Debug, Clone)]
+pub struct Export {
+  entity_names: VecDeque<CollaborativeEntityName>,
+  since: Option<chrono::NaiveDateTime>,
+  after: Option<UUID4>,
+  batch_size: usize,
+}
+
+impl Export {
+  /**
+   * Lê o próximo lote de registros, ou `None` quando não há mais nada a exportar.
+   * Uma falha encerra a exportação.
+   */
+  pub async fn next_batch(
+    &mut self,
+    catalog_repository: &mut impl CatalogRepository,
+  ) -> Result<Option<Vec<ImportRecord>>, Error> {
+    while let Some(entity_name) = self.entity_names.front() {
+      let query = ExportQuery {
+        entity_name: entity_name.clone(),
+        since: self.since,
+        after: self.after.clone(),
+        limit: self.batch_size,
+      };
+
+      let records = match catalog_repository.export(&query).await {
+        Ok(records) => records,
+        Err(error) => {
+          self.entity_names.clear();
+          return Err(Error::RepositoryError(error));
+        }
+      };
+
+      if records.len() < self.batch_size {
+        self.entity_names.pop_front();
+        self.after = None;
+      } else {
+        self.after = records.last().map(|(id, _)| id.clone());
+      }
+
+      if !records.is_empty() {
+        return Ok(Some(
+          records.into_iter().map(|(_, record)| record).collect(),
+        ));
+      }
+    }
+
+    Ok(None)
+  }
+}
+
+/**
+ * Inicia a exportação do catálogo no formato da importação, um tipo de entidade
+ * por vez e na ordem em que a importação os processa, para que o resultado possa
+ * ser importado novamente.
+ */
+pub fn execute(input: Input) -> Result<Export, Error> {
+  if !input.actor.is_curator() {
+    return Err(Error::UserIsNotACurator);
+  }
+
+  if let Some(entity_name) = input
+    .entity_names
+    .iter()
+    .find(|entity_name| !EXPORTABLE.contains(entity_name))
+  {
+    return Err(Error::UnsupportedEntity(entity_name.clone()));
+  }
+
+  let entity_names = EXPORTABLE
+    .into_iter()
+    .filter(|entity_name| input.entity_names.is_empty() || input.entity_names.contains(entity_name))
+    .collect();
+
+  Ok(Export {
+    entity_names,
+    since: input.since,
+    after: None,
+    batch_size: input.batch_size.max(1),
+  })
+}
@@ -0,0 +1,2452 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+  domain::{
+    album::repository::AlbumRepository,
+    artist::repository::ArtistRepository,
+    catalog::{
+      slug_or_generate, AlbumRecord, ArtistRecord, CatalogRepository, CreditRecord, DeletedRecord,
+      GenreRecord, ImportRecord, LyricsRecord, MediaRecord, MemberRecord, SourceRecord,
+    },
+    genre::GenreRepository,
+    lyrics::repository::LyricsRepository,
+    media::repository::MediaRepository,
+    source::repository::SourceRepository,
+  },
+  shared::vo::{CollaborativeEntityName, Slug, UUID4},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mode {
+  /**
+   * Apenas relata o que seria criado ou atualizado, sem gravar nada.
+   */
+  DryRun,
+
+  /**
+   * Cria uma atividade pendente por registro, a ser revisada pelos curadores.
+   */
+  Propose,
+
+  /**
+   * Cria as atividades e as aprova imediatamente em nome do curador.
+   */
+  Apply,
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+  UserIsNotACurator,
+  GenreRepositoryError(crate::domain::genre::Error),
+  ArtistRepositoryError(crate::domain::artist::repository::Error),
+  AlbumRepositoryError(crate::domain::album::repository::Error),
+  MediaRepositoryError(crate::domain::media::repository::Error),
+  SourceRepositoryError(crate::domain::source::repository::Error),
+  LyricsRepositoryError(crate::domain::lyrics::repository::Error),
+  CatalogRepositoryError(crate::domain::catalog::Error),
+}
+
+/**
+ * Motivo pelo qual um registro não foi importado. Não interrompe o restante do lote.
+ */
+#[derive(Debug, Clone)]
+pub enum RecordError {
+  UnresolvedReference {
+    field: &'static str,
+    reference: String,
+  },
+  MissingArtist,
+  InvalidSourceUrl(crate::domain::source::source_url::Error),
+  GenreContributionError(crate::domain::genre::stories::contribute::Error),
+  ArtistContributionError(crate::domain::artist::stories::contribute::Error),
+  AlbumContributionError(crate::domain::album::stories::contribute::Error),
+  MediaContributionError(crate::domain::media::stories::contribute::Error),
+  SourceContributionError(crate::domain::source::stories::contribute::Error),
+  LyricsContributionError(crate::domain::lyrics::stories::contribute::Error),
+
+  /**
+   * A atividade foi criada, mas continua pendente.
+   */
+  ApproveError {
+    activity_id: UUID4,
+    error: crate::domain::activity::stories::approve::Error,
+  },
+}
+
+#[derive(Debug, Clone)]
+pub enum Outcome {
+  Create,
+
+  /**
+   * Campos que serão alterados no registro existente.
+   */
+  Update(Vec<String>),
+
+  /**
+   * O registro já existe, com os mesmos dados, ou já apareceu antes no arquivo.
+   */
+  Unchanged,
+
+  /**
+   * A entidade vinculada ao registro de exclusão será excluída.
+   */
+  Delete,
+  Failed(RecordError),
+}
+
+#[derive(Debug, Clone)]
+pub struct Entry {
+  /**
+   * Linha do registro no arquivo importado.
+   */
+  pub line: usize,
+  pub entity: &'static str,
+  pub key: String,
+  pub outcome: Outcome,
+
+  /**
+   * Atividade criada para o registro. Nunca é preenchida em uma simulação.
+   */
+  pub activity_id: Option<UUID4>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Report {
+  pub entries: Vec<Entry>,
+}
+
+impl Report {
+  fn count(&self, predicate: impl Fn(&Outcome) -> bool) -> usize {
+    self
+      .entries
+      .iter()
+      .filter(|entry| predicate(&entry.outcome))
+      .count()
+  }
+
+  pub fn created(&self) -> usize {
+    self.count(|outcome| matches!(outcome, Outcome::Create))
+  }
+
+  pub fn updated(&self) -> usize {
+    self.count(|outcome| matches!(outcome, Outcome::Update(_)))
+  }
+
+  pub fn unchanged(&self) -> usize {
+    self.count(|outcome| matches!(outcome, Outcome::Unchanged))
+  }
+
+  pub fn deleted(&self) -> usize {
+    self.count(|outcome| matches!(outcome, Outcome::Delete))
+  }
+
+  pub fn failed(&self) -> usize {
+    self.count(|outcome| matches!(outcome, Outcome::Failed(_)))
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct Input {
+  pub actor: crate::domain::user::User,
+  pub mode: Mode,
+
+  /**
+   * Registros a importar, acompanhados da linha em que aparecem no arquivo.
+   */
+  pub records: Vec<(usize, ImportRecord)>,
+  pub deletion_impact_threshold: u64,
+
+  /**
+   * Base de onde vêm os `external_id` dos registros, como `musicbrainz`. Quando
+   * informada, os identificadores são vinculados às entidades gravadas no modo de
+   * aplicação, e uma nova importação da mesma base os reconhece mesmo que nome ou slug tenham mudado.
+   */
+  pub origin: Option<String>,
+}
+
+/**
+ * Falha ao importar um registro: erros de repositório interrompem a importação,
+ * os demais são registrados no relatório.
+ */
+enum Failure {
+  Fatal(Error),
+  Record(RecordError),
+}
+
+impl From<Error> for Failure {
+  fn from(error: Error) -> Self {
+    Failure::Fatal(error)
+  }
+}
+
+impl From<RecordError> for Failure {
+  fn from(error: RecordError) -> Self {
+    Failure::Record(error)
+  }
+}
+
+/**
+ * Decisão tomada para um registro após compará-lo com o que já existe.
+ */
+enum Decision<C, U> {
+  Create(C),
+  Update { id: UUID4, changes: U },
+  Unchanged(UUID4),
+}
+
+impl<C, U: serde::Serialize> Decision<C, U> {
+  fn outcome(&self) -> Outcome {
+    match self {
+      Decision::Create(_) => Outcome::Create,
+      Decision::Update { changes, .. } => Outcome::Update(changed_fields(changes)),
+      Decision::Unchanged(_) => Outcome::Unchanged,
+    }
+  }
+
+  fn existing_id(&self) -> Option<UUID4> {
+    match self {
+      Decision::Create(_) => None,
+      Decision::Update { id, .. } | Decision::Unchanged(id) => Some(id.clone()),
+    }
+  }
+
+  fn into_change_input<I>(
+    self,
+    create: impl FnOnce(C) -> I,
+    update: impl FnOnce(UUID4, U) -> I,
+  ) -> Option<I> {
+    match self {
+      Decision::Create(input) => Some(create(input)),
+      Decision::Update { id, changes } => Some(update(id, changes)),
+      Decision::Unchanged(_) => None,
+    }
+  }
+}
+
+fn changed_fields(changes: &impl serde::Serialize) -> Vec<String> {
+  match serde_json::to_value(changes) {
+    Ok(serde_json::Value::Object(fields)) => fields
+      .into_iter()
+      .filter(|(_, value)| !value.is_null())
+      .map(|(field, _)| field)
+      .collect(),
+    _ => Vec::new(),
+  }
+}
+
+fn differs<T: PartialEq + Clone>(current: &T, wanted: Option<&T>) -> Option<T> {
+  wanted.filter(|wanted| *wanted != current).cloned()
+}
+
+fn differs_optional<T: PartialEq + Clone>(current: &Option<T>, wanted: &Option<T>) -> Option<T> {
+  wanted
+    .as_ref()
+    .filter(|wanted| current.as_ref() != Some(*wanted))
+    .cloned()
+}
+
+#[derive(Debug, Clone)]
+struct Resolved {
+  id: UUID4,
+  slug: Slug,
+}
+
+/**
+ * Entidades já resolvidas durante a importação, indexadas pela chave canônica e pelo
+ * `external_id` informado no arquivo ou vinculado em uma importação anterior.
+ */
+#[derive(Default)]
+struct Known(HashMap<String, Resolved>);
+
+impl Known {
+  fn get(&self, entity: &str, reference: &str) -> Option<Resolved> {
+    self.0.get(&format!("{}:{}", entity, reference)).cloned()
+  }
+
+  fn insert(&mut self, entity: &str, key: &str, external_id: &Option<String>, resolved: Resolved) {
+    if let Some(external_id) = external_id {
+      self
+        .0
+        .insert(format!("{}:{}", entity, external_id), resolved.clone());
+    }
+
+    self.0.insert(format!("{}:{}", entity, key), resolved);
+  }
+
+  /**
+   * Entidade vinculada ao `external_id` do próprio registro.
+   */
+  fn linked(&self, entity: &str, external_id: &Option<String>) -> Option<Resolved> {
+    external_id
+      .as_ref()
+      .and_then(|external_id| self.get(entity, external_id))
+  }
+}
+
+fn unresolved(field: &'static str, reference: &str) -> RecordError {
+  RecordError::UnresolvedReference {
+    field,
+    reference: reference.to_string(),
+  }
+}
+
+/**
+ * Separa uma referência no formato `<slug do artista>/<slug>`.
+ */
+fn split_reference(reference: &str) -> Option<(Slug, Slug)> {
+  let (artist_slug, slug) = reference.split_once('/')?;
+  Some((Slug::new(slug).ok()?, Slug::new(artist_slug).ok()?))
+}
+
+async fn resolve_genre(
+  genre_repository: &mut impl GenreRepository,
+  known: &Known,
+  reference: &str,
+) -> Result<Option<Resolved>, Error> {
+  if let Some(resolved) = known.get("genre", reference) {
+    return Ok(Some(resolved));
+  }
+
+  let Ok(slug) = Slug::new(reference) else {
+    return Ok(None);
+  };
+
+  let genre = genre_repository
+    .find_genre_and_subgenre_by_slug(&slug)
+    .await
+    .map_err(Error::GenreRepositoryError)?;
+
+  Ok(genre.map(|genre| Resolved {
+    id: genre.genre().id().clone(),
+    slug: genre.genre().slug().clone(),
+  }))
+}
+
+async fn resolve_artist(
+  artist_repository: &mut impl ArtistRepository,
+  known: &Known,
+  reference: &str,
+) -> Result<Option<Resolved>, Error> {
+  if let Some(resolved) = known.get("artist", reference) {
+    return Ok(Some(resolved));
+  }
+
+  let Ok(slug) = Slug::new(reference) else {
+    return Ok(None);
+  };
+
+  let artist = artist_repository
+    .find_by_slug(&slug)
+    .await
+    .map_err(Error::ArtistRepositoryError)?;
+
+  Ok(artist.map(|artist| Resolved {
+    id: artist.id().clone(),
+    slug: artist.slug().clone(),
+  }))
+}
+
+async fn resolve_album(
+  album_repository: &mut impl AlbumRepository,
+  known: &Known,
+  reference: &str,
+) -> Result<Option<Resolved>, Error> {
+  if let Some(resolved) = known.get("album", reference) {
+    return Ok(Some(resolved));
+  }
+
+  let Some((slug, artist_slug)) = split_reference(reference) else {
+    return Ok(None);
+  };
+
+  let album = album_repository
+    .find_by_slug(&slug, &artist_slug)
+    .await
+    .map_err(Error::AlbumRepositoryError)?;
+
+  Ok(album.map(|album| Resolved {
+    id: album.album().id().clone(),
+    slug: album.album().slug().clone(),
+  }))
+}
+
+async fn resolve_media(
+  media_repository: &mut impl MediaRepository,
+  known: &Known,
+  reference: &str,
+) -> Result<Option<Resolved>, Error> {
+  if let Some(resolved) = known.get("media", reference) {
+    return Ok(Some(resolved));
+  }
+
+  let Some((slug, artist_slug)) = split_reference(reference) else {
+    return Ok(None);
+  };
+
+  let media = media_repository
+    .find_by_slug(&slug, &artist_slug)
+    .await
+    .map_err(Error::MediaRepositoryError)?;
+
+  Ok(media.map(|media| Resolved {
+    id: media.media.id().clone(),
+    slug: media.media.slug().clone(),
+  }))
+}
+
+async fn resolve_artists(
+  artist_repository: &mut impl ArtistRepository,
+  known: &Known,
+  field: &'static str,
+  references: &[String],
+) -> Result<Vec<Resolved>, Failure> {
+  let mut resolved = Vec::with_capacity(references.len());
+
+  for reference in references {
+    resolved.push(
+      resolve_artist(artist_repository, known, reference)
+        .await?
+        .ok_or_else(|| unresolved(field, reference))?,
+    );
+  }
+
+  Ok(resolved)
+}
+
+async fn resolve_genres(
+  genre_repository: &mut impl GenreRepository,
+  known: &Known,
+  references: &[String],
+) -> Result<HashSet<UUID4>, Failure> {
+  let mut ids = HashSet::new();
+
+  for reference in references {
+    let genre = resolve_genre(genre_repository, known, reference)
+      .await?
+      .ok_or_else(|| unresolved("genres", reference))?;
+    ids.insert(genre.id);
+  }
+
+  Ok(ids)
+}
+
+async fn resolve_albums(
+  album_repository: &mut impl AlbumRepository,
+  known: &Known,
+  references: &[String],
+) -> Result<HashSet<UUID4>, Failure> {
+  let mut ids = HashSet::new();
+
+  for reference in references {
+    let album = resolve_album(album_repository, known, reference)
+      .await?
+      .ok_or_else(|| unresolved("albums", reference))?;
+    ids.insert(album.id);
+  }
+
+  Ok(ids)
+}
+
+async fn resolve_members(
+  artist_repository: &mut impl ArtistRepository,
+  known: &Known,
+  members: &[MemberRecord],
+) -> Result<Vec<crate::domain::artist::ArtistMember>, Failure> {
+  let mut resolved = Vec::with_capacity(members.len());
+
+  for member in members {
+    let artist = resolve_artist(artist_repository, known, &member.artist)
+      .await?
+      .ok_or_else(|| unresolved("members", &member.artist))?;
+
+    resolved.push(crate::domain::artist::ArtistMember {
+      artist_id: artist.id,
+      started_at: member.started_at,
+      ended_at: member.ended_at,
+    });
+  }
+
+  Ok(resolved)
+}
+
+/**
+ * Resolve os créditos na ordem de suas posições.
+ */
+async fn resolve_credits(
+  artist_repository: &mut impl ArtistRepository,
+  known: &Known,
+  credits: &[CreditRecord],
+) -> Result<Vec<crate::domain::media::credit::Credit>, Failure> {
+  let mut credits = credits.iter().collect::<Vec<_>>();
+  credits.sort_by_key(|credit| credit.position);
+
+  let mut resolved = Vec::with_capacity(credits.len());
+
+  for credit in credits {
+    let artist = resolve_artist(artist_repository, known, &credit.artist)
+      .await?
+      .ok_or_else(|| unresolved("credits", &credit.artist))?;
+
+    resolved.push(crate::domain::media::credit::Credit {
+      artist_id: artist.id,
+      role: credit.role.clone(),
+    });
+  }
+
+  Ok(resolved)
+}
+
+fn ids(resolved: &[Resolved]) -> HashSet<UUID4> {
+  resolved
+    .iter()
+    .map(|resolved| resolved.id.clone())
+    .collect()
+}
+
+async fn plan_genre(
+  genre_repository: &mut impl GenreRepository,
+  known: &Known,
+  record: &GenreRecord,
+) -> Result<
+  Decision<
+    crate::domain::genre::stories::contribute::CreateGenreInput,
+    crate::domain::genre::contribution::changes::Changes,
+  >,
+  Failure,
+> {
+  let slug = slug_or_generate(&record.slug, &record.name);
+
+  let parent_id = match &record.parent {
+    Some(parent) => Some(
+      resolve_genre(genre_repository, known, parent)
+        .await?
+        .ok_or_else(|| unresolved("parent", parent))?
+        .id,
+    ),
+    None => None,
+  };
+
+  if let Some(resolved) = known.get("genre", &slug.to_string()) {
+    return Ok(Decision::Unchanged(resolved.id));
+  }
+
+  let linked = match known.linked("genre", &record.external_id) {
+    Some(linked) => genre_repository
+      .find_by_id(&linked.id)
+      .await
+      .map_err(Error::GenreRepositoryError)?,
+    None => None,
+  };
+
+  let genre = match linked {
+    Some(genre) => Some(genre),
+    None => genre_repository
+      .find_genre_and_subgenre_by_slug(&slug)
+      .await
+      .map_err(Error::GenreRepositoryError)?
+      .map(|genre| genre.genre().clone()),
+  };
+
+  let Some(genre) = genre else {
+    return Ok(Decision::Create(
+      crate::domain::genre::stories::contribute::CreateGenreInput {
+        name: record.name.clone(),
+        slug: Some(slug),
+        parent_id,
+      },
+    ));
+  };
+
+  let changes = crate::domain::genre::contribution::changes::Changes {
+    name: differs(genre.name(), Some(&record.name)),
+    parent_id: record
+      .parent
+      .as_ref()
+      .and(differs(genre.parent_id(), Some(&parent_id))),
+    ..Default::default()
+  };
+
+  Ok(decide(genre.id(), changes))
+}
+
+async fn plan_artist(
+  artist_repository: &mut impl ArtistRepository,
+  known: &Known,
+  record: &ArtistRecord,
+) -> Result<
+  Decision<
+    crate::domain::artist::stories::contribute::CreateArtistInput,
+    crate::domain::artist::contribution::changes::Changes,
+  >,
+  Failure,
+> {
+  let members = match &record.members {
+    Some(members) => Some(resolve_members(artist_repository, known, members).await?),
+    None => None,
+  };
+
+  let slug = slug_or_generate(&record.slug, &record.name);
+
+  if let Some(resolved) = known.get("artist", &slug.to_string()) {
+    return Ok(Decision::Unchanged(resolved.id));
+  }
+
+  let linked = match known.linked("artist", &record.external_id) {
+    Some(linked) => artist_repository
+      .find_by_id(&linked.id)
+      .await
+      .map_err(Error::ArtistRepositoryError)?,
+    None => None,
+  };
+
+  let artist = match linked {
+    Some(artist) => Some(artist),
+    None => artist_repository
+      .find_by_slug(&slug)
+      .await
+      .map_err(Error::ArtistRepositoryError)?,
+  };
+
+  let Some(artist) = artist else {
+    return Ok(Decision::Create(
+      crate::domain::artist::stories::contribute::CreateArtistInput {
+        name: record.name.clone(),
+        slug: Some(slug),
+        country: record.country.clone(),
+        artist_type: record.artist_type.clone(),
+        aliases: record
+          .aliases
+          .as_ref()
+          .map(|aliases| aliases.iter().cloned().collect()),
+        members,
+      },
+    ));
+  };
+
+  let changes = crate::domain::artist::contribution::changes::Changes {
+    name: differs(artist.name(), Some(&record.name)),
+    country: differs_optional(artist.country(), &record.country),
+    artist_type: differs(artist.artist_type(), record.artist_type.as_ref()),
+    aliases: differs(
+      artist.aliases(),
+      record
+        .aliases
+        .as_ref()
+        .map(|aliases| aliases.iter().cloned().collect())
+        .as_ref(),
+    ),
+    members: differs(artist.members(), members.as_ref()),
+    ..Default::default()
+  };
+
+  Ok(decide(artist.id(), changes))
+}
+
+async fn plan_album(
+  album_repository: &mut impl AlbumRepository,
+  artist_repository: &mut impl ArtistRepository,
+  known: &Known,
+  record: &AlbumRecord,
+) -> Result<
+  (
+    String,
+    Decision<
+      crate::domain::album::stories::contribute::CreateInput,
+      crate::domain::album::contribution::changes::Changes,
+    >,
+  ),
+  Failure,
+> {
+  let artists = resolve_artists(artist_repository, known, "artists", &record.artists).await?;
+  let main_artist = artists.first().ok_or(RecordError::MissingArtist)?;
+
+  let slug = slug_or_generate(&record.slug, &record.name);
+  let key = format!("{}/{}", main_artist.slug, slug);
+  let artist_ids = ids(&artists);
+
+  let release_group_id = match &record.release_group {
+    Some(reference) => {
+      let album = resolve_album(album_repository, known, reference)
+        .await?
+        .ok_or_else(|| unresolved("release_group", reference))?;
+
+      // O álbum referenciado pode fazer parte de um grupo inaugurado por outro álbum.
+      let release_group_id = album_repository
+        .find_by_id(&album.id)
+        .await
+        .map_err(Error::AlbumRepositoryError)?
+        .map(|album| album.release_group_id().clone());
+
+      Some(release_group_id.unwrap_or(album.id))
+    }
+    None => None,
+  };
+
+  if let Some(resolved) = known.get("album", &key) {
+    return Ok((key, Decision::Unchanged(resolved.id)));
+  }
+
+  let linked = match known.linked("album", &record.external_id) {
+    Some(linked) => album_repository
+      .find_by_id(&linked.id)
+      .await
+      .map_err(Error::AlbumRepositoryError)?,
+    None => None,
+  };
+
+  let album = match linked {
+    Some(album) => Some(album),
+    None => album_repository
+      .find_by_slug(&slug, &main_artist.slug)
+      .await
+      .map_err(Error::AlbumRepositoryError)?
+      .map(|album| album.album().clone()),
+  };
+
+  let Some(album) = album else {
+    return Ok((
+      key,
+      Decision::Create(crate::domain::album::stories::contribute::CreateInput {
+        name: record.name.clone(),
+        slug: Some(slug),
+        cover: record.cover.clone(),
+        album_type: record.album_type.clone(),
+        release_date: record.release_date,
+        parental_rating: record.parental_rating,
+        artist_ids,
+        label: record.label.clone(),
+        catalog_number: record.catalog_number.clone(),
+        barcode: record.barcode.clone(),
+        release_country: record.release_country.clone(),
+        release_group_id,
+        edition: record.edition.clone(),
+      }),
+    ));
+  };
+
+  let changes = crate::domain::album::contribution::changes::Changes {
+    name: differs(album.name(), Some(&record.name)),
+    cover: differs_optional(album.cover(), &record.cover),
+    album_type: differs(album.album_type(), Some(&record.album_type)),
+    release_date: differs_optional(album.release_date(), &record.release_date),
+    parental_rating: differs(album.parental_rating(), record.parental_rating.as_ref()),
+    artist_ids: differs(album.artist_ids(), Some(&artist_ids)),
+    label: differs_optional(album.label(), &record.label),
+    catalog_number: differs_optional(album.catalog_number(), &record.catalog_number),
+    barcode: differs_optional(album.barcode(), &record.barcode),
+    release_country: differs_optional(album.release_country(), &record.release_country),
+    edition: differs_optional(album.edition(), &record.edition),
+    release_group_id: differs(album.release_group_id(), release_group_id.as_ref()),
+    ..Default::default()
+  };
+
+  Ok((key, decide(album.id(), changes)))
+}
+
+async fn plan_media(
+  media_repository: &mut impl MediaRepository,
+  artist_repository: &mut impl ArtistRepository,
+  genre_repository: &mut impl GenreRepository,
+  album_repository: &mut impl AlbumRepository,
+  known: &Known,
+  record: &MediaRecord,
+) -> Result<
+  (
+    String,
+    Decision<
+      crate::domain::media::stories::contribute::CreateMediaInput,
+      crate::domain::media::contribution::changes::Changes,
+    >,
+  ),
+  Failure,
+> {
+  let interpreters = resolve_artists(
+    artist_repository,
+    known,
+    "interpreters",
+    &record.interpreters,
+  )
+  .await?;
+  let main_artist = interpreters.first().ok_or(RecordError::MissingArtist)?;
+  let interpreter_ids = ids(&interpreters);
+
+  let composer_ids = match &record.composers {
+    Some(composers) => Some(ids(
+      &resolve_artists(artist_repository, known, "composers", composers).await?,
+    )),
+    None => None,
+  };
+
+  let genre_ids = match &record.genres {
+    Some(genres) => Some(resolve_genres(genre_repository, known, genres).await?),
+    None => None,
+  };
+
+  let album_ids = match &record.albums {
+    Some(albums) => Some(resolve_albums(album_repository, known, albums).await?),
+    None => None,
+  };
+
+  let credits = match &record.credits {
+    Some(credits) => Some(resolve_credits(artist_repository, known, credits).await?),
+    None => None,
+  };
+
+  let slug = slug_or_generate(&record.slug, &record.name);
+  let key = format!("{}/{}", main_artist.slug, slug);
+
+  if let Some(resolved) = known.get("media", &key) {
+    return Ok((key, Decision::Unchanged(resolved.id)));
+  }
+
+  let linked = match known.linked("media", &record.external_id) {
+    Some(linked) => media_repository
+      .find_by_id(&linked.id)
+      .await
+      .map_err(Error::MediaRepositoryError)?,
+    None => None,
+  };
+
+  let media = match linked {
+    Some(media) => Some(media),
+    None => media_repository
+      .find_by_slug(&slug, &main_artist.slug)
+      .await
+      .map_err(Error::MediaRepositoryError)?
+      .map(|media| media.media),
+  };
+
+  let Some(media) = media else {
+    return Ok((
+      key,
+      Decision::Create(
+        crate::domain::media::stories::contribute::CreateMediaInput {
+          name: record.name.clone(),
+          media_type: record.media_type.clone(),
+          slug: Some(slug),
+          release_date: record.release_date,
+          cover: record.cover.clone(),
+          genre_ids,
+          parental_rating: record.parental_rating,
+          duration: record.duration,
+          isrc: record.isrc.clone(),
+          bpm: record.bpm,
+          key: record.key.clone(),
+          language: record.language.clone(),
+          composer_ids,
+          interpreter_ids,
+          album_ids: album_ids.unwrap_or_default(),
+          credits,
+          is_single: record.is_single,
+        },
+      ),
+    ));
+  };
+
+  let changes = crate::domain::media::contribution::changes::Changes {
+    name: differs(media.name(), Some(&record.name)),
+    media_type: differs(media.media_type(), Some(&record.media_type)),
+    release_date: differs_optional(media.release_date(), &record.release_date),
+    cover: differs_optional(media.cover(), &record.cover).map(Some),
+    genre_ids: differs(media.genre_ids(), genre_ids.as_ref()),
+    parental_rating: differs(media.parental_rating(), record.parental_rating.as_ref()),
+    duration: differs_optional(media.duration(), &record.duration),
+    isrc: differs_optional(media.isrc(), &record.isrc),
+    bpm: differs_optional(media.bpm(), &record.bpm),
+    key: differs_optional(media.key(), &record.key),
+    language: differs_optional(media.language(), &record.language),
+    composer_ids: differs(media.composer_ids(), composer_ids.as_ref()),
+    interpreter_ids: differs(media.interpreter_ids(), Some(&interpreter_ids)),
+    album_ids: differs(media.album_ids(), album_ids.as_ref()),
+    is_single: differs(media.is_single(), record.is_single.as_ref()),
+    credits: differs(media.credits(), credits.as_ref()),
+    ..Default::default()
+  };
+
+  Ok((key, decide(media.id(), changes)))
+}
+
+async fn plan_source(
+  source_repository: &mut impl SourceRepository,
+  media_repository: &mut impl MediaRepository,
+  known: &Known,
+  record: &SourceRecord,
+) -> Result<
+  (
+    String,
+    Decision<
+      crate::domain::source::stories::contribute::CreateSourceInput,
+      crate::domain::source::contribution::changes::Changes,
+    >,
+  ),
+  Failure,
+> {
+  let media = resolve_media(media_repository, known, &record.media)
+    .await?
+    .ok_or_else(|| unresolved("media", &record.media))?;
+
+  let canonical = crate::domain::source::source_url::parse(&record.source_type, &record.src)
+    .map_err(RecordError::InvalidSourceUrl)?;
+
+  let key = format!(
+    "{}/{}/{}",
+    media.id, record.source_type, canonical.external_id
+  );
+
+  if let Some(resolved) = known.get("source", &key) {
+    return Ok((key, Decision::Unchanged(resolved.id)));
+  }
+
+  let source = source_repository
+    .find_by_external_id(&media.id, &record.source_type, &canonical.external_id)
+    .await
+    .map_err(Error::SourceRepositoryError)?;
+
+  match source {
+    Some(source) => Ok((key, Decision::Unchanged(source.id().clone()))),
+    None => Ok((
+      key,
+      Decision::Create(
+        crate::domain::source::stories::contribute::CreateSourceInput {
+          source_type: record.source_type.clone(),
+          src: record.src.clone(),
+          media_id: media.id,
+          start_offset: record.start_offset,
+          duration: record.duration,
+        },
+      ),
+    )),
+  }
+}
+
+/**
+ * A letra é comparada com a versão mais recente da media; qualquer diferença gera
+ * uma nova versão.
+ */
+async fn plan_lyrics(
+  lyrics_repository: &mut impl LyricsRepository,
+  media_repository: &mut impl MediaRepository,
+  known: &Known,
+  record: &LyricsRecord,
+) -> Result<Decision<crate::domain::lyrics::stories::contribute::CreateLyricsInput, ()>, Failure> {
+  let media = resolve_media(media_repository, known, &record.media)
+    .await?
+    .ok_or_else(|| unresolved("media", &record.media))?;
+
+  let current = lyrics_repository
+    .find_by_media_id(&media.id, None)
+    .await
+    .map_err(Error::LyricsRepositoryError)?
+    .filter(|current| {
+      current.content() == &record.content
+        && current.language() == &record.language
+        && current
+          .is_synced()
+          .then(|| crate::domain::lyrics::lyrics_entity::LyricLine::to_lrc(current.lines()))
+          == record.lrc
+    });
+
+  match current {
+    Some(current) => Ok(Decision::Unchanged(current.id().clone())),
+    None => Ok(Decision::Create(
+      crate::domain::lyrics::stories::contribute::CreateLyricsInput {
+        media_id: media.id,
+        language: record.language.clone(),
+        content: Some(record.content.clone()),
+        lrc: record.lrc.clone(),
+      },
+    )),
+  }
+}
+
+/**
+ * Propõe a exclusão da entidade vinculada a um registro de exclusão.
+ */
+#[allow(clippy::too_many_arguments)]
+async fn propose_deletion(
+  genre_repository: &mut impl GenreRepository,
+  artist_repository: &mut impl ArtistRepository,
+  album_repository: &mut impl AlbumRepository,
+  media_repository: &mut impl MediaRepository,
+  source_repository: &mut impl SourceRepository,
+  lyrics_repository: &mut impl LyricsRepository,
+  activity_repository: &mut impl crate::domain::activity::repository::ActivityRepository,
+  actor_id: UUID4,
+  record: &DeletedRecord,
+  id: UUID4,
+) -> Result<crate::domain::activity::Activity, RecordError> {
+  use crate::domain::{album, artist, genre, lyrics, media, source};
+
+  match record.entity {
+    CollaborativeEntityName::Genre => genre::stories::contribute::execute(
+      genre_repository,
+      activity_repository,
+      genre::stories::contribute::Input {
+        actor_id,
+        data: genre::stories::contribute::ChangeInput::Delete(id),
+      },
+    )
+    .await
+    .map_err(RecordError::GenreContributionError),
+    CollaborativeEntityName::Artist => artist::stories::contribute::execute(
+      artist_repository,
+      activity_repository,
+      artist::stories::contribute::Input {
+        actor_id,
+        data: artist::stories::contribute::ChangeInput::Delete(id),
+      },
+    )
+    .await
+    .map_err(RecordError::ArtistContributionError),
+    CollaborativeEntityName::Album => album::stories::contribute::execute(
+      album_repository,
+      artist_repository,
+      activity_repository,
+      album::stories::contribute::Input {
+        actor_id,
+        data: album::stories::contribute::ChangeInput::Delete(id),
+      },
+    )
+    .await
+    .map_err(RecordError::AlbumContributionError),
+    CollaborativeEntityName::Media => media::stories::contribute::execute(
+      media_repository,
+      artist_repository,
+      genre_repository,
+      album_repository,
+      activity_repository,
+      media::stories::contribute::Input {
+        actor_id,
+        data: media::stories::contribute::ChangeInput::Delete(id),
+      },
+    )
+    .await
+    .map_err(RecordError::MediaContributionError),
+    CollaborativeEntityName::Source => source::stories::contribute::execute(
+      source_repository,
+      activity_repository,
+      source::stories::contribute::Input {
+        actor_id,
+        data: source::stories::contribute::ChangeInput::Delete(id),
+      },
+    )
+    .await
+    .map_err(RecordError::SourceContributionError),
+    CollaborativeEntityName::Lyrics => lyrics::stories::contribute::execute(
+      lyrics_repository,
+      media_repository,
+      activity_repository,
+      lyrics::stories::contribute::Input {
+        actor_id,
+        data: lyrics::stories::contribute::ChangeInput::Delete(id),
+      },
+    )
+    .await
+    .map_err(RecordError::LyricsContributionError),
+  }
+}
+
+fn decide<C, U: Default + PartialEq>(id: &UUID4, changes: U) -> Decision<C, U> {
+  if changes == U::default() {
+    Decision::Unchanged(id.clone())
+  } else {
+    Decision::Update {
+      id: id.clone(),
+      changes,
+    }
+  }
+}
+
+/**
+ * Identificador da entidade criada pela atividade.
+ */
+fn created_id(activity: &crate::domain::activity::Activity) -> Option<UUID4> {
+  use crate::shared::vo::Contribution;
+
+  match activity.contribution() {
+    Contribution::Genre(crate::domain::genre::contribution::Contribution::Create(genre)) => {
+      Some(genre.id().clone())
+    }
+    Contribution::Artist(crate::domain::artist::contribution::Contribution::Create(artist)) => {
+      Some(artist.id().clone())
+    }
+    Contribution::Album(crate::domain::album::contribution::Contribution::Create(album)) => {
+      Some(album.id().clone())
+    }
+    Contribution::Media(crate::domain::media::contribution::Contribution::Create(media)) => {
+      Some(media.id().clone())
+    }
+    Contribution::Source(crate::domain::source::contribution::Contribution::Create(source)) => {
+      Some(source.id().clone())
+    }
+    Contribution::Lyrics(crate::domain::lyrics::contribution::Contribution::Create(lyrics)) => {
+      Some(lyrics.id().clone())
+    }
+    _ => None,
+  }
+}
+
+/**
+ * Resultado da importação de um registro.
+ */
+struct Imported {
+  outcome: Outcome,
+  activity: Option<crate::domain::activity::Activity>,
+
+  /**
+   * Entidade correspondente ao registro, para que os registros seguintes possam referenciá-la.
+   */
+  key: String,
+  resolved: Resolved,
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn import_record(
+  genre_repository: &mut impl GenreRepository,
+  artist_repository: &mut impl ArtistRepository,
+  album_repository: &mut impl AlbumRepository,
+  media_repository: &mut impl MediaRepository,
+  source_repository: &mut impl SourceRepository,
+  lyrics_repository: &mut impl LyricsRepository,
+  activity_repository: &mut impl crate::domain::activity::repository::ActivityRepository,
+  known: &Known,
+  input: &Input,
+  record: &ImportRecord,
+) -> Result<Imported, Failure> {
+  let propose = input.mode != Mode::DryRun;
+  let actor_id = input.actor.id().clone();
+
+  let (mut key, mut slug, outcome, existing_id, activity) = match record {
+    ImportRecord::Genre(record) => {
+      let decision = plan_genre(genre_repository, known, record).await?;
+      let (outcome, existing_id) = (decision.outcome(), decision.existing_id());
+
+      let activity = match decision.into_change_input(
+        crate::domain::genre::stories::contribute::ChangeInput::Create,
+        |id, changes| crate::domain::genre::stories::contribute::ChangeInput::Update {
+          id,
+          changes,
+        },
+      ) {
+        Some(data) if propose => Some(
+          crate::domain::genre::stories::contribute::execute(
+            genre_repository,
+            activity_repository,
+            crate::domain::genre::stories::contribute::Input { actor_id, data },
+          )
+          .await
+          .map_err(RecordError::GenreContributionError)?,
+        ),
+        _ => None,
+      };
+
+      let slug = slug_or_generate(&record.slug, &record.name);
+      (slug.to_string(), slug, outcome, existing_id, activity)
+    }
+
+    ImportRecord::Artist(record) => {
+      let decision = plan_artist(artist_repository, known, record).await?;
+      let (outcome, existing_id) = (decision.outcome(), decision.existing_id());
+
+      let activity = match decision.into_change_input(
+        crate::domain::artist::stories::contribute::ChangeInput::Create,
+        |id, changes| crate::domain::artist::stories::contribute::ChangeInput::Update {
+          id,
+          changes,
+        },
+      ) {
+        Some(data) if propose => Some(
+          crate::domain::artist::stories::contribute::execute(
+            artist_repository,
+            activity_repository,
+            crate::domain::artist::stories::contribute::Input { actor_id, data },
+          )
+          .await
+          .map_err(RecordError::ArtistContributionError)?,
+        ),
+        _ => None,
+      };
+
+      let slug = slug_or_generate(&record.slug, &record.name);
+      (slug.to_string(), slug, outcome, existing_id, activity)
+    }
+
+    ImportRecord::Album(record) => {
+      let (key, decision) = plan_album(album_repository, artist_repository, known, record).await?;
+      let (outcome, existing_id) = (decision.outcome(), decision.existing_id());
+
+      let activity = match decision.into_change_input(
+        crate::domain::album::stories::contribute::ChangeInput::Create,
+        |id, changes| crate::domain::album::stories::contribute::ChangeInput::Update {
+          id,
+          changes,
+        },
+      ) {
+        Some(data) if propose => Some(
+          crate::domain::album::stories::contribute::execute(
+            album_repository,
+            artist_repository,
+            activity_repository,
+            crate::domain::album::stories::contribute::Input { actor_id, data },
+          )
+          .await
+          .map_err(RecordError::AlbumContributionError)?,
+        ),
+        _ => None,
+      };
+
+      (
+        key,
+        slug_or_generate(&record.slug, &record.name),
+        outcome,
+        existing_id,
+        activity,
+      )
+    }
+
+    ImportRecord::Media(record) => {
+      let (key, decision) = plan_media(
+        media_repository,
+        artist_repository,
+        genre_repository,
+        album_repository,
+        known,
+        record,
+      )
+      .await?;
+      let (outcome, existing_id) = (decision.outcome(), decision.existing_id());
+
+      let activity = match decision.into_change_input(
+        crate::domain::media::stories::contribute::ChangeInput::Create,
+        |id, changes| crate::domain::media::stories::contribute::ChangeInput::Update {
+          id,
+          changes,
+        },
+      ) {
+        Some(data) if propose => Some(
+          crate::domain::media::stories::contribute::execute(
+            media_repository,
+            artist_repository,
+            genre_repository,
+            album_repository,
+            activity_repository,
+            crate::domain::media::stories::contribute::Input { actor_id, data },
+          )
+          .await
+          .map_err(RecordError::MediaContributionError)?,
+        ),
+        _ => None,
+      };
+
+      let slug = slug_or_generate(&record.slug, &record.name);
+      (key, slug, outcome, existing_id, activity)
+    }
+
+    ImportRecord::Source(record) => {
+      let (key, decision) = plan_source(source_repository, media_repository, known, record).await?;
+      let (outcome, existing_id) = (decision.outcome(), decision.existing_id());
+
+      let activity = match decision.into_change_input(
+        crate::domain::source::stories::contribute::ChangeInput::Create,
+        |id, changes| crate::domain::source::stories::contribute::ChangeInput::Update {
+          id,
+          changes,
+        },
+      ) {
+        Some(data) if propose => Some(
+          crate::domain::source::stories::contribute::execute(
+            source_repository,
+            activity_repository,
+            crate::domain::source::stories::contribute::Input { actor_id, data },
+          )
+          .await
+          .map_err(RecordError::SourceContributionError)?,
+        ),
+        _ => None,
+      };
+
+      (key, Slug::default(), outcome, existing_id, activity)
+    }
+
+    ImportRecord::Lyrics(record) => {
+      let decision = plan_lyrics(lyrics_repository, media_repository, known, record).await?;
+      let (outcome, existing_id) = (decision.outcome(), decision.existing_id());
+
+      let activity = match decision {
+        Decision::Create(data) if propose => Some(
+          crate::domain::lyrics::stories::contribute::execute(
+            lyrics_repository,
+            media_repository,
+            activity_repository,
+            crate::domain::lyrics::stories::contribute::Input {
+              actor_id,
+              data: crate::domain::lyrics::stories::contribute::ChangeInput::Create(data),
+            },
+          )
+          .await
+          .map_err(RecordError::LyricsContributionError)?,
+        ),
+        _ => None,
+      };
+
+      (
+        record.media.clone(),
+        Slug::default(),
+        outcome,
+        existing_id,
+        activity,
+      )
+    }
+
+    // Sem um vínculo da mesma origem, não há o que excluir.
+    ImportRecord::Deleted(record) => {
+      let linked = known.get(record.entity_name(), &record.external_id);
+
+      let activity = match &linked {
+        Some(linked) if propose => Some(
+          propose_deletion(
+            genre_repository,
+            artist_repository,
+            album_repository,
+            media_repository,
+            source_repository,
+            lyrics_repository,
+            activity_repository,
+            actor_id,
+            record,
+            linked.id.clone(),
+          )
+          .await?,
+        ),
+        _ => None,
+      };
+
+      let outcome = match linked {
+        Some(_) => Outcome::Delete,
+        None => Outcome::Unchanged,
+      };
+
+      (
+        record.external_id.clone(),
+        Slug::default(),
+        outcome,
+        linked.map(|linked| linked.id),
+        activity,
+      )
+    }
+  };
+
+  // A entidade vinculada pelo `external_id` mantém o slug atual, ainda que o nome
+  // tenha mudado.
+  if let Some(linked) = known
+    .linked(record.entity_name(), external_id(record))
+    .filter(|linked| existing_id.as_ref() == Some(&linked.id))
+  {
+    key = match key.split_once('/') {
+      Some((artist_slug, _)) => format!("{}/{}", artist_slug, linked.slug),
+      None => linked.slug.to_string(),
+    };
+    slug = linked.slug;
+  }
+
+  // Na simulação, as entidades que seriam criadas recebem um identificador provisório.
+  let id = existing_id
+    .or_else(|| activity.as_ref().and_then(created_id))
+    .unwrap_or_else(UUID4::generate);
+
+  let mut imported = Imported {
+    outcome,
+    activity,
+    key,
+    resolved: Resolved { id, slug },
+  };
+
+  let Some(activity_id) = imported
+    .activity
+    .as_ref()
+    .filter(|_| input.mode == Mode::Apply)
+    .map(|activity| activity.id().clone())
+  else {
+    return Ok(imported);
+  };
+
+  let activity = crate::domain::activity::stories::approve::execute(
+    activity_repository,
+    genre_repository,
+    artist_repository,
+    album_repository,
+    media_repository,
+    source_repository,
+    lyrics_repository,
+    crate::domain::activity::stories::approve::Input {
+      activity_id: activity_id.clone(),
+      actor: input.actor.clone(),
+      deletion_impact_threshold: input.deletion_impact_threshold,
+    },
+  )
+  .await
+  .map_err(|error| RecordError::ApproveError { activity_id, error })?;
+
+  imported.activity = Some(activity);
+
+  Ok(imported)
+}
+
+/**
+ * `external_id` do registro, pelo qual ele pode ser referenciado e vinculado à
+ * entidade importada. Fontes são encontradas pela URL e letras pela media, e não
+ * são vinculadas; registros de exclusão apenas usam vínculos já existentes.
+ */
+fn external_id(record: &ImportRecord) -> &Option<String> {
+  match record {
+    ImportRecord::Genre(record) => &record.external_id,
+    ImportRecord::Artist(record) => &record.external_id,
+    ImportRecord::Album(record) => &record.external_id,
+    ImportRecord::Media(record) => &record.external_id,
+    ImportRecord::Source(_) | ImportRecord::Lyrics(_) | ImportRecord::Deleted(_) => &None,
+  }
+}
+
+fn collaborative_entity_name(entity: &str) -> CollaborativeEntityName {
+  match entity {
+    "genre" => CollaborativeEntityName::Genre,
+    "artist" => CollaborativeEntityName::Artist,
+    "album" => CollaborativeEntityName::Album,
+    "media" => CollaborativeEntityName::Media,
+    _ => CollaborativeEntityName::Source,
+  }
+}
+
+/**
+ * Referências do registro que podem ser `external_id` vinculados em uma importação
+ * anterior, incluindo o do próprio registro.
+ */
+fn references(record: &ImportRecord) -> Vec<(&'static str, &String)> {
+  let mut references = external_id(record)
+    .iter()
+    .map(|external_id| (record.entity_name(), external_id))
+    .collect::<Vec<_>>();
+
+  match record {
+    ImportRecord::Genre(record) => {
+      references.extend(record.parent.iter().map(|parent| ("genre", parent)))
+    }
+    ImportRecord::Artist(record) => references.extend(
+      record
+        .members
+        .iter()
+        .flatten()
+        .map(|member| ("artist", &member.artist)),
+    ),
+    ImportRecord::Album(record) => {
+      references.extend(record.artists.iter().map(|artist| ("artist", artist)));
+      references.extend(record.release_group.iter().map(|album| ("album", album)));
+    }
+    ImportRecord::Media(record) => {
+      references.extend(record.interpreters.iter().map(|artist| ("artist", artist)));
+      references.extend(
+        record
+          .composers
+          .iter()
+          .flatten()
+          .map(|artist| ("artist", artist)),
+      );
+      references.extend(record.genres.iter().flatten().map(|genre| ("genre", genre)));
+      references.extend(record.albums.iter().flatten().map(|album| ("album", album)));
+      references.extend(
+        record
+          .credits
+          .iter()
+          .flatten()
+          .map(|credit| ("artist", &credit.artist)),
+      );
+    }
+    ImportRecord::Source(record) => references.push(("media", &record.media)),
+    ImportRecord::Lyrics(record) => references.push(("media", &record.media)),
+    ImportRecord::Deleted(record) => {
+      // Apenas gêneros, artistas, álbuns e medias são vinculados.
+      if !matches!(
+        record.entity,
+        CollaborativeEntityName::Source | CollaborativeEntityName::Lyrics
+      ) {
+        references.push((record.entity_name(), &record.external_id));
+      }
+    }
+  }
+
+  references
+}
+
+/**
+ * Carrega as entidades vinculadas em importações anteriores aos identificadores
+ * externos referenciados pelos registros. Vínculos a entidades que não existem,
+ * como as de atividades ainda pendentes, são ignorados.
+ */
+#[allow(clippy::too_many_arguments)]
+async fn link_external_ids(
+  genre_repository: &mut impl GenreRepository,
+  artist_repository: &mut impl ArtistRepository,
+  album_repository: &mut impl AlbumRepository,
+  media_repository: &mut impl MediaRepository,
+  catalog_repository: &mut impl CatalogRepository,
+  origin: &str,
+  records: &[(usize, ImportRecord)],
+  known: &mut Known,
+) -> Result<(), Error> {
+  let references = records
+    .iter()
+    .flat_map(|(_, record)| references(record))
+    .collect::<HashSet<_>>();
+
+  for (entity, reference) in references {
+    let Some(id) = catalog_repository
+      .find_by_external_id(origin, &collaborative_entity_name(entity), reference)
+      .await
+      .map_err(Error::CatalogRepositoryError)?
+    else {
+      continue;
+    };
+
+    let slug = match entity {
+      "genre" => genre_repository
+        .find_by_id(&id)
+        .await
+        .map_err(Error::GenreRepositoryError)?
+        .map(|genre| genre.slug().clone()),
+      "artist" => artist_repository
+        .find_by_id(&id)
+        .await
+        .map_err(Error::ArtistRepositoryError)?
+        .map(|artist| artist.slug().clone()),
+      "album" => album_repository
+        .find_by_id(&id)
+        .await
+        .map_err(Error::AlbumRepositoryError)?
+        .map(|album| album.slug().clone()),
+      _ => media_repository
+        .find_by_id(&id)
+        .await
+        .map_err(Error::MediaRepositoryError)?
+        .map(|media| media.slug().clone()),
+    };
+
+    if let Some(slug) = slug {
+      known.insert(entity, reference, &None, Resolved { id, slug });
+    }
+  }
+
+  Ok(())
+}
+
+/**
+ * Profundidade de cada gênero do arquivo na hierarquia formada pelos próprios
+ * registros, para que os pais sejam importados antes dos filhos independentemente
+ * da ordem em que aparecem.
+ */
+fn genre_depths(records: &[(usize, ImportRecord)]) -> HashMap<String, usize> {
+  let genres = records
+    .iter()
+    .filter_map(|(_, record)| match record {
+      ImportRecord::Genre(genre) => Some((record.key(), genre)),
+      _ => None,
+    })
+    .collect::<Vec<_>>();
+
+  let mut parents = HashMap::new();
+
+  for (key, genre) in &genres {
+    parents.insert(key.clone(), genre.parent.clone());
+
+    if let Some(external_id) = &genre.external_id {
+      parents.insert(external_id.clone(), genre.parent.clone());
+    }
+  }
+
+  genres
+    .iter()
+    .map(|(key, _)| {
+      let mut depth = 0;
+      let mut parent = parents.get(key).cloned().flatten();
+
+      // O limite evita laços em hierarquias circulares.
+      while let Some(reference) = parent.filter(|_| depth < genres.len()) {
+        depth += 1;
+        parent = parents.get(&reference).cloned().flatten();
+      }
+
+      (key.clone(), depth)
+    })
+    .collect()
+}
+
+/**
+ * Posição do registro entre os do mesmo tipo: gêneros depois de seus pais, grupos
+ * depois de seus membros e álbuns depois do álbum do grupo de lançamento.
+ */
+fn depth(record: &ImportRecord, genre_depths: &HashMap<String, usize>) -> usize {
+  match record {
+    ImportRecord::Genre(_) => genre_depths.get(&record.key()).copied().unwrap_or_default(),
+    ImportRecord::Artist(artist) => usize::from(
+      artist
+        .members
+        .as_ref()
+        .is_some_and(|members| !members.is_empty()),
+    ),
+    ImportRecord::Album(album) => usize::from(album.release_group.is_some()),
+    _ => 0,
+  }
+}
+
+/**
+ * Importa registros do catálogo pelo mesmo fluxo de contribuições da API.
+ *
+ * Cada registro é comparado com o que já existe, pelo slug ou pelo identificador
+ * canônico da fonte, e resulta em uma criação, uma atualização apenas dos campos
+ * informados, ou em nada. Os registros são processados na ordem gênero, artista,
+ * álbum, media, fonte, letra e exclusão, mantendo a ordem do arquivo dentro de cada
+ * tipo, exceto pelos gêneros, importados sempre depois de seus pais, pelos grupos,
+ * depois de seus membros, e pelos álbuns de um grupo de lançamento.
+ *
+ * No modo de proposta, as atividades ficam pendentes e, portanto, álbuns e medias
+ * não podem referenciar artistas criados no mesmo lote; no modo de aplicação cada
+ * atividade é aprovada antes do registro seguinte.
+ *
+ * Com uma origem informada, as entidades vinculadas aos `external_id` em
+ * importações anteriores têm precedência sobre a busca pelo slug, e os registros de
+ * exclusão propõem a exclusão das entidades vinculadas.
+ */
+#[allow(clippy::too_many_arguments)]
+pub async fn execute(
+  genre_repository: &mut impl GenreRepository,
+  artist_repository: &mut impl ArtistRepository,
+  album_repository: &mut impl AlbumRepository,
+  media_repository: &mut impl MediaRepository,
+  source_repository: &mut impl SourceRepository,
+  lyrics_repository: &mut impl LyricsRepository,
+  activity_repository: &mut impl crate::domain::activity::repository::ActivityRepository,
+  catalog_repository: &mut impl CatalogRepository,
+  input: Input,
+) -> Result<Report, Error> {
+  if input.mode == Mode::Apply && !input.actor.is_curator() {
+    return Err(Error::UserIsNotACurator);
+  }
+
+  let depths = genre_depths(&input.records);
+
+  let mut records = input.records.iter().collect::<Vec<_>>();
+  records.sort_by_key(|(_, record)| (record.rank(), depth(record, &depths)));
+
+  let mut known = Known::default();
+  let mut report = Report::default();
+
+  if let Some(origin) = &input.origin {
+    link_external_ids(
+      genre_repository,
+      artist_repository,
+      album_repository,
+      media_repository,
+      catalog_repository,
+      origin,
+      &input.records,
+      &mut known,
+    )
+    .await?;
+  }
+
+  for (line, record) in records {
+    let result = import_record(
+      genre_repository,
+      artist_repository,
+      album_repository,
+      media_repository,
+      source_repository,
+      lyrics_repository,
+      activity_repository,
+      &known,
+      &input,
+      record,
+    )
+    .await;
+
+    let mut key = record.key();
+
+    let (outcome, activity_id) = match result {
+      Ok(imported) => {
+        let external_id = external_id(record);
+
+        // Apenas entidades já gravadas são vinculadas: uma atividade pendente pode ser
+        // rejeitada, e o vínculo apontaria para uma entidade que nunca vai existir.
+        if let (Some(origin), Some(external_id)) = (&input.origin, external_id) {
+          if input.mode == Mode::Apply {
+            catalog_repository
+              .save_external_id(
+                origin,
+                &collaborative_entity_name(record.entity_name()),
+                external_id,
+                &imported.resolved.id,
+              )
+              .await
+              .map_err(Error::CatalogRepositoryError)?;
+          }
+        }
+
+        known.insert(
+          record.entity_name(),
+          &imported.key,
+          external_id,
+          imported.resolved,
+        );
+
+        // A chave das fontes usa o identificador da media, pouco útil no relatório.
+        if !matches!(record, ImportRecord::Source(_)) {
+          key = imported.key;
+        }
+
+        (
+          imported.outcome,
+          imported.activity.map(|activity| activity.id().clone()),
+        )
+      }
+      Err(Failure::Record(error)) => {
+        let activity_id = match &error {
+          RecordError::ApproveError { activity_id, .. } => Some(activity_id.clone()),
+          _ => None,
+        };
+
+        (Outcome::Failed(error), activity_id)
+      }
+      Err(Failure::Fatal(error)) => return Err(error),
+    };
+
+    report.entries.push(Entry {
+      line: *line,
+      entity: record.entity_name(),
+      key,
+      outcome,
+      activity_id,
+    });
+  }
+
+  Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use crate::{
+    domain::{
+      activity::ActivityStatus,
+      album::{repository::AlbumRepository, Album, AlbumType},
+      artist::{repository::ArtistRepository, Artist, ArtistMember, ArtistType},
+      catalog::{GenreRecord, ImportRecord},
+      genre::{Genre, GenreRepository},
+      lyrics::{
+        lyrics_entity::{LyricLine, Lyrics},
+        repository::LyricsRepository,
+      },
+      media::{
+        credit::{Credit, CreditRole},
+        repository::MediaRepository,
+        Media, MediaType,
+      },
+      source::{
+        repository::SourceRepository,
+        source_entity::{Source, SourceType},
+      },
+      user::User,
+    },
+    infra::in_memory::{
+      InMemoryActivityRepository, InMemoryAlbumRepository, InMemoryArtistRepository,
+      InMemoryCatalogRepository, InMemoryGenreRepository, InMemoryLyricsRepository,
+      InMemoryMediaRepository, InMemorySourceRepository,
+    },
+    shared::vo::{CollaborativeEntityName, Slug},
+  };
+
+  use super::{Error, Mode, Outcome, RecordError, Report};
+
+  #[derive(Default)]
+  struct Repositories {
+    genre: InMemoryGenreRepository,
+    artist: InMemoryArtistRepository,
+    album: InMemoryAlbumRepository,
+    media: InMemoryMediaRepository,
+    source: InMemorySourceRepository,
+    lyrics: InMemoryLyricsRepository,
+    activity: InMemoryActivityRepository,
+    catalog: InMemoryCatalogRepository,
+  }
+
+  impl Repositories {
+    async fn import(
+      &mut self,
+      actor: &User,
+      mode: Mode,
+      records: Vec<serde_json::Value>,
+    ) -> Result<Report, Error> {
+      super::execute(
+        &mut self.genre,
+        &mut self.artist,
+        &mut self.album,
+        &mut self.media,
+        &mut self.source,
+        &mut self.lyrics,
+        &mut self.activity,
+        &mut self.catalog,
+        super::Input {
+          actor: actor.clone(),
+          mode,
+          records: records
+            .into_iter()
+            .enumerate()
+            .map(|(index, record)| (index + 1, serde_json::from_value(record).unwrap()))
+            .collect(),
+          deletion_impact_threshold: 100,
+          origin: Some(String::from("teste")),
+        },
+      )
+      .await
+    }
+  }
+
+  fn curator() -> User {
+    User::builder()
+      .username(String::from("curator"))
+      .is_curator(true)
+      .build()
+  }
+
+  fn outcomes(report: &Report) -> Vec<(&str, String)> {
+    report
+      .entries
+      .iter()
+      .map(|entry| {
+        let outcome = match &entry.outcome {
+          Outcome::Create => String::from("create"),
+          Outcome::Update(fields) => format!("update {}", fields.join(",")),
+          Outcome::Unchanged => String::from("unchanged"),
+          Outcome::Delete => String::from("delete"),
+          Outcome::Failed(error) => format!("failed {:?}", error),
+        };
+        (entry.key.as_str(), outcome)
+      })
+      .collect()
+  }
+
+  fn genre(slug: &str, parent: Option<&str>) -> ImportRecord {
+    ImportRecord::Genre(GenreRecord {
+      external_id: None,
+      name: slug.to_string(),
+      slug: Some(crate::shared::vo::Slug::new(slug).unwrap()),
+      parent: parent.map(String::from),
+    })
+  }
+
+  #[test]
+  fn test_genre_depths() {
+    let records = vec![
+      (1, genre("hardcore", Some("punk"))),
+      (2, genre("punk", Some("rock"))),
+      (3, genre("rock", None)),
+      (4, genre("mpb", Some("existente"))),
+      (5, genre("a", Some("b"))),
+      (6, genre("b", Some("a"))),
+    ];
+
+    let depths = super::genre_depths(&records);
+
+    assert_eq!(depths["rock"], 0);
+    assert_eq!(depths["punk"], 1);
+    assert_eq!(
+      depths["hardcore"], 2,
+      "Os filhos devem ser importados depois dos pais"
+    );
+    assert_eq!(
+      depths["mpb"], 1,
+      "Pais fora do arquivo são buscados no catálogo"
+    );
+    assert!(
+      depths["a"] <= records.len(),
+      "Hierarquias circulares não devem travar a importação"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_create_and_update_only_given_fields() {
+    let mut repositories = Repositories::default();
+
+    let artist = Artist::builder()
+      .name(String::from("Banda"))
+      .slug(Slug::new("banda").unwrap())
+      .country(Some(String::from("BR")))
+      .artist_type(ArtistType::Group)
+      .build();
+    repositories.artist.create(&artist).await.unwrap();
+
+    let report = repositories
+      .import(
+        &curator(),
+        Mode::Apply,
+        vec![
+          json!({ "type": "artist", "name": "Banda Nova", "slug": "banda" }),
+          json!({ "type": "genre", "name": "Rock" }),
+        ],
+      )
+      .await
+      .unwrap();
+
+    assert_eq!(
+      outcomes(&report),
+      vec![
+        ("rock", String::from("create")),
+        ("banda", String::from("update name")),
+      ],
+      "Apenas os campos informados deveriam ser alterados"
+    );
+
+    let updated = repositories
+      .artist
+      .find_by_id(artist.id())
+      .await
+      .unwrap()
+      .unwrap();
+    assert_eq!(updated.name(), "Banda Nova");
+    assert_eq!(
+      updated.country(),
+      &Some(String::from("BR")),
+      "Campos ausentes no registro deveriam ser mantidos"
+    );
+    assert_eq!(updated.artist_type(), &ArtistType::Group);
+  }
+
+  #[tokio::test]
+  async fn test_unchanged_records() {
+    let mut repositories = Repositories::default();
+    let records = vec![
+      json!({ "type": "genre", "name": "Rock" }),
+      json!({ "type": "artist", "name": "Banda" }),
+      json!({ "type": "artist", "name": "Banda" }),
+    ];
+
+    let report = repositories
+      .import(&curator(), Mode::Apply, records.clone())
+      .await
+      .unwrap();
+
+    assert_eq!(report.created(), 2);
+    assert_eq!(
+      report.unchanged(),
+      1,
+      "Um registro repetido no arquivo não deveria ser importado de novo"
+    );
+
+    let report = repositories
+      .import(&curator(), Mode::Apply, records)
+      .await
+      .unwrap();
+
+    assert_eq!(
+      report.unchanged(),
+      3,
+      "Registros iguais aos existentes não deveriam gerar alterações"
+    );
+    assert_eq!(repositories.activity.activities.len(), 2);
+    assert_eq!(repositories.artist.artists.len(), 1);
+  }
+
+  #[tokio::test]
+  async fn test_resolve_references() {
+    let mut repositories = Repositories::default();
+
+    let artist = Artist::builder()
+      .name(String::from("Banda"))
+      .slug(Slug::new("banda").unwrap())
+      .build();
+    repositories.artist.create(&artist).await.unwrap();
+    repositories
+      .album
+      .artists
+      .insert(artist.id().to_string(), artist.clone());
+
+    let album = Album::builder()
+      .name(String::from("Disco"))
+      .slug(Slug::new("disco").unwrap())
+      .album_type(AlbumType::Album)
+      .artist_ids([artist.id().clone()].into())
+      .build();
+    repositories.album.create(&album).await.unwrap();
+
+    let report = repositories
+      .import(
+        &curator(),
+        Mode::Apply,
+        vec![
+          json!({ "type": "genre", "name": "Rock" }),
+          json!({
+            "type": "media",
+            "name": "Faixa",
+            "media_type": "Song",
+            "interpreters": ["banda"],
+            "genres": ["rock"],
+            "albums": ["banda/disco"],
+          }),
+          json!({
+            "type": "source",
+            "media": "banda/faixa",
+            "source_type": "Youtube",
+            "src": "https://www.youtube.com/watch?v=dQw4w9WgXcQ",
+          }),
+          json!({
+            "type": "media",
+            "name": "Outra",
+            "media_type": "Song",
+            "interpreters": ["banda"],
+            "albums": ["banda/inexistente"],
+          }),
+        ],
+      )
+      .await
+      .unwrap();
+
+    assert_eq!(report.created(), 3);
+    assert!(
+      matches!(
+        &report.entries[2].outcome,
+        Outcome::Failed(RecordError::UnresolvedReference { field: "albums", reference })
+          if reference == "banda/inexistente"
+      ),
+      "Um álbum inexistente não deveria ser resolvido: {:?}",
+      report.entries[2]
+    );
+
+    let media = repositories
+      .media
+      .medias
+      .values()
+      .find(|media| media.slug().to_string() == "faixa")
+      .unwrap();
+    let genre = repositories.genre.genres.values().next().unwrap();
+
+    assert_eq!(media.interpreter_ids(), &[artist.id().clone()].into());
+    assert_eq!(media.genre_ids(), &[genre.id().clone()].into());
+    assert_eq!(
+      media.album_ids(),
+      &[album.id().clone()].into(),
+      "O álbum deveria ser resolvido por <artista>/<slug>"
+    );
+
+    let source = repositories.source.sources.values().next().unwrap();
+    assert_eq!(
+      source.media_id(),
+      media.id(),
+      "A fonte deveria referenciar a media criada no mesmo arquivo"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_import_genre_parents_first() {
+    let mut repositories = Repositories::default();
+
+    let report = repositories
+      .import(
+        &curator(),
+        Mode::Apply,
+        vec![
+          json!({ "type": "genre", "name": "Hardcore", "parent": "punk" }),
+          json!({ "type": "genre", "name": "Punk", "parent": "rock" }),
+          json!({ "type": "genre", "name": "Rock" }),
+        ],
+      )
+      .await
+      .unwrap();
+
+    assert_eq!(
+      outcomes(&report),
+      vec![
+        ("rock", String::from("create")),
+        ("punk", String::from("create")),
+        ("hardcore", String::from("create")),
+      ],
+      "Os pais deveriam ser importados antes dos filhos"
+    );
+
+    let genre = |slug: &str| {
+      repositories
+        .genre
+        .genres
+        .values()
+        .find(|genre| genre.slug().to_string() == slug)
+        .unwrap()
+        .clone()
+    };
+
+    assert_eq!(genre("punk").parent_id(), &Some(genre("rock").id().clone()));
+    assert_eq!(
+      genre("hardcore").parent_id(),
+      &Some(genre("punk").id().clone())
+    );
+  }
+
+  #[tokio::test]
+  async fn test_dry_run() {
+    let mut repositories = Repositories::default();
+
+    let report = repositories
+      .import(
+        &curator(),
+        Mode::DryRun,
+        vec![
+          json!({ "type": "artist", "name": "Banda", "external_id": "mbid-banda" }),
+          json!({
+            "type": "album",
+            "name": "Disco",
+            "album_type": "Album",
+            "artists": ["mbid-banda"],
+          }),
+        ],
+      )
+      .await
+      .unwrap();
+
+    assert_eq!(
+      report.created(),
+      2,
+      "A simulação deveria resolver as entidades que seriam criadas"
+    );
+    assert!(report
+      .entries
+      .iter()
+      .all(|entry| entry.activity_id.is_none()));
+    assert!(repositories.activity.activities.is_empty());
+    assert!(repositories.artist.artists.is_empty());
+    assert!(
+      repositories.catalog.external_ids.is_empty(),
+      "Os identificadores provisórios não deveriam ser vinculados"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_propose() {
+    let mut repositories = Repositories::default();
+    let user = User::builder().username(String::from("user")).build();
+
+    let report = repositories
+      .import(
+        &user,
+        Mode::Propose,
+        vec![
+          json!({ "type": "genre", "name": "Rock", "external_id": "mbid-rock" }),
+          json!({ "type": "artist", "name": "Banda" }),
+        ],
+      )
+      .await
+      .unwrap();
+
+    assert_eq!(report.created(), 2);
+    assert!(report
+      .entries
+      .iter()
+      .all(|entry| entry.activity_id.is_some()));
+    assert!(
+      repositories
+        .activity
+        .activities
+        .values()
+        .all(|activity| *activity.status() == ActivityStatus::Pending),
+      "As atividades propostas deveriam ficar pendentes"
+    );
+    assert!(repositories.genre.genres.is_empty());
+    assert!(repositories.artist.artists.is_empty());
+    assert!(
+      repositories.catalog.external_ids.is_empty(),
+      "Entidades pendentes não deveriam ser vinculadas"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_apply_approves_each_record() {
+    let mut repositories = Repositories::default();
+
+    let report = repositories
+      .import(
+        &curator(),
+        Mode::Apply,
+        vec![
+          json!({ "type": "artist", "name": "Banda", "external_id": "mbid-banda" }),
+          json!({
+            "type": "album",
+            "name": "Disco",
+            "album_type": "Album",
+            "artists": ["mbid-banda"],
+          }),
+        ],
+      )
+      .await
+      .unwrap();
+
+    assert_eq!(
+      report.created(),
+      2,
+      "O álbum deveria referenciar o artista aprovado no registro anterior"
+    );
+    assert!(repositories
+      .activity
+      .activities
+      .values()
+      .all(|activity| *activity.status() == ActivityStatus::Approved));
+
+    let artist = repositories.artist.artists.values().next().unwrap();
+    let album = repositories.album.albums.values().next().unwrap();
+    assert_eq!(album.artist_ids(), &[artist.id().clone()].into());
+    assert_eq!(
+      repositories
+        .catalog
+        .external_ids
+        .get("teste:Artist:mbid-banda"),
+      Some(artist.id()),
+      "O artista criado deveria ser vinculado ao identificador externo"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_apply_requires_curator() {
+    let mut repositories = Repositories::default();
+    let user = User::builder().username(String::from("user")).build();
+
+    let result = repositories
+      .import(
+        &user,
+        Mode::Apply,
+        vec![json!({ "type": "genre", "name": "Rock" })],
+      )
+      .await;
+
+    assert!(matches!(result, Err(Error::UserIsNotACurator)));
+    assert!(repositories.activity.activities.is_empty());
+  }
+  async fn export(
+    repositories: &mut Repositories,
+    since: Option<chrono::NaiveDateTime>,
+  ) -> Vec<serde_json::Value> {
+    let mut export = crate::domain::catalog::stories::export::execute(
+      crate::domain::catalog::stories::export::Input {
+        actor: curator(),
+        entity_names: Vec::new(),
+        since,
+        batch_size: 1,
+      },
+    )
+    .unwrap();
+
+    let mut records = Vec::new();
+    while let Some(batch) = export.next_batch(&mut repositories.catalog).await.unwrap() {
+      records.extend(
+        batch
+          .into_iter()
+          .map(|record| serde_json::to_value(record).unwrap()),
+      );
+    }
+
+    records
+  }
+
+  #[tokio::test]
+  async fn test_export_round_trip() {
+    let mut repositories = Repositories::default();
+
+    let rock = Genre::builder()
+      .name(String::from("Rock"))
+      .slug(Slug::new("rock").unwrap())
+      .build();
+    let punk = Genre::builder()
+      .name(String::from("Punk"))
+      .slug(Slug::new("punk").unwrap())
+      .parent_id(Some(rock.id().clone()))
+      .build();
+    let singer = Artist::builder()
+      .name(String::from("Vocalista"))
+      .slug(Slug::new("vocalista").unwrap())
+      .build();
+    let artist = Artist::builder()
+      .name(String::from("Banda"))
+      .slug(Slug::new("banda").unwrap())
+      .artist_type(ArtistType::Group)
+      .aliases([String::from("A Banda")].into())
+      .members(vec![ArtistMember {
+        artist_id: singer.id().clone(),
+        started_at: chrono::NaiveDate::from_ymd_opt(1982, 8, 1),
+        ended_at: None,
+      }])
+      .build();
+    let album = Album::builder()
+      .name(String::from("Disco"))
+      .slug(Slug::new("disco").unwrap())
+      .album_type(AlbumType::Album)
+      .artist_ids([artist.id().clone()].into())
+      .build();
+    let deluxe = Album::builder()
+      .name(String::from("Disco Deluxe"))
+      .slug(Slug::new("disco-deluxe").unwrap())
+      .album_type(AlbumType::Album)
+      .artist_ids([artist.id().clone()].into())
+      .release_group_id(album.id().clone())
+      .build();
+    let media = Media::builder()
+      .name(String::from("Faixa"))
+      .slug(Slug::new("faixa").unwrap())
+      .media_type(MediaType::Song)
+      .interpreter_ids([artist.id().clone()].into())
+      .genre_ids([punk.id().clone()].into())
+      .album_ids([album.id().clone()].into())
+      .credits(vec![Credit {
+        artist_id: singer.id().clone(),
+        role: CreditRole::Producer,
+      }])
+      .build();
+    let source = Source::builder()
+      .media_id(media.id().clone())
+      .source_type(SourceType::Youtube)
+      .src(String::from("https://www.youtube.com/watch?v=dQw4w9WgXcQ"))
+      .external_id(Some(String::from("dQw4w9WgXcQ")))
+      .build();
+    let lyrics = Lyrics::builder()
+      .media_id(media.id().clone())
+      .content(String::from("Primeira linha"))
+      .lines(vec![LyricLine {
+        time: 12_500,
+        text: String::from("Primeira linha"),
+      }])
+      .build();
+
+    for genre in [&rock, &punk] {
+      repositories.genre.create(genre).await.unwrap();
+      repositories
+        .catalog
+        .genres
+        .insert(genre.id().to_string(), genre.clone());
+    }
+    for artist in [&singer, &artist] {
+      repositories.artist.create(artist).await.unwrap();
+      repositories
+        .album
+        .artists
+        .insert(artist.id().to_string(), artist.clone());
+      repositories
+        .media
+        .artists
+        .insert(artist.id().to_string(), artist.clone());
+      repositories
+        .catalog
+        .artists
+        .insert(artist.id().to_string(), artist.clone());
+    }
+    for album in [&album, &deluxe] {
+      repositories.album.create(album).await.unwrap();
+      repositories
+        .catalog
+        .albums
+        .insert(album.id().to_string(), album.clone());
+    }
+    repositories.media.create(&media).await.unwrap();
+    repositories.source.create(&source).await.unwrap();
+    repositories.lyrics.create(&lyrics).await.unwrap();
+
+    let catalog = &mut repositories.catalog;
+    catalog.medias.insert(media.id().to_string(), media.clone());
+    catalog
+      .sources
+      .insert(source.id().to_string(), source.clone());
+    catalog
+      .lyrics
+      .insert(lyrics.id().to_string(), lyrics.clone());
+
+    let records = export(&mut repositories, None).await;
+
+    assert_eq!(
+      records.len(),
+      9,
+      "Todas as entidades deveriam ser exportadas"
+    );
+    assert!(
+      records
+        .iter()
+        .any(|record| record["release_group"] == "banda/disco"),
+      "O álbum deveria referenciar o grupo de lançamento: {:?}",
+      records
+    );
+
+    let report = repositories
+      .import(&curator(), Mode::DryRun, records)
+      .await
+      .unwrap();
+
+    assert_eq!(
+      report.unchanged(),
+      9,
+      "Reimportar a exportação não deveria alterar nada: {:?}",
+      outcomes(&report)
+    );
+  }
+
+  #[tokio::test]
+  async fn test_incremental_export_deletes_linked_entities() {
+    let mut repositories = Repositories::default();
+    let since = crate::shared::util::naive_now() - chrono::Duration::hours(1);
+
+    let artist = Artist::builder()
+      .name(String::from("Banda"))
+      .slug(Slug::new("banda").unwrap())
+      .build();
+    repositories.artist.create(&artist).await.unwrap();
+
+    let catalog = &mut repositories.catalog;
+    for (id, deleted_at) in [
+      ("mbid-banda", since + chrono::Duration::minutes(30)),
+      ("mbid-antiga", since - chrono::Duration::minutes(30)),
+      ("mbid-outra", since + chrono::Duration::minutes(30)),
+    ] {
+      catalog.deleted.insert(
+        String::from(id),
+        (CollaborativeEntityName::Artist, deleted_at),
+      );
+    }
+    catalog
+      .external_ids
+      .insert(String::from("teste:Artist:mbid-banda"), artist.id().clone());
+
+    assert!(
+      export(&mut repositories, None).await.is_empty(),
+      "A exportação completa não deveria ter registros de exclusão"
+    );
+
+    let records = export(&mut repositories, Some(since)).await;
+
+    assert_eq!(
+      records,
+      vec![
+        json!({ "type": "deleted", "entity": "Artist", "external_id": "mbid-banda" }),
+        json!({ "type": "deleted", "entity": "Artist", "external_id": "mbid-outra" }),
+      ],
+      "Apenas as exclusões depois de since deveriam ser exportadas"
+    );
+
+    let report = repositories
+      .import(&curator(), Mode::Apply, records)
+      .await
+      .unwrap();
+
+    assert_eq!(
+      outcomes(&report),
+      vec![
+        ("mbid-banda", String::from("delete")),
+        ("mbid-outra", String::from("unchanged")),
+      ],
+      "Apenas a entidade vinculada deveria ser excluída"
+    );
+    assert!(
+      repositories.artist.artists.is_empty(),
+      "O artista vinculado deveria ser excluído"
+    );
+  }
+}
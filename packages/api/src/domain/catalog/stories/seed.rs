@@ -0,0 +1,406 @@
+use crate::{
+  domain::{
+    album::{repository::AlbumRepository, Album},
+    artist::{repository::ArtistRepository, Artist},
+    genre::{Genre, GenreRepository},
+    media::{repository::MediaRepository, Media, MediaType},
+    source::{
+      repository::SourceRepository,
+      source_entity::{Source, SourceType},
+    },
+    user::{User, UserRepository, UserRepositoryError},
+  },
+  shared::vo::{Slug, UUID4},
+};
+
+#[derive(Debug)]
+pub enum Error {
+  InvalidSourceUrl(crate::domain::source::source_url::Error),
+  UserRepositoryError(UserRepositoryError),
+  GenreRepositoryError(crate::domain::genre::Error),
+  ArtistRepositoryError(crate::domain::artist::repository::Error),
+  AlbumRepositoryError(crate::domain::album::repository::Error),
+  MediaRepositoryError(crate::domain::media::repository::Error),
+  SourceRepositoryError(crate::domain::source::repository::Error),
+}
+
+/**
+ * Dados iniciais, os mesmos inseridos pelas migrações de seed.
+ */
+#[derive(Debug, Clone, Default)]
+pub struct Seed {
+  pub users: Vec<User>,
+  pub genres: Vec<Genre>,
+  pub artists: Vec<Artist>,
+  pub albums: Vec<Album>,
+  pub medias: Vec<Media>,
+  pub sources: Vec<Source>,
+}
+
+/**
+ * Quantidade de registros dos dados iniciais em cada situação.
+ */
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Output {
+  pub created: usize,
+  pub restored: usize,
+  pub unchanged: usize,
+}
+
+fn id(value: &str) -> UUID4 {
+  UUID4::new(value).unwrap()
+}
+
+fn slug(value: &str) -> Slug {
+  Slug::new(value).unwrap()
+}
+
+impl Seed {
+  /**
+   * Dados das migrações `*_seed.up.sql`.
+   */
+  pub fn initial() -> Self {
+    let genre_id = id("5ce0c5ad-1a57-4874-b195-c72b2feb938b");
+    let artist_id = id("4d171119-0ff4-4fd2-b75e-614f20221bbe");
+    let album_id = id("b9d5b052-06a9-4af7-8d3f-80f4c3b3c47a");
+    let release_date = chrono::NaiveDate::from_ymd_opt(1982, 11, 30);
+
+    let tracks = [
+      (
+        "79e1cb2a-a865-4d45-92e7-259c5c94c07e",
+        "Wanna Be Startin Somethin",
+        "wanna-be-startin-somethin",
+        "90e34016-9fd8-4c63-a238-ee079a5260da",
+        "1XMvPTFzgVU",
+      ),
+      (
+        "fc460aed-9865-49d8-91aa-1965cd4b0d18",
+        "Baby Be Mine",
+        "baby-be-mine",
+        "d7c6cf1d-4ccb-4c13-9f0b-cbf1c3947c45",
+        "O3tnOVideSo",
+      ),
+      (
+        "b3747258-b1f6-4a4c-801d-3f38e2baddd1",
+        "The Girl Is Mine",
+        "the-girl-is-mine",
+        "2cbb473b-9ba7-421e-91f5-7b1c95f0fb29",
+        "8GB9BULxZ8c",
+      ),
+      (
+        "51d5916d-e6ec-4239-8873-0d60daa5f88d",
+        "Thriller",
+        "thriller",
+        "17932738-c1c0-4620-880a-ede79bac612f",
+        "sOnqjkJTMaA",
+      ),
+      (
+        "c9ceceb8-97a6-404f-bced-f7116cf5db25",
+        "Beat It",
+        "beat-it",
+        "bf1a1c81-0f50-462a-ace8-c8a76d99d3aa",
+        "oRdxUFDoQe0",
+      ),
+      (
+        "6ff9fe67-2f05-4e1e-a105-6425b72ff4cc",
+        "Billie Jean",
+        "billie-jean",
+        "3db05ec6-0151-442b-906c-e4d2bb97b94a",
+        "Zi_XLOBDo_Y",
+      ),
+      (
+        "50276085-9780-4352-8ad7-bde738c8de31",
+        "Human Nature",
+        "human-nature",
+        "97a66a3a-36b4-41b4-b228-0a5ac75cc761",
+        "ElN_4vUvTPs",
+      ),
+      (
+        "73125191-81d0-4e33-adaa-eba8393a1d55",
+        "P.Y.T. (Pretty Young Thing)",
+        "pyt-pretty-young-thing",
+        "9353cef2-f3c7-44e7-9a42-5555768e6d92",
+        "1ZZQuj6htF4",
+      ),
+      (
+        "3ebcc6f3-9a55-4fd2-ac3f-827e42a384eb",
+        "The Lady in My Life",
+        "the-lady-in-my-life",
+        "7f25b5ee-13b3-4407-a408-4db2baa34f3d",
+        "cJLH5yXoqi8",
+      ),
+    ];
+
+    Self {
+      users: vec![
+        User::builder()
+          .id(id("b5fc35ba-0990-482b-8988-6b25f25f9052"))
+          .username(String::from("erisnilton"))
+          .password(String::from(
+            "$2b$10$6rT1/kS7Vf0LCt2u1ElXUe9apiX/zIAEuT9WalcbK.Te5kbGNqDsm",
+          ))
+          .display_name(String::from("Erisnilton"))
+          .email(String::from("contato@erisnilton.dev"))
+          .build(),
+        User::builder()
+          .id(id("8bcf473d-3744-4ad5-b3eb-3d9c49979fd6"))
+          .username(String::from("sallon"))
+          .password(String::from(
+            "$2b$10$8jFC/7a3fGSNa4eY4TGlFOY4Q/g7CB6peIdnZE01wo4b4UFXpA96C",
+          ))
+          .display_name(String::from("Salomão Neto"))
+          .email(String::from("contato@sallon.dev"))
+          .is_curator(true)
+          .build(),
+      ],
+      genres: vec![Genre::builder()
+        .id(genre_id)
+        .name(String::from("Pop"))
+        .slug(slug("pop"))
+        .build()],
+      artists: vec![Artist::builder()
+        .id(artist_id.clone())
+        .name(String::from("Michael Jackson"))
+        .slug(slug("michael-jackson"))
+        .country(Some(String::from("USA")))
+        .build()],
+      albums: vec![Album::builder()
+        .id(album_id.clone())
+        .release_group_id(album_id.clone())
+        .name(String::from("Thriller"))
+        .cover(Some(String::from("https://example.com/album/thriller.jpg")))
+        .slug(slug("thriller"))
+        .release_date(release_date)
+        .parental_rating(12)
+        .artist_ids([artist_id.clone()].into())
+        .build()],
+      medias: tracks
+        .iter()
+        .enumerate()
+        .map(|(index, (media_id, name, media_slug, _, _))| {
+          Media::builder()
+            .id(id(media_id))
+            .name(String::from(*name))
+            .media_type(MediaType::Song)
+            .slug(slug(media_slug))
+            .release_date(release_date)
+            .cover(Some(format!("cover{}.jpg", index + 1)))
+            .album_ids([album_id.clone()].into())
+            .interpreter_ids([artist_id.clone()].into())
+            .build()
+        })
+        .collect(),
+      sources: tracks
+        .iter()
+        .map(|(media_id, _, _, source_id, video_id)| {
+          Source::builder()
+            .id(id(source_id))
+            .media_id(id(media_id))
+            .source_type(SourceType::Youtube)
+            .src(format!("https://www.youtube.com/watch?v={}", video_id))
+            .build()
+        })
+        .collect(),
+    }
+  }
+}
+
+/**
+ * Situação de um registro dos dados iniciais antes de `execute`.
+ */
+enum Outcome {
+  Created,
+  Restored,
+  Unchanged,
+}
+
+impl Output {
+  fn add(&mut self, outcome: Outcome) {
+    match outcome {
+      Outcome::Created => self.created += 1,
+      Outcome::Restored => self.restored += 1,
+      Outcome::Unchanged => self.unchanged += 1,
+    }
+  }
+}
+
+/**
+ * Decide o que fazer com um registro a partir das buscas entre os ativos e os excluídos.
+ */
+macro_rules! ensure {
+  ($repository:expr, $entity:expr, $error:path) => {{
+    let id = $entity.id();
+
+    if $repository.find_by_id(id).await.map_err($error)?.is_some() {
+      Outcome::Unchanged
+    } else if $repository
+      .find_deleted_by_id(id)
+      .await
+      .map_err($error)?
+      .is_some()
+    {
+      $repository.restore_by_id(id).await.map_err($error)?;
+      Outcome::Restored
+    } else {
+      $repository.create($entity).await.map_err($error)?;
+      Outcome::Created
+    }
+  }};
+}
+
+/**
+ * Garante que os dados iniciais existam, gravando-os pelos repositórios.
+ *
+ * Registros ausentes são criados, registros excluídos logicamente são restaurados e
+ * os que já existem não são alterados. Nada é apagado, então as contribuições ligadas
+ * aos dados iniciais são preservadas.
+ */
+pub async fn execute(
+  user_repository: &mut impl UserRepository,
+  genre_repository: &mut impl GenreRepository,
+  artist_repository: &mut impl ArtistRepository,
+  album_repository: &mut impl AlbumRepository,
+  media_repository: &mut impl MediaRepository,
+  source_repository: &mut impl SourceRepository,
+  seed: Seed,
+) -> Result<Output, Error> {
+  let mut output = Output::default();
+
+  for user in &seed.users {
+    let exists = user_repository
+      .find_by_id(user.id().clone())
+      .await
+      .map_err(Error::UserRepositoryError)?
+      .is_some();
+
+    if exists {
+      output.add(Outcome::Unchanged);
+    } else {
+      user_repository
+        .create(user)
+        .await
+        .map_err(Error::UserRepositoryError)?;
+      output.add(Outcome::Created);
+    }
+  }
+
+  for genre in &seed.genres {
+    output.add(ensure!(
+      genre_repository,
+      genre,
+      Error::GenreRepositoryError
+    ));
+  }
+
+  for artist in &seed.artists {
+    output.add(ensure!(
+      artist_repository,
+      artist,
+      Error::ArtistRepositoryError
+    ));
+  }
+
+  for album in &seed.albums {
+    output.add(ensure!(
+      album_repository,
+      album,
+      Error::AlbumRepositoryError
+    ));
+  }
+
+  for media in &seed.medias {
+    output.add(ensure!(
+      media_repository,
+      media,
+      Error::MediaRepositoryError
+    ));
+  }
+
+  for source in seed.sources {
+    // O identificador externo é extraído da URL, como nas contribuições de fonte.
+    let canonical = crate::domain::source::source_url::parse(source.source_type(), source.src())
+      .map_err(Error::InvalidSourceUrl)?;
+
+    let mut source = source;
+    source.set_src(canonical.src);
+    source.set_external_id(Some(canonical.external_id));
+
+    output.add(ensure!(
+      source_repository,
+      &source,
+      Error::SourceRepositoryError
+    ));
+  }
+
+  Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::infra::in_memory::{
+    InMemoryAlbumRepository, InMemoryArtistRepository, InMemoryGenreRepository,
+    InMemoryMediaRepository, InMemorySourceRepository, InMemoryUserRepository,
+  };
+
+  #[derive(Default)]
+  struct Repositories {
+    user: InMemoryUserRepository,
+    genre: InMemoryGenreRepository,
+    artist: InMemoryArtistRepository,
+    album: InMemoryAlbumRepository,
+    media: InMemoryMediaRepository,
+    source: InMemorySourceRepository,
+  }
+
+  async fn seed(repositories: &mut Repositories) -> Output {
+    execute(
+      &mut repositories.user,
+      &mut repositories.genre,
+      &mut repositories.artist,
+      &mut repositories.album,
+      &mut repositories.media,
+      &mut repositories.source,
+      Seed::initial(),
+    )
+    .await
+    .unwrap()
+  }
+
+  #[tokio::test]
+  async fn test_seed_creates_restores_and_keeps_records() {
+    let mut repositories = Repositories::default();
+
+    assert_eq!(
+      seed(&mut repositories).await,
+      Output {
+        created: 23,
+        restored: 0,
+        unchanged: 0,
+      },
+      "Todos os dados iniciais deveriam ser criados em um banco vazio"
+    );
+
+    assert!(
+      repositories
+        .source
+        .sources
+        .values()
+        .all(|source| source.external_id().is_some()),
+      "As fontes deveriam ser gravadas com o identificador externo"
+    );
+
+    let artist = Seed::initial().artists.remove(0);
+    repositories.artist.delete_by_id(artist.id()).await.unwrap();
+
+    assert_eq!(
+      seed(&mut repositories).await,
+      Output {
+        created: 0,
+        restored: 1,
+        unchanged: 22,
+      },
+      "O artista excluído deveria ser restaurado e os demais mantidos"
+    );
+  }
+}
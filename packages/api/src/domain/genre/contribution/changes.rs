@@ -1,6 +1,6 @@
 use crate::shared::vo::{Slug, UUID4};
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Changes {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub name: Option<String>,
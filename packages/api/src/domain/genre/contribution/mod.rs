@@ -10,6 +10,7 @@ pub enum Contribution {
     changes: changes::Changes,
   },
   Delete(Genre),
+  Restore(Genre),
 }
 
 impl Default for Contribution {
@@ -12,9 +12,24 @@ pub enum Error {
   DatabaseError(String),
 }
 
+crate::sort_fields! {
+  /**
+   * Campos pelos quais os gêneros podem ser ordenados.
+   *
+   * A popularidade é o número de mídias do gênero.
+   */
+  pub enum SortField {
+    Name => "name",
+    CreatedAt => "created_at",
+    UpdatedAt => "updated_at",
+    Popularity => "popularity",
+  }
+}
+
 #[derive(Debug, Clone)]
 pub struct FindAllQuery {
   pub page: RequestPageParams,
+  pub sort: Option<crate::shared::sort::Sort<SortField>>,
   pub parent_id: Option<UUID4>,
   pub search: Option<String>,
 }
@@ -36,10 +51,39 @@ pub trait GenreRepository {
   fn find_by_id(&mut self, id: &UUID4) -> impl Future<Output = Result<Option<Genre>, Error>>;
 
   /**
-   * Deleta um gênero musical pelo seu identificador.
+   * Busca os gêneros musicais com os identificadores informados. Identificadores inexistentes são ignorados.
+   */
+  fn find_by_ids(
+    &mut self,
+    ids: &std::collections::HashSet<UUID4>,
+  ) -> impl Future<Output = Result<Vec<Genre>, Error>>;
+
+  /**
+   * Exclui logicamente um gênero musical, que deixa de ser retornado pelas consultas até ser restaurado.
    */
   fn delete_by_id(&mut self, id: &UUID4) -> impl Future<Output = Result<(), Error>>;
 
+  /**
+   * Busca um gênero musical excluído pelo seu identificador.
+   */
+  fn find_deleted_by_id(
+    &mut self,
+    id: &UUID4,
+  ) -> impl Future<Output = Result<Option<Genre>, Error>>;
+
+  /**
+   * Restaura um gênero musical excluído.
+   */
+  fn restore_by_id(&mut self, id: &UUID4) -> impl Future<Output = Result<(), Error>>;
+
+  /**
+   * Calcula o impacto da exclusão de um gênero musical: as medias do gênero, seus subgêneros e os relacionamentos.
+   */
+  fn deletion_impact(
+    &mut self,
+    id: &UUID4,
+  ) -> impl Future<Output = Result<crate::domain::activity::DeletionImpact, Error>>;
+
   /**
    * Busca um gênero musical pelo seu identificador e retorna seus subgêneros.
    */
@@ -31,7 +31,14 @@ pub async fn execute(
     }
     crate::domain::genre::contribution::Contribution::Delete(genre) => {
       repository_genre
-        .delete_by_id(&genre.id())
+        .delete_by_id(genre.id())
+        .await
+        .map_err(Error::RepositoryError)?;
+      Ok(())
+    }
+    crate::domain::genre::contribution::Contribution::Restore(genre) => {
+      repository_genre
+        .restore_by_id(genre.id())
         .await
         .map_err(Error::RepositoryError)?;
       Ok(())
@@ -30,6 +30,7 @@ pub enum ChangeInput {
     changes: crate::domain::genre::contribution::changes::Changes,
   },
   Delete(crate::shared::vo::UUID4),
+  Restore(crate::shared::vo::UUID4),
 }
 
 #[derive(Debug, Clone)]
@@ -68,6 +69,19 @@ pub async fn execute(
       }
     }
 
+    ChangeInput::Restore(id) => {
+      let genre = genre_repository
+        .find_deleted_by_id(&id)
+        .await
+        .map_err(Error::GenreRepositoryError)?;
+
+      if let Some(genre) = genre {
+        crate::domain::genre::contribution::Contribution::Restore(genre)
+      } else {
+        return Err(Error::GenreNotFound);
+      }
+    }
+
     ChangeInput::Update { id, changes } => {
       let genre = genre_repository
         .find_by_id(&id)
@@ -85,9 +99,20 @@ pub async fn execute(
     }
   };
 
+  let impact = match &contribution {
+    crate::domain::genre::contribution::Contribution::Delete(genre) => Some(
+      genre_repository
+        .deletion_impact(genre.id())
+        .await
+        .map_err(Error::GenreRepositoryError)?,
+    ),
+    _ => None,
+  };
+
   let activity = crate::domain::activity::Activity::builder()
     .user_id(input.actor_id)
     .contribution(shared::vo::Contribution::Genre(contribution))
+    .impact(impact)
     .status(crate::domain::activity::ActivityStatus::Pending)
     .build();
 
@@ -98,3 +123,60 @@ pub async fn execute(
 
   Ok(activity)
 }
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    domain::genre::{Genre, GenreRepository},
+    infra::in_memory::{InMemoryActivityRepository, InMemoryGenreRepository},
+    shared::vo::{Slug, UUID4},
+  };
+
+  #[tokio::test]
+  async fn test_delete_records_impact() {
+    let mut genre_repository = InMemoryGenreRepository::default();
+    let mut activity_repository = InMemoryActivityRepository::default();
+
+    let rock = Genre::builder()
+      .name(String::from("Rock"))
+      .slug(Slug::new("rock").unwrap())
+      .build();
+    let punk = Genre::builder()
+      .name(String::from("Punk"))
+      .slug(Slug::new("punk").unwrap())
+      .parent_id(Some(rock.id().clone()))
+      .build();
+    let hardcore = Genre::builder()
+      .name(String::from("Hardcore"))
+      .slug(Slug::new("hardcore").unwrap())
+      .parent_id(Some(punk.id().clone()))
+      .build();
+
+    for genre in [&rock, &punk, &hardcore] {
+      genre_repository.create(genre).await.unwrap();
+    }
+
+    let activity = super::execute(
+      &mut genre_repository,
+      &mut activity_repository,
+      super::Input {
+        actor_id: UUID4::generate(),
+        data: super::ChangeInput::Delete(rock.id().clone()),
+      },
+    )
+    .await
+    .expect("Falha ao propor a exclusão do gênero");
+
+    let impact = activity
+      .impact()
+      .clone()
+      .expect("O impacto da exclusão não foi registrado");
+
+    assert_eq!(
+      impact.subgenres, 2,
+      "Os subgêneros indiretos também deveriam ser contados"
+    );
+    assert!(impact.requires_elevated_approval(1));
+    assert!(!impact.requires_elevated_approval(2));
+  }
+}
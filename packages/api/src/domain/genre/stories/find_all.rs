@@ -8,6 +8,7 @@ pub enum Error {
 }
 pub struct Input {
   pub page: RequestPageParams,
+  pub sort: Option<crate::shared::sort::Sort<crate::domain::genre::SortField>>,
   pub parent_id: Option<UUID4>,
   pub search: Option<String>,
 }
@@ -18,6 +19,7 @@ pub async fn execute(
   let result = genre_repository
     .find_all(&FindAllQuery {
       page: input.page,
+      sort: input.sort,
       parent_id: input.parent_id,
       search: input.search,
     })
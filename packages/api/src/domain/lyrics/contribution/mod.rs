@@ -0,0 +1,11 @@
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum Contribution {
+  Create(super::lyrics_entity::Lyrics),
+  Delete(super::lyrics_entity::Lyrics),
+}
+
+impl Default for Contribution {
+  fn default() -> Self {
+    Self::Create(super::lyrics_entity::Lyrics::default())
+  }
+}
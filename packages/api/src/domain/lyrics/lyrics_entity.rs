@@ -0,0 +1,204 @@
+use domain_proc_macros::Entity;
+
+use crate::shared::{util::naive_now, vo::UUID4};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LrcError {
+  InvalidLine(usize),
+}
+
+/**
+ * Linha de letra sincronizada, com o instante (em milissegundos) em que deve ser exibida.
+ */
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct LyricLine {
+  pub time: u32,
+  pub text: String,
+}
+
+impl LyricLine {
+  fn parse_timestamp(value: &str) -> Option<u32> {
+    let (minutes, seconds) = value.split_once(':')?;
+    let (seconds, fraction) = seconds.split_once('.').unwrap_or((seconds, "0"));
+
+    let minutes = minutes.parse::<u32>().ok()?;
+    let seconds = seconds.parse::<u32>().ok()?;
+
+    if seconds >= 60 || fraction.is_empty() || fraction.len() > 3 {
+      return None;
+    }
+
+    let fraction = fraction.parse::<u32>().ok()? * 10u32.pow(3 - fraction.len() as u32);
+
+    // Marcadores grandes demais para caber em milissegundos são recusados.
+    minutes
+      .checked_mul(60)?
+      .checked_add(seconds)?
+      .checked_mul(1000)?
+      .checked_add(fraction)
+  }
+
+  /**
+   * Interpreta uma letra no formato LRC (`[mm:ss.xx]texto`). Uma linha pode ter vários
+   * marcadores de tempo; tags de metadados (`[ar:...]`, `[ti:...]`) e linhas vazias são ignoradas.
+   */
+  pub fn parse_lrc(input: &str) -> Result<Vec<LyricLine>, LrcError> {
+    let mut lines = Vec::new();
+
+    for (index, raw) in input.lines().enumerate() {
+      let mut rest = raw.trim();
+
+      if rest.is_empty() {
+        continue;
+      }
+
+      let mut times = Vec::new();
+      let mut is_tag = false;
+
+      while let Some(tag) = rest.strip_prefix('[') {
+        let Some((tag, tail)) = tag.split_once(']') else {
+          return Err(LrcError::InvalidLine(index + 1));
+        };
+
+        match Self::parse_timestamp(tag) {
+          Some(time) => times.push(time),
+          None
+            if times.is_empty()
+              && tag
+                .split_once(':')
+                .is_some_and(|(key, _)| key.chars().all(|c| c.is_ascii_alphabetic())) =>
+          {
+            is_tag = true
+          }
+          None => return Err(LrcError::InvalidLine(index + 1)),
+        }
+
+        rest = tail;
+      }
+
+      if is_tag && times.is_empty() {
+        continue;
+      }
+
+      if times.is_empty() {
+        return Err(LrcError::InvalidLine(index + 1));
+      }
+
+      lines.extend(times.into_iter().map(|time| LyricLine {
+        time,
+        text: rest.trim().to_string(),
+      }));
+    }
+
+    lines.sort_by_key(|line| line.time);
+
+    Ok(lines)
+  }
+
+  /**
+   * Converte as linhas de volta para o formato LRC.
+   */
+  pub fn to_lrc(lines: &[LyricLine]) -> String {
+    lines
+      .iter()
+      .map(|line| {
+        format!(
+          "[{:02}:{:02}.{:02}]{}",
+          line.time / 60_000,
+          (line.time / 1000) % 60,
+          (line.time % 1000) / 10,
+          line.text
+        )
+      })
+      .collect::<Vec<_>>()
+      .join("\n")
+  }
+}
+
+/**
+ * Versão da letra de uma media. Cada contribuição aprovada gera uma nova versão, preservando o histórico.
+ */
+#[derive(Debug, Entity, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Lyrics {
+  id: UUID4,
+  media_id: UUID4,
+  version: u32,
+  language: Option<String>,
+  content: String,
+  lines: Vec<LyricLine>,
+  created_at: chrono::NaiveDateTime,
+  updated_at: chrono::NaiveDateTime,
+}
+
+impl Lyrics {
+  pub fn is_synced(&self) -> bool {
+    !self.lines.is_empty()
+  }
+}
+
+impl Default for Lyrics {
+  fn default() -> Self {
+    let now = naive_now();
+    Self {
+      id: UUID4::default(),
+      media_id: UUID4::default(),
+      version: 1,
+      language: None,
+      content: String::new(),
+      lines: Vec::new(),
+      created_at: now,
+      updated_at: now,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_lrc() {
+    let lines =
+      LyricLine::parse_lrc("[ar:Artista]\n[00:12.50]Primeira linha\n\n[01:02.345][00:05]Refrão")
+        .expect("Falha ao interpretar LRC");
+
+    assert_eq!(
+      lines,
+      vec![
+        LyricLine {
+          time: 5_000,
+          text: String::from("Refrão")
+        },
+        LyricLine {
+          time: 12_500,
+          text: String::from("Primeira linha")
+        },
+        LyricLine {
+          time: 62_345,
+          text: String::from("Refrão")
+        },
+      ]
+    );
+    assert_eq!(
+      LyricLine::to_lrc(&lines[..2]),
+      "[00:05.00]Refrão\n[00:12.50]Primeira linha"
+    );
+  }
+
+  #[test]
+  fn test_parse_lrc_invalid_line() {
+    assert_eq!(
+      LyricLine::parse_lrc("[00:01.00]ok\nsem marcador"),
+      Err(LrcError::InvalidLine(2))
+    );
+    assert_eq!(
+      LyricLine::parse_lrc("[00:75.00]segundos inválidos"),
+      Err(LrcError::InvalidLine(1))
+    );
+    assert_eq!(
+      LyricLine::parse_lrc("[00:01.00]ok\n[99999999:00.00]minutos demais"),
+      Err(LrcError::InvalidLine(2)),
+      "Um marcador que excede o limite de tempo deve ser recusado"
+    );
+  }
+}
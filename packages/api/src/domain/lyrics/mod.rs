@@ -0,0 +1,4 @@
+pub mod contribution;
+pub mod lyrics_entity;
+pub mod repository;
+pub mod stories;
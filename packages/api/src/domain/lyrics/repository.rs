@@ -0,0 +1,44 @@
+use std::future::Future;
+
+use crate::shared::vo::UUID4;
+
+use super::lyrics_entity::Lyrics;
+
+#[derive(Debug, Clone)]
+pub enum Error {
+  DatabaseError(String),
+}
+
+pub trait LyricsRepository {
+  /**
+   * Cria uma nova versão de letra.
+   */
+  fn create(&mut self, lyrics: &Lyrics) -> impl Future<Output = Result<(), Error>>;
+
+  /**
+   * Busca uma versão de letra pelo seu identificador e retorna a letra encontrada ou None caso não exista.
+   */
+  fn find_by_id(&mut self, id: &UUID4) -> impl Future<Output = Result<Option<Lyrics>, Error>>;
+
+  /**
+   * Busca a letra de uma media. Quando a versão não é informada, retorna a versão mais recente.
+   */
+  fn find_by_media_id(
+    &mut self,
+    media_id: &UUID4,
+    version: Option<u32>,
+  ) -> impl Future<Output = Result<Option<Lyrics>, Error>>;
+
+  /**
+   * Lista todas as versões de letra de uma media, da mais recente para a mais antiga.
+   */
+  fn find_versions_by_media_id(
+    &mut self,
+    media_id: &UUID4,
+  ) -> impl Future<Output = Result<Vec<Lyrics>, Error>>;
+
+  /**
+   * Deleta uma versão de letra pelo seu identificador.
+   */
+  fn delete_by_id(&mut self, id: &UUID4) -> impl Future<Output = Result<(), Error>>;
+}
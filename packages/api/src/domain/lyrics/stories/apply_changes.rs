@@ -0,0 +1,38 @@
+#[derive(Debug, Clone)]
+pub enum Error {
+  RepositoryError(crate::domain::lyrics::repository::Error),
+}
+
+pub type Input = crate::domain::lyrics::contribution::Contribution;
+
+pub async fn execute(
+  lyrics_repository: &mut impl crate::domain::lyrics::repository::LyricsRepository,
+  input: Input,
+) -> Result<(), Error> {
+  match input {
+    crate::domain::lyrics::contribution::Contribution::Create(mut lyrics) => {
+      // A versão é definida na aprovação, pois outras contribuições podem ter sido aprovadas antes.
+      let latest = lyrics_repository
+        .find_by_media_id(lyrics.media_id(), None)
+        .await
+        .map_err(Error::RepositoryError)?;
+
+      *lyrics.version_mut() = latest.map_or(1, |latest| latest.version() + 1);
+
+      lyrics_repository
+        .create(&lyrics)
+        .await
+        .map_err(Error::RepositoryError)?;
+
+      Ok(())
+    }
+    crate::domain::lyrics::contribution::Contribution::Delete(lyrics) => {
+      lyrics_repository
+        .delete_by_id(lyrics.id())
+        .await
+        .map_err(Error::RepositoryError)?;
+
+      Ok(())
+    }
+  }
+}
@@ -0,0 +1,111 @@
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CreateLyricsInput {
+  pub media_id: crate::shared::vo::UUID4,
+  pub language: Option<String>,
+  pub content: Option<String>,
+  pub lrc: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum ChangeInput {
+  Create(CreateLyricsInput),
+  Delete(crate::shared::vo::UUID4),
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+  LyricsNotFound,
+  MediaNotFound,
+  EmptyLyrics,
+  InvalidLrc(crate::domain::lyrics::lyrics_entity::LrcError),
+  LyricsRepositoryError(crate::domain::lyrics::repository::Error),
+  MediaRepositoryError(crate::domain::media::repository::Error),
+  ActivityRepositoryError(crate::domain::activity::repository::Error),
+}
+
+#[derive(Debug, Clone)]
+pub struct Input {
+  pub actor_id: crate::shared::vo::UUID4,
+  pub data: ChangeInput,
+}
+
+fn build_lyrics(
+  input: CreateLyricsInput,
+) -> Result<crate::domain::lyrics::lyrics_entity::Lyrics, Error> {
+  let lines = match &input.lrc {
+    Some(lrc) => {
+      crate::domain::lyrics::lyrics_entity::LyricLine::parse_lrc(lrc).map_err(Error::InvalidLrc)?
+    }
+    None => Vec::new(),
+  };
+
+  // Sem texto simples, o conteúdo é derivado das linhas sincronizadas.
+  let content = input
+    .content
+    .filter(|content| !content.trim().is_empty())
+    .unwrap_or_else(|| {
+      lines
+        .iter()
+        .map(|line| line.text.as_str())
+        .collect::<Vec<_>>()
+        .join("\n")
+    });
+
+  if content.trim().is_empty() {
+    return Err(Error::EmptyLyrics);
+  }
+
+  Ok(
+    crate::domain::lyrics::lyrics_entity::Lyrics::builder()
+      .media_id(input.media_id)
+      .language(input.language)
+      .content(content)
+      .lines(lines)
+      .build(),
+  )
+}
+
+pub async fn execute(
+  lyrics_repository: &mut impl crate::domain::lyrics::repository::LyricsRepository,
+  media_repository: &mut impl crate::domain::media::repository::MediaRepository,
+  activity_repository: &mut impl crate::domain::activity::repository::ActivityRepository,
+  input: Input,
+) -> Result<crate::domain::activity::Activity, Error> {
+  let contribution: crate::domain::lyrics::contribution::Contribution = match input.data {
+    ChangeInput::Create(lyrics) => {
+      media_repository
+        .find_by_id(&lyrics.media_id)
+        .await
+        .map_err(Error::MediaRepositoryError)?
+        .ok_or(Error::MediaNotFound)?;
+
+      crate::domain::lyrics::contribution::Contribution::Create(build_lyrics(lyrics)?)
+    }
+
+    ChangeInput::Delete(id) => {
+      let lyrics = lyrics_repository
+        .find_by_id(&id)
+        .await
+        .map_err(Error::LyricsRepositoryError)?;
+
+      if let Some(lyrics) = lyrics {
+        crate::domain::lyrics::contribution::Contribution::Delete(lyrics)
+      } else {
+        return Err(Error::LyricsNotFound);
+      }
+    }
+  };
+
+  let activity = crate::domain::activity::Activity::builder()
+    .user_id(input.actor_id)
+    .contribution(crate::shared::vo::Contribution::Lyrics(contribution))
+    .status(crate::domain::activity::ActivityStatus::Pending)
+    .build();
+
+  activity_repository
+    .create(&activity)
+    .await
+    .map_err(Error::ActivityRepositoryError)?;
+
+  Ok(activity)
+}
@@ -0,0 +1,131 @@
+#[derive(Debug, Clone)]
+pub enum Error {
+  RepositoryError(crate::domain::lyrics::repository::Error),
+  MediaRepositoryError(crate::domain::media::repository::Error),
+  LyricsNotFound,
+  MediaNotFound,
+  ParentalRatingRestricted,
+}
+
+#[derive(Debug, Clone)]
+pub struct Input {
+  pub media_id: crate::shared::vo::UUID4,
+  pub version: Option<u32>,
+  pub parental_rating_limit: u8,
+}
+
+/**
+ * Garante que a mídia existe e está dentro da classificação indicativa do usuário.
+ */
+async fn check_media(
+  media_repository: &mut impl crate::domain::media::repository::MediaRepository,
+  media_id: &crate::shared::vo::UUID4,
+  parental_rating_limit: u8,
+) -> Result<(), Error> {
+  let media = media_repository
+    .find_by_id(media_id)
+    .await
+    .map_err(Error::MediaRepositoryError)?
+    .ok_or(Error::MediaNotFound)?;
+
+  if *media.parental_rating() > parental_rating_limit {
+    return Err(Error::ParentalRatingRestricted);
+  }
+
+  Ok(())
+}
+
+pub async fn execute(
+  lyrics_repository: &mut impl crate::domain::lyrics::repository::LyricsRepository,
+  media_repository: &mut impl crate::domain::media::repository::MediaRepository,
+  input: Input,
+) -> Result<crate::domain::lyrics::lyrics_entity::Lyrics, Error> {
+  check_media(
+    media_repository,
+    &input.media_id,
+    input.parental_rating_limit,
+  )
+  .await?;
+
+  lyrics_repository
+    .find_by_media_id(&input.media_id, input.version)
+    .await
+    .map_err(Error::RepositoryError)?
+    .ok_or(Error::LyricsNotFound)
+}
+
+pub async fn versions(
+  lyrics_repository: &mut impl crate::domain::lyrics::repository::LyricsRepository,
+  media_repository: &mut impl crate::domain::media::repository::MediaRepository,
+  media_id: &crate::shared::vo::UUID4,
+  parental_rating_limit: u8,
+) -> Result<Vec<crate::domain::lyrics::lyrics_entity::Lyrics>, Error> {
+  check_media(media_repository, media_id, parental_rating_limit).await?;
+
+  lyrics_repository
+    .find_versions_by_media_id(media_id)
+    .await
+    .map_err(Error::RepositoryError)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    domain::{
+      lyrics::{lyrics_entity::Lyrics, repository::LyricsRepository},
+      media::{repository::MediaRepository, Media},
+    },
+    infra::in_memory::{InMemoryLyricsRepository, InMemoryMediaRepository},
+  };
+
+  use super::*;
+
+  #[tokio::test]
+  async fn test_find_lyrics_of_restricted_media() {
+    let mut lyrics_repository = InMemoryLyricsRepository::default();
+    let mut media_repository = InMemoryMediaRepository::default();
+
+    let media = Media::builder()
+      .name(String::from("Teste"))
+      .parental_rating(16)
+      .build();
+    media_repository.create(&media).await.unwrap();
+
+    let lyrics = Lyrics::builder()
+      .media_id(media.id().clone())
+      .version(1)
+      .content(String::from("Letra"))
+      .build();
+    lyrics_repository.create(&lyrics).await.unwrap();
+
+    let input = |parental_rating_limit| Input {
+      media_id: media.id().clone(),
+      version: None,
+      parental_rating_limit,
+    };
+
+    let restricted = execute(&mut lyrics_repository, &mut media_repository, input(12)).await;
+    let restricted_versions = versions(
+      &mut lyrics_repository,
+      &mut media_repository,
+      media.id(),
+      12,
+    )
+    .await;
+
+    assert!(
+      matches!(restricted, Err(Error::ParentalRatingRestricted)),
+      "Letra de mídia acima da classificação indicativa não deveria ser retornada"
+    );
+    assert!(
+      matches!(restricted_versions, Err(Error::ParentalRatingRestricted)),
+      "Versões de mídia acima da classificação indicativa não deveriam ser listadas"
+    );
+
+    let found = execute(&mut lyrics_repository, &mut media_repository, input(16))
+      .await
+      .expect("Erro ao buscar letra");
+
+    assert_eq!(found, lyrics, "Letra não é igual à esperada");
+  }
+}
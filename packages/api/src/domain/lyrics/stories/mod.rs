@@ -0,0 +1,3 @@
+pub mod apply_changes;
+pub mod contribute;
+pub mod find_by_media;
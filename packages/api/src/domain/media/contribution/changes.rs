@@ -1,4 +1,4 @@
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Changes {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub name: Option<String>,
@@ -21,6 +21,21 @@ pub struct Changes {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub parental_rating: Option<u8>,
 
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub duration: Option<u32>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub isrc: Option<crate::shared::vo::Isrc>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub bpm: Option<u16>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub key: Option<String>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub language: Option<String>,
+
   #[serde(skip_serializing_if = "Option::is_none")]
   pub composer_ids: Option<std::collections::HashSet<crate::shared::vo::UUID4>>,
 
@@ -30,6 +45,9 @@ pub struct Changes {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub album_ids: Option<std::collections::HashSet<crate::shared::vo::UUID4>>,
 
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub credits: Option<Vec<crate::domain::media::credit::Credit>>,
+
   #[serde(skip_serializing_if = "Option::is_none")]
   pub is_single: Option<bool>,
 }
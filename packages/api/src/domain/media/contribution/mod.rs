@@ -2,6 +2,7 @@ use super::Media;
 
 pub mod changes;
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum Contribution {
   Create(Media),
@@ -10,6 +11,7 @@ pub enum Contribution {
     changes: changes::Changes,
   },
   Delete(Media),
+  Restore(Media),
 }
 
 impl Default for Contribution {
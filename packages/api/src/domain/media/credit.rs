@@ -0,0 +1,58 @@
+use std::{fmt::Display, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum CreditRole {
+  Lyricist,
+  Producer,
+  FeaturedArtist,
+  Arranger,
+  Mixing,
+  Mastering,
+}
+
+impl Display for CreditRole {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Lyricist => write!(f, "Lyricist"),
+      Self::Producer => write!(f, "Producer"),
+      Self::FeaturedArtist => write!(f, "FeaturedArtist"),
+      Self::Arranger => write!(f, "Arranger"),
+      Self::Mixing => write!(f, "Mixing"),
+      Self::Mastering => write!(f, "Mastering"),
+    }
+  }
+}
+
+impl FromStr for CreditRole {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "Lyricist" => Ok(Self::Lyricist),
+      "Producer" => Ok(Self::Producer),
+      "FeaturedArtist" => Ok(Self::FeaturedArtist),
+      "Arranger" => Ok(Self::Arranger),
+      "Mixing" => Ok(Self::Mixing),
+      "Mastering" => Ok(Self::Mastering),
+      _ => Err(format!("Invalid CreditRole: {}", s)),
+    }
+  }
+}
+
+/**
+ * Crédito de um artista em uma media. A posição do crédito na lista define a ordem de exibição.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct Credit {
+  pub artist_id: crate::shared::vo::UUID4,
+  pub role: CreditRole,
+}
+
+/**
+ * Crédito com o artista resolvido, usado na leitura de medias.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArtistCredit {
+  pub role: CreditRole,
+  pub artist: crate::domain::artist::Artist,
+}
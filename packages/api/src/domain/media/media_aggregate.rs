@@ -4,5 +4,26 @@ pub struct MediaAggregate {
   pub media: Media,
   pub interpreters: Vec<crate::domain::artist::Artist>,
   pub composers: Vec<crate::domain::artist::Artist>,
+  pub credits: Vec<crate::domain::media::credit::ArtistCredit>,
   pub sources: Vec<crate::domain::source::source_entity::Source>,
+  pub genres: Vec<crate::domain::genre::Genre>,
+  pub albums: Vec<crate::domain::album::Album>,
+}
+
+impl MediaAggregate {
+  /**
+   * Retorna a mídia apenas se estiver dentro da classificação indicativa informada,
+   * ocultando os álbuns que a ultrapassam.
+   */
+  pub fn restrict_parental_rating(mut self, limit: u8) -> Option<Self> {
+    if *self.media.parental_rating() > limit {
+      return None;
+    }
+
+    self
+      .albums
+      .retain(|album| *album.parental_rating() <= limit);
+
+    Some(self)
+  }
 }
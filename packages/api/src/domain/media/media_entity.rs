@@ -4,7 +4,7 @@ use domain_proc_macros::Entity;
 
 use crate::shared::{util::naive_now, vo::Slug};
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum MediaType {
   Song,
   Instrumental,
@@ -40,10 +40,17 @@ pub struct Media {
   release_date: Option<chrono::NaiveDate>,
   cover: Option<String>,
   parental_rating: u8,
+  duration: Option<u32>,
+  isrc: Option<crate::shared::vo::Isrc>,
+  bpm: Option<u16>,
+  key: Option<String>,
+  language: Option<String>,
   genre_ids: std::collections::HashSet<crate::shared::vo::UUID4>,
   composer_ids: std::collections::HashSet<crate::shared::vo::UUID4>,
   interpreter_ids: std::collections::HashSet<crate::shared::vo::UUID4>,
   album_ids: std::collections::HashSet<crate::shared::vo::UUID4>,
+  #[serde(default)]
+  credits: Vec<super::credit::Credit>,
   is_single: bool,
   created_at: chrono::NaiveDateTime,
   updated_at: chrono::NaiveDateTime,
@@ -72,6 +79,21 @@ impl Media {
     if let Some(value) = changes.parental_rating {
       self.parental_rating = value;
     }
+    if let Some(value) = changes.duration {
+      self.duration = Some(value);
+    }
+    if let Some(value) = &changes.isrc {
+      self.isrc = Some(value.clone());
+    }
+    if let Some(value) = changes.bpm {
+      self.bpm = Some(value);
+    }
+    if let Some(value) = &changes.key {
+      self.key = Some(value.clone());
+    }
+    if let Some(value) = &changes.language {
+      self.language = Some(value.clone());
+    }
     if let Some(value) = &changes.composer_ids {
       self.composer_ids = value.clone();
     }
@@ -81,6 +103,9 @@ impl Media {
     if let Some(value) = &changes.album_ids {
       self.album_ids = value.clone();
     }
+    if let Some(value) = &changes.credits {
+      self.credits = value.clone();
+    }
 
     self.updated_at = naive_now();
   }
@@ -96,10 +121,16 @@ impl Default for Media {
       slug: Slug::default(),
       cover: None,
       parental_rating: 0,
+      duration: None,
+      isrc: None,
+      bpm: None,
+      key: None,
+      language: None,
       genre_ids: std::collections::HashSet::default(),
       composer_ids: std::collections::HashSet::default(),
       interpreter_ids: std::collections::HashSet::default(),
       album_ids: std::collections::HashSet::default(),
+      credits: Vec::new(),
       is_single: false,
       created_at: naive_now(),
       updated_at: naive_now(),
@@ -2,6 +2,7 @@ mod media_entity;
 pub use media_entity::*;
 
 pub mod contribution;
+pub mod credit;
 pub mod media_aggregate;
 pub mod repository;
 pub mod stories;
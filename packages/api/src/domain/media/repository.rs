@@ -1,8 +1,9 @@
-use std::collections::HashSet;
 use std::future::Future;
 
 use crate::shared::{
-  paged::{Paged, RequestPageParams},
+  facet::Facet,
+  filter::ListFilter,
+  paged::{CursorPageParams, CursorPaged, Paged, RequestPageParams},
   vo::{Slug, UUID4},
 };
 
@@ -13,23 +14,53 @@ pub enum Error {
   DatabaseError(String),
 }
 
+crate::sort_fields! {
+  /**
+   * Campos pelos quais as mídias podem ser ordenadas.
+   *
+   * A popularidade é o número de álbuns que contêm a mídia.
+   */
+  pub enum SortField {
+    Name => "name",
+    ReleaseDate => "release_date",
+    CreatedAt => "created_at",
+    UpdatedAt => "updated_at",
+    Popularity => "popularity",
+  }
+}
+
+crate::facet_fields! {
+  /**
+   * Campos pelos quais as mídias de uma listagem podem ser contadas.
+   */
+  pub enum FacetField {
+    MediaType => "media_type",
+    ParentalRating => "parental_rating",
+    ReleaseYear => "release_year",
+    ReleaseDecade => "release_decade",
+    Genre => "genre",
+  }
+}
+
 #[derive(Debug, Clone)]
 pub struct FindByQuery {
   pub page: RequestPageParams,
+  pub sort: Option<crate::shared::sort::Sort<SortField>>,
   pub search: Option<String>,
   pub release_date: Option<chrono::NaiveDate>,
   pub min_release_date: Option<chrono::NaiveDate>,
   pub max_release_date: Option<chrono::NaiveDate>,
-  pub parental_rating: Option<u8>,
+  pub parental_rating: Option<ListFilter<u8>>,
   pub min_parental_rating: Option<u8>,
   pub max_parental_rating: Option<u8>,
   pub is_single: Option<bool>,
-  pub media_type: Option<crate::domain::media::MediaType>,
+  pub media_type: Option<ListFilter<crate::domain::media::MediaType>>,
   pub slug: Option<Slug>,
-  pub artist_ids: Option<HashSet<UUID4>>,
-  pub composer_ids: Option<HashSet<UUID4>>,
-  pub genre_ids: Option<HashSet<UUID4>>,
-  pub album_ids: Option<HashSet<UUID4>>,
+  pub artist_ids: Option<ListFilter<UUID4>>,
+  pub composer_ids: Option<ListFilter<UUID4>>,
+  pub genre_ids: Option<ListFilter<UUID4>>,
+  pub album_ids: Option<ListFilter<UUID4>>,
+  pub credit_role: Option<crate::domain::media::credit::CreditRole>,
 }
 
 pub trait MediaRepository {
@@ -49,12 +80,64 @@ pub trait MediaRepository {
   fn find_by_id(&mut self, id: &UUID4) -> impl Future<Output = Result<Option<Media>, Error>>;
 
   /**
-   * Deleta uma media pelo seu identificador.
+   * Busca uma media pelo seu identificador junto com as entidades relacionadas.
+   */
+  fn find_aggregate_by_id(
+    &mut self,
+    id: &UUID4,
+  ) -> impl Future<Output = Result<Option<super::media_aggregate::MediaAggregate>, Error>>;
+
+  /**
+   * Busca uma media pelo seu slug e pelo slug de um de seus intérpretes.
+   */
+  fn find_by_slug(
+    &mut self,
+    slug: &Slug,
+    artist_slug: &Slug,
+  ) -> impl Future<Output = Result<Option<super::media_aggregate::MediaAggregate>, Error>>;
+
+  /**
+   * Exclui logicamente uma media, que deixa de ser retornada pelas consultas até ser restaurada.
    */
   fn delete_by_id(&mut self, id: &UUID4) -> impl Future<Output = Result<(), Error>>;
 
+  /**
+   * Busca uma media excluída pelo seu identificador.
+   */
+  fn find_deleted_by_id(
+    &mut self,
+    id: &UUID4,
+  ) -> impl Future<Output = Result<Option<Media>, Error>>;
+
+  /**
+   * Restaura uma media excluída.
+   */
+  fn restore_by_id(&mut self, id: &UUID4) -> impl Future<Output = Result<(), Error>>;
+
   fn find_by(
     &mut self,
     query: &FindByQuery,
   ) -> impl Future<Output = Result<Paged<super::media_aggregate::MediaAggregate>, Error>>;
+
+  /**
+   * Busca medias com paginação por cursor, da mais recente para a mais antiga.
+   *
+   * Os campos `page` e `sort` da consulta são ignorados.
+   */
+  fn find_by_cursor(
+    &mut self,
+    query: &FindByQuery,
+    page: &CursorPageParams,
+  ) -> impl Future<Output = Result<CursorPaged<super::media_aggregate::MediaAggregate>, Error>>;
+
+  /**
+   * Conta as mídias que atendem aos filtros da consulta agrupando-as por cada um dos campos.
+   *
+   * Os campos `page` e `sort` da consulta são ignorados.
+   */
+  fn facets(
+    &mut self,
+    query: &FindByQuery,
+    fields: &[FacetField],
+  ) -> impl Future<Output = Result<Vec<Facet<FacetField>>, Error>>;
 }
@@ -37,11 +37,11 @@ async fn delete_single(
   media: &mut Media,
   album_repository: &mut impl crate::domain::album::repository::AlbumRepository,
 ) -> Result<(), Error> {
-  if media.album_ids().contains(&media.id()) {
+  if media.album_ids().contains(media.id()) {
     let media_id = media.id().clone();
     media.album_ids_mut().remove(&media_id);
     album_repository
-      .delete_by_id(&media.id())
+      .delete_by_id(media.id())
       .await
       .map_err(Error::AlbumRepositoryError)?;
   }
@@ -90,10 +90,25 @@ pub async fn execute(
         delete_single(&mut media, album_repository).await?;
       }
       media_repository
-        .delete_by_id(&media.id())
+        .delete_by_id(media.id())
         .await
         .map_err(Error::RepositoryError)?;
       Ok(())
     }
+    crate::domain::media::contribution::Contribution::Restore(media) => {
+      media_repository
+        .restore_by_id(media.id())
+        .await
+        .map_err(Error::RepositoryError)?;
+
+      // O álbum do single é excluído junto com a media e volta com ela.
+      if *media.is_single() {
+        album_repository
+          .restore_by_id(media.id())
+          .await
+          .map_err(Error::AlbumRepositoryError)?;
+      }
+      Ok(())
+    }
   }
 }
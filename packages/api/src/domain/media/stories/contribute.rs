@@ -14,12 +14,24 @@ pub struct CreateMediaInput {
 
   pub parental_rating: Option<u8>,
 
+  pub duration: Option<u32>,
+
+  pub isrc: Option<crate::shared::vo::Isrc>,
+
+  pub bpm: Option<u16>,
+
+  pub key: Option<String>,
+
+  pub language: Option<String>,
+
   pub composer_ids: Option<std::collections::HashSet<crate::shared::vo::UUID4>>,
 
   pub interpreter_ids: std::collections::HashSet<crate::shared::vo::UUID4>,
 
   pub album_ids: std::collections::HashSet<crate::shared::vo::UUID4>,
 
+  pub credits: Option<Vec<crate::domain::media::credit::Credit>>,
+
   pub is_single: Option<bool>,
 }
 
@@ -38,9 +50,15 @@ impl From<CreateMediaInput> for crate::domain::media::Media {
       .cover(value.cover)
       .genre_ids(value.genre_ids.unwrap_or_default())
       .parental_rating(value.parental_rating.unwrap_or_default())
+      .duration(value.duration)
+      .isrc(value.isrc)
+      .bpm(value.bpm)
+      .key(value.key)
+      .language(value.language)
       .composer_ids(value.composer_ids.unwrap_or_default())
       .interpreter_ids(value.interpreter_ids)
       .album_ids(value.album_ids)
+      .credits(value.credits.unwrap_or_default())
       .is_single(value.is_single.unwrap_or_default())
       .build()
   }
@@ -54,15 +72,166 @@ pub enum ChangeInput {
     changes: crate::domain::media::contribution::changes::Changes,
   },
   Delete(crate::shared::vo::UUID4),
+  Restore(crate::shared::vo::UUID4),
 }
 
 #[derive(Debug, Clone)]
 pub enum Error {
   MediaNotFound,
+  DuplicatedCredit(crate::domain::media::credit::Credit),
+  MissingReferences(crate::shared::reference::MissingReferences),
   MediaRepositoryError(crate::domain::media::repository::Error),
+  ArtistRepositoryError(crate::domain::artist::repository::Error),
+  GenreRepositoryError(crate::domain::genre::Error),
+  AlbumRepositoryError(crate::domain::album::repository::Error),
   ActivityRepositoryError(crate::domain::activity::repository::Error),
 }
 
+/**
+ * Garante que um artista não seja creditado mais de uma vez com o mesmo papel.
+ */
+fn validate_credits(credits: &[crate::domain::media::credit::Credit]) -> Result<(), Error> {
+  let mut seen = std::collections::HashSet::new();
+
+  for credit in credits {
+    if !seen.insert(credit) {
+      return Err(Error::DuplicatedCredit(credit.clone()));
+    }
+  }
+
+  Ok(())
+}
+
+/**
+ * Ids referenciados por uma contribuição, que precisam existir antes que ela
+ * seja registrada para evitar falhas de chave estrangeira na aprovação.
+ */
+#[derive(Debug, Clone, Copy)]
+struct References<'a> {
+  genre_ids: Option<&'a std::collections::HashSet<crate::shared::vo::UUID4>>,
+  composer_ids: Option<&'a std::collections::HashSet<crate::shared::vo::UUID4>>,
+  interpreter_ids: Option<&'a std::collections::HashSet<crate::shared::vo::UUID4>>,
+  album_ids: Option<&'a std::collections::HashSet<crate::shared::vo::UUID4>>,
+  credits: Option<&'a [crate::domain::media::credit::Credit]>,
+}
+
+impl<'a> From<&'a crate::domain::media::Media> for References<'a> {
+  fn from(media: &'a crate::domain::media::Media) -> Self {
+    Self {
+      genre_ids: Some(media.genre_ids()),
+      composer_ids: Some(media.composer_ids()),
+      interpreter_ids: Some(media.interpreter_ids()),
+      album_ids: Some(media.album_ids()),
+      credits: Some(media.credits().as_slice()),
+    }
+  }
+}
+
+impl<'a> From<&'a crate::domain::media::contribution::changes::Changes> for References<'a> {
+  fn from(changes: &'a crate::domain::media::contribution::changes::Changes) -> Self {
+    Self {
+      genre_ids: changes.genre_ids.as_ref(),
+      composer_ids: changes.composer_ids.as_ref(),
+      interpreter_ids: changes.interpreter_ids.as_ref(),
+      album_ids: changes.album_ids.as_ref(),
+      credits: changes.credits.as_deref(),
+    }
+  }
+}
+
+/**
+ * Garante que todos os artistas, gêneros e álbuns referenciados existam.
+ */
+async fn validate_references(
+  artist_repository: &mut impl crate::domain::artist::repository::ArtistRepository,
+  genre_repository: &mut impl crate::domain::genre::GenreRepository,
+  album_repository: &mut impl crate::domain::album::repository::AlbumRepository,
+  references: References<'_>,
+) -> Result<(), Error> {
+  let credit_artist_ids = references
+    .credits
+    .unwrap_or_default()
+    .iter()
+    .map(|credit| &credit.artist_id)
+    .collect::<Vec<_>>();
+
+  let artist_ids = references
+    .composer_ids
+    .into_iter()
+    .flatten()
+    .chain(references.interpreter_ids.into_iter().flatten())
+    .chain(credit_artist_ids.iter().copied())
+    .cloned()
+    .collect::<std::collections::HashSet<_>>();
+
+  let existing_artist_ids = if artist_ids.is_empty() {
+    std::collections::HashSet::new()
+  } else {
+    artist_repository
+      .find_by_ids(&artist_ids)
+      .await
+      .map_err(Error::ArtistRepositoryError)?
+      .into_iter()
+      .map(|artist| artist.id().clone())
+      .collect()
+  };
+
+  let genre_ids = references
+    .genre_ids
+    .into_iter()
+    .flatten()
+    .cloned()
+    .collect::<std::collections::HashSet<_>>();
+
+  let existing_genre_ids = genre_repository
+    .find_by_ids(&genre_ids)
+    .await
+    .map_err(Error::GenreRepositoryError)?
+    .into_iter()
+    .map(|genre| genre.id().clone())
+    .collect();
+
+  let album_ids = references
+    .album_ids
+    .into_iter()
+    .flatten()
+    .cloned()
+    .collect::<std::collections::HashSet<_>>();
+
+  let existing_album_ids = album_repository
+    .find_by_ids(&album_ids)
+    .await
+    .map_err(Error::AlbumRepositoryError)?
+    .into_iter()
+    .map(|album| album.id().clone())
+    .collect();
+
+  let mut missing = crate::shared::reference::MissingReferences::default();
+  missing.check(
+    "genre_ids",
+    references.genre_ids.into_iter().flatten(),
+    &existing_genre_ids,
+  );
+  missing.check(
+    "composer_ids",
+    references.composer_ids.into_iter().flatten(),
+    &existing_artist_ids,
+  );
+  missing.check(
+    "interpreter_ids",
+    references.interpreter_ids.into_iter().flatten(),
+    &existing_artist_ids,
+  );
+  missing.check(
+    "album_ids",
+    references.album_ids.into_iter().flatten(),
+    &existing_album_ids,
+  );
+  missing.check("credits", credit_artist_ids, &existing_artist_ids);
+
+  missing.into_result().map_err(Error::MissingReferences)
+}
+
 #[derive(Debug, Clone)]
 pub struct Input {
   pub actor_id: crate::shared::vo::UUID4,
@@ -71,12 +240,24 @@ pub struct Input {
 
 pub async fn execute(
   media_repository: &mut impl crate::domain::media::repository::MediaRepository,
+  artist_repository: &mut impl crate::domain::artist::repository::ArtistRepository,
+  genre_repository: &mut impl crate::domain::genre::GenreRepository,
+  album_repository: &mut impl crate::domain::album::repository::AlbumRepository,
   activity_repository: &mut impl crate::domain::activity::repository::ActivityRepository,
   input: Input,
 ) -> Result<crate::domain::activity::Activity, Error> {
   let contribution: crate::domain::media::contribution::Contribution = match input.data {
     ChangeInput::Create(media) => {
-      crate::domain::media::contribution::Contribution::Create(media.into())
+      let media: crate::domain::media::Media = media.into();
+      validate_credits(media.credits())?;
+      validate_references(
+        artist_repository,
+        genre_repository,
+        album_repository,
+        References::from(&media),
+      )
+      .await?;
+      crate::domain::media::contribution::Contribution::Create(media)
     }
 
     ChangeInput::Delete(id) => {
@@ -92,13 +273,38 @@ pub async fn execute(
       }
     }
 
+    ChangeInput::Restore(id) => {
+      let media = media_repository
+        .find_deleted_by_id(&id)
+        .await
+        .map_err(Error::MediaRepositoryError)?;
+
+      if let Some(media) = media {
+        crate::domain::media::contribution::Contribution::Restore(media)
+      } else {
+        return Err(Error::MediaNotFound);
+      }
+    }
+
     ChangeInput::Update { id, changes } => {
+      if let Some(credits) = &changes.credits {
+        validate_credits(credits)?;
+      }
+
       let media = media_repository
         .find_by_id(&id)
         .await
         .map_err(Error::MediaRepositoryError)?;
 
       if let Some(media) = media {
+        validate_references(
+          artist_repository,
+          genre_repository,
+          album_repository,
+          References::from(&changes),
+        )
+        .await?;
+
         crate::domain::media::contribution::Contribution::Update {
           entity: media,
           changes,
@@ -122,3 +328,126 @@ pub async fn execute(
 
   Ok(activity)
 }
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    domain::{
+      album::{repository::AlbumRepository, Album, AlbumType},
+      artist::{repository::ArtistRepository, Artist},
+      genre::{Genre, GenreRepository},
+      media::MediaType,
+    },
+    infra::in_memory::{
+      InMemoryActivityRepository, InMemoryAlbumRepository, InMemoryArtistRepository,
+      InMemoryGenreRepository, InMemoryMediaRepository,
+    },
+    shared::vo::{Slug, UUID4},
+  };
+
+  #[tokio::test]
+  async fn test_missing_references() {
+    let mut media_repository = InMemoryMediaRepository::default();
+    let mut artist_repository = InMemoryArtistRepository::default();
+    let mut genre_repository = InMemoryGenreRepository::default();
+    let mut album_repository = InMemoryAlbumRepository::default();
+    let mut activity_repository = InMemoryActivityRepository::default();
+
+    let artist = Artist::builder()
+      .name(String::from("Teste"))
+      .slug(Slug::new("teste").unwrap())
+      .build();
+    artist_repository.create(&artist).await.unwrap();
+
+    let genre = Genre::builder()
+      .name(String::from("Rock"))
+      .slug(Slug::new("rock").unwrap())
+      .build();
+    genre_repository.create(&genre).await.unwrap();
+
+    let album = Album::builder()
+      .name(String::from("Teste"))
+      .slug(Slug::new("teste").unwrap())
+      .album_type(AlbumType::Album)
+      .artist_ids([artist.id().clone()].into())
+      .build();
+    album_repository.create(&album).await.unwrap();
+
+    let missing_artist_id = UUID4::generate();
+    let missing_genre_id = UUID4::generate();
+    let missing_album_id = UUID4::generate();
+
+    let input = |interpreter_ids, genre_ids, album_ids| super::Input {
+      actor_id: UUID4::generate(),
+      data: super::ChangeInput::Create(super::CreateMediaInput {
+        name: String::from("Teste"),
+        media_type: MediaType::Song,
+        slug: None,
+        release_date: None,
+        cover: None,
+        genre_ids: Some(genre_ids),
+        parental_rating: None,
+        duration: None,
+        isrc: None,
+        bpm: None,
+        key: None,
+        language: None,
+        composer_ids: None,
+        interpreter_ids,
+        album_ids,
+        credits: None,
+        is_single: None,
+      }),
+    };
+
+    let result = super::execute(
+      &mut media_repository,
+      &mut artist_repository,
+      &mut genre_repository,
+      &mut album_repository,
+      &mut activity_repository,
+      input(
+        [artist.id().clone(), missing_artist_id.clone()].into(),
+        [genre.id().clone(), missing_genre_id.clone()].into(),
+        [album.id().clone(), missing_album_id.clone()].into(),
+      ),
+    )
+    .await;
+
+    match result {
+      Err(super::Error::MissingReferences(missing)) => {
+        assert_eq!(
+          missing.fields().get("interpreter_ids"),
+          Some(&vec![missing_artist_id]),
+          "Apenas o artista inexistente deveria ser apontado"
+        );
+        assert_eq!(
+          missing.fields().get("genre_ids"),
+          Some(&vec![missing_genre_id]),
+          "Apenas o gênero inexistente deveria ser apontado"
+        );
+        assert_eq!(
+          missing.fields().get("album_ids"),
+          Some(&vec![missing_album_id]),
+          "Apenas o álbum inexistente deveria ser apontado"
+        );
+      }
+      other => panic!("Esperava referências ausentes, obteve {:?}", other),
+    }
+
+    super::execute(
+      &mut media_repository,
+      &mut artist_repository,
+      &mut genre_repository,
+      &mut album_repository,
+      &mut activity_repository,
+      input(
+        [artist.id().clone()].into(),
+        [genre.id().clone()].into(),
+        [album.id().clone()].into(),
+      ),
+    )
+    .await
+    .expect("Contribuição com referências existentes deveria ser aceita");
+  }
+}
@@ -0,0 +1,34 @@
+use crate::{domain::media::repository::FacetField, shared::facet::Facet};
+
+pub struct Input {
+  /**
+   * Filtros da listagem sobre a qual as facetas são calculadas; a paginação e a
+   * ordenação são ignoradas.
+   */
+  pub filter: super::find_by::Input,
+
+  /**
+   * Campos a agrupar; vazio para todos os campos disponíveis.
+   */
+  pub fields: Vec<FacetField>,
+}
+
+pub enum Error {
+  RepositoryError(crate::domain::media::repository::Error),
+}
+
+pub async fn execute(
+  media_repository: &mut impl crate::domain::media::repository::MediaRepository,
+  input: Input,
+) -> Result<Vec<Facet<FacetField>>, Error> {
+  let fields = if input.fields.is_empty() {
+    FacetField::ALL.to_vec()
+  } else {
+    input.fields
+  };
+
+  media_repository
+    .facets(&input.filter.into(), &fields)
+    .await
+    .map_err(Error::RepositoryError)
+}
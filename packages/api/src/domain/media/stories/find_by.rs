@@ -1,41 +1,35 @@
 use crate::shared::{
+  filter::ListFilter,
   paged::RequestPageParams,
   vo::{Slug, UUID4},
 };
 
-use std::collections::HashSet;
-
 #[derive(Debug, Clone)]
 pub struct Input {
   pub page: RequestPageParams,
+  pub sort: Option<crate::shared::sort::Sort<crate::domain::media::repository::SortField>>,
   pub search: Option<String>,
   pub release_date: Option<chrono::NaiveDate>,
   pub min_release_date: Option<chrono::NaiveDate>,
   pub max_release_date: Option<chrono::NaiveDate>,
-  pub parental_rating: Option<u8>,
+  pub parental_rating: Option<ListFilter<u8>>,
   pub min_parental_rating: Option<u8>,
   pub max_parental_rating: Option<u8>,
   pub is_single: Option<bool>,
-  pub media_type: Option<crate::domain::media::MediaType>,
+  pub media_type: Option<ListFilter<crate::domain::media::MediaType>>,
   pub slug: Option<Slug>,
-  pub artist_ids: Option<HashSet<UUID4>>,
-  pub composer_ids: Option<HashSet<UUID4>>,
-  pub genre_ids: Option<HashSet<UUID4>>,
-  pub album_ids: Option<HashSet<UUID4>>,
-}
-
-pub enum Error {
-  RepositoryError(crate::domain::media::repository::Error),
+  pub artist_ids: Option<ListFilter<UUID4>>,
+  pub composer_ids: Option<ListFilter<UUID4>>,
+  pub genre_ids: Option<ListFilter<UUID4>>,
+  pub album_ids: Option<ListFilter<UUID4>>,
+  pub credit_role: Option<crate::domain::media::credit::CreditRole>,
 }
 
-pub async fn execute(
-  media_repository: &mut impl crate::domain::media::repository::MediaRepository,
-  input: Input,
-) -> Result<crate::shared::paged::Paged<crate::domain::media::media_aggregate::MediaAggregate>, Error>
-{
-  let result = media_repository
-    .find_by(&crate::domain::media::repository::FindByQuery {
+impl From<Input> for crate::domain::media::repository::FindByQuery {
+  fn from(input: Input) -> Self {
+    Self {
       page: input.page,
+      sort: input.sort,
       release_date: input.release_date,
       min_release_date: input.min_release_date,
       max_release_date: input.max_release_date,
@@ -49,8 +43,23 @@ pub async fn execute(
       composer_ids: input.composer_ids,
       genre_ids: input.genre_ids,
       album_ids: input.album_ids,
+      credit_role: input.credit_role,
       search: input.search,
-    })
+    }
+  }
+}
+
+pub enum Error {
+  RepositoryError(crate::domain::media::repository::Error),
+}
+
+pub async fn execute(
+  media_repository: &mut impl crate::domain::media::repository::MediaRepository,
+  input: Input,
+) -> Result<crate::shared::paged::Paged<crate::domain::media::media_aggregate::MediaAggregate>, Error>
+{
+  let result = media_repository
+    .find_by(&input.into())
     .await
     .map_err(Error::RepositoryError)?;
 
@@ -0,0 +1,29 @@
+use crate::shared::paged::{CursorPageParams, CursorPaged};
+
+pub struct Input {
+  pub page: CursorPageParams,
+
+  /**
+   * Filtros da listagem; a paginação por cursor não aceita outra ordenação.
+   */
+  pub filter: super::find_by::Input,
+}
+
+pub enum Error {
+  SortNotSupported,
+  RepositoryError(crate::domain::media::repository::Error),
+}
+
+pub async fn execute(
+  media_repository: &mut impl crate::domain::media::repository::MediaRepository,
+  input: Input,
+) -> Result<CursorPaged<crate::domain::media::media_aggregate::MediaAggregate>, Error> {
+  if input.filter.sort.is_some() {
+    return Err(Error::SortNotSupported);
+  }
+
+  media_repository
+    .find_by_cursor(&input.filter.into(), &input.page)
+    .await
+    .map_err(Error::RepositoryError)
+}
@@ -0,0 +1,25 @@
+use crate::domain::{
+  self,
+  media::{media_aggregate::MediaAggregate, repository::MediaRepository},
+};
+
+#[derive(Debug, Clone)]
+pub enum Error {
+  RepositoryError(domain::media::repository::Error),
+  MediaNotFound,
+  ParentalRatingRestricted,
+}
+
+pub async fn execute(
+  media_repository: &mut impl MediaRepository,
+  id: &crate::shared::vo::UUID4,
+  parental_rating_limit: u8,
+) -> Result<MediaAggregate, Error> {
+  media_repository
+    .find_aggregate_by_id(id)
+    .await
+    .map_err(Error::RepositoryError)?
+    .ok_or(Error::MediaNotFound)?
+    .restrict_parental_rating(parental_rating_limit)
+    .ok_or(Error::ParentalRatingRestricted)
+}
@@ -0,0 +1,26 @@
+use crate::domain::{
+  self,
+  media::{media_aggregate::MediaAggregate, repository::MediaRepository},
+};
+
+#[derive(Debug, Clone)]
+pub enum Error {
+  RepositoryError(domain::media::repository::Error),
+  MediaNotFound,
+  ParentalRatingRestricted,
+}
+
+pub async fn execute(
+  media_repository: &mut impl MediaRepository,
+  slug: &crate::shared::vo::Slug,
+  artist_slug: &crate::shared::vo::Slug,
+  parental_rating_limit: u8,
+) -> Result<MediaAggregate, Error> {
+  media_repository
+    .find_by_slug(slug, artist_slug)
+    .await
+    .map_err(Error::RepositoryError)?
+    .ok_or(Error::MediaNotFound)?
+    .restrict_parental_rating(parental_rating_limit)
+    .ok_or(Error::ParentalRatingRestricted)
+}
@@ -1,3 +1,7 @@
 pub mod apply_changes;
 pub mod contribute;
+pub mod facets;
 pub mod find_by;
+pub mod find_by_cursor;
+pub mod find_by_id;
+pub mod find_by_slug;
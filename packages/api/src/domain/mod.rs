@@ -1,7 +1,10 @@
 pub mod activity;
 pub mod album;
 pub mod artist;
+pub mod catalog;
 pub mod genre;
+pub mod lyrics;
 pub mod media;
+pub mod search;
 pub mod source;
 pub mod user;
@@ -0,0 +1,6 @@
+mod repository;
+mod search_result;
+
+pub use repository::*;
+pub use search_result::*;
+pub mod stories;
@@ -0,0 +1,65 @@
+use std::future::Future;
+
+use crate::shared::{
+  paged::{Paged, RequestPageParams},
+  vo::CollaborativeEntityName,
+};
+
+use super::{SearchResult, Suggestion};
+
+#[derive(Debug, Clone)]
+pub enum Error {
+  DatabaseError(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchQuery {
+  pub page: RequestPageParams,
+  pub term: String,
+  pub entity_names: Vec<CollaborativeEntityName>,
+  pub limit_per_type: usize,
+
+  /**
+   * Oculta álbuns e mídias acima desta classificação indicativa.
+   */
+  pub max_parental_rating: Option<u8>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AutocompleteQuery {
+  pub entity_name: CollaborativeEntityName,
+  pub term: String,
+  pub limit: usize,
+
+  /**
+   * Oculta álbuns e mídias acima desta classificação indicativa.
+   */
+  pub max_parental_rating: Option<u8>,
+}
+
+pub trait SearchRepository {
+  /**
+   * Busca o termo em todas as entidades informadas e retorna os resultados
+   * ordenados por relevância.
+   *
+   * Cada tipo de entidade contribui com no máximo `limit_per_type` resultados.
+   */
+  fn search(
+    &mut self,
+    query: &SearchQuery,
+  ) -> impl Future<Output = Result<Paged<SearchResult>, Error>>;
+
+  /**
+   * Busca as entidades do tipo informado cujo nome, ou alguma palavra do nome,
+   * começa com o termo buscado.
+   */
+  fn autocomplete(
+    &mut self,
+    query: &AutocompleteQuery,
+  ) -> impl Future<Output = Result<Vec<Suggestion>, Error>>;
+
+  /**
+   * Reconstrói os índices usados pela busca e atualiza as estatísticas das tabelas pesquisadas.
+   */
+  fn reindex(&mut self) -> impl Future<Output = Result<(), Error>>;
+}
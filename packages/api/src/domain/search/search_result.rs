@@ -0,0 +1,25 @@
+use crate::shared::vo::{CollaborativeEntityName, Slug, UUID4};
+
+/**
+ * Resultado da busca global, identificado pelo tipo da entidade encontrada.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchResult {
+  pub entity_name: CollaborativeEntityName,
+  pub id: UUID4,
+  pub name: String,
+  pub slug: Slug,
+  pub rank: f32,
+}
+
+/**
+ * Sugestão do autocomplete, com uma dica para diferenciar entidades de mesmo nome
+ * (ex: país do artista ou ano de lançamento do álbum).
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+  pub id: UUID4,
+  pub name: String,
+  pub slug: Slug,
+  pub hint: Option<String>,
+}
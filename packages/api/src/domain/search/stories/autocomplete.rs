@@ -0,0 +1,49 @@
+use crate::{
+  domain::search::{AutocompleteQuery, SearchRepository, Suggestion},
+  shared::vo::CollaborativeEntityName,
+};
+
+use super::search::SEARCHABLE_ENTITIES;
+
+pub const DEFAULT_LIMIT: usize = 10;
+
+pub const MAX_LIMIT: usize = 50;
+
+#[derive(Debug)]
+pub enum Error {
+  EmptyTerm,
+  UnsupportedEntity(CollaborativeEntityName),
+  RepositoryError(crate::domain::search::Error),
+}
+
+pub struct Input {
+  pub entity_name: CollaborativeEntityName,
+  pub term: String,
+  pub limit: Option<usize>,
+  pub max_parental_rating: Option<u8>,
+}
+
+pub async fn execute(
+  search_repository: &mut impl SearchRepository,
+  input: Input,
+) -> Result<Vec<Suggestion>, Error> {
+  let term = input.term.trim().to_string();
+
+  if term.is_empty() {
+    return Err(Error::EmptyTerm);
+  }
+
+  if !SEARCHABLE_ENTITIES.contains(&input.entity_name) {
+    return Err(Error::UnsupportedEntity(input.entity_name));
+  }
+
+  search_repository
+    .autocomplete(&AutocompleteQuery {
+      entity_name: input.entity_name,
+      term,
+      limit: input.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT),
+      max_parental_rating: input.max_parental_rating,
+    })
+    .await
+    .map_err(Error::RepositoryError)
+}
@@ -0,0 +1,3 @@
+pub mod autocomplete;
+pub mod reindex;
+pub mod search;
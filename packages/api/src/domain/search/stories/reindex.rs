@@ -0,0 +1,13 @@
+use crate::domain::search::SearchRepository;
+
+#[derive(Debug)]
+pub enum Error {
+  RepositoryError(crate::domain::search::Error),
+}
+
+pub async fn execute(search_repository: &mut impl SearchRepository) -> Result<(), Error> {
+  search_repository
+    .reindex()
+    .await
+    .map_err(Error::RepositoryError)
+}
@@ -0,0 +1,77 @@
+use crate::{
+  domain::search::{SearchQuery, SearchRepository, SearchResult},
+  shared::{
+    paged::{Paged, RequestPageParams},
+    vo::CollaborativeEntityName,
+  },
+};
+
+/**
+ * Entidades que podem ser encontradas pela busca global.
+ */
+pub const SEARCHABLE_ENTITIES: [CollaborativeEntityName; 4] = [
+  CollaborativeEntityName::Artist,
+  CollaborativeEntityName::Album,
+  CollaborativeEntityName::Media,
+  CollaborativeEntityName::Genre,
+];
+
+pub const DEFAULT_LIMIT_PER_TYPE: usize = 20;
+
+pub const MAX_LIMIT_PER_TYPE: usize = 100;
+
+#[derive(Debug)]
+pub enum Error {
+  EmptyTerm,
+  UnsupportedEntity(CollaborativeEntityName),
+  RepositoryError(crate::domain::search::Error),
+}
+
+pub struct Input {
+  pub page: RequestPageParams,
+  pub term: String,
+  pub entity_names: Option<Vec<CollaborativeEntityName>>,
+  pub limit_per_type: Option<usize>,
+  pub max_parental_rating: Option<u8>,
+}
+
+pub async fn execute(
+  search_repository: &mut impl SearchRepository,
+  input: Input,
+) -> Result<Paged<SearchResult>, Error> {
+  let term = input.term.trim().to_string();
+
+  if term.is_empty() {
+    return Err(Error::EmptyTerm);
+  }
+
+  let mut entity_names = Vec::new();
+
+  for entity_name in input
+    .entity_names
+    .filter(|entity_names| !entity_names.is_empty())
+    .unwrap_or(SEARCHABLE_ENTITIES.to_vec())
+  {
+    if !SEARCHABLE_ENTITIES.contains(&entity_name) {
+      return Err(Error::UnsupportedEntity(entity_name));
+    }
+
+    if !entity_names.contains(&entity_name) {
+      entity_names.push(entity_name);
+    }
+  }
+
+  search_repository
+    .search(&SearchQuery {
+      page: input.page,
+      term,
+      entity_names,
+      limit_per_type: input
+        .limit_per_type
+        .unwrap_or(DEFAULT_LIMIT_PER_TYPE)
+        .clamp(1, MAX_LIMIT_PER_TYPE),
+      max_parental_rating: input.max_parental_rating,
+    })
+    .await
+    .map_err(Error::RepositoryError)
+}
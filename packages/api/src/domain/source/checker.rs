@@ -0,0 +1,17 @@
+use std::future::Future;
+
+use super::source_entity::{Source, SourceStatus};
+
+#[derive(Debug, Clone)]
+pub enum Error {
+  CheckFailed(String),
+}
+
+/**
+ * Verifica se uma fonte continua disponível no provedor.
+ *
+ * Implementações que acessam a rede ficam na infraestrutura; os testes usam um stub local.
+ */
+pub trait SourceChecker {
+  fn check(&mut self, source: &Source) -> impl Future<Output = Result<SourceStatus, Error>>;
+}
@@ -0,0 +1,17 @@
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Changes {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub source_type: Option<crate::domain::source::source_entity::SourceType>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub src: Option<String>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub external_id: Option<String>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub start_offset: Option<u32>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub duration: Option<u32>,
+}
@@ -1,7 +1,23 @@
+pub mod changes;
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum Contribution {
   Create(super::source_entity::Source),
+  Update {
+    entity: super::source_entity::Source,
+    changes: changes::Changes,
+  },
   Delete(super::source_entity::Source),
+  Restore(super::source_entity::Source),
+
+  /**
+   * Denúncia de uma fonte quebrada; ao ser aprovada, altera o status da fonte.
+   */
+  Report {
+    entity: super::source_entity::Source,
+    status: super::source_entity::SourceStatus,
+    reason: Option<String>,
+  },
 }
 
 impl Default for Contribution {
@@ -1,4 +1,6 @@
+pub mod checker;
 pub mod contribution;
 pub mod repository;
 pub mod source_entity;
+pub mod source_url;
 pub mod stories;
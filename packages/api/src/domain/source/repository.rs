@@ -16,6 +16,14 @@ pub trait SourceRepository {
     source: &super::source_entity::Source,
   ) -> impl Future<Output = Result<(), Error>>;
 
+  /**
+   * Atualiza uma fonte.
+   */
+  fn update(
+    &mut self,
+    source: &super::source_entity::Source,
+  ) -> impl Future<Output = Result<(), Error>>;
+
   /**
    * Busca uma fonte pelo seu identificador e retorna a fonte encontrada ou None caso não exista.
    */
@@ -25,7 +33,48 @@ pub trait SourceRepository {
   ) -> impl Future<Output = Result<Option<super::source_entity::Source>, Error>>;
 
   /**
-   * Deleta uma fonte pelo seu identificador.
+   * Busca todas as fontes de uma mídia.
+   */
+  fn find_by_media_id(
+    &mut self,
+    media_id: &UUID4,
+  ) -> impl Future<Output = Result<Vec<super::source_entity::Source>, Error>>;
+
+  /**
+   * Busca as fontes verificadas há mais tempo, começando pelas que nunca foram verificadas.
+   */
+  fn find_least_recently_checked(
+    &mut self,
+    limit: usize,
+  ) -> impl Future<Output = Result<Vec<super::source_entity::Source>, Error>>;
+
+  /**
+   * Busca uma fonte da mídia pelo identificador canônico no provedor.
+   *
+   * Fontes excluídas também são retornadas, já que continuam reservando o identificador.
+   */
+  fn find_by_external_id(
+    &mut self,
+    media_id: &UUID4,
+    source_type: &super::source_entity::SourceType,
+    external_id: &str,
+  ) -> impl Future<Output = Result<Option<super::source_entity::Source>, Error>>;
+
+  /**
+   * Exclui logicamente uma fonte, que deixa de ser retornada pelas consultas até ser restaurada.
    */
   fn delete_by_id(&mut self, id: &UUID4) -> impl Future<Output = Result<(), Error>>;
+
+  /**
+   * Busca uma fonte excluída pelo seu identificador.
+   */
+  fn find_deleted_by_id(
+    &mut self,
+    id: &UUID4,
+  ) -> impl Future<Output = Result<Option<super::source_entity::Source>, Error>>;
+
+  /**
+   * Restaura uma fonte excluída.
+   */
+  fn restore_by_id(&mut self, id: &UUID4) -> impl Future<Output = Result<(), Error>>;
 }
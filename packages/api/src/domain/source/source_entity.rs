@@ -7,6 +7,28 @@ pub enum SourceType {
   Youtube,
   Soundcloud,
   Vimeo,
+  Spotify,
+  Deezer,
+  AppleMusic,
+  Bandcamp,
+
+  /**
+   * Link direto para um arquivo de áudio (mp3, ogg, flac...).
+   */
+  DirectAudio,
+}
+
+impl SourceType {
+  pub const ALL: [SourceType; 8] = [
+    SourceType::Youtube,
+    SourceType::Soundcloud,
+    SourceType::Vimeo,
+    SourceType::Spotify,
+    SourceType::Deezer,
+    SourceType::AppleMusic,
+    SourceType::Bandcamp,
+    SourceType::DirectAudio,
+  ];
 }
 
 impl std::fmt::Display for SourceType {
@@ -14,7 +36,12 @@ impl std::fmt::Display for SourceType {
     match self {
       SourceType::Youtube => write!(f, "Youtube"),
       SourceType::Soundcloud => write!(f, "Soundcloud"),
-      SourceType::Vimeo => write!(f, "Video"),
+      SourceType::Vimeo => write!(f, "Vimeo"),
+      SourceType::Spotify => write!(f, "Spotify"),
+      SourceType::Deezer => write!(f, "Deezer"),
+      SourceType::AppleMusic => write!(f, "AppleMusic"),
+      SourceType::Bandcamp => write!(f, "Bandcamp"),
+      SourceType::DirectAudio => write!(f, "DirectAudio"),
     }
   }
 }
@@ -22,12 +49,41 @@ impl std::fmt::Display for SourceType {
 impl std::str::FromStr for SourceType {
   type Err = String;
 
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    SourceType::ALL
+      .into_iter()
+      .find(|source_type| source_type.to_string() == s)
+      .ok_or_else(|| format!("Invalid SourceType: {}", s))
+  }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum SourceStatus {
+  #[default]
+  Active,
+  Unavailable,
+  RegionRestricted,
+}
+
+impl std::fmt::Display for SourceStatus {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      SourceStatus::Active => write!(f, "Active"),
+      SourceStatus::Unavailable => write!(f, "Unavailable"),
+      SourceStatus::RegionRestricted => write!(f, "RegionRestricted"),
+    }
+  }
+}
+
+impl std::str::FromStr for SourceStatus {
+  type Err = String;
+
   fn from_str(s: &str) -> Result<Self, Self::Err> {
     match s {
-      "Youtube" => Ok(SourceType::Youtube),
-      "Soundcloud" => Ok(SourceType::Soundcloud),
-      "Video" => Ok(SourceType::Vimeo),
-      _ => Err(format!("Invalid SourceType: {}", s)),
+      "Active" => Ok(SourceStatus::Active),
+      "Unavailable" => Ok(SourceStatus::Unavailable),
+      "RegionRestricted" => Ok(SourceStatus::RegionRestricted),
+      _ => Err(format!("Invalid SourceStatus: {}", s)),
     }
   }
 }
@@ -37,7 +93,23 @@ pub struct Source {
   id: UUID4,
   source_type: SourceType,
   src: String,
+
+  /**
+   * Identificador do vídeo ou faixa no provedor, extraído de `src`.
+   */
+  external_id: Option<String>,
   media_id: UUID4,
+
+  #[serde(default)]
+  status: SourceStatus,
+  last_checked_at: Option<chrono::NaiveDateTime>,
+
+  /**
+   * Trecho da fonte que corresponde à mídia, em segundos.
+   */
+  start_offset: Option<u32>,
+  duration: Option<u32>,
+
   created_at: chrono::NaiveDateTime,
   updated_at: chrono::NaiveDateTime,
 }
@@ -49,9 +121,57 @@ impl Default for Source {
       id: UUID4::default(),
       source_type: SourceType::Youtube,
       src: String::new(),
+      external_id: None,
+      status: SourceStatus::default(),
+      last_checked_at: None,
+      start_offset: None,
+      duration: None,
       media_id: UUID4::default(),
       created_at: now,
       updated_at: now,
     }
   }
 }
+
+impl Source {
+  pub fn apply_changes(&mut self, changes: &super::contribution::changes::Changes) {
+    if let Some(value) = &changes.source_type {
+      self.source_type = value.clone();
+    }
+
+    if let Some(value) = &changes.src {
+      self.src = value.clone();
+    }
+
+    if let Some(value) = &changes.external_id {
+      self.external_id = Some(value.clone());
+    }
+
+    if let Some(value) = changes.start_offset {
+      self.start_offset = Some(value);
+    }
+
+    if let Some(value) = changes.duration {
+      self.duration = Some(value);
+    }
+
+    self.updated_at = naive_now();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_source_type_round_trip() {
+    for source_type in SourceType::ALL {
+      assert_eq!(
+        source_type.to_string().parse::<SourceType>(),
+        Ok(source_type.clone()),
+        "{} não foi convertido de volta",
+        source_type
+      );
+    }
+  }
+}
@@ -0,0 +1,371 @@
+use super::source_entity::SourceType;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+  InvalidUrl(String),
+  HostMismatch {
+    source_type: SourceType,
+    host: String,
+  },
+  MissingId(SourceType),
+}
+
+impl std::fmt::Display for Error {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::InvalidUrl(src) => write!(f, "Invalid URL: {}", src),
+      Self::HostMismatch { source_type, host } => {
+        write!(f, "Host {} does not belong to {}", host, source_type)
+      }
+      Self::MissingId(source_type) => {
+        write!(f, "Could not find a {} identifier in the URL", source_type)
+      }
+    }
+  }
+}
+
+/**
+ * Identificador canônico de uma fonte, extraído da URL informada pelo usuário.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct CanonicalSource {
+  pub external_id: String,
+  pub src: String,
+}
+
+/**
+ * Valida a URL de acordo com o provedor e a normaliza para a forma canônica.
+ *
+ * Nenhuma requisição é feita: apenas o formato da URL é analisado.
+ */
+pub fn parse(source_type: &SourceType, src: &str) -> Result<CanonicalSource, Error> {
+  let src = src.trim();
+  let url = if src.contains("://") {
+    url::Url::parse(src)
+  } else {
+    url::Url::parse(&format!("https://{}", src))
+  }
+  .map_err(|_| Error::InvalidUrl(src.to_string()))?;
+
+  if !matches!(url.scheme(), "http" | "https") {
+    return Err(Error::InvalidUrl(src.to_string()));
+  }
+
+  let host = url
+    .host_str()
+    .ok_or_else(|| Error::InvalidUrl(src.to_string()))?
+    .to_lowercase();
+  let host = host.strip_prefix("www.").unwrap_or(&host);
+
+  let segments: Vec<&str> = url
+    .path_segments()
+    .map(|segments| segments.filter(|segment| !segment.is_empty()).collect())
+    .unwrap_or_default();
+
+  let mismatch = || Error::HostMismatch {
+    source_type: source_type.clone(),
+    host: host.to_string(),
+  };
+
+  let external_id = match source_type {
+    SourceType::Youtube => {
+      let id = match host {
+        "youtu.be" => segments.first().map(|id| id.to_string()),
+        "youtube.com" | "m.youtube.com" | "music.youtube.com" | "youtube-nocookie.com" => {
+          match segments.as_slice() {
+            ["watch"] => url
+              .query_pairs()
+              .find(|(key, _)| key == "v")
+              .map(|(_, value)| value.to_string()),
+            ["embed" | "shorts" | "live" | "v", id, ..] => Some(id.to_string()),
+            _ => None,
+          }
+        }
+        _ => return Err(mismatch()),
+      };
+
+      id.filter(|id| is_youtube_id(id))
+    }
+
+    SourceType::Soundcloud => match host {
+      "soundcloud.com" | "m.soundcloud.com" => match segments.as_slice() {
+        [user, track] if !is_soundcloud_reserved(user) && !is_soundcloud_reserved(track) => {
+          Some(format!("{}/{}", user, track).to_lowercase())
+        }
+        _ => None,
+      },
+      _ => return Err(mismatch()),
+    },
+
+    SourceType::Vimeo => match host {
+      "vimeo.com" => segments
+        .iter()
+        .rev()
+        .find(|segment| is_numeric(segment))
+        .map(|id| id.to_string()),
+      "player.vimeo.com" => match segments.as_slice() {
+        ["video", id, ..] if is_numeric(id) => Some(id.to_string()),
+        _ => None,
+      },
+      _ => return Err(mismatch()),
+    },
+
+    SourceType::Spotify => match host {
+      "open.spotify.com" => {
+        // Links localizados incluem um prefixo como "intl-pt".
+        let segments = match segments.as_slice() {
+          [locale, rest @ ..] if locale.starts_with("intl-") => rest,
+          segments => segments,
+        };
+
+        match segments {
+          ["track", id, ..] if is_spotify_id(id) => Some(id.to_string()),
+          _ => None,
+        }
+      }
+      _ => return Err(mismatch()),
+    },
+
+    SourceType::Deezer => match host {
+      "deezer.com" => match segments.as_slice() {
+        ["track", id, ..] | [_, "track", id, ..] if is_numeric(id) => Some(id.to_string()),
+        _ => None,
+      },
+      _ => return Err(mismatch()),
+    },
+
+    SourceType::AppleMusic => match host {
+      "music.apple.com" => match segments.as_slice() {
+        [_, "album", ..] => url
+          .query_pairs()
+          .find(|(key, _)| key == "i")
+          .map(|(_, value)| value.to_string())
+          .filter(|id| is_numeric(id)),
+        [_, "song", .., id] if is_numeric(id) => Some(id.to_string()),
+        _ => None,
+      },
+      _ => return Err(mismatch()),
+    },
+
+    SourceType::Bandcamp => match host.strip_suffix(".bandcamp.com") {
+      Some(artist) if !artist.is_empty() && !artist.contains('.') => match segments.as_slice() {
+        ["track", track] => Some(format!("{}/{}", artist, track).to_lowercase()),
+        _ => None,
+      },
+      _ => return Err(mismatch()),
+    },
+
+    SourceType::DirectAudio => {
+      let is_audio = segments.last().is_some_and(|file| {
+        file
+          .rsplit_once('.')
+          .is_some_and(|(_, extension)| is_audio_extension(&extension.to_lowercase()))
+      });
+
+      if is_audio {
+        let mut url = url.clone();
+        url.set_fragment(None);
+        Some(url.to_string())
+      } else {
+        None
+      }
+    }
+  }
+  .ok_or_else(|| Error::MissingId(source_type.clone()))?;
+
+  let src = match source_type {
+    SourceType::Youtube => format!("https://www.youtube.com/watch?v={}", external_id),
+    SourceType::Soundcloud => format!("https://soundcloud.com/{}", external_id),
+    SourceType::Vimeo => format!("https://vimeo.com/{}", external_id),
+    SourceType::Spotify => format!("https://open.spotify.com/track/{}", external_id),
+    SourceType::Deezer => format!("https://www.deezer.com/track/{}", external_id),
+    SourceType::AppleMusic => format!("https://music.apple.com/song/{}", external_id),
+    SourceType::Bandcamp => {
+      let (artist, track) = external_id.split_once('/').unwrap();
+      format!("https://{}.bandcamp.com/track/{}", artist, track)
+    }
+    SourceType::DirectAudio => external_id.clone(),
+  };
+
+  Ok(CanonicalSource { external_id, src })
+}
+
+fn is_numeric(id: &str) -> bool {
+  !id.is_empty() && id.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn is_spotify_id(id: &str) -> bool {
+  id.len() == 22 && id.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+fn is_audio_extension(extension: &str) -> bool {
+  matches!(
+    extension,
+    "mp3" | "ogg" | "oga" | "opus" | "flac" | "wav" | "m4a" | "aac" | "weba"
+  )
+}
+
+fn is_youtube_id(id: &str) -> bool {
+  id.len() == 11
+    && id
+      .bytes()
+      .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+}
+
+/**
+ * Segmentos do SoundCloud que não identificam uma faixa (playlists, perfis, etc).
+ */
+fn is_soundcloud_reserved(segment: &str) -> bool {
+  matches!(
+    segment,
+    "sets" | "likes" | "tracks" | "albums" | "reposts" | "followers" | "following" | "discover"
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_youtube_variants() {
+    let urls = [
+      "https://www.youtube.com/watch?v=VHA2ASr8rmY",
+      "http://youtube.com/watch?feature=share&v=VHA2ASr8rmY",
+      "https://m.youtube.com/watch?v=VHA2ASr8rmY&t=42",
+      "https://youtu.be/VHA2ASr8rmY?si=abc",
+      "https://www.youtube.com/embed/VHA2ASr8rmY",
+      "https://music.youtube.com/watch?v=VHA2ASr8rmY",
+      "youtube.com/shorts/VHA2ASr8rmY",
+    ];
+
+    for url in urls {
+      let result = parse(&SourceType::Youtube, url).expect(url);
+
+      assert_eq!(result.external_id, "VHA2ASr8rmY", "{}", url);
+      assert_eq!(
+        result.src, "https://www.youtube.com/watch?v=VHA2ASr8rmY",
+        "{}",
+        url
+      );
+    }
+  }
+
+  #[test]
+  fn test_parse_soundcloud_and_vimeo() {
+    let soundcloud = parse(
+      &SourceType::Soundcloud,
+      "https://soundcloud.com/Artist-Name/Some-Track?in=playlist",
+    )
+    .expect("URL do SoundCloud válida foi rejeitada");
+
+    assert_eq!(soundcloud.external_id, "artist-name/some-track");
+    assert_eq!(
+      soundcloud.src,
+      "https://soundcloud.com/artist-name/some-track"
+    );
+
+    for url in [
+      "https://vimeo.com/76979871",
+      "https://vimeo.com/channels/staffpicks/76979871",
+      "https://player.vimeo.com/video/76979871?h=abc",
+    ] {
+      let vimeo = parse(&SourceType::Vimeo, url).expect(url);
+
+      assert_eq!(vimeo.external_id, "76979871", "{}", url);
+      assert_eq!(vimeo.src, "https://vimeo.com/76979871", "{}", url);
+    }
+  }
+
+  #[test]
+  fn test_parse_streaming_providers() {
+    let cases = [
+      (
+        SourceType::Spotify,
+        "https://open.spotify.com/intl-pt/track/4uLU6hMCjMI75M1A2tKUQC?si=abc",
+        "4uLU6hMCjMI75M1A2tKUQC",
+        "https://open.spotify.com/track/4uLU6hMCjMI75M1A2tKUQC",
+      ),
+      (
+        SourceType::Deezer,
+        "https://www.deezer.com/br/track/3135556",
+        "3135556",
+        "https://www.deezer.com/track/3135556",
+      ),
+      (
+        SourceType::AppleMusic,
+        "https://music.apple.com/br/album/bad/1440857781?i=1440858209",
+        "1440858209",
+        "https://music.apple.com/song/1440858209",
+      ),
+      (
+        SourceType::AppleMusic,
+        "https://music.apple.com/us/song/bad/1440858209",
+        "1440858209",
+        "https://music.apple.com/song/1440858209",
+      ),
+      (
+        SourceType::Bandcamp,
+        "https://Artist.bandcamp.com/track/some-track",
+        "artist/some-track",
+        "https://artist.bandcamp.com/track/some-track",
+      ),
+      (
+        SourceType::DirectAudio,
+        "https://cdn.example.com/audio/track.MP3#t=10",
+        "https://cdn.example.com/audio/track.MP3",
+        "https://cdn.example.com/audio/track.MP3",
+      ),
+    ];
+
+    for (source_type, url, external_id, src) in cases {
+      let result = parse(&source_type, url).expect(url);
+
+      assert_eq!(result.external_id, external_id, "{}", url);
+      assert_eq!(result.src, src, "{}", url);
+    }
+
+    assert_eq!(
+      parse(&SourceType::DirectAudio, "https://example.com/page.html"),
+      Err(Error::MissingId(SourceType::DirectAudio))
+    );
+    assert!(matches!(
+      parse(
+        &SourceType::Bandcamp,
+        "https://bandcamp.com/track/some-track"
+      ),
+      Err(Error::HostMismatch { .. })
+    ));
+  }
+
+  #[test]
+  fn test_parse_rejects_mismatches() {
+    assert!(matches!(
+      parse(
+        &SourceType::Vimeo,
+        "https://www.youtube.com/watch?v=VHA2ASr8rmY"
+      ),
+      Err(Error::HostMismatch { .. })
+    ));
+    assert_eq!(
+      parse(
+        &SourceType::Youtube,
+        "https://www.youtube.com/watch?v=short"
+      ),
+      Err(Error::MissingId(SourceType::Youtube))
+    );
+    assert_eq!(
+      parse(
+        &SourceType::Soundcloud,
+        "https://soundcloud.com/artist/sets/album"
+      ),
+      Err(Error::MissingId(SourceType::Soundcloud))
+    );
+    assert!(matches!(
+      parse(
+        &SourceType::Youtube,
+        "ftp://youtube.com/watch?v=VHA2ASr8rmY"
+      ),
+      Err(Error::InvalidUrl(_))
+    ));
+  }
+}
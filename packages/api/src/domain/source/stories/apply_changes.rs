@@ -1,6 +1,7 @@
 #[derive(Debug, Clone)]
 pub enum Error {
   RepositoryError(crate::domain::source::repository::Error),
+  SourceNotFound(crate::shared::vo::UUID4),
 }
 
 pub type Input = crate::domain::source::contribution::Contribution;
@@ -18,12 +19,125 @@ pub async fn execute(
 
       Ok(())
     }
+    crate::domain::source::contribution::Contribution::Update {
+      entity: mut source,
+      changes,
+    } => {
+      source.apply_changes(&changes);
+      source_repository
+        .update(&source)
+        .await
+        .map_err(Error::RepositoryError)?;
+      Ok(())
+    }
+    crate::domain::source::contribution::Contribution::Report { entity, status, .. } => {
+      // A fonte pode ter mudado desde a denúncia; apenas o status é alterado.
+      let mut source = source_repository
+        .find_by_id(entity.id())
+        .await
+        .map_err(Error::RepositoryError)?
+        .ok_or_else(|| Error::SourceNotFound(entity.id().clone()))?;
+
+      source.set_status(status);
+      source_repository
+        .update(&source)
+        .await
+        .map_err(Error::RepositoryError)?;
+      Ok(())
+    }
     crate::domain::source::contribution::Contribution::Delete(source) => {
       source_repository
-        .delete_by_id(&source.id())
+        .delete_by_id(source.id())
+        .await
+        .map_err(Error::RepositoryError)?;
+      Ok(())
+    }
+    crate::domain::source::contribution::Contribution::Restore(source) => {
+      source_repository
+        .restore_by_id(source.id())
         .await
         .map_err(Error::RepositoryError)?;
       Ok(())
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    domain::source::{
+      contribution::Contribution,
+      repository::SourceRepository,
+      source_entity::{Source, SourceStatus},
+    },
+    infra::in_memory::InMemorySourceRepository,
+  };
+
+  use super::*;
+
+  #[tokio::test]
+  async fn test_report_changes_only_status() {
+    let mut source_repository = InMemorySourceRepository::default();
+
+    let reported = Source::builder()
+      .src(String::from("https://www.youtube.com/watch?v=VHA2ASr8rmY"))
+      .start_offset(Some(10))
+      .build();
+
+    let mut current = reported.clone();
+    current.set_start_offset(Some(42));
+
+    source_repository
+      .create(&current)
+      .await
+      .expect("Erro ao criar fonte");
+
+    execute(
+      &mut source_repository,
+      Contribution::Report {
+        entity: reported.clone(),
+        status: SourceStatus::Unavailable,
+        reason: None,
+      },
+    )
+    .await
+    .expect("Erro ao aplicar denúncia");
+
+    let source = source_repository
+      .find_by_id(reported.id())
+      .await
+      .expect("Erro ao buscar fonte")
+      .expect("Fonte não encontrada");
+
+    assert_eq!(
+      source.status(),
+      &SourceStatus::Unavailable,
+      "Status não foi alterado"
+    );
+    assert_eq!(
+      source.start_offset(),
+      &Some(42),
+      "Denúncia não deveria sobrescrever a fonte com o snapshot"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_report_missing_source() {
+    let mut source_repository = InMemorySourceRepository::default();
+
+    let result = execute(
+      &mut source_repository,
+      Contribution::Report {
+        entity: Source::default(),
+        status: SourceStatus::Unavailable,
+        reason: None,
+      },
+    )
+    .await;
+
+    assert!(
+      matches!(result, Err(Error::SourceNotFound(_))),
+      "Denúncia de fonte inexistente deveria falhar"
+    );
+  }
+}
@@ -0,0 +1,93 @@
+use crate::shared::util::naive_now;
+
+#[derive(Debug, Clone)]
+pub enum Error {
+  SourceNotFound,
+  RepositoryError(crate::domain::source::repository::Error),
+  CheckerError(crate::domain::source::checker::Error),
+}
+
+pub async fn execute(
+  source_repository: &mut impl crate::domain::source::repository::SourceRepository,
+  source_checker: &mut impl crate::domain::source::checker::SourceChecker,
+  source_id: &crate::shared::vo::UUID4,
+) -> Result<crate::domain::source::source_entity::Source, Error> {
+  let mut source = source_repository
+    .find_by_id(source_id)
+    .await
+    .map_err(Error::RepositoryError)?
+    .ok_or(Error::SourceNotFound)?;
+
+  let status = source_checker
+    .check(&source)
+    .await
+    .map_err(Error::CheckerError)?;
+
+  source.set_status(status);
+  source.set_last_checked_at(Some(naive_now()));
+
+  source_repository
+    .update(&source)
+    .await
+    .map_err(Error::RepositoryError)?;
+
+  Ok(source)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    domain::source::{
+      repository::SourceRepository,
+      source_entity::{Source, SourceStatus},
+    },
+    infra::in_memory::{InMemorySourceChecker, InMemorySourceRepository},
+  };
+
+  #[tokio::test]
+  async fn test_check_updates_status_and_timestamp() {
+    let mut source_repository = InMemorySourceRepository::default();
+    let mut source_checker = InMemorySourceChecker::default();
+
+    let source = Source::builder()
+      .src(String::from("https://www.youtube.com/watch?v=VHA2ASr8rmY"))
+      .build();
+
+    source_repository.create(&source).await.unwrap();
+    source_checker
+      .statuses
+      .insert(source.src().clone(), SourceStatus::RegionRestricted);
+
+    let checked = super::execute(&mut source_repository, &mut source_checker, source.id())
+      .await
+      .expect("Falha ao verificar a fonte");
+
+    let stored = source_repository
+      .find_by_id(source.id())
+      .await
+      .unwrap()
+      .expect("Fonte não encontrada");
+
+    assert_eq!(*checked.status(), SourceStatus::RegionRestricted);
+    assert!(
+      checked.last_checked_at().is_some(),
+      "Data da verificação não foi registrada"
+    );
+    assert_eq!(stored, checked, "Status verificado não foi persistido");
+  }
+
+  #[tokio::test]
+  async fn test_check_unknown_source() {
+    let mut source_repository = InMemorySourceRepository::default();
+    let mut source_checker = InMemorySourceChecker::default();
+
+    let result = super::execute(
+      &mut source_repository,
+      &mut source_checker,
+      &crate::shared::vo::UUID4::generate(),
+    )
+    .await;
+
+    assert!(matches!(result, Err(super::Error::SourceNotFound)));
+  }
+}
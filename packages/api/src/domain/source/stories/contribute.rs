@@ -3,27 +3,51 @@ pub struct CreateSourceInput {
   pub source_type: crate::domain::source::source_entity::SourceType,
   pub src: String,
   pub media_id: crate::shared::vo::UUID4,
+  pub start_offset: Option<u32>,
+  pub duration: Option<u32>,
 }
 
-impl From<CreateSourceInput> for crate::domain::source::source_entity::Source {
-  fn from(value: CreateSourceInput) -> Self {
-    crate::domain::source::source_entity::Source::builder()
-      .source_type(value.source_type)
-      .src(value.src)
-      .media_id(value.media_id)
-      .build()
+impl TryFrom<CreateSourceInput> for crate::domain::source::source_entity::Source {
+  type Error = crate::domain::source::source_url::Error;
+
+  fn try_from(value: CreateSourceInput) -> Result<Self, Self::Error> {
+    let canonical = crate::domain::source::source_url::parse(&value.source_type, &value.src)?;
+
+    Ok(
+      crate::domain::source::source_entity::Source::builder()
+        .source_type(value.source_type)
+        .src(canonical.src)
+        .external_id(Some(canonical.external_id))
+        .media_id(value.media_id)
+        .start_offset(value.start_offset)
+        .duration(value.duration)
+        .build(),
+    )
   }
 }
 
 #[derive(Debug, Clone)]
 pub enum ChangeInput {
   Create(CreateSourceInput),
+  Update {
+    id: crate::shared::vo::UUID4,
+    changes: crate::domain::source::contribution::changes::Changes,
+  },
   Delete(crate::shared::vo::UUID4),
+  Restore(crate::shared::vo::UUID4),
+  Report {
+    id: crate::shared::vo::UUID4,
+    status: crate::domain::source::source_entity::SourceStatus,
+    reason: Option<String>,
+  },
 }
 
 #[derive(Debug, Clone)]
 pub enum Error {
   SourceNotFound,
+  InvalidSourceUrl(crate::domain::source::source_url::Error),
+  DuplicatedSource(crate::shared::vo::UUID4),
+  ReportedStatusIsActive,
   SourceRepositoryError(crate::domain::source::repository::Error),
   ActivityRepositoryError(crate::domain::activity::repository::Error),
 }
@@ -34,6 +58,27 @@ pub struct Input {
   pub data: ChangeInput,
 }
 
+async fn ensure_not_duplicated(
+  source_repository: &mut impl crate::domain::source::repository::SourceRepository,
+  source: &crate::domain::source::source_entity::Source,
+) -> Result<(), Error> {
+  let duplicated = source_repository
+    .find_by_external_id(
+      source.media_id(),
+      source.source_type(),
+      source.external_id().as_deref().unwrap_or_default(),
+    )
+    .await
+    .map_err(Error::SourceRepositoryError)?;
+
+  match duplicated {
+    Some(duplicated) if duplicated.id() != source.id() => {
+      Err(Error::DuplicatedSource(duplicated.id().clone()))
+    }
+    _ => Ok(()),
+  }
+}
+
 pub async fn execute(
   source_repository: &mut impl crate::domain::source::repository::SourceRepository,
   activity_repository: &mut impl crate::domain::activity::repository::ActivityRepository,
@@ -41,7 +86,41 @@ pub async fn execute(
 ) -> Result<crate::domain::activity::Activity, Error> {
   let contribution: crate::domain::source::contribution::Contribution = match input.data {
     ChangeInput::Create(source) => {
-      crate::domain::source::contribution::Contribution::Create(source.into())
+      let source = crate::domain::source::source_entity::Source::try_from(source)
+        .map_err(Error::InvalidSourceUrl)?;
+
+      ensure_not_duplicated(source_repository, &source).await?;
+
+      crate::domain::source::contribution::Contribution::Create(source)
+    }
+
+    ChangeInput::Update { id, mut changes } => {
+      let mut source = source_repository
+        .find_by_id(&id)
+        .await
+        .map_err(Error::SourceRepositoryError)?
+        .ok_or(Error::SourceNotFound)?;
+
+      // O identificador canônico é recalculado a partir do provedor e da URL resultantes.
+      if changes.source_type.is_some() || changes.src.is_some() {
+        let canonical = crate::domain::source::source_url::parse(
+          changes.source_type.as_ref().unwrap_or(source.source_type()),
+          changes.src.as_deref().unwrap_or(source.src()),
+        )
+        .map_err(Error::InvalidSourceUrl)?;
+
+        changes.src = Some(canonical.src);
+        changes.external_id = Some(canonical.external_id);
+      }
+
+      source.apply_changes(&changes);
+
+      ensure_not_duplicated(source_repository, &source).await?;
+
+      crate::domain::source::contribution::Contribution::Update {
+        entity: source,
+        changes,
+      }
     }
 
     ChangeInput::Delete(id) => {
@@ -56,6 +135,37 @@ pub async fn execute(
         return Err(Error::SourceNotFound);
       }
     }
+
+    ChangeInput::Restore(id) => {
+      let source = source_repository
+        .find_deleted_by_id(&id)
+        .await
+        .map_err(Error::SourceRepositoryError)?;
+
+      if let Some(source) = source {
+        crate::domain::source::contribution::Contribution::Restore(source)
+      } else {
+        return Err(Error::SourceNotFound);
+      }
+    }
+
+    ChangeInput::Report { id, status, reason } => {
+      if status == crate::domain::source::source_entity::SourceStatus::Active {
+        return Err(Error::ReportedStatusIsActive);
+      }
+
+      let source = source_repository
+        .find_by_id(&id)
+        .await
+        .map_err(Error::SourceRepositoryError)?
+        .ok_or(Error::SourceNotFound)?;
+
+      crate::domain::source::contribution::Contribution::Report {
+        entity: source,
+        status,
+        reason,
+      }
+    }
   };
 
   let activity = crate::domain::activity::Activity::builder()
@@ -71,3 +181,71 @@ pub async fn execute(
 
   Ok(activity)
 }
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    domain::{
+      activity::ActivityStatus,
+      source::{
+        contribution::Contribution,
+        repository::SourceRepository,
+        source_entity::{Source, SourceStatus},
+      },
+    },
+    infra::in_memory::{InMemoryActivityRepository, InMemorySourceRepository},
+    shared::vo::UUID4,
+  };
+
+  #[tokio::test]
+  async fn test_report_broken_source() {
+    let mut source_repository = InMemorySourceRepository::default();
+    let mut activity_repository = InMemoryActivityRepository::default();
+
+    let source = Source::builder()
+      .src(String::from("https://www.youtube.com/watch?v=VHA2ASr8rmY"))
+      .build();
+    source_repository.create(&source).await.unwrap();
+
+    let activity = super::execute(
+      &mut source_repository,
+      &mut activity_repository,
+      super::Input {
+        actor_id: UUID4::generate(),
+        data: super::ChangeInput::Report {
+          id: source.id().clone(),
+          status: SourceStatus::Unavailable,
+          reason: Some(String::from("Vídeo removido")),
+        },
+      },
+    )
+    .await
+    .expect("Falha ao denunciar a fonte");
+
+    assert_eq!(*activity.status(), ActivityStatus::Pending);
+    assert_eq!(
+      *activity.contribution(),
+      crate::shared::vo::Contribution::Source(Contribution::Report {
+        entity: source.clone(),
+        status: SourceStatus::Unavailable,
+        reason: Some(String::from("Vídeo removido")),
+      })
+    );
+
+    let result = super::execute(
+      &mut source_repository,
+      &mut activity_repository,
+      super::Input {
+        actor_id: UUID4::generate(),
+        data: super::ChangeInput::Report {
+          id: source.id().clone(),
+          status: SourceStatus::Active,
+          reason: None,
+        },
+      },
+    )
+    .await;
+
+    assert!(matches!(result, Err(super::Error::ReportedStatusIsActive)));
+  }
+}
@@ -0,0 +1,84 @@
+#[derive(Debug, Clone)]
+pub enum Error {
+  SourceRepositoryError(crate::domain::source::repository::Error),
+  MediaRepositoryError(crate::domain::media::repository::Error),
+  MediaNotFound,
+  ParentalRatingRestricted,
+}
+
+pub async fn execute(
+  source_repository: &mut impl crate::domain::source::repository::SourceRepository,
+  media_repository: &mut impl crate::domain::media::repository::MediaRepository,
+  media_id: &crate::shared::vo::UUID4,
+  parental_rating_limit: u8,
+) -> Result<Vec<crate::domain::source::source_entity::Source>, Error> {
+  let media = media_repository
+    .find_by_id(media_id)
+    .await
+    .map_err(Error::MediaRepositoryError)?
+    .ok_or(Error::MediaNotFound)?;
+
+  if *media.parental_rating() > parental_rating_limit {
+    return Err(Error::ParentalRatingRestricted);
+  }
+
+  source_repository
+    .find_by_media_id(media_id)
+    .await
+    .map_err(Error::SourceRepositoryError)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    domain::{
+      media::{repository::MediaRepository, Media},
+      source::{repository::SourceRepository, source_entity::Source},
+    },
+    infra::in_memory::{InMemoryMediaRepository, InMemorySourceRepository},
+  };
+
+  use super::*;
+
+  #[tokio::test]
+  async fn test_find_sources_of_restricted_media() {
+    let mut source_repository = InMemorySourceRepository::default();
+    let mut media_repository = InMemoryMediaRepository::default();
+
+    let media = Media::builder()
+      .name(String::from("Teste"))
+      .parental_rating(18)
+      .build();
+    media_repository.create(&media).await.unwrap();
+
+    let source = Source::builder()
+      .src(String::from("https://www.youtube.com/watch?v=VHA2ASr8rmY"))
+      .media_id(media.id().clone())
+      .build();
+    source_repository.create(&source).await.unwrap();
+
+    let restricted = execute(
+      &mut source_repository,
+      &mut media_repository,
+      media.id(),
+      12,
+    )
+    .await;
+
+    assert!(
+      matches!(restricted, Err(Error::ParentalRatingRestricted)),
+      "Fontes de mídia acima da classificação indicativa não deveriam ser listadas"
+    );
+
+    let sources = execute(
+      &mut source_repository,
+      &mut media_repository,
+      media.id(),
+      18,
+    )
+    .await
+    .expect("Erro ao listar fontes");
+
+    assert_eq!(sources, vec![source], "Fontes não são iguais às esperadas");
+  }
+}
@@ -1,2 +1,4 @@
 pub mod apply_changes;
+pub mod check;
 pub mod contribute;
+pub mod find_by_media;
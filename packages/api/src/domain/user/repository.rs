@@ -13,6 +13,8 @@ pub enum UserRepositoryError {
 pub trait UserRepository {
   fn create(&mut self, user: &User) -> impl Future<Output = Result<User, UserRepositoryError>>;
 
+  fn update(&mut self, user: &User) -> impl Future<Output = Result<User, UserRepositoryError>>;
+
   fn find_by_username(
     &mut self,
     username: impl Into<String>,
@@ -1,2 +1,4 @@
+pub mod promote;
+pub mod update_preferences;
 pub mod user_login;
 pub mod user_register;
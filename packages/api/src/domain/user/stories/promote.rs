@@ -0,0 +1,89 @@
+use crate::domain::user::{User, UserRepository, UserRepositoryError};
+
+#[derive(Debug)]
+pub enum PromoteError {
+  UserNotFound,
+  RepositoryError(UserRepositoryError),
+}
+
+impl From<UserRepositoryError> for PromoteError {
+  fn from(error: UserRepositoryError) -> Self {
+    PromoteError::RepositoryError(error)
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct Input {
+  pub username: String,
+
+  /**
+   * Também concede permissão para aprovar contribuições que exigem aprovação elevada.
+   */
+  pub is_admin: bool,
+}
+
+/**
+ * Torna o usuário um curador e, opcionalmente, um administrador.
+ */
+pub async fn execute(
+  user_repository: &mut impl UserRepository,
+  input: Input,
+) -> Result<User, PromoteError> {
+  let mut user = user_repository
+    .find_by_username(input.username)
+    .await?
+    .ok_or(PromoteError::UserNotFound)?;
+
+  user.set_is_curator(true);
+
+  if input.is_admin {
+    user.set_is_admin(true);
+  }
+
+  user_repository.update(&user).await?;
+
+  Ok(user)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[tokio::test]
+  async fn promote_should_keep_admin_when_not_requested() {
+    let mut user_repository = crate::infra::in_memory::InMemoryUserRepository::default();
+
+    let user = User::builder()
+      .username(String::from("username"))
+      .is_admin(true)
+      .build();
+    user_repository.create(&user).await.unwrap();
+
+    let user = execute(
+      &mut user_repository,
+      Input {
+        username: String::from("username"),
+        is_admin: false,
+      },
+    )
+    .await
+    .expect("Falha ao promover o usuário");
+
+    assert!(user.is_curator(), "O usuário deveria ser curador");
+    assert!(
+      user.is_admin(),
+      "O usuário não deveria perder a administração"
+    );
+
+    let result = execute(
+      &mut user_repository,
+      Input {
+        username: String::from("unknown"),
+        is_admin: false,
+      },
+    )
+    .await;
+
+    assert!(matches!(result, Err(PromoteError::UserNotFound)));
+  }
+}
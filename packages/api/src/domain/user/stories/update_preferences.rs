@@ -0,0 +1,93 @@
+use validator::{Validate, ValidationErrors};
+
+use crate::{
+  domain::user::{User, UserRepository, UserRepositoryError},
+  shared::{util::naive_now, vo::UUID4},
+};
+
+#[derive(Debug)]
+pub enum UpdatePreferencesError {
+  UserNotFound,
+  BirthDateInFuture,
+  InvalidInput(ValidationErrors),
+  RepositoryError(UserRepositoryError),
+}
+
+impl From<UserRepositoryError> for UpdatePreferencesError {
+  fn from(error: UserRepositoryError) -> Self {
+    UpdatePreferencesError::RepositoryError(error)
+  }
+}
+
+impl From<ValidationErrors> for UpdatePreferencesError {
+  fn from(error: ValidationErrors) -> Self {
+    UpdatePreferencesError::InvalidInput(error)
+  }
+}
+
+#[derive(Debug, Clone, validator::Validate)]
+pub struct Input {
+  pub user_id: UUID4,
+
+  #[validate(range(max = 18))]
+  pub max_parental_rating: Option<u8>,
+
+  pub birth_date: Option<chrono::NaiveDate>,
+}
+
+/**
+ * Substitui as preferências de conteúdo do usuário.
+ */
+pub async fn execute(
+  user_repository: &mut impl UserRepository,
+  input: Input,
+) -> Result<User, UpdatePreferencesError> {
+  input.validate()?;
+
+  if input
+    .birth_date
+    .is_some_and(|birth_date| birth_date > naive_now().date())
+  {
+    return Err(UpdatePreferencesError::BirthDateInFuture);
+  }
+
+  let mut user = user_repository
+    .find_by_id(input.user_id)
+    .await?
+    .ok_or(UpdatePreferencesError::UserNotFound)?;
+
+  user.set_max_parental_rating(input.max_parental_rating);
+  user.set_birth_date(input.birth_date);
+
+  user_repository.update(&user).await?;
+
+  Ok(user)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[tokio::test]
+  async fn update_preferences_should_reject_future_birth_date() {
+    let mut user_repository = crate::infra::in_memory::InMemoryUserRepository::default();
+
+    let user = User::builder().username(String::from("username")).build();
+    user_repository.create(&user).await.unwrap();
+
+    let result = execute(
+      &mut user_repository,
+      Input {
+        user_id: user.id().clone(),
+        max_parental_rating: Some(12),
+        birth_date: Some(naive_now().date() + chrono::Days::new(1)),
+      },
+    )
+    .await;
+
+    assert!(
+      matches!(result, Err(UpdatePreferencesError::BirthDateInFuture)),
+      "Não foi retornado o erro de data de nascimento no futuro"
+    );
+  }
+}
@@ -12,10 +12,74 @@ pub struct User {
   password: String,
   is_curator: bool,
 
+  /**
+   * Administradores podem aprovar contribuições que exigem aprovação elevada.
+   */
+  is_admin: bool,
+
+  /**
+   * Classificação indicativa máxima que o usuário deseja ver.
+   */
+  max_parental_rating: Option<u8>,
+
+  /**
+   * Data de nascimento, usada para limitar a classificação indicativa pela idade.
+   */
+  birth_date: Option<chrono::NaiveDate>,
+
   created_at: chrono::NaiveDateTime,
   updated_at: chrono::NaiveDateTime,
 }
 
+/**
+ * Faixas de classificação indicativa, em anos.
+ */
+pub const PARENTAL_RATINGS: [u8; 6] = [0, 10, 12, 14, 16, MAX_PARENTAL_RATING];
+
+/**
+ * Maior classificação indicativa existente, que não restringe nenhum conteúdo.
+ */
+pub const MAX_PARENTAL_RATING: u8 = 18;
+
+/**
+ * Maior faixa de classificação indicativa adequada para a idade informada.
+ */
+pub fn parental_rating_for_age(age: u32) -> u8 {
+  PARENTAL_RATINGS
+    .into_iter()
+    .rev()
+    .find(|rating| u32::from(*rating) <= age)
+    .unwrap_or_default()
+}
+
+/**
+ * Restringe o filtro de classificação indicativa máxima pedido ao limite de quem está vendo.
+ */
+pub fn clamp_parental_rating(requested: Option<u8>, limit: u8) -> Option<u8> {
+  match requested {
+    Some(requested) => Some(requested.min(limit)),
+    None if limit < MAX_PARENTAL_RATING => Some(limit),
+    None => None,
+  }
+}
+
+impl User {
+  /**
+   * Classificação indicativa máxima que o usuário pode ver, considerando a preferência e a idade.
+   */
+  pub fn parental_rating_limit(&self, today: chrono::NaiveDate) -> u8 {
+    let preference = self.max_parental_rating.unwrap_or(MAX_PARENTAL_RATING);
+
+    match self
+      .birth_date
+      .and_then(|birth_date| today.years_since(birth_date))
+    {
+      Some(age) => preference.min(parental_rating_for_age(age)),
+      None => preference,
+    }
+  }
+}
+
 impl Default for User {
   fn default() -> Self {
     let now = naive_now();
@@ -27,9 +91,70 @@ impl Default for User {
       email: String::default(),
       password: String::default(),
       is_curator: false,
+      is_admin: false,
+
+      max_parental_rating: None,
+      birth_date: None,
 
       created_at: now,
       updated_at: now,
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parental_rating_limit() {
+    let today = chrono::NaiveDate::from_ymd_opt(2024, 12, 16).unwrap();
+
+    let adult = User::default();
+    assert_eq!(
+      adult.parental_rating_limit(today),
+      18,
+      "Sem preferências o limite deve ser 18"
+    );
+
+    let teenager = User::builder()
+      .birth_date(chrono::NaiveDate::from_ymd_opt(2011, 12, 17))
+      .build();
+    assert_eq!(
+      teenager.parental_rating_limit(today),
+      12,
+      "Aos 12 anos o limite deve ser 12"
+    );
+
+    let restricted = User::builder()
+      .max_parental_rating(Some(10))
+      .birth_date(chrono::NaiveDate::from_ymd_opt(1990, 1, 1))
+      .build();
+    assert_eq!(
+      restricted.parental_rating_limit(today),
+      10,
+      "A preferência deve prevalecer sobre a idade"
+    );
+
+    assert_eq!(
+      clamp_parental_rating(None, 18),
+      None,
+      "Sem limite não deve haver filtro"
+    );
+    assert_eq!(
+      clamp_parental_rating(None, 14),
+      Some(14),
+      "O limite deve virar filtro"
+    );
+    assert_eq!(
+      clamp_parental_rating(Some(16), 14),
+      Some(14),
+      "O filtro não pode passar do limite"
+    );
+    assert_eq!(
+      clamp_parental_rating(Some(10), 14),
+      Some(10),
+      "Um filtro menor deve ser mantido"
+    );
+  }
+}
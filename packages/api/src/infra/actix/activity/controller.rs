@@ -15,6 +15,7 @@ async fn aprove_activity(
   let mut album_reposirtory = di::album::repositories::AlbumRepository::new(&app_state);
   let mut media_repository = di::media::repositories::MediaRepository::new(&app_state);
   let mut source_repository = di::source::repositories::SourceRepository::new(&app_state);
+  let mut lyrics_repository = di::lyrics::repositories::LyricsRepository::new(&app_state);
 
   let actor = crate::infra::actix::utils::get_actor(&mut user_repository, &session).await;
 
@@ -30,7 +31,12 @@ async fn aprove_activity(
     &mut album_reposirtory,
     &mut media_repository,
     &mut source_repository,
-    domain::activity::stories::approve::Input { activity_id, actor },
+    &mut lyrics_repository,
+    domain::activity::stories::approve::Input {
+      activity_id,
+      actor,
+      deletion_impact_threshold: app_state.deletion_impact_threshold,
+    },
   )
   .await;
 
@@ -78,8 +84,37 @@ async fn reject_activity(
   }
 }
 
+async fn find_all(
+  request: actix_web::HttpRequest,
+  state: actix_web::web::Data<AppState>,
+  actix_web::web::Query(page): actix_web::web::Query<infra::actix::shared::PageParams>,
+  actix_web::web::Query(filter): actix_web::web::Query<super::dto::FindAllQuery>,
+  session: actix_session::Session,
+) -> Result<actix_web::HttpResponse, infra::actix::errors::ErrorResponse> {
+  let activity_repository = di::activity::repositories::ActivityRepository::new(&state);
+  let mut user_repository = di::user::repositories::UserRepository::new(&state);
+  let actor = crate::infra::actix::utils::get_actor(&mut user_repository, &session).await?;
+
+  let activities = domain::activity::stories::find_all::execute(
+    &activity_repository,
+    domain::activity::stories::find_all::Input {
+      actor,
+      page: page.cursor_page_params(state.max_per_page)?,
+      user_id: filter.user_id,
+    },
+  )
+  .await
+  .map_err(infra::actix::errors::ErrorResponse::from)?;
+
+  Ok(infra::actix::shared::cursor_paged_response(
+    &request,
+    activities.map(super::presenters::PublicActivityPresenter::from),
+  ))
+}
+
 pub fn configure(config: &mut actix_web::web::ServiceConfig) {
   config
+    .route("/activities", actix_web::web::get().to(find_all))
     .route(
       "/activities/{activity_id}/reject",
       actix_web::web::patch().to(reject_activity),
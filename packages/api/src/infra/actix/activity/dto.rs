@@ -3,3 +3,8 @@ pub struct RejectActivityDto {
   #[validate(length(min = 10, max = 255))]
   pub reason: String,
 }
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct FindAllQuery {
+  pub user_id: Option<crate::shared::vo::UUID4>,
+}
@@ -34,11 +34,21 @@ impl From<domain::activity::stories::approve::Error> for ErrorResponse {
       domain::activity::stories::approve::Error::ArtistApplyError(error) => error.into(),
       domain::activity::stories::approve::Error::GenreApplyError(error) => error.into(),
       domain::activity::stories::approve::Error::RepositoryError(error) => error.into(),
+      domain::activity::stories::approve::Error::GenreRepositoryError(error) => error.into(),
+      domain::activity::stories::approve::Error::ArtistRepositoryError(error) => error.into(),
+      domain::activity::stories::approve::Error::AlbumRepositoryError(error) => error.into(),
+      domain::activity::stories::approve::Error::ElevatedApprovalRequired(impact) => {
+        ErrorResponse::Forbidden(format!(
+          "Deletion affects {} items and must be approved by an administrator",
+          impact.total()
+        ))
+      }
       domain::activity::stories::approve::Error::UserIsNotACurator => {
         ErrorResponse::Forbidden(String::from("User is not a curator"))
       }
       domain::activity::stories::approve::Error::MediaApplyError(error) => error.into(),
       domain::activity::stories::approve::Error::SourceApplyError(error) => error.into(),
+      domain::activity::stories::approve::Error::LyricsApplyError(error) => error.into(),
     }
   }
 }
@@ -57,3 +67,11 @@ impl From<domain::activity::stories::reject::Error> for ErrorResponse {
     }
   }
 }
+
+impl From<domain::activity::stories::find_all::Error> for ErrorResponse {
+  fn from(value: domain::activity::stories::find_all::Error) -> Self {
+    match value {
+      domain::activity::stories::find_all::Error::RepositoryError(error) => error.into(),
+    }
+  }
+}
@@ -27,6 +27,8 @@ pub struct PublicActivityPresenter {
   pub curator_id: Option<UUID4>,
   pub revision_date: Option<chrono::NaiveDate>,
   pub contribution: Contribution,
+  pub impact: Option<crate::domain::activity::DeletionImpact>,
+
   pub created_at: chrono::NaiveDateTime,
   pub updated_at: chrono::NaiveDateTime,
 }
@@ -40,10 +42,11 @@ impl From<crate::domain::activity::Activity> for PublicActivityPresenter {
       user_id: value.user_id().clone(),
       curator_id: value.curator_id().clone(),
       revision_date: value.revision_date().map(|date| date.into()),
-      contribution: value.contribution().clone().into(),
+      contribution: value.contribution().clone(),
+      impact: value.impact().clone(),
 
-      created_at: value.created_at().clone(),
-      updated_at: value.updated_at().clone(),
+      created_at: *value.created_at(),
+      updated_at: *value.updated_at(),
     }
   }
 }
@@ -1,9 +1,12 @@
 use actix_web::web;
-use infra::actix::{errors::ErrorResponse, shared::PageParams};
+use infra::actix::{
+  errors::ErrorResponse,
+  shared::{paged_headers, parse_list_filter, Faceted, ListQuery, PageParams},
+};
 
 use crate::*;
 
-use super::presenter::{AlbumAggregatePresenter, AlbumPresenter};
+use super::presenter::AlbumAggregatePresenter;
 
 async fn create_album_activity(
   app_state: actix_web::web::Data<AppState>,
@@ -13,10 +16,12 @@ async fn create_album_activity(
   let actor_id = infra::actix::utils::get_actor_id(&session)?;
 
   let mut album_repository = di::album::repositories::AlbumRepository::new(&app_state);
+  let mut artist_repository = di::artist::repositories::ArtistRepository::new(&app_state);
   let mut activity_repository = di::activity::repositories::ActivityRepository::new(&app_state);
 
   let activity = domain::album::stories::contribute::execute(
     &mut album_repository,
+    &mut artist_repository,
     &mut activity_repository,
     domain::album::stories::contribute::Input {
       actor_id,
@@ -36,6 +41,10 @@ async fn create_album(
   actix_web::web::Json(data): actix_web::web::Json<super::dto::CreateAlbumDTO>,
   session: actix_session::Session,
 ) -> impl actix_web::Responder {
+  if let Err(error) = validator::Validate::validate(&data) {
+    return infra::actix::errors::ErrorResponse::from(error).into();
+  }
+
   match create_album_activity(
     app_state,
     session,
@@ -54,6 +63,10 @@ async fn update_album(
   session: actix_session::Session,
   path: actix_web::web::Path<shared::vo::UUID4>,
 ) -> impl actix_web::Responder {
+  if let Err(error) = validator::Validate::validate(&data) {
+    return infra::actix::errors::ErrorResponse::from(error).into();
+  }
+
   let album_id = path.into_inner();
 
   match create_album_activity(
@@ -90,52 +103,107 @@ async fn delete_album(
   }
 }
 
+async fn restore_album(
+  app_state: actix_web::web::Data<AppState>,
+  session: actix_session::Session,
+  path: actix_web::web::Path<shared::vo::UUID4>,
+) -> impl actix_web::Responder {
+  let album_id = path.into_inner();
+
+  match create_album_activity(
+    app_state,
+    session,
+    domain::album::stories::contribute::ChangeInput::Restore(album_id),
+  )
+  .await
+  {
+    Ok(response) => response,
+    Err(error) => error.into(),
+  }
+}
+
 async fn find_album_slug(
   app_state: actix_web::web::Data<AppState>,
   path: actix_web::web::Path<(shared::vo::Slug, shared::vo::Slug)>,
-) -> impl actix_web::Responder {
+  session: actix_session::Session,
+) -> Result<actix_web::HttpResponse, ErrorResponse> {
   let (artist_slug, slug) = path.into_inner();
 
+  let parental_rating_limit =
+    infra::actix::utils::get_viewer_parental_rating_limit(&app_state, &session).await?;
   let mut album_repository = di::album::repositories::AlbumRepository::new(&app_state);
 
-  match domain::album::stories::find_by_slug::execute(&mut album_repository, &slug, &artist_slug)
-    .await
-  {
-    Ok(aggregate) => actix_web::HttpResponse::Ok().json(AlbumAggregatePresenter::from(aggregate)),
-    Err(error) => ErrorResponse::from(error).into(),
-  }
+  let aggregate = domain::album::stories::find_by_slug::execute(
+    &mut album_repository,
+    &slug,
+    &artist_slug,
+    parental_rating_limit,
+  )
+  .await?;
+
+  Ok(actix_web::HttpResponse::Ok().json(AlbumAggregatePresenter::from(aggregate)))
 }
 
 async fn find_by(
+  request: actix_web::HttpRequest,
   app_state: actix_web::web::Data<AppState>,
   web::Query(page): web::Query<PageParams>,
-  web::Query(filter): web::Query<super::dto::FindAllQuery>,
-) -> impl actix_web::Responder {
+  ListQuery(filter): ListQuery<super::dto::FindAllQuery>,
+  session: actix_session::Session,
+) -> Result<actix_web::HttpResponse, ErrorResponse> {
+  let parental_rating_limit =
+    infra::actix::utils::get_viewer_parental_rating_limit(&app_state, &session).await?;
   let mut album_repository = di::album::repositories::AlbumRepository::new(&app_state);
-  match domain::album::stories::find_by::execute(
-    &mut album_repository,
-    domain::album::stories::find_by::Input {
-      page: page.into(),
-      slug: filter.slug,
-      name: filter.name,
-      artist_ids: filter.artist_id.map(|id| vec![id]),
-      album_type: filter.album_type,
-      release_date: filter.release_date,
-      min_release_date: filter.min_release_date,
-      max_release_date: filter.max_release_date,
-      parental_rating: filter.parental_rating,
-      min_parental_rating: filter.min_parental_rating,
-      max_parental_rating: filter.max_parental_rating,
-      search: filter.search,
-    },
-  )
-  .await
-  {
-    Ok(result) => actix_web::HttpResponse::Ok().json(
-      shared::paged::Paged::<AlbumAggregatePresenter>::from(result),
+  let facet_fields = filter
+    .facet_fields()
+    .map_err(|error| ErrorResponse::BadRequest(error, None))?;
+
+  let input = domain::album::stories::find_by::Input {
+    page: page.request_params(app_state.max_per_page)?,
+    sort: filter.sort,
+    slug: filter.slug,
+    name: filter.name,
+    artist_ids: parse_list_filter(&filter.artist_id, filter.artist_match)?,
+    album_type: parse_list_filter(&filter.album_type, None)?,
+    release_date: filter.release_date,
+    min_release_date: filter.min_release_date,
+    max_release_date: filter.max_release_date,
+    parental_rating: parse_list_filter(&filter.parental_rating, None)?,
+    min_parental_rating: filter.min_parental_rating,
+    max_parental_rating: domain::user::clamp_parental_rating(
+      filter.max_parental_rating,
+      parental_rating_limit,
     ),
-    Err(err) => ErrorResponse::from(err).into(),
-  }
+    search: filter.search,
+    label: filter.label,
+    barcode: filter.barcode,
+    release_country: filter.release_country,
+    release_group_id: filter.release_group_id,
+    group_editions: filter.group_editions.unwrap_or_default(),
+  };
+
+  let facets = match facet_fields {
+    Some(fields) => Some(
+      domain::album::stories::facets::execute(
+        &mut album_repository,
+        domain::album::stories::facets::Input {
+          filter: input.clone(),
+          fields,
+        },
+      )
+      .await
+      .map_err(ErrorResponse::from)?,
+    ),
+    None => None,
+  };
+
+  let albums = shared::paged::Paged::<AlbumAggregatePresenter>::from(
+    domain::album::stories::find_by::execute(&mut album_repository, input)
+      .await
+      .map_err(ErrorResponse::from)?,
+  );
+
+  Ok(paged_headers(&request, &albums).json(Faceted::new(albums, facets)))
 }
 
 pub fn configure(config: &mut actix_web::web::ServiceConfig) {
@@ -153,5 +221,9 @@ pub fn configure(config: &mut actix_web::web::ServiceConfig) {
     .route(
       "/contribute/album/{album_id}",
       actix_web::web::delete().to(delete_album),
+    )
+    .route(
+      "/contribute/album/{album_id}/restore",
+      actix_web::web::post().to(restore_album),
     );
 }
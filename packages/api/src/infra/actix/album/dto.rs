@@ -7,7 +7,7 @@ use crate::{
   infra,
   shared::{
     paged::Paged,
-    vo::{Slug, UUID4},
+    vo::{Barcode, Slug, UUID4},
   },
 };
 
@@ -15,17 +15,67 @@ use super::presenter::AlbumAggregatePresenter;
 
 #[derive(Debug, serde::Deserialize)]
 pub struct FindAllQuery {
+  /**
+   * Campo de ordenação, ex: `name` ou `-created_at` para ordem decrescente.
+   */
+  pub sort: Option<crate::shared::sort::Sort<crate::domain::album::repository::SortField>>,
+
+  /**
+   * Facetas a contar sobre os filtros atuais, separadas por vírgula, ex:
+   * `genre,release_decade`. Vazio para todas as facetas.
+   */
+  pub facets: Option<String>,
+
   pub name: Option<String>,
   pub slug: Option<Slug>,
-  pub artist_id: Option<UUID4>,
-  pub album_type: Option<domain::album::AlbumType>,
+
+  /**
+   * Artistas separados por vírgula ou com o parâmetro repetido; valores precedidos por
+   * `!` são excluídos.
+   */
+  pub artist_id: Option<String>,
+
+  /**
+   * `any` (padrão) para álbuns de qualquer um dos artistas ou `all` para álbuns de
+   * todos eles.
+   */
+  pub artist_match: Option<crate::shared::filter::MatchMode>,
+
+  /**
+   * Tipos de álbum separados por vírgula; valores precedidos por `!` são excluídos.
+   */
+  pub album_type: Option<String>,
+
   pub release_date: Option<chrono::NaiveDate>,
   pub min_release_date: Option<chrono::NaiveDate>,
   pub max_release_date: Option<chrono::NaiveDate>,
-  pub parental_rating: Option<u8>,
+
+  /**
+   * Classificações indicativas separadas por vírgula; valores precedidos por `!` são
+   * excluídos, ex: `!16,!18`.
+   */
+  pub parental_rating: Option<String>,
+
   pub min_parental_rating: Option<u8>,
   pub max_parental_rating: Option<u8>,
   pub search: Option<String>,
+  pub label: Option<String>,
+  pub barcode: Option<Barcode>,
+  pub release_country: Option<String>,
+  pub release_group_id: Option<UUID4>,
+  pub group_editions: Option<bool>,
+}
+
+impl FindAllQuery {
+  pub fn facet_fields(
+    &self,
+  ) -> Result<Option<Vec<crate::domain::album::repository::FacetField>>, String> {
+    self
+      .facets
+      .as_deref()
+      .map(crate::infra::actix::shared::parse_list)
+      .transpose()
+  }
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize, validator::Validate)]
@@ -45,6 +95,22 @@ pub struct CreateAlbumDTO {
 
   #[validate(length(min = 1))]
   pub artist_ids: HashSet<UUID4>,
+
+  #[validate(length(max = 255))]
+  pub label: Option<String>,
+
+  #[validate(length(max = 64))]
+  pub catalog_number: Option<String>,
+
+  pub barcode: Option<Barcode>,
+
+  #[validate(length(equal = 2))]
+  pub release_country: Option<String>,
+
+  pub release_group_id: Option<UUID4>,
+
+  #[validate(length(max = 64))]
+  pub edition: Option<String>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, validator::Validate, Default)]
@@ -66,6 +132,22 @@ pub struct UpdateAlbumDto {
 
   #[validate(length(min = 1))]
   pub artist_ids: Option<HashSet<UUID4>>,
+
+  #[validate(length(max = 255))]
+  pub label: Option<String>,
+
+  #[validate(length(max = 64))]
+  pub catalog_number: Option<String>,
+
+  pub barcode: Option<Barcode>,
+
+  #[validate(length(equal = 2))]
+  pub release_country: Option<String>,
+
+  pub release_group_id: Option<UUID4>,
+
+  #[validate(length(max = 64))]
+  pub edition: Option<String>,
 }
 
 impl From<infra::actix::album::dto::CreateAlbumDTO>
@@ -74,11 +156,18 @@ impl From<infra::actix::album::dto::CreateAlbumDTO>
   fn from(value: infra::actix::album::dto::CreateAlbumDTO) -> Self {
     Self {
       name: value.name,
+      slug: None,
       cover: value.cover,
       album_type: value.album_type,
       release_date: value.release_date,
       parental_rating: value.parental_rating,
       artist_ids: value.artist_ids,
+      label: value.label,
+      catalog_number: value.catalog_number,
+      barcode: value.barcode,
+      release_country: value.release_country.map(|country| country.to_uppercase()),
+      release_group_id: value.release_group_id,
+      edition: value.edition,
     }
   }
 }
@@ -93,6 +182,12 @@ impl From<UpdateAlbumDto> for domain::album::contribution::changes::Changes {
       release_date: value.release_date,
       parental_rating: value.parental_rating,
       artist_ids: value.artist_ids,
+      label: value.label,
+      catalog_number: value.catalog_number,
+      barcode: value.barcode,
+      release_country: value.release_country.map(|country| country.to_uppercase()),
+      release_group_id: value.release_group_id,
+      edition: value.edition,
     }
   }
 }
@@ -1,9 +1,5 @@
-use domain::album::album_aggregate::AlbumAggregate;
-
 use crate::*;
 
-use super::presenter::AlbumAggregatePresenter;
-
 impl From<domain::album::stories::apply_changes::Error> for infra::actix::errors::ErrorResponse {
   fn from(value: domain::album::stories::apply_changes::Error) -> Self {
     match value {
@@ -36,6 +32,14 @@ impl From<domain::album::stories::contribute::Error> for infra::actix::errors::E
         infra::actix::errors::ErrorResponse::NotFound(String::from("Album not found"))
       }
       domain::album::stories::contribute::Error::AlbumRepositoryError(error) => error.into(),
+      domain::album::stories::contribute::Error::ArtistRepositoryError(error) => error.into(),
+      domain::album::stories::contribute::Error::MissingReferences(missing) => missing.into(),
+      domain::album::stories::contribute::Error::ReleaseGroupNotFound(id) => {
+        infra::actix::errors::ErrorResponse::BadRequest(
+          format!("Release group {} not found", id),
+          None,
+        )
+      }
     }
   }
 }
@@ -46,6 +50,11 @@ impl From<domain::album::stories::find_by_slug::Error> for infra::actix::errors:
       domain::album::stories::find_by_slug::Error::AlbumNotFound => {
         infra::actix::errors::ErrorResponse::NotFound(String::from("Album not found"))
       }
+      domain::album::stories::find_by_slug::Error::ParentalRatingRestricted => {
+        infra::actix::errors::ErrorResponse::Forbidden(String::from(
+          "Content restricted by parental rating",
+        ))
+      }
       domain::album::stories::find_by_slug::Error::RepositoryError(error) => error.into(),
     }
   }
@@ -58,3 +67,11 @@ impl From<domain::album::stories::find_by::Error> for infra::actix::errors::Erro
     }
   }
 }
+
+impl From<domain::album::stories::facets::Error> for infra::actix::errors::ErrorResponse {
+  fn from(value: domain::album::stories::facets::Error) -> Self {
+    match value {
+      domain::album::stories::facets::Error::RepositoryError(error) => error.into(),
+    }
+  }
+}
@@ -1,7 +1,7 @@
 use crate::{
   domain::album::{album_aggregate::AlbumAggregate, Album},
   infra::actix::artist::presenter::ArtistPresenter,
-  shared::vo::{Slug, UUID4},
+  shared::vo::{Barcode, Slug, UUID4},
 };
 
 #[derive(Debug, serde::Serialize)]
@@ -12,6 +12,12 @@ pub struct AlbumPresenter {
   cover: Option<String>,
   parental_rating: u8,
   release_date: Option<chrono::NaiveDate>,
+  label: Option<String>,
+  catalog_number: Option<String>,
+  barcode: Option<Barcode>,
+  release_country: Option<String>,
+  release_group_id: UUID4,
+  edition: Option<String>,
   created_at: chrono::NaiveDateTime,
   updated_at: chrono::NaiveDateTime,
 }
@@ -23,6 +29,12 @@ impl From<Album> for AlbumPresenter {
       name: value.name().clone(),
       cover: value.cover().clone(),
       release_date: *value.release_date(),
+      label: value.label().clone(),
+      catalog_number: value.catalog_number().clone(),
+      barcode: value.barcode().clone(),
+      release_country: value.release_country().clone(),
+      release_group_id: value.release_group_id().clone(),
+      edition: value.edition().clone(),
       parental_rating: *value.parental_rating(),
       slug: value.slug().clone(),
       created_at: *value.created_at(),
@@ -39,6 +51,12 @@ pub struct AlbumAggregatePresenter {
   cover: Option<String>,
   parental_rating: u8,
   release_date: Option<chrono::NaiveDate>,
+  label: Option<String>,
+  catalog_number: Option<String>,
+  barcode: Option<Barcode>,
+  release_country: Option<String>,
+  release_group_id: UUID4,
+  edition: Option<String>,
   artists: Vec<ArtistPresenter>,
   created_at: chrono::NaiveDateTime,
   updated_at: chrono::NaiveDateTime,
@@ -52,6 +70,12 @@ impl From<AlbumAggregate> for AlbumAggregatePresenter {
       name: album.name().clone(),
       cover: album.cover().clone(),
       release_date: *album.release_date(),
+      label: album.label().clone(),
+      catalog_number: album.catalog_number().clone(),
+      barcode: album.barcode().clone(),
+      release_country: album.release_country().clone(),
+      release_group_id: album.release_group_id().clone(),
+      edition: album.edition().clone(),
       parental_rating: *album.parental_rating(),
       slug: album.slug().clone(),
       artists: artists
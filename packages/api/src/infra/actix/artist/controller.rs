@@ -3,7 +3,7 @@ use infra::actix::errors::ErrorResponse;
 
 use crate::*;
 
-use super::presenter::ArtistPresenter;
+use super::presenter::{ArtistAggregatePresenter, ArtistPresenter};
 
 async fn create_artist_activity(
   app_state: actix_web::web::Data<AppState>,
@@ -36,6 +36,10 @@ async fn create_artist(
   actix_web::web::Json(data): actix_web::web::Json<super::dto::CreateArtistDTO>,
   session: actix_session::Session,
 ) -> impl actix_web::Responder {
+  if let Err(error) = validator::Validate::validate(&data) {
+    return infra::actix::errors::ErrorResponse::from(error).into();
+  }
+
   match create_artist_activity(
     app_state,
     session,
@@ -54,6 +58,10 @@ async fn update_artist(
   session: actix_session::Session,
   path: actix_web::web::Path<shared::vo::UUID4>,
 ) -> impl actix_web::Responder {
+  if let Err(error) = validator::Validate::validate(&data) {
+    return infra::actix::errors::ErrorResponse::from(error).into();
+  }
+
   let artist_id = path.into_inner();
 
   match create_artist_activity(
@@ -90,6 +98,25 @@ async fn delete_artist(
   }
 }
 
+async fn restore_artist(
+  app_state: actix_web::web::Data<AppState>,
+  session: actix_session::Session,
+  path: actix_web::web::Path<shared::vo::UUID4>,
+) -> impl actix_web::Responder {
+  let artist_id = path.into_inner();
+
+  match create_artist_activity(
+    app_state,
+    session,
+    domain::artist::stories::contribute::ChangeInput::Restore(artist_id),
+  )
+  .await
+  {
+    Ok(response) => response,
+    Err(error) => error.into(),
+  }
+}
+
 async fn find_by_slug(
   app_state: actix_web::web::Data<AppState>,
   path: actix_web::web::Path<shared::vo::Slug>,
@@ -98,30 +125,39 @@ async fn find_by_slug(
   let mut artist_repository = di::artist::repositories::ArtistRepository::new(&app_state);
 
   match domain::artist::stories::find_by_slug::execute(&mut artist_repository, &slug).await {
-    Ok(artist) => actix_web::HttpResponse::Ok().json(ArtistPresenter::from(artist)),
+    Ok(artist) => actix_web::HttpResponse::Ok().json(ArtistAggregatePresenter::from(artist)),
     Err(error) => ErrorResponse::from(error).into(),
   }
 }
 
 async fn find_all(
+  request: actix_web::HttpRequest,
   app_state: actix_web::web::Data<AppState>,
   web::Query(page): web::Query<infra::actix::shared::PageParams>,
   web::Query(filter): web::Query<super::dto::FindAllQuery>,
 ) -> impl actix_web::Responder {
+  let page = match page.request_params(app_state.max_per_page) {
+    Ok(page) => page,
+    Err(error) => return error.into(),
+  };
+
   let mut artist_repository = di::artist::repositories::ArtistRepository::new(&app_state);
   match domain::artist::stories::find_all::execute(
     &mut artist_repository,
     domain::artist::stories::find_all::Input {
-      page: page.into(),
+      page,
+      sort: filter.sort,
       search: filter.search,
       country: filter.country,
+      artist_type: filter.artist_type,
     },
   )
   .await
   {
-    Ok(result) => {
-      actix_web::HttpResponse::Ok().json(shared::paged::Paged::<ArtistPresenter>::from(result))
-    }
+    Ok(result) => infra::actix::shared::paged_response(
+      &request,
+      shared::paged::Paged::<ArtistPresenter>::from(result),
+    ),
     Err(err) => infra::actix::errors::ErrorResponse::from(err).into(),
   }
 }
@@ -141,5 +177,9 @@ pub fn configure(config: &mut actix_web::web::ServiceConfig) {
     .route(
       "/contribute/artist/{artist_id}",
       actix_web::web::delete().to(delete_artist),
+    )
+    .route(
+      "/contribute/artist/{artist_id}/restore",
+      actix_web::web::post().to(restore_artist),
     );
 }
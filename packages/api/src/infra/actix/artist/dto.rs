@@ -1,12 +1,34 @@
-use domain::artist::Artist;
-use shared::vo::Slug;
-
 use crate::*;
 
 #[derive(Debug, serde::Deserialize)]
 pub struct FindAllQuery {
+  /**
+   * Campo de ordenação, ex: `name` ou `-created_at` para ordem decrescente.
+   */
+  pub sort: Option<crate::shared::sort::Sort<crate::domain::artist::repository::SortField>>,
+
   pub search: Option<String>,
   pub country: Option<String>,
+  pub artist_type: Option<crate::domain::artist::ArtistType>,
+}
+
+/**
+ * Cada nome alternativo segue os mesmos limites do nome do artista.
+ */
+fn validate_aliases(
+  aliases: &std::collections::HashSet<String>,
+) -> Result<(), validator::ValidationError> {
+  if aliases
+    .iter()
+    .all(|alias| (1..=128).contains(&alias.chars().count()))
+  {
+    Ok(())
+  } else {
+    Err(
+      validator::ValidationError::new("length")
+        .with_message("Each alias must have between 1 and 128 characters".into()),
+    )
+  }
 }
 
 #[derive(Debug, serde::Deserialize, validator::Validate)]
@@ -18,6 +40,13 @@ pub struct CreateArtistDTO {
 
   #[validate(length(min = 1, max = 128))]
   country: Option<String>,
+
+  artist_type: Option<crate::domain::artist::ArtistType>,
+
+  #[validate(custom(function = "validate_aliases"))]
+  aliases: Option<std::collections::HashSet<String>>,
+
+  members: Option<Vec<crate::domain::artist::ArtistMember>>,
 }
 
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize, validator::Validate, Default)]
@@ -29,6 +58,13 @@ pub struct UpdateArtistDto {
 
   #[validate(length(min = 1, max = 128))]
   pub country: Option<String>,
+
+  pub artist_type: Option<crate::domain::artist::ArtistType>,
+
+  #[validate(custom(function = "validate_aliases"))]
+  pub aliases: Option<std::collections::HashSet<String>>,
+
+  pub members: Option<Vec<crate::domain::artist::ArtistMember>>,
 }
 
 impl From<CreateArtistDTO> for crate::domain::artist::stories::contribute::CreateArtistInput {
@@ -37,6 +73,9 @@ impl From<CreateArtistDTO> for crate::domain::artist::stories::contribute::Creat
       name: value.name,
       country: value.country,
       slug: value.slug,
+      artist_type: value.artist_type,
+      aliases: value.aliases,
+      members: value.members,
     }
   }
 }
@@ -47,6 +86,40 @@ impl From<UpdateArtistDto> for crate::domain::artist::contribution::changes::Cha
       name: value.name,
       country: value.country,
       slug: value.slug,
+      artist_type: value.artist_type,
+      aliases: value.aliases,
+      members: value.members,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_validate_aliases() {
+    let dto = |alias: &str| UpdateArtistDto {
+      aliases: Some(std::collections::HashSet::from([String::from(alias)])),
+      ..Default::default()
+    };
+
+    assert!(
+      validator::Validate::validate(&dto("Chico")).is_ok(),
+      "Nome alternativo válido foi rejeitado"
+    );
+
+    for alias in [String::new(), "a".repeat(129)] {
+      match validator::Validate::validate(&dto(&alias))
+        .map_err(infra::actix::errors::ErrorResponse::from)
+      {
+        Err(infra::actix::errors::ErrorResponse::BadRequest(_, Some(errors))) => assert!(
+          errors.contains_key("aliases"),
+          "Nome alternativo com {} caracteres deveria ser rejeitado",
+          alias.len()
+        ),
+        other => panic!("Era esperado um BadRequest, obtido {:?}", other),
+      }
     }
   }
 }
@@ -10,6 +10,16 @@ impl From<domain::artist::stories::contribute::Error> for infra::actix::errors::
         infra::actix::errors::ErrorResponse::NotFound(String::from("Artist not found"))
       }
       domain::artist::stories::contribute::Error::ArtistRepositoryError(error) => error.into(),
+      domain::artist::stories::contribute::Error::MembersRequireGroup => {
+        infra::actix::errors::ErrorResponse::BadRequest(
+          String::from("Only artists of type Group can have members"),
+          None,
+        )
+      }
+      domain::artist::stories::contribute::Error::InvalidMembership(message) => {
+        infra::actix::errors::ErrorResponse::BadRequest(message, None)
+      }
+      domain::artist::stories::contribute::Error::MissingReferences(missing) => missing.into(),
     }
   }
 }
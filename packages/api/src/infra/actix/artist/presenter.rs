@@ -1,4 +1,10 @@
-use crate::{domain::artist::Artist, shared::vo::Slug};
+use crate::{
+  domain::artist::{
+    artist_aggregate::{ArtistAggregate, ArtistMembership},
+    Artist, ArtistType,
+  },
+  shared::vo::Slug,
+};
 
 #[derive(Debug, serde::Serialize)]
 pub struct ArtistPresenter {
@@ -6,6 +12,8 @@ pub struct ArtistPresenter {
   name: String,
   slug: Slug,
   country: Option<String>,
+  artist_type: ArtistType,
+  aliases: Vec<String>,
 }
 
 impl From<crate::domain::artist::Artist> for ArtistPresenter {
@@ -15,6 +23,56 @@ impl From<crate::domain::artist::Artist> for ArtistPresenter {
       name: value.name().to_string(),
       slug: value.slug().clone(),
       country: value.country().clone(),
+      artist_type: value.artist_type().clone(),
+      aliases: {
+        let mut aliases = value.aliases().iter().cloned().collect::<Vec<_>>();
+        aliases.sort();
+        aliases
+      },
+    }
+  }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ArtistMembershipPresenter {
+  #[serde(flatten)]
+  artist: ArtistPresenter,
+  started_at: Option<chrono::NaiveDate>,
+  ended_at: Option<chrono::NaiveDate>,
+}
+
+impl From<ArtistMembership> for ArtistMembershipPresenter {
+  fn from(value: ArtistMembership) -> Self {
+    Self {
+      artist: ArtistPresenter::from(value.artist),
+      started_at: value.started_at,
+      ended_at: value.ended_at,
+    }
+  }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ArtistAggregatePresenter {
+  #[serde(flatten)]
+  artist: ArtistPresenter,
+  members: Vec<ArtistMembershipPresenter>,
+  groups: Vec<ArtistMembershipPresenter>,
+}
+
+impl From<ArtistAggregate> for ArtistAggregatePresenter {
+  fn from(value: ArtistAggregate) -> Self {
+    let (artist, members, groups) = value.into_parts();
+
+    Self {
+      artist: ArtistPresenter::from(artist),
+      members: members
+        .into_iter()
+        .map(ArtistMembershipPresenter::from)
+        .collect(),
+      groups: groups
+        .into_iter()
+        .map(ArtistMembershipPresenter::from)
+        .collect(),
     }
   }
 }
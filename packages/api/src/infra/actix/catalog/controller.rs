@@ -0,0 +1,82 @@
+use actix_web::web;
+use infra::actix::errors::ErrorResponse;
+
+use crate::*;
+
+/**
+ * Exporta o catálogo em JSON Lines, no formato aceito pela importação.
+ *
+ * A resposta é enviada à medida que os lotes são lidos. O cabeçalho
+ * `X-Export-Started-At` informa o momento em que a exportação começou, a ser
+ * usado como `since` na próxima exportação incremental.
+ *
+ * Na exportação incremental, as entidades excluídas depois de `since` são
+ * exportadas como registros de exclusão.
+ */
+async fn export(
+  app_state: web::Data<AppState>,
+  web::Query(query): web::Query<super::dto::ExportQuery>,
+  session: actix_session::Session,
+) -> impl actix_web::Responder {
+  let entity_names = match query.entity_names() {
+    Ok(entity_names) => entity_names,
+    Err(error) => return ErrorResponse::BadRequest(error, None).into(),
+  };
+
+  let mut user_repository = di::user::repositories::UserRepository::new(&app_state);
+
+  let actor = match infra::actix::utils::get_actor(&mut user_repository, &session).await {
+    Ok(actor) => actor,
+    Err(error) => return error.into(),
+  };
+
+  let started_at = shared::util::naive_now();
+
+  let export =
+    match domain::catalog::stories::export::execute(domain::catalog::stories::export::Input {
+      actor,
+      entity_names,
+      since: query.since,
+      batch_size: infra::catalog::EXPORT_BATCH_SIZE,
+    }) {
+      Ok(export) => export,
+      Err(error) => return ErrorResponse::from(error).into(),
+    };
+
+  let catalog_repository = di::catalog::repositories::CatalogRepository::new(&app_state);
+
+  let body = futures::stream::unfold(
+    (export, catalog_repository),
+    |(mut export, mut catalog_repository)| async move {
+      match export.next_batch(&mut catalog_repository).await {
+        Ok(Some(records)) => Some((
+          Ok(web::Bytes::from(infra::catalog::to_json_lines(&records))),
+          (export, catalog_repository),
+        )),
+        Ok(None) => None,
+        Err(error) => {
+          log::error!("Catalog export failed: {:?}", error);
+
+          Some((
+            Err(actix_web::error::ErrorInternalServerError(
+              ErrorResponse::from(error),
+            )),
+            (export, catalog_repository),
+          ))
+        }
+      }
+    },
+  );
+
+  actix_web::HttpResponse::Ok()
+    .content_type("application/x-ndjson")
+    .insert_header((
+      "X-Export-Started-At",
+      started_at.format(infra::catalog::SINCE_FORMAT).to_string(),
+    ))
+    .streaming(body)
+}
+
+pub fn configure(config: &mut actix_web::web::ServiceConfig) {
+  config.route("/catalog/export", actix_web::web::get().to(export));
+}
@@ -0,0 +1,28 @@
+use crate::shared::vo::CollaborativeEntityName;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct ExportQuery {
+  /**
+   * Tipos de entidade separados por vírgula, ex: `Artist,Album`.
+   */
+  pub types: Option<String>,
+
+  /**
+   * Exporta apenas o que foi alterado depois deste momento, ex: `2024-12-19T10:00:00`.
+   */
+  pub since: Option<chrono::NaiveDateTime>,
+}
+
+impl ExportQuery {
+  pub fn entity_names(&self) -> Result<Vec<CollaborativeEntityName>, String> {
+    self
+      .types
+      .as_deref()
+      .unwrap_or_default()
+      .split(',')
+      .map(str::trim)
+      .filter(|name| !name.is_empty())
+      .map(str::parse)
+      .collect()
+  }
+}
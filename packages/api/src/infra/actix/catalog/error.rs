@@ -0,0 +1,68 @@
+use crate::{
+  domain::{
+    self,
+    catalog::stories::import::{Error, RecordError},
+  },
+  infra::actix::errors::ErrorResponse,
+};
+
+impl From<domain::catalog::Error> for ErrorResponse {
+  fn from(value: domain::catalog::Error) -> Self {
+    match value {
+      domain::catalog::Error::DatabaseError(error) => ErrorResponse::InternalServerError(error),
+    }
+  }
+}
+
+impl From<domain::catalog::stories::export::Error> for ErrorResponse {
+  fn from(value: domain::catalog::stories::export::Error) -> Self {
+    match value {
+      domain::catalog::stories::export::Error::UserIsNotACurator => {
+        ErrorResponse::Forbidden(String::from("Only curators can export the catalog"))
+      }
+      domain::catalog::stories::export::Error::UnsupportedEntity(entity_name) => {
+        ErrorResponse::BadRequest(format!("{} is not exportable", entity_name), None)
+      }
+      domain::catalog::stories::export::Error::RepositoryError(error) => error.into(),
+    }
+  }
+}
+
+impl From<Error> for ErrorResponse {
+  fn from(value: Error) -> Self {
+    match value {
+      Error::UserIsNotACurator => {
+        ErrorResponse::Forbidden(String::from("Only curators can apply an import"))
+      }
+      Error::GenreRepositoryError(error) => error.into(),
+      Error::ArtistRepositoryError(error) => error.into(),
+      Error::AlbumRepositoryError(error) => error.into(),
+      Error::MediaRepositoryError(error) => error.into(),
+      Error::SourceRepositoryError(error) => error.into(),
+      Error::LyricsRepositoryError(error) => error.into(),
+      Error::CatalogRepositoryError(error) => error.into(),
+    }
+  }
+}
+
+impl From<RecordError> for ErrorResponse {
+  fn from(value: RecordError) -> Self {
+    match value {
+      RecordError::UnresolvedReference { field, reference } => ErrorResponse::BadRequest(
+        String::from("Referenced entities not found"),
+        Some([(field.to_string(), vec![reference])].into()),
+      ),
+      RecordError::MissingArtist => {
+        ErrorResponse::BadRequest(String::from("At least one artist is required"), None)
+      }
+      RecordError::InvalidSourceUrl(error) => ErrorResponse::BadRequest(error.to_string(), None),
+      RecordError::GenreContributionError(error) => error.into(),
+      RecordError::ArtistContributionError(error) => error.into(),
+      RecordError::AlbumContributionError(error) => error.into(),
+      RecordError::MediaContributionError(error) => error.into(),
+      RecordError::SourceContributionError(error) => error.into(),
+      RecordError::LyricsContributionError(error) => error.into(),
+      RecordError::ApproveError { error, .. } => error.into(),
+    }
+  }
+}
@@ -84,3 +84,47 @@ impl From<ErrorResponse> for HttpResponse {
     }
   }
 }
+
+impl From<crate::shared::reference::MissingReferences> for ErrorResponse {
+  fn from(value: crate::shared::reference::MissingReferences) -> Self {
+    ErrorResponse::BadRequest(
+      String::from("Referenced entities not found"),
+      Some(
+        value
+          .fields()
+          .iter()
+          .map(|(field, ids)| (field.clone(), ids.iter().map(|id| id.to_string()).collect()))
+          .collect(),
+      ),
+    )
+  }
+}
+
+impl From<validator::ValidationErrors> for ErrorResponse {
+  fn from(value: validator::ValidationErrors) -> Self {
+    ErrorResponse::BadRequest(
+      String::from("Invalid input"),
+      Some(
+        value
+          .field_errors()
+          .into_iter()
+          .map(|(field, errors)| {
+            (
+              field.to_string(),
+              errors
+                .iter()
+                .map(|error| {
+                  error
+                    .message
+                    .clone()
+                    .unwrap_or_else(|| error.code.clone())
+                    .to_string()
+                })
+                .collect(),
+            )
+          })
+          .collect(),
+      ),
+    )
+  }
+}
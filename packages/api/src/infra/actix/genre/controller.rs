@@ -1,7 +1,8 @@
-use std::result;
-
 use actix_web::web;
-use infra::actix::{errors::ErrorResponse, shared::PageParams};
+use infra::actix::{
+  errors::ErrorResponse,
+  shared::{paged_response, PageParams},
+};
 use shared::paged::Paged;
 
 use crate::*;
@@ -93,6 +94,25 @@ async fn delete_genre(
   }
 }
 
+async fn restore_genre(
+  app_state: actix_web::web::Data<AppState>,
+  session: actix_session::Session,
+  path: actix_web::web::Path<shared::vo::UUID4>,
+) -> impl actix_web::Responder {
+  let genre_id = path.into_inner();
+
+  match create_genre_activity(
+    app_state,
+    session,
+    domain::genre::stories::contribute::ChangeInput::Restore(genre_id),
+  )
+  .await
+  {
+    Ok(response) => response,
+    Err(error) => error.into(),
+  }
+}
+
 async fn get_genre_by_slug(
   app_state: actix_web::web::Data<AppState>,
   path: actix_web::web::Path<shared::vo::Slug>,
@@ -109,22 +129,29 @@ async fn get_genre_by_slug(
 }
 
 async fn find_all(
+  request: actix_web::HttpRequest,
   app_state: actix_web::web::Data<AppState>,
   web::Query(page): web::Query<PageParams>,
   web::Query(filter): web::Query<super::dto::FindAllQuery>,
 ) -> impl actix_web::Responder {
+  let page = match page.request_params(app_state.max_per_page) {
+    Ok(page) => page,
+    Err(error) => return error.into(),
+  };
+
   let mut genre_repository = di::genre::repositories::GenreRepository::new(&app_state);
   match domain::genre::stories::find_all::execute(
     &mut genre_repository,
     domain::genre::stories::find_all::Input {
-      page: page.into(),
+      page,
+      sort: filter.sort,
       parent_id: filter.parent_id,
       search: filter.search,
     },
   )
   .await
   {
-    Ok(result) => actix_web::HttpResponse::Ok().json(Paged::<GenrePresenter>::from(result)),
+    Ok(result) => paged_response(&request, Paged::<GenrePresenter>::from(result)),
     Err(err) => ErrorResponse::from(err).into(),
   }
 }
@@ -140,6 +167,10 @@ pub fn configure(config: &mut actix_web::web::ServiceConfig) {
       "/contribute/genre/{genre_id}",
       actix_web::web::delete().to(delete_genre),
     )
+    .route(
+      "/contribute/genre/{genre_id}/restore",
+      actix_web::web::post().to(restore_genre),
+    )
     .route("/genre/{slug}", actix_web::web::get().to(get_genre_by_slug))
     .route("/genres", actix_web::web::get().to(find_all));
 }
@@ -5,6 +5,11 @@ use crate::{
 
 #[derive(Debug, serde::Deserialize)]
 pub struct FindAllQuery {
+  /**
+   * Campo de ordenação, ex: `name` ou `-created_at` para ordem decrescente.
+   */
+  pub sort: Option<crate::shared::sort::Sort<crate::domain::genre::SortField>>,
+
   pub parent_id: Option<UUID4>,
   pub search: Option<String>,
 }
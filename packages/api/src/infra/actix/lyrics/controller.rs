@@ -0,0 +1,157 @@
+use actix_web::web;
+
+use crate::*;
+
+use super::presenter::{LyricsPresenter, LyricsVersionPresenter};
+
+async fn create_lyrics_activity(
+  app_state: actix_web::web::Data<AppState>,
+  session: actix_session::Session,
+  changes: domain::lyrics::stories::contribute::ChangeInput,
+) -> Result<actix_web::HttpResponse, infra::actix::errors::ErrorResponse> {
+  let actor_id = infra::actix::utils::get_actor_id(&session)?;
+
+  let mut lyrics_repository = di::lyrics::repositories::LyricsRepository::new(&app_state);
+  let mut media_repository = di::media::repositories::MediaRepository::new(&app_state);
+  let mut activity_repository = di::activity::repositories::ActivityRepository::new(&app_state);
+
+  let activity = domain::lyrics::stories::contribute::execute(
+    &mut lyrics_repository,
+    &mut media_repository,
+    &mut activity_repository,
+    domain::lyrics::stories::contribute::Input {
+      actor_id,
+      data: changes,
+    },
+  )
+  .await
+  .map_err(infra::actix::errors::ErrorResponse::from)?;
+
+  Ok(
+    actix_web::HttpResponse::Created()
+      .json(infra::actix::activity::presenters::PublicActivityPresenter::from(activity)),
+  )
+}
+
+async fn create_lyrics(
+  app_state: actix_web::web::Data<AppState>,
+  actix_web::web::Json(data): actix_web::web::Json<super::dto::CreateLyricsDTO>,
+  session: actix_session::Session,
+) -> impl actix_web::Responder {
+  if let Err(error) = validator::Validate::validate(&data) {
+    return infra::actix::errors::ErrorResponse::from(error).into();
+  }
+
+  match create_lyrics_activity(
+    app_state,
+    session,
+    domain::lyrics::stories::contribute::ChangeInput::Create(data.into()),
+  )
+  .await
+  {
+    Ok(response) => response,
+    Err(error) => error.into(),
+  }
+}
+
+async fn delete_lyrics(
+  app_state: actix_web::web::Data<AppState>,
+  session: actix_session::Session,
+  path: actix_web::web::Path<shared::vo::UUID4>,
+) -> impl actix_web::Responder {
+  let lyrics_id = path.into_inner();
+
+  match create_lyrics_activity(
+    app_state,
+    session,
+    domain::lyrics::stories::contribute::ChangeInput::Delete(lyrics_id),
+  )
+  .await
+  {
+    Ok(response) => response,
+    Err(error) => error.into(),
+  }
+}
+
+async fn find_by_media(
+  app_state: actix_web::web::Data<AppState>,
+  path: actix_web::web::Path<shared::vo::UUID4>,
+  web::Query(query): web::Query<super::dto::FindLyricsQuery>,
+  session: actix_session::Session,
+) -> impl actix_web::Responder {
+  let parental_rating_limit =
+    match infra::actix::utils::get_viewer_parental_rating_limit(&app_state, &session).await {
+      Ok(limit) => limit,
+      Err(error) => return error.into(),
+    };
+
+  let mut lyrics_repository = di::lyrics::repositories::LyricsRepository::new(&app_state);
+  let mut media_repository = di::media::repositories::MediaRepository::new(&app_state);
+
+  match domain::lyrics::stories::find_by_media::execute(
+    &mut lyrics_repository,
+    &mut media_repository,
+    domain::lyrics::stories::find_by_media::Input {
+      media_id: path.into_inner(),
+      version: query.version,
+      parental_rating_limit,
+    },
+  )
+  .await
+  {
+    Ok(lyrics) => actix_web::HttpResponse::Ok().json(LyricsPresenter::from(lyrics)),
+    Err(error) => infra::actix::errors::ErrorResponse::from(error).into(),
+  }
+}
+
+async fn find_versions(
+  app_state: actix_web::web::Data<AppState>,
+  path: actix_web::web::Path<shared::vo::UUID4>,
+  session: actix_session::Session,
+) -> impl actix_web::Responder {
+  let parental_rating_limit =
+    match infra::actix::utils::get_viewer_parental_rating_limit(&app_state, &session).await {
+      Ok(limit) => limit,
+      Err(error) => return error.into(),
+    };
+
+  let mut lyrics_repository = di::lyrics::repositories::LyricsRepository::new(&app_state);
+  let mut media_repository = di::media::repositories::MediaRepository::new(&app_state);
+
+  match domain::lyrics::stories::find_by_media::versions(
+    &mut lyrics_repository,
+    &mut media_repository,
+    &path,
+    parental_rating_limit,
+  )
+  .await
+  {
+    Ok(versions) => actix_web::HttpResponse::Ok().json(
+      versions
+        .into_iter()
+        .map(LyricsVersionPresenter::from)
+        .collect::<Vec<_>>(),
+    ),
+    Err(error) => infra::actix::errors::ErrorResponse::from(error).into(),
+  }
+}
+
+pub fn configure(config: &mut actix_web::web::ServiceConfig) {
+  config
+    .route(
+      "/medias/{media_id}/lyrics",
+      actix_web::web::get().to(find_by_media),
+    )
+    .route(
+      "/medias/{media_id}/lyrics/versions",
+      actix_web::web::get().to(find_versions),
+    )
+    .route(
+      "/contribute/lyrics",
+      actix_web::web::post().to(create_lyrics),
+    )
+    .route(
+      "/contribute/lyrics/{lyrics_id}",
+      actix_web::web::delete().to(delete_lyrics),
+    );
+}
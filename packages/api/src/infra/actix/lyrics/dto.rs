@@ -0,0 +1,31 @@
+use crate::*;
+
+#[derive(Debug, serde::Deserialize, validator::Validate)]
+pub struct CreateLyricsDTO {
+  pub media_id: shared::vo::UUID4,
+
+  #[validate(length(min = 2, max = 8))]
+  pub language: Option<String>,
+
+  #[validate(length(min = 1))]
+  pub content: Option<String>,
+
+  #[validate(length(min = 1))]
+  pub lrc: Option<String>,
+}
+
+impl From<CreateLyricsDTO> for crate::domain::lyrics::stories::contribute::CreateLyricsInput {
+  fn from(value: CreateLyricsDTO) -> Self {
+    Self {
+      media_id: value.media_id,
+      language: value.language,
+      content: value.content,
+      lrc: value.lrc,
+    }
+  }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct FindLyricsQuery {
+  pub version: Option<u32>,
+}
@@ -0,0 +1,76 @@
+impl From<crate::domain::lyrics::stories::apply_changes::Error>
+  for crate::infra::actix::errors::ErrorResponse
+{
+  fn from(value: crate::domain::lyrics::stories::apply_changes::Error) -> Self {
+    match value {
+      crate::domain::lyrics::stories::apply_changes::Error::RepositoryError(error) => error.into(),
+    }
+  }
+}
+
+impl From<crate::domain::lyrics::repository::Error> for crate::infra::actix::errors::ErrorResponse {
+  fn from(value: crate::domain::lyrics::repository::Error) -> Self {
+    match value {
+      crate::domain::lyrics::repository::Error::DatabaseError(error) => {
+        crate::infra::actix::errors::ErrorResponse::InternalServerError(error)
+      }
+    }
+  }
+}
+
+impl From<crate::domain::lyrics::stories::contribute::Error>
+  for crate::infra::actix::errors::ErrorResponse
+{
+  fn from(value: crate::domain::lyrics::stories::contribute::Error) -> Self {
+    match value {
+      crate::domain::lyrics::stories::contribute::Error::ActivityRepositoryError(error) => {
+        error.into()
+      }
+      crate::domain::lyrics::stories::contribute::Error::LyricsRepositoryError(error) => {
+        error.into()
+      }
+      crate::domain::lyrics::stories::contribute::Error::MediaRepositoryError(error) => {
+        error.into()
+      }
+      crate::domain::lyrics::stories::contribute::Error::LyricsNotFound => {
+        crate::infra::actix::errors::ErrorResponse::NotFound(String::from("Lyrics not found"))
+      }
+      crate::domain::lyrics::stories::contribute::Error::MediaNotFound => {
+        crate::infra::actix::errors::ErrorResponse::NotFound(String::from("Media not found"))
+      }
+      crate::domain::lyrics::stories::contribute::Error::EmptyLyrics => {
+        crate::infra::actix::errors::ErrorResponse::BadRequest(
+          String::from("Lyrics must have a content or LRC lines"),
+          None,
+        )
+      }
+      crate::domain::lyrics::stories::contribute::Error::InvalidLrc(
+        crate::domain::lyrics::lyrics_entity::LrcError::InvalidLine(line),
+      ) => crate::infra::actix::errors::ErrorResponse::BadRequest(
+        format!("Invalid LRC line {}", line),
+        None,
+      ),
+    }
+  }
+}
+
+impl From<crate::domain::lyrics::stories::find_by_media::Error>
+  for crate::infra::actix::errors::ErrorResponse
+{
+  fn from(value: crate::domain::lyrics::stories::find_by_media::Error) -> Self {
+    match value {
+      crate::domain::lyrics::stories::find_by_media::Error::RepositoryError(error) => error.into(),
+      crate::domain::lyrics::stories::find_by_media::Error::LyricsNotFound => {
+        crate::infra::actix::errors::ErrorResponse::NotFound(String::from("Lyrics not found"))
+      }
+      crate::domain::lyrics::stories::find_by_media::Error::MediaRepositoryError(error) => {
+        error.into()
+      }
+      // A mídia restrita é tratada como inexistente para não revelar o conteúdo.
+      crate::domain::lyrics::stories::find_by_media::Error::MediaNotFound
+      | crate::domain::lyrics::stories::find_by_media::Error::ParentalRatingRestricted => {
+        crate::infra::actix::errors::ErrorResponse::NotFound(String::from("Media not found"))
+      }
+    }
+  }
+}
@@ -0,0 +1,52 @@
+use crate::domain::lyrics::lyrics_entity::{LyricLine, Lyrics};
+use crate::shared::vo::UUID4;
+
+#[derive(Debug, serde::Serialize)]
+pub struct LyricsPresenter {
+  id: UUID4,
+  media_id: UUID4,
+  version: u32,
+  language: Option<String>,
+  content: String,
+  lines: Vec<LyricLine>,
+  lrc: Option<String>,
+  created_at: chrono::NaiveDateTime,
+  updated_at: chrono::NaiveDateTime,
+}
+
+impl From<Lyrics> for LyricsPresenter {
+  fn from(value: Lyrics) -> Self {
+    Self {
+      id: value.id().clone(),
+      media_id: value.media_id().clone(),
+      version: *value.version(),
+      language: value.language().clone(),
+      content: value.content().clone(),
+      lrc: value.is_synced().then(|| LyricLine::to_lrc(value.lines())),
+      lines: value.lines().clone(),
+      created_at: *value.created_at(),
+      updated_at: *value.updated_at(),
+    }
+  }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct LyricsVersionPresenter {
+  id: UUID4,
+  version: u32,
+  language: Option<String>,
+  is_synced: bool,
+  created_at: chrono::NaiveDateTime,
+}
+
+impl From<Lyrics> for LyricsVersionPresenter {
+  fn from(value: Lyrics) -> Self {
+    Self {
+      id: value.id().clone(),
+      version: *value.version(),
+      language: value.language().clone(),
+      is_synced: value.is_synced(),
+      created_at: *value.created_at(),
+    }
+  }
+}
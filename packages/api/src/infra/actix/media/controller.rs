@@ -1,7 +1,7 @@
-use std::collections::HashSet;
-
 use actix_web::web;
-use infra::actix::shared::PageParams;
+use infra::actix::shared::{
+  cursor_paged_headers, paged_headers, parse_list_filter, Faceted, ListQuery, PageParams,
+};
 
 use crate::*;
 
@@ -13,10 +13,16 @@ async fn create_media_activity(
   let actor_id = infra::actix::utils::get_actor_id(&session)?;
 
   let mut media_repository = di::media::repositories::MediaRepository::new(&app_state);
+  let mut artist_repository = di::artist::repositories::ArtistRepository::new(&app_state);
+  let mut genre_repository = di::genre::repositories::GenreRepository::new(&app_state);
+  let mut album_repository = di::album::repositories::AlbumRepository::new(&app_state);
   let mut activity_repository = di::activity::repositories::ActivityRepository::new(&app_state);
 
   let activity = domain::media::stories::contribute::execute(
     &mut media_repository,
+    &mut artist_repository,
+    &mut genre_repository,
+    &mut album_repository,
     &mut activity_repository,
     domain::media::stories::contribute::Input {
       actor_id,
@@ -37,6 +43,10 @@ async fn create_media(
   actix_web::web::Json(data): actix_web::web::Json<super::dto::CreateMediaDTO>,
   session: actix_session::Session,
 ) -> impl actix_web::Responder {
+  if let Err(error) = validator::Validate::validate(&data) {
+    return infra::actix::errors::ErrorResponse::from(error).into();
+  }
+
   match create_media_activity(
     app_state,
     session,
@@ -55,6 +65,10 @@ async fn update_media(
   session: actix_session::Session,
   path: actix_web::web::Path<shared::vo::UUID4>,
 ) -> impl actix_web::Responder {
+  if let Err(error) = validator::Validate::validate(&data) {
+    return infra::actix::errors::ErrorResponse::from(error).into();
+  }
+
   let media_id = path.into_inner();
 
   match create_media_activity(
@@ -91,45 +105,164 @@ async fn delete_media(
   }
 }
 
-async fn find_by(
+async fn restore_media(
   app_state: actix_web::web::Data<AppState>,
-  web::Query(page): web::Query<PageParams>,
-  web::Query(filter): web::Query<super::dto::FindByQuery>,
+  session: actix_session::Session,
+  path: actix_web::web::Path<shared::vo::UUID4>,
 ) -> impl actix_web::Responder {
-  let mut media_repository = di::media::repositories::MediaRepository::new(&app_state);
-  match domain::media::stories::find_by::execute(
-    &mut media_repository,
-    domain::media::stories::find_by::Input {
-      page: page.into(),
-      search: filter.search,
-      release_date: filter.release_date,
-      min_release_date: filter.min_release_date,
-      max_release_date: filter.max_release_date,
-      parental_rating: filter.parental_rating,
-      min_parental_rating: filter.min_parental_rating,
-      max_parental_rating: filter.max_parental_rating,
-      is_single: filter.is_single,
-      media_type: filter.media_type,
-      slug: filter.slug,
-      artist_ids: filter.artist_id.map(|id| HashSet::from([id.clone()])),
-      composer_ids: filter.composer_id.map(|id| HashSet::from([id.clone()])),
-      genre_ids: filter.genre_id.map(|id| HashSet::from([id.clone()])),
-      album_ids: filter.album_id.map(|id| HashSet::from([id.clone()])),
-    },
+  let media_id = path.into_inner();
+
+  match create_media_activity(
+    app_state,
+    session,
+    domain::media::stories::contribute::ChangeInput::Restore(media_id),
   )
   .await
   {
-    Ok(media) => actix_web::HttpResponse::Ok().json(crate::shared::paged::Paged::<
-      infra::actix::media::presenter::MediaAggregatePresenter,
-    >::from(media)),
-    Err(error) => infra::actix::errors::ErrorResponse::from(error).into(),
+    Ok(response) => response,
+    Err(error) => error.into(),
   }
 }
 
+async fn find_by(
+  request: actix_web::HttpRequest,
+  app_state: actix_web::web::Data<AppState>,
+  web::Query(page): web::Query<PageParams>,
+  ListQuery(filter): ListQuery<super::dto::FindByQuery>,
+  session: actix_session::Session,
+) -> Result<actix_web::HttpResponse, infra::actix::errors::ErrorResponse> {
+  let parental_rating_limit =
+    infra::actix::utils::get_viewer_parental_rating_limit(&app_state, &session).await?;
+  let mut media_repository = di::media::repositories::MediaRepository::new(&app_state);
+  let cursor_page = page.cursor_params(app_state.max_per_page).transpose()?;
+  let facet_fields = filter
+    .facet_fields()
+    .map_err(|error| infra::actix::errors::ErrorResponse::BadRequest(error, None))?;
+
+  let input = domain::media::stories::find_by::Input {
+    page: page.request_params(app_state.max_per_page)?,
+    sort: filter.sort,
+    search: filter.search,
+    release_date: filter.release_date,
+    min_release_date: filter.min_release_date,
+    max_release_date: filter.max_release_date,
+    parental_rating: parse_list_filter(&filter.parental_rating, None)?,
+    min_parental_rating: filter.min_parental_rating,
+    max_parental_rating: domain::user::clamp_parental_rating(
+      filter.max_parental_rating,
+      parental_rating_limit,
+    ),
+    is_single: filter.is_single,
+    media_type: parse_list_filter(&filter.media_type, None)?,
+    slug: filter.slug,
+    artist_ids: parse_list_filter(&filter.artist_id, filter.artist_match)?,
+    composer_ids: parse_list_filter(&filter.composer_id, filter.composer_match)?,
+    genre_ids: parse_list_filter(&filter.genre_id, filter.genre_match)?,
+    album_ids: parse_list_filter(&filter.album_id, filter.album_match)?,
+    credit_role: filter.credit_role,
+  };
+
+  let facets = match facet_fields {
+    Some(fields) => Some(
+      domain::media::stories::facets::execute(
+        &mut media_repository,
+        domain::media::stories::facets::Input {
+          filter: input.clone(),
+          fields,
+        },
+      )
+      .await
+      .map_err(infra::actix::errors::ErrorResponse::from)?,
+    ),
+    None => None,
+  };
+
+  if let Some(cursor_page) = cursor_page {
+    let media = domain::media::stories::find_by_cursor::execute(
+      &mut media_repository,
+      domain::media::stories::find_by_cursor::Input {
+        page: cursor_page,
+        filter: input,
+      },
+    )
+    .await
+    .map_err(infra::actix::errors::ErrorResponse::from)?
+    .map(infra::actix::media::presenter::MediaAggregatePresenter::from);
+
+    return Ok(cursor_paged_headers(&request, &media).json(Faceted::new(media, facets)));
+  }
+
+  let media =
+    crate::shared::paged::Paged::<infra::actix::media::presenter::MediaAggregatePresenter>::from(
+      domain::media::stories::find_by::execute(&mut media_repository, input)
+        .await
+        .map_err(infra::actix::errors::ErrorResponse::from)?,
+    );
+
+  Ok(paged_headers(&request, &media).json(Faceted::new(media, facets)))
+}
+
+async fn find_media_slug(
+  app_state: actix_web::web::Data<AppState>,
+  path: actix_web::web::Path<(shared::vo::Slug, shared::vo::Slug)>,
+  session: actix_session::Session,
+) -> Result<actix_web::HttpResponse, infra::actix::errors::ErrorResponse> {
+  let (artist_slug, slug) = path.into_inner();
+
+  let parental_rating_limit =
+    infra::actix::utils::get_viewer_parental_rating_limit(&app_state, &session).await?;
+  let mut media_repository = di::media::repositories::MediaRepository::new(&app_state);
+
+  let aggregate = domain::media::stories::find_by_slug::execute(
+    &mut media_repository,
+    &slug,
+    &artist_slug,
+    parental_rating_limit,
+  )
+  .await?;
+
+  Ok(
+    actix_web::HttpResponse::Ok()
+      .json(infra::actix::media::presenter::MediaAggregatePresenter::from(aggregate)),
+  )
+}
+
+async fn find_media_id(
+  app_state: actix_web::web::Data<AppState>,
+  path: actix_web::web::Path<shared::vo::UUID4>,
+  session: actix_session::Session,
+) -> Result<actix_web::HttpResponse, infra::actix::errors::ErrorResponse> {
+  let media_id = path.into_inner();
+
+  let parental_rating_limit =
+    infra::actix::utils::get_viewer_parental_rating_limit(&app_state, &session).await?;
+  let mut media_repository = di::media::repositories::MediaRepository::new(&app_state);
+
+  let aggregate = domain::media::stories::find_by_id::execute(
+    &mut media_repository,
+    &media_id,
+    parental_rating_limit,
+  )
+  .await?;
+
+  Ok(
+    actix_web::HttpResponse::Ok()
+      .json(infra::actix::media::presenter::MediaAggregatePresenter::from(aggregate)),
+  )
+}
+
 pub fn configure(config: &mut actix_web::web::ServiceConfig) {
   config
     .route("/contribute/media", actix_web::web::post().to(create_media))
     .route("/medias", actix_web::web::get().to(find_by))
+    .route(
+      "/medias/{media_id}",
+      actix_web::web::get().to(find_media_id),
+    )
+    .route(
+      "/artists/{artist_slug}/medias/{slug}",
+      actix_web::web::get().to(find_media_slug),
+    )
     .route(
       "/contribute/media/{media_id}",
       actix_web::web::patch().to(update_media),
@@ -137,5 +270,9 @@ pub fn configure(config: &mut actix_web::web::ServiceConfig) {
     .route(
       "/contribute/media/{media_id}",
       actix_web::web::delete().to(delete_media),
+    )
+    .route(
+      "/contribute/media/{media_id}/restore",
+      actix_web::web::post().to(restore_media),
     );
 }
@@ -1,23 +1,73 @@
-use shared::vo::{Slug, UUID4};
+use shared::{filter::MatchMode, vo::Slug};
 
 use crate::*;
 
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct FindByQuery {
+  /**
+   * Campo de ordenação, ex: `name` ou `-created_at` para ordem decrescente.
+   */
+  pub sort: Option<crate::shared::sort::Sort<crate::domain::media::repository::SortField>>,
+
+  /**
+   * Facetas a contar sobre os filtros atuais, separadas por vírgula, ex:
+   * `genre,release_decade`. Vazio para todas as facetas.
+   */
+  pub facets: Option<String>,
+
   pub search: Option<String>,
   pub release_date: Option<chrono::NaiveDate>,
   pub min_release_date: Option<chrono::NaiveDate>,
   pub max_release_date: Option<chrono::NaiveDate>,
-  pub parental_rating: Option<u8>,
+  /**
+   * Classificações indicativas separadas por vírgula; valores precedidos por `!` são
+   * excluídos, ex: `!16,!18`.
+   */
+  pub parental_rating: Option<String>,
+
   pub min_parental_rating: Option<u8>,
   pub max_parental_rating: Option<u8>,
   pub is_single: Option<bool>,
-  pub media_type: Option<crate::domain::media::MediaType>,
+
+  /**
+   * Tipos de mídia separados por vírgula; valores precedidos por `!` são excluídos.
+   */
+  pub media_type: Option<String>,
+
   pub slug: Option<Slug>,
-  pub artist_id: Option<UUID4>,
-  pub composer_id: Option<UUID4>,
-  pub genre_id: Option<UUID4>,
-  pub album_id: Option<UUID4>,
+
+  /**
+   * Identificadores separados por vírgula ou com o parâmetro repetido; valores
+   * precedidos por `!` são excluídos. O mesmo vale para `composer_id`, `genre_id` e
+   * `album_id`.
+   */
+  pub artist_id: Option<String>,
+
+  /**
+   * `any` (padrão) para mídias com qualquer um dos intérpretes ou `all` para mídias
+   * com todos eles. O mesmo vale para `composer_match`, `genre_match` e `album_match`.
+   */
+  pub artist_match: Option<MatchMode>,
+
+  pub composer_id: Option<String>,
+  pub composer_match: Option<MatchMode>,
+  pub genre_id: Option<String>,
+  pub genre_match: Option<MatchMode>,
+  pub album_id: Option<String>,
+  pub album_match: Option<MatchMode>,
+  pub credit_role: Option<crate::domain::media::credit::CreditRole>,
+}
+
+impl FindByQuery {
+  pub fn facet_fields(
+    &self,
+  ) -> Result<Option<Vec<crate::domain::media::repository::FacetField>>, String> {
+    self
+      .facets
+      .as_deref()
+      .map(crate::infra::actix::shared::parse_list)
+      .transpose()
+  }
 }
 
 #[derive(Debug, serde::Deserialize, validator::Validate)]
@@ -37,6 +87,20 @@ pub struct CreateMediaDTO {
   #[validate(range(min = 0, max = 18))]
   pub parental_rating: Option<u8>,
 
+  #[validate(range(max = infra::actix::shared::MAX_INTEGER))]
+  pub duration: Option<u32>,
+
+  pub isrc: Option<crate::shared::vo::Isrc>,
+
+  #[validate(range(min = 1, max = 999))]
+  pub bpm: Option<u16>,
+
+  #[validate(length(min = 1, max = 16))]
+  pub key: Option<String>,
+
+  #[validate(length(min = 2, max = 8))]
+  pub language: Option<String>,
+
   pub genre_ids: Option<std::collections::HashSet<crate::shared::vo::UUID4>>,
 
   pub composer_ids: Option<std::collections::HashSet<crate::shared::vo::UUID4>>,
@@ -45,6 +109,8 @@ pub struct CreateMediaDTO {
 
   pub album_ids: std::collections::HashSet<crate::shared::vo::UUID4>,
 
+  pub credits: Option<Vec<crate::domain::media::credit::Credit>>,
+
   pub is_single: Option<bool>,
 }
 
@@ -67,12 +133,28 @@ pub struct UpdateMediaDto {
   #[validate(range(min = 0, max = 18))]
   pub parental_rating: Option<u8>,
 
+  #[validate(range(max = infra::actix::shared::MAX_INTEGER))]
+  pub duration: Option<u32>,
+
+  pub isrc: Option<crate::shared::vo::Isrc>,
+
+  #[validate(range(min = 1, max = 999))]
+  pub bpm: Option<u16>,
+
+  #[validate(length(min = 1, max = 16))]
+  pub key: Option<String>,
+
+  #[validate(length(min = 2, max = 8))]
+  pub language: Option<String>,
+
   pub composer_ids: Option<std::collections::HashSet<crate::shared::vo::UUID4>>,
 
   pub interpreter_ids: Option<std::collections::HashSet<crate::shared::vo::UUID4>>,
 
   pub album_ids: Option<std::collections::HashSet<crate::shared::vo::UUID4>>,
 
+  pub credits: Option<Vec<crate::domain::media::credit::Credit>>,
+
   pub is_single: Option<bool>,
 }
 
@@ -85,10 +167,16 @@ impl From<CreateMediaDTO> for crate::domain::media::stories::contribute::CreateM
       release_date: value.release_date,
       cover: value.cover,
       parental_rating: value.parental_rating,
+      duration: value.duration,
+      isrc: value.isrc,
+      bpm: value.bpm,
+      key: value.key,
+      language: value.language,
       genre_ids: value.genre_ids,
       composer_ids: value.composer_ids,
       interpreter_ids: value.interpreter_ids,
       album_ids: value.album_ids,
+      credits: value.credits,
       is_single: value.is_single,
     }
   }
@@ -104,9 +192,15 @@ impl From<UpdateMediaDto> for crate::domain::media::contribution::changes::Chang
       cover: value.cover,
       genre_ids: value.genre_ids,
       parental_rating: value.parental_rating,
+      duration: value.duration,
+      isrc: value.isrc,
+      bpm: value.bpm,
+      key: value.key,
+      language: value.language,
       composer_ids: value.composer_ids,
       interpreter_ids: value.interpreter_ids,
       album_ids: value.album_ids,
+      credits: value.credits,
       is_single: value.is_single,
     }
   }
@@ -121,10 +215,16 @@ pub struct MediaPresenter {
   release_date: Option<chrono::NaiveDate>,
   cover: Option<String>,
   parental_rating: u8,
+  duration: Option<u32>,
+  isrc: Option<crate::shared::vo::Isrc>,
+  bpm: Option<u16>,
+  key: Option<String>,
+  language: Option<String>,
   genre_ids: std::collections::HashSet<crate::shared::vo::UUID4>,
   composer_ids: std::collections::HashSet<crate::shared::vo::UUID4>,
   interpreter_ids: std::collections::HashSet<crate::shared::vo::UUID4>,
   album_ids: std::collections::HashSet<crate::shared::vo::UUID4>,
+  credits: Vec<crate::domain::media::credit::Credit>,
   is_single: bool,
   created_at: chrono::NaiveDateTime,
   updated_at: chrono::NaiveDateTime,
@@ -137,16 +237,53 @@ impl From<domain::media::Media> for MediaPresenter {
       name: value.name().clone(),
       media_type: value.media_type().clone(),
       slug: value.slug().clone(),
-      release_date: value.release_date().clone(),
+      release_date: *value.release_date(),
       cover: value.cover().clone(),
-      parental_rating: value.parental_rating().clone(),
+      parental_rating: *value.parental_rating(),
+      duration: *value.duration(),
+      isrc: value.isrc().clone(),
+      bpm: *value.bpm(),
+      key: value.key().clone(),
+      language: value.language().clone(),
       genre_ids: value.genre_ids().clone(),
       composer_ids: value.composer_ids().clone(),
       interpreter_ids: value.interpreter_ids().clone(),
       album_ids: value.album_ids().clone(),
-      is_single: value.is_single().clone(),
-      created_at: value.created_at().clone(),
-      updated_at: value.updated_at().clone(),
+      credits: value.credits().clone(),
+      is_single: *value.is_single(),
+      created_at: *value.created_at(),
+      updated_at: *value.updated_at(),
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_duration_above_integer_column() {
+    let dto = UpdateMediaDto {
+      duration: Some(u32::MAX),
+      ..Default::default()
+    };
+
+    match validator::Validate::validate(&dto).map_err(infra::actix::errors::ErrorResponse::from) {
+      Err(infra::actix::errors::ErrorResponse::BadRequest(_, Some(errors))) => assert!(
+        errors.contains_key("duration"),
+        "Duração acima de i32::MAX deveria ser rejeitada"
+      ),
+      other => panic!("Era esperado um BadRequest, obtido {:?}", other),
+    }
+
+    let dto = UpdateMediaDto {
+      duration: Some(215_000),
+      ..Default::default()
+    };
+
+    assert!(
+      validator::Validate::validate(&dto).is_ok(),
+      "Duração válida foi rejeitada"
+    );
+  }
+}
@@ -33,6 +33,23 @@ impl From<crate::domain::media::stories::contribute::Error>
         crate::infra::actix::errors::ErrorResponse::NotFound(String::from("Media not found"))
       }
       crate::domain::media::stories::contribute::Error::MediaRepositoryError(error) => error.into(),
+      crate::domain::media::stories::contribute::Error::ArtistRepositoryError(error) => {
+        error.into()
+      }
+      crate::domain::media::stories::contribute::Error::GenreRepositoryError(error) => error.into(),
+      crate::domain::media::stories::contribute::Error::AlbumRepositoryError(error) => error.into(),
+      crate::domain::media::stories::contribute::Error::MissingReferences(missing) => {
+        missing.into()
+      }
+      crate::domain::media::stories::contribute::Error::DuplicatedCredit(credit) => {
+        crate::infra::actix::errors::ErrorResponse::BadRequest(
+          format!(
+            "Artist {} is credited more than once as {}",
+            credit.artist_id, credit.role
+          ),
+          None,
+        )
+      }
     }
   }
 }
@@ -46,3 +63,65 @@ impl From<crate::domain::media::stories::find_by::Error>
     }
   }
 }
+
+impl From<crate::domain::media::stories::find_by_slug::Error>
+  for crate::infra::actix::errors::ErrorResponse
+{
+  fn from(value: crate::domain::media::stories::find_by_slug::Error) -> Self {
+    match value {
+      crate::domain::media::stories::find_by_slug::Error::MediaNotFound => {
+        crate::infra::actix::errors::ErrorResponse::NotFound(String::from("Media not found"))
+      }
+      crate::domain::media::stories::find_by_slug::Error::ParentalRatingRestricted => {
+        crate::infra::actix::errors::ErrorResponse::Forbidden(String::from(
+          "Content restricted by parental rating",
+        ))
+      }
+      crate::domain::media::stories::find_by_slug::Error::RepositoryError(error) => error.into(),
+    }
+  }
+}
+
+impl From<crate::domain::media::stories::find_by_id::Error>
+  for crate::infra::actix::errors::ErrorResponse
+{
+  fn from(value: crate::domain::media::stories::find_by_id::Error) -> Self {
+    match value {
+      crate::domain::media::stories::find_by_id::Error::MediaNotFound => {
+        crate::infra::actix::errors::ErrorResponse::NotFound(String::from("Media not found"))
+      }
+      crate::domain::media::stories::find_by_id::Error::ParentalRatingRestricted => {
+        crate::infra::actix::errors::ErrorResponse::Forbidden(String::from(
+          "Content restricted by parental rating",
+        ))
+      }
+      crate::domain::media::stories::find_by_id::Error::RepositoryError(error) => error.into(),
+    }
+  }
+}
+
+impl From<crate::domain::media::stories::find_by_cursor::Error>
+  for crate::infra::actix::errors::ErrorResponse
+{
+  fn from(value: crate::domain::media::stories::find_by_cursor::Error) -> Self {
+    match value {
+      crate::domain::media::stories::find_by_cursor::Error::SortNotSupported => {
+        crate::infra::actix::errors::ErrorResponse::BadRequest(
+          String::from("Sorting is not supported with cursor pagination"),
+          None,
+        )
+      }
+      crate::domain::media::stories::find_by_cursor::Error::RepositoryError(error) => error.into(),
+    }
+  }
+}
+
+impl From<crate::domain::media::stories::facets::Error>
+  for crate::infra::actix::errors::ErrorResponse
+{
+  fn from(value: crate::domain::media::stories::facets::Error) -> Self {
+    match value {
+      crate::domain::media::stories::facets::Error::RepositoryError(error) => error.into(),
+    }
+  }
+}
@@ -6,6 +6,21 @@ use crate::{
   },
 };
 
+#[derive(Debug, serde::Serialize)]
+pub struct CreditPresenter {
+  role: crate::domain::media::credit::CreditRole,
+  artist: crate::infra::actix::artist::presenter::ArtistPresenter,
+}
+
+impl From<crate::domain::media::credit::ArtistCredit> for CreditPresenter {
+  fn from(value: crate::domain::media::credit::ArtistCredit) -> Self {
+    Self {
+      role: value.role,
+      artist: value.artist.into(),
+    }
+  }
+}
+
 #[derive(Debug, serde::Serialize)]
 pub struct MediaAggregatePresenter {
   id: UUID4,
@@ -16,8 +31,17 @@ pub struct MediaAggregatePresenter {
   release_date: Option<chrono::NaiveDate>,
   cover: Option<String>,
   parental_rating: u8,
+  duration: Option<u32>,
+  isrc: Option<crate::shared::vo::Isrc>,
+  bpm: Option<u16>,
+  key: Option<String>,
+  language: Option<String>,
   composers: Vec<crate::infra::actix::artist::presenter::ArtistPresenter>,
   interpreters: Vec<crate::infra::actix::artist::presenter::ArtistPresenter>,
+  credits: Vec<CreditPresenter>,
+  sources: Vec<crate::infra::actix::source::presenter::SourcePresenter>,
+  genres: Vec<crate::infra::actix::genre::presenter::GenrePresenter>,
+  albums: Vec<crate::infra::actix::album::presenter::AlbumPresenter>,
   created_at: chrono::NaiveDateTime,
   updated_at: chrono::NaiveDateTime,
 }
@@ -32,6 +56,11 @@ impl From<crate::domain::media::media_aggregate::MediaAggregate> for MediaAggreg
       release_date: *value.media.release_date(),
       cover: value.media.cover().clone(),
       parental_rating: *value.media.parental_rating(),
+      duration: *value.media.duration(),
+      isrc: value.media.isrc().clone(),
+      bpm: *value.media.bpm(),
+      key: value.media.key().clone(),
+      language: value.media.language().clone(),
       composers: value
         .composers
         .into_iter()
@@ -42,6 +71,26 @@ impl From<crate::domain::media::media_aggregate::MediaAggregate> for MediaAggreg
         .into_iter()
         .map(crate::infra::actix::artist::presenter::ArtistPresenter::from)
         .collect(),
+      credits: value
+        .credits
+        .into_iter()
+        .map(CreditPresenter::from)
+        .collect(),
+      sources: value
+        .sources
+        .into_iter()
+        .map(crate::infra::actix::source::presenter::SourcePresenter::from)
+        .collect(),
+      genres: value
+        .genres
+        .into_iter()
+        .map(crate::infra::actix::genre::presenter::GenrePresenter::from)
+        .collect(),
+      albums: value
+        .albums
+        .into_iter()
+        .map(crate::infra::actix::album::presenter::AlbumPresenter::from)
+        .collect(),
       is_single: *value.media.is_single(),
       created_at: *value.media.created_at(),
       updated_at: *value.media.updated_at(),
@@ -1,9 +1,12 @@
 pub mod activity;
 pub mod album;
 pub mod artist;
+pub mod catalog;
 pub mod errors;
 pub mod genre;
+pub mod lyrics;
 pub mod media;
+pub mod search;
 pub mod shared;
 pub mod source;
 pub mod user;
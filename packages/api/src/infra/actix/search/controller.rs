@@ -0,0 +1,101 @@
+use actix_web::web;
+use infra::actix::{
+  errors::ErrorResponse,
+  shared::{paged_response, PageParams},
+};
+use shared::paged::Paged;
+
+use crate::*;
+
+use super::presenter::{SearchResultPresenter, SuggestionPresenter};
+
+async fn search(
+  request: actix_web::HttpRequest,
+  app_state: web::Data<AppState>,
+  web::Query(page): web::Query<PageParams>,
+  web::Query(query): web::Query<super::dto::SearchQuery>,
+  session: actix_session::Session,
+) -> impl actix_web::Responder {
+  let page = match page.request_params(app_state.max_per_page) {
+    Ok(page) => page,
+    Err(error) => return error.into(),
+  };
+
+  let parental_rating_limit =
+    match infra::actix::utils::get_viewer_parental_rating_limit(&app_state, &session).await {
+      Ok(limit) => limit,
+      Err(error) => return error.into(),
+    };
+
+  let entity_names = match query.entity_names() {
+    Ok(entity_names) => entity_names,
+    Err(error) => return ErrorResponse::BadRequest(error, None).into(),
+  };
+
+  let mut search_repository = di::search::repositories::SearchRepository::new(&app_state);
+
+  match domain::search::stories::search::execute(
+    &mut search_repository,
+    domain::search::stories::search::Input {
+      page,
+      term: query.q,
+      entity_names,
+      limit_per_type: query.limit_per_type,
+      max_parental_rating: domain::user::clamp_parental_rating(None, parental_rating_limit),
+    },
+  )
+  .await
+  {
+    Ok(result) => paged_response(&request, Paged::<SearchResultPresenter>::from(result)),
+    Err(error) => ErrorResponse::from(error).into(),
+  }
+}
+
+async fn autocomplete(
+  app_state: web::Data<AppState>,
+  path: web::Path<String>,
+  web::Query(query): web::Query<super::dto::AutocompleteQuery>,
+  session: actix_session::Session,
+) -> impl actix_web::Responder {
+  let entity_name = match path.into_inner().parse() {
+    Ok(entity_name) => entity_name,
+    Err(error) => return ErrorResponse::BadRequest(error, None).into(),
+  };
+
+  let parental_rating_limit =
+    match infra::actix::utils::get_viewer_parental_rating_limit(&app_state, &session).await {
+      Ok(limit) => limit,
+      Err(error) => return error.into(),
+    };
+
+  let mut search_repository = di::search::repositories::SearchRepository::new(&app_state);
+
+  match domain::search::stories::autocomplete::execute(
+    &mut search_repository,
+    domain::search::stories::autocomplete::Input {
+      entity_name,
+      term: query.q,
+      limit: query.limit,
+      max_parental_rating: domain::user::clamp_parental_rating(None, parental_rating_limit),
+    },
+  )
+  .await
+  {
+    Ok(suggestions) => actix_web::HttpResponse::Ok().json(
+      suggestions
+        .into_iter()
+        .map(SuggestionPresenter::from)
+        .collect::<Vec<_>>(),
+    ),
+    Err(error) => ErrorResponse::from(error).into(),
+  }
+}
+
+pub fn configure(config: &mut actix_web::web::ServiceConfig) {
+  config
+    .route("/search", actix_web::web::get().to(search))
+    .route(
+      "/autocomplete/{entity_name}",
+      actix_web::web::get().to(autocomplete),
+    );
+}
@@ -0,0 +1,36 @@
+use crate::shared::vo::CollaborativeEntityName;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct SearchQuery {
+  pub q: String,
+
+  /**
+   * Tipos de entidade separados por vírgula, ex: `Artist,Album`.
+   */
+  pub types: Option<String>,
+
+  pub limit_per_type: Option<usize>,
+}
+
+impl SearchQuery {
+  pub fn entity_names(&self) -> Result<Option<Vec<CollaborativeEntityName>>, String> {
+    self
+      .types
+      .as_ref()
+      .map(|types| {
+        types
+          .split(',')
+          .map(str::trim)
+          .filter(|name| !name.is_empty())
+          .map(str::parse)
+          .collect()
+      })
+      .transpose()
+  }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct AutocompleteQuery {
+  pub q: String,
+  pub limit: Option<usize>,
+}
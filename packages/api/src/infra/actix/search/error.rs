@@ -0,0 +1,48 @@
+use crate::{
+  domain::{self, search::Error},
+  infra::actix::errors::ErrorResponse,
+};
+
+impl From<Error> for ErrorResponse {
+  fn from(value: Error) -> Self {
+    match value {
+      Error::DatabaseError(err) => ErrorResponse::InternalServerError(err),
+    }
+  }
+}
+
+impl From<domain::search::stories::search::Error> for ErrorResponse {
+  fn from(value: domain::search::stories::search::Error) -> Self {
+    match value {
+      domain::search::stories::search::Error::EmptyTerm => {
+        ErrorResponse::BadRequest(String::from("Search term must not be empty"), None)
+      }
+      domain::search::stories::search::Error::UnsupportedEntity(entity_name) => {
+        ErrorResponse::BadRequest(format!("{} is not searchable", entity_name), None)
+      }
+      domain::search::stories::search::Error::RepositoryError(error) => error.into(),
+    }
+  }
+}
+
+impl From<domain::search::stories::autocomplete::Error> for ErrorResponse {
+  fn from(value: domain::search::stories::autocomplete::Error) -> Self {
+    match value {
+      domain::search::stories::autocomplete::Error::EmptyTerm => {
+        ErrorResponse::BadRequest(String::from("Search term must not be empty"), None)
+      }
+      domain::search::stories::autocomplete::Error::UnsupportedEntity(entity_name) => {
+        ErrorResponse::BadRequest(format!("{} is not searchable", entity_name), None)
+      }
+      domain::search::stories::autocomplete::Error::RepositoryError(error) => error.into(),
+    }
+  }
+}
+
+impl From<domain::search::stories::reindex::Error> for ErrorResponse {
+  fn from(value: domain::search::stories::reindex::Error) -> Self {
+    match value {
+      domain::search::stories::reindex::Error::RepositoryError(error) => error.into(),
+    }
+  }
+}
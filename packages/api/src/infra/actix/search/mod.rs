@@ -0,0 +1,4 @@
+pub mod controller;
+pub mod dto;
+pub mod error;
+pub mod presenter;
@@ -0,0 +1,62 @@
+use crate::{
+  domain::search::{SearchResult, Suggestion},
+  shared::{
+    paged::Paged,
+    vo::{CollaborativeEntityName, Slug, UUID4},
+  },
+};
+
+#[derive(Debug, serde::Serialize)]
+pub struct SearchResultPresenter {
+  entity_name: CollaborativeEntityName,
+  id: UUID4,
+  name: String,
+  slug: Slug,
+  rank: f32,
+}
+
+impl From<SearchResult> for SearchResultPresenter {
+  fn from(value: SearchResult) -> Self {
+    Self {
+      entity_name: value.entity_name,
+      id: value.id,
+      name: value.name,
+      slug: value.slug,
+      rank: value.rank,
+    }
+  }
+}
+
+impl From<Paged<SearchResult>> for Paged<SearchResultPresenter> {
+  fn from(value: Paged<SearchResult>) -> Self {
+    Self {
+      items: value
+        .items
+        .into_iter()
+        .map(SearchResultPresenter::from)
+        .collect(),
+      total_items: value.total_items,
+      total_pages: value.total_pages,
+      page: value.page,
+    }
+  }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct SuggestionPresenter {
+  id: UUID4,
+  name: String,
+  slug: Slug,
+  hint: Option<String>,
+}
+
+impl From<Suggestion> for SuggestionPresenter {
+  fn from(value: Suggestion) -> Self {
+    Self {
+      id: value.id,
+      name: value.name,
+      slug: value.slug,
+      hint: value.hint,
+    }
+  }
+}
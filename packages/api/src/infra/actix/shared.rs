@@ -2,13 +2,428 @@
 pub struct PageParams {
   pub page: Option<usize>,
   pub per_page: Option<usize>,
+
+  /**
+   * Ativa a paginação por cursor. Vazio para a primeira página ou o valor de
+   * `next_cursor`/`prev_cursor` de uma resposta anterior.
+   */
+  pub cursor: Option<String>,
+
+  /**
+   * Na paginação por cursor, indica se o total de registros deve ser contado.
+   */
+  pub include_total: Option<bool>,
 }
 
-impl From<PageParams> for crate::shared::paged::RequestPageParams {
-  fn from(value: PageParams) -> Self {
+/**
+ * Quantidade de itens por página quando o cliente não informa `per_page`.
+ */
+pub const DEFAULT_PER_PAGE: usize = 20;
+
+/**
+ * Maior valor aceito em campos numéricos gravados em colunas INTEGER do banco.
+ */
+pub const MAX_INTEGER: u32 = i32::MAX as u32;
+
+impl PageParams {
+  /**
+   * Valida os parâmetros informados pelo cliente, limitando o tamanho da página a `max_per_page`.
+   */
+  pub fn validate(&self, max_per_page: usize) -> Result<(), super::errors::ErrorResponse> {
+    let mut errors = std::collections::HashMap::<String, Vec<String>>::new();
+
+    match self.page {
+      Some(0) => errors
+        .entry(String::from("page"))
+        .or_default()
+        .push(String::from("page must be greater than or equal to 1")),
+      // O deslocamento (page - 1) * per_page precisa caber no OFFSET do banco.
+      Some(page)
+        if (page - 1)
+          .checked_mul(self.per_page.unwrap_or(DEFAULT_PER_PAGE))
+          .and_then(|skip| i64::try_from(skip).ok())
+          .is_none() =>
+      {
+        errors
+          .entry(String::from("page"))
+          .or_default()
+          .push(String::from("page is too large"))
+      }
+      _ => {}
+    }
+
+    match self.per_page {
+      Some(0) => errors
+        .entry(String::from("per_page"))
+        .or_default()
+        .push(String::from("per_page must be greater than or equal to 1")),
+      Some(per_page) if per_page > max_per_page => errors
+        .entry(String::from("per_page"))
+        .or_default()
+        .push(format!(
+          "per_page must be less than or equal to {}",
+          max_per_page
+        )),
+      _ => {}
+    }
+
+    if errors.is_empty() {
+      Ok(())
+    } else {
+      Err(super::errors::ErrorResponse::BadRequest(
+        String::from("Invalid pagination parameters"),
+        Some(errors),
+      ))
+    }
+  }
+
+  /**
+   * Retorna os parâmetros da paginação por número de página já validados.
+   */
+  pub fn request_params(
+    &self,
+    max_per_page: usize,
+  ) -> Result<crate::shared::paged::RequestPageParams, super::errors::ErrorResponse> {
+    self.validate(max_per_page)?;
+
+    Ok(crate::shared::paged::RequestPageParams {
+      page: self.page.unwrap_or(1),
+      per_page: self.per_page.unwrap_or(DEFAULT_PER_PAGE),
+    })
+  }
+
+  /**
+   * Retorna os parâmetros da paginação por cursor, caso o cliente tenha optado por ela.
+   */
+  pub fn cursor_params(
+    &self,
+    max_per_page: usize,
+  ) -> Option<Result<crate::shared::paged::CursorPageParams, super::errors::ErrorResponse>> {
+    self
+      .cursor
+      .as_ref()
+      .map(|_| self.cursor_page_params(max_per_page))
+  }
+
+  /**
+   * Interpreta os parâmetros como paginação por cursor, começando pela primeira
+   * página quando nenhum cursor for informado.
+   */
+  pub fn cursor_page_params(
+    &self,
+    max_per_page: usize,
+  ) -> Result<crate::shared::paged::CursorPageParams, super::errors::ErrorResponse> {
+    self.validate(max_per_page)?;
+
+    let cursor = match self.cursor.as_deref().map(str::trim) {
+      None | Some("") => None,
+      Some(cursor) => Some(
+        cursor
+          .parse()
+          .map_err(|error| super::errors::ErrorResponse::BadRequest(error, None))?,
+      ),
+    };
+
+    Ok(crate::shared::paged::CursorPageParams {
+      cursor,
+      limit: self.per_page.unwrap_or(DEFAULT_PER_PAGE),
+      include_total: self.include_total.unwrap_or(false),
+    })
+  }
+}
+
+/**
+ * Monta a URL da requisição atual trocando o valor de um parâmetro da query string.
+ */
+fn link_to(request: &actix_web::HttpRequest, param: &str, value: &str) -> String {
+  let mut url = request.full_url();
+
+  let pairs = url
+    .query_pairs()
+    .filter(|(key, _)| key != param)
+    .map(|(key, value)| (key.into_owned(), value.into_owned()))
+    .collect::<Vec<_>>();
+
+  url
+    .query_pairs_mut()
+    .clear()
+    .extend_pairs(pairs)
+    .append_pair(param, value);
+
+  format!("<{}>", url)
+}
+
+/**
+ * Prepara a resposta de uma listagem paginada com os cabeçalhos `Link` (RFC 8288) e
+ * `X-Total-Count`.
+ */
+pub fn paged_headers<T>(
+  request: &actix_web::HttpRequest,
+  paged: &crate::shared::paged::Paged<T>,
+) -> actix_web::HttpResponseBuilder {
+  let last_page = paged.total_pages.max(1);
+  let mut links = vec![
+    format!(r#"{}; rel="first""#, link_to(request, "page", "1")),
+    format!(
+      r#"{}; rel="last""#,
+      link_to(request, "page", &last_page.to_string())
+    ),
+  ];
+
+  if paged.page > 1 {
+    links.push(format!(
+      r#"{}; rel="prev""#,
+      link_to(
+        request,
+        "page",
+        &(paged.page - 1).min(last_page).to_string()
+      )
+    ));
+  }
+
+  if paged.page < paged.total_pages {
+    links.push(format!(
+      r#"{}; rel="next""#,
+      link_to(request, "page", &(paged.page + 1).to_string())
+    ));
+  }
+
+  let mut response = actix_web::HttpResponse::Ok();
+
+  response
+    .insert_header(("X-Total-Count", paged.total_items.to_string()))
+    .insert_header((actix_web::http::header::LINK, links.join(", ")));
+
+  response
+}
+
+/**
+ * Responde uma listagem paginada com os cabeçalhos de `paged_headers`.
+ */
+pub fn paged_response<T: serde::Serialize>(
+  request: &actix_web::HttpRequest,
+  paged: crate::shared::paged::Paged<T>,
+) -> actix_web::HttpResponse {
+  paged_headers(request, &paged).json(paged)
+}
+
+/**
+ * Prepara a resposta de uma listagem paginada por cursor com o cabeçalho `Link` e,
+ * quando o total tiver sido contado, `X-Total-Count`.
+ */
+pub fn cursor_paged_headers<T>(
+  request: &actix_web::HttpRequest,
+  paged: &crate::shared::paged::CursorPaged<T>,
+) -> actix_web::HttpResponseBuilder {
+  let links = [("next", &paged.next_cursor), ("prev", &paged.prev_cursor)]
+    .into_iter()
+    .filter_map(|(rel, cursor)| {
+      cursor
+        .as_ref()
+        .map(|cursor| format!(r#"{}; rel="{}""#, link_to(request, "cursor", cursor), rel))
+    })
+    .collect::<Vec<_>>();
+
+  let mut response = actix_web::HttpResponse::Ok();
+
+  if let Some(total_items) = paged.total_items {
+    response.insert_header(("X-Total-Count", total_items.to_string()));
+  }
+
+  if !links.is_empty() {
+    response.insert_header((actix_web::http::header::LINK, links.join(", ")));
+  }
+
+  response
+}
+
+/**
+ * Responde uma listagem paginada por cursor com os cabeçalhos de `cursor_paged_headers`.
+ */
+pub fn cursor_paged_response<T: serde::Serialize>(
+  request: &actix_web::HttpRequest,
+  paged: crate::shared::paged::CursorPaged<T>,
+) -> actix_web::HttpResponse {
+  cursor_paged_headers(request, &paged).json(paged)
+}
+
+/**
+ * Listagem paginada acompanhada das contagens por faceta, quando solicitadas.
+ */
+#[derive(Debug, serde::Serialize)]
+pub struct Faceted<P> {
+  #[serde(flatten)]
+  pub page: P,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub facets: Option<std::collections::BTreeMap<String, Vec<crate::shared::facet::FacetValue>>>,
+}
+
+impl<P> Faceted<P> {
+  pub fn new<F: std::fmt::Display>(
+    page: P,
+    facets: Option<Vec<crate::shared::facet::Facet<F>>>,
+  ) -> Self {
     Self {
-      page: value.page.unwrap_or(1),
-      per_page: value.per_page.unwrap_or(20),
+      page,
+      facets: facets.map(|facets| {
+        facets
+          .into_iter()
+          .map(|facet| (facet.field.to_string(), facet.values))
+          .collect()
+      }),
+    }
+  }
+}
+
+/**
+ * Interpreta uma lista de valores separados por vírgula, ex: `genre,release_decade`.
+ */
+pub fn parse_list<T: std::str::FromStr<Err = String>>(value: &str) -> Result<Vec<T>, String> {
+  value
+    .split(',')
+    .map(str::trim)
+    .filter(|item| !item.is_empty())
+    .map(str::parse)
+    .collect()
+}
+
+/**
+ * Extrai a query string como `web::Query`, aceitando parâmetros repetidos: os valores de
+ * uma mesma chave são unidos por vírgula, ex: `genre_id=a&genre_id=b` vira `genre_id=a,b`.
+ */
+#[derive(Debug)]
+pub struct ListQuery<T>(pub T);
+
+impl<T: serde::de::DeserializeOwned> actix_web::FromRequest for ListQuery<T> {
+  type Error = super::errors::ErrorResponse;
+  type Future = std::future::Ready<Result<Self, Self::Error>>;
+
+  fn from_request(
+    request: &actix_web::HttpRequest,
+    _: &mut actix_web::dev::Payload,
+  ) -> Self::Future {
+    let mut pairs = Vec::<(String, String)>::new();
+
+    for (key, value) in url::form_urlencoded::parse(request.query_string().as_bytes()) {
+      match pairs.iter_mut().find(|(existing, _)| *existing == key) {
+        Some((_, existing)) => {
+          existing.push(',');
+          existing.push_str(&value);
+        }
+        None => pairs.push((key.into_owned(), value.into_owned())),
+      }
+    }
+
+    let query = url::form_urlencoded::Serializer::new(String::new())
+      .extend_pairs(pairs)
+      .finish();
+
+    std::future::ready(
+      actix_web::web::Query::<T>::from_query(&query)
+        .map(|query| ListQuery(query.into_inner()))
+        .map_err(|error| super::errors::ErrorResponse::BadRequest(error.to_string(), None)),
+    )
+  }
+}
+
+/**
+ * Interpreta um filtro de múltiplos valores recebido na query string.
+ */
+pub fn parse_list_filter<T: std::str::FromStr + Eq + std::hash::Hash>(
+  value: &Option<String>,
+  mode: Option<crate::shared::filter::MatchMode>,
+) -> Result<Option<crate::shared::filter::ListFilter<T>>, super::errors::ErrorResponse> {
+  value
+    .as_deref()
+    .map(|value| crate::shared::filter::ListFilter::parse(value, mode.unwrap_or_default()))
+    .transpose()
+    .map_err(|error| super::errors::ErrorResponse::BadRequest(error, None))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_validate_page_params() {
+    let params = |page, per_page| PageParams {
+      page,
+      per_page,
+      cursor: None,
+      include_total: None,
+    };
+
+    assert!(params(None, None).validate(100).is_ok());
+    assert!(params(Some(2), Some(100)).validate(100).is_ok());
+
+    match params(Some(0), Some(101)).validate(100) {
+      Err(super::super::errors::ErrorResponse::BadRequest(_, Some(errors))) => {
+        assert!(
+          errors.contains_key("page"),
+          "page = 0 deveria ser rejeitado"
+        );
+        assert!(
+          errors.contains_key("per_page"),
+          "per_page acima do limite deveria ser rejeitado"
+        );
+      }
+      other => panic!("Era esperado um BadRequest, obtido {:?}", other),
+    }
+
+    assert!(
+      params(None, Some(0)).validate(100).is_err(),
+      "per_page = 0 deveria ser rejeitado"
+    );
+
+    assert!(
+      params(Some(usize::MAX), Some(100)).validate(100).is_err(),
+      "Página cujo deslocamento não cabe no OFFSET deveria ser rejeitada"
+    );
+  }
+
+  #[test]
+  fn test_paged_response_headers() {
+    let request = actix_web::test::TestRequest::get()
+      .uri("/artists?search=rock&page=2&per_page=10")
+      .to_http_request();
+
+    let response = paged_response(
+      &request,
+      crate::shared::paged::Paged::from_tuple(
+        (25, vec![1, 2, 3]),
+        crate::shared::paged::RequestPageParams {
+          page: 2,
+          per_page: 10,
+        },
+      ),
+    );
+
+    let header = |name| {
+      response
+        .headers()
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+        .to_string()
+    };
+
+    assert_eq!(header("X-Total-Count"), "25");
+
+    let link = header("Link");
+
+    for expected in [
+      r#"search=rock&per_page=10&page=1>; rel="first""#,
+      r#"search=rock&per_page=10&page=3>; rel="last""#,
+      r#"search=rock&per_page=10&page=1>; rel="prev""#,
+      r#"search=rock&per_page=10&page=3>; rel="next""#,
+    ] {
+      assert!(
+        link.contains(expected),
+        "Cabeçalho Link deveria conter {}: {}",
+        expected,
+        link
+      );
     }
   }
 }
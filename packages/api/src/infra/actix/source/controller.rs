@@ -31,6 +31,10 @@ async fn create_source(
   actix_web::web::Json(data): actix_web::web::Json<super::dto::CreateSourceDTO>,
   session: actix_session::Session,
 ) -> impl actix_web::Responder {
+  if let Err(error) = validator::Validate::validate(&data) {
+    return infra::actix::errors::ErrorResponse::from(error).into();
+  }
+
   match create_source_activity(
     app_state,
     session,
@@ -43,6 +47,33 @@ async fn create_source(
   }
 }
 
+async fn update_source(
+  app_state: actix_web::web::Data<AppState>,
+  actix_web::web::Json(data): actix_web::web::Json<super::dto::UpdateSourceDTO>,
+  session: actix_session::Session,
+  path: actix_web::web::Path<shared::vo::UUID4>,
+) -> impl actix_web::Responder {
+  if let Err(error) = validator::Validate::validate(&data) {
+    return infra::actix::errors::ErrorResponse::from(error).into();
+  }
+
+  let source_id = path.into_inner();
+
+  match create_source_activity(
+    app_state,
+    session,
+    domain::source::stories::contribute::ChangeInput::Update {
+      id: source_id,
+      changes: data.into(),
+    },
+  )
+  .await
+  {
+    Ok(response) => response,
+    Err(error) => error.into(),
+  }
+}
+
 async fn delete_source(
   app_state: actix_web::web::Data<AppState>,
   session: actix_session::Session,
@@ -62,6 +93,89 @@ async fn delete_source(
   }
 }
 
+async fn restore_source(
+  app_state: actix_web::web::Data<AppState>,
+  session: actix_session::Session,
+  path: actix_web::web::Path<shared::vo::UUID4>,
+) -> impl actix_web::Responder {
+  let source_id = path.into_inner();
+
+  match create_source_activity(
+    app_state,
+    session,
+    domain::source::stories::contribute::ChangeInput::Restore(source_id),
+  )
+  .await
+  {
+    Ok(response) => response,
+    Err(error) => error.into(),
+  }
+}
+
+async fn report_source(
+  app_state: actix_web::web::Data<AppState>,
+  actix_web::web::Json(data): actix_web::web::Json<super::dto::ReportSourceDTO>,
+  session: actix_session::Session,
+  path: actix_web::web::Path<shared::vo::UUID4>,
+) -> impl actix_web::Responder {
+  if let Err(error) = validator::Validate::validate(&data) {
+    return infra::actix::errors::ErrorResponse::from(error).into();
+  }
+
+  let source_id = path.into_inner();
+
+  match create_source_activity(
+    app_state,
+    session,
+    domain::source::stories::contribute::ChangeInput::Report {
+      id: source_id,
+      status: data
+        .status
+        .unwrap_or(domain::source::source_entity::SourceStatus::Unavailable),
+      reason: data.reason,
+    },
+  )
+  .await
+  {
+    Ok(response) => response,
+    Err(error) => error.into(),
+  }
+}
+
+async fn find_by_media(
+  app_state: actix_web::web::Data<AppState>,
+  path: actix_web::web::Path<shared::vo::UUID4>,
+  session: actix_session::Session,
+) -> impl actix_web::Responder {
+  let media_id = path.into_inner();
+
+  let parental_rating_limit =
+    match infra::actix::utils::get_viewer_parental_rating_limit(&app_state, &session).await {
+      Ok(limit) => limit,
+      Err(error) => return error.into(),
+    };
+
+  let mut source_repository = di::source::repositories::SourceRepository::new(&app_state);
+  let mut media_repository = di::media::repositories::MediaRepository::new(&app_state);
+
+  match domain::source::stories::find_by_media::execute(
+    &mut source_repository,
+    &mut media_repository,
+    &media_id,
+    parental_rating_limit,
+  )
+  .await
+  {
+    Ok(sources) => actix_web::HttpResponse::Ok().json(
+      sources
+        .into_iter()
+        .map(super::presenter::SourcePresenter::from)
+        .collect::<Vec<_>>(),
+    ),
+    Err(error) => infra::actix::errors::ErrorResponse::from(error).into(),
+  }
+}
+
 pub fn configure(config: &mut actix_web::web::ServiceConfig) {
   config
     .route(
@@ -69,7 +183,23 @@ pub fn configure(config: &mut actix_web::web::ServiceConfig) {
       actix_web::web::post().to(create_source),
     )
     .route(
-      "/contribute/source/{genre_id}",
+      "/contribute/source/{source_id}",
+      actix_web::web::patch().to(update_source),
+    )
+    .route(
+      "/contribute/source/{source_id}",
       actix_web::web::delete().to(delete_source),
+    )
+    .route(
+      "/contribute/source/{source_id}/restore",
+      actix_web::web::post().to(restore_source),
+    )
+    .route(
+      "/contribute/source/{source_id}/report",
+      actix_web::web::post().to(report_source),
+    )
+    .route(
+      "/medias/{media_id}/sources",
+      actix_web::web::get().to(find_by_media),
     );
 }
@@ -6,6 +6,12 @@ pub struct CreateSourceDTO {
   pub src: String,
   pub media_id: shared::vo::UUID4,
   pub source_type: domain::source::source_entity::SourceType,
+
+  #[validate(range(max = infra::actix::shared::MAX_INTEGER))]
+  pub start_offset: Option<u32>,
+
+  #[validate(range(max = infra::actix::shared::MAX_INTEGER))]
+  pub duration: Option<u32>,
 }
 impl From<CreateSourceDTO> for crate::domain::source::stories::contribute::CreateSourceInput {
   fn from(value: infra::actix::source::dto::CreateSourceDTO) -> Self {
@@ -13,23 +19,41 @@ impl From<CreateSourceDTO> for crate::domain::source::stories::contribute::Creat
       src: value.src,
       media_id: value.media_id,
       source_type: value.source_type,
+      start_offset: value.start_offset,
+      duration: value.duration,
     }
   }
 }
 
-#[derive(Debug, serde::Serialize)]
-pub struct SourcePresenter {
-  id: crate::shared::vo::UUID4,
-  source_type: domain::source::source_entity::SourceType,
-  src: String,
+#[derive(Debug, serde::Deserialize, validator::Validate)]
+pub struct UpdateSourceDTO {
+  #[validate(length(min = 1))]
+  pub src: Option<String>,
+  pub source_type: Option<domain::source::source_entity::SourceType>,
+
+  #[validate(range(max = infra::actix::shared::MAX_INTEGER))]
+  pub start_offset: Option<u32>,
+
+  #[validate(range(max = infra::actix::shared::MAX_INTEGER))]
+  pub duration: Option<u32>,
 }
 
-impl From<domain::source::source_entity::Source> for SourcePresenter {
-  fn from(value: domain::source::source_entity::Source) -> Self {
+impl From<UpdateSourceDTO> for domain::source::contribution::changes::Changes {
+  fn from(value: UpdateSourceDTO) -> Self {
     Self {
-      id: value.id().clone(),
-      source_type: value.source_type().clone(),
-      src: value.src().clone(),
+      source_type: value.source_type,
+      src: value.src,
+      external_id: None,
+      start_offset: value.start_offset,
+      duration: value.duration,
     }
   }
 }
+
+#[derive(Debug, serde::Deserialize, validator::Validate)]
+pub struct ReportSourceDTO {
+  pub status: Option<domain::source::source_entity::SourceStatus>,
+
+  #[validate(length(max = 512))]
+  pub reason: Option<String>,
+}
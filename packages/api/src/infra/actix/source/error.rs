@@ -4,6 +4,9 @@ impl From<crate::domain::source::stories::apply_changes::Error>
   fn from(value: crate::domain::source::stories::apply_changes::Error) -> Self {
     match value {
       crate::domain::source::stories::apply_changes::Error::RepositoryError(error) => error.into(),
+      crate::domain::source::stories::apply_changes::Error::SourceNotFound(id) => {
+        crate::infra::actix::errors::ErrorResponse::NotFound(format!("Source {} not found", id))
+      }
     }
   }
 }
@@ -32,6 +35,57 @@ impl From<crate::domain::source::stories::contribute::Error>
       crate::domain::source::stories::contribute::Error::SourceRepositoryError(error) => {
         error.into()
       }
+      crate::domain::source::stories::contribute::Error::InvalidSourceUrl(error) => {
+        crate::infra::actix::errors::ErrorResponse::BadRequest(error.to_string(), None)
+      }
+      crate::domain::source::stories::contribute::Error::ReportedStatusIsActive => {
+        crate::infra::actix::errors::ErrorResponse::BadRequest(
+          String::from("A report must describe a broken source"),
+          None,
+        )
+      }
+      crate::domain::source::stories::contribute::Error::DuplicatedSource(id) => {
+        crate::infra::actix::errors::ErrorResponse::Conflict(format!(
+          "Source already registered for this media: {}",
+          id
+        ))
+      }
+    }
+  }
+}
+
+impl From<crate::domain::source::stories::find_by_media::Error>
+  for crate::infra::actix::errors::ErrorResponse
+{
+  fn from(value: crate::domain::source::stories::find_by_media::Error) -> Self {
+    match value {
+      // A mídia restrita é tratada como inexistente para não revelar o conteúdo.
+      crate::domain::source::stories::find_by_media::Error::MediaNotFound
+      | crate::domain::source::stories::find_by_media::Error::ParentalRatingRestricted => {
+        crate::infra::actix::errors::ErrorResponse::NotFound(String::from("Media not found"))
+      }
+      crate::domain::source::stories::find_by_media::Error::MediaRepositoryError(error) => {
+        error.into()
+      }
+      crate::domain::source::stories::find_by_media::Error::SourceRepositoryError(error) => {
+        error.into()
+      }
+    }
+  }
+}
+
+impl From<crate::domain::source::stories::check::Error>
+  for crate::infra::actix::errors::ErrorResponse
+{
+  fn from(value: crate::domain::source::stories::check::Error) -> Self {
+    match value {
+      crate::domain::source::stories::check::Error::SourceNotFound => {
+        crate::infra::actix::errors::ErrorResponse::NotFound(String::from("Source not found"))
+      }
+      crate::domain::source::stories::check::Error::RepositoryError(error) => error.into(),
+      crate::domain::source::stories::check::Error::CheckerError(
+        crate::domain::source::checker::Error::CheckFailed(error),
+      ) => crate::infra::actix::errors::ErrorResponse::InternalServerError(error),
     }
   }
 }
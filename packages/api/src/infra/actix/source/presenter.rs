@@ -0,0 +1,34 @@
+use crate::*;
+
+#[derive(Debug, serde::Serialize)]
+pub struct SourcePresenter {
+  id: shared::vo::UUID4,
+  source_type: domain::source::source_entity::SourceType,
+  src: String,
+  external_id: Option<String>,
+  media_id: shared::vo::UUID4,
+  status: domain::source::source_entity::SourceStatus,
+  last_checked_at: Option<chrono::NaiveDateTime>,
+  start_offset: Option<u32>,
+  duration: Option<u32>,
+  created_at: chrono::NaiveDateTime,
+  updated_at: chrono::NaiveDateTime,
+}
+
+impl From<domain::source::source_entity::Source> for SourcePresenter {
+  fn from(value: domain::source::source_entity::Source) -> Self {
+    Self {
+      id: value.id().clone(),
+      source_type: value.source_type().clone(),
+      src: value.src().clone(),
+      external_id: value.external_id().clone(),
+      media_id: value.media_id().clone(),
+      status: value.status().clone(),
+      last_checked_at: *value.last_checked_at(),
+      start_offset: *value.start_offset(),
+      duration: *value.duration(),
+      created_at: *value.created_at(),
+      updated_at: *value.updated_at(),
+    }
+  }
+}
@@ -1,6 +1,6 @@
 use actix_session::Session;
 use actix_web::{
-  web::{get, post, Data, Json},
+  web::{get, post, put, Data, Json},
   HttpResponse, Responder,
 };
 
@@ -8,8 +8,8 @@ use serde_json::json;
 
 use crate::{
   di,
-  domain::user::stories::{user_login, user_register},
-  infra::actix::errors::ErrorResponse,
+  domain::user::stories::{update_preferences, user_login, user_register},
+  infra::actix::{errors::ErrorResponse, utils::get_actor},
   AppState,
 };
 
@@ -88,9 +88,43 @@ async fn get_me(session: Session) -> impl Responder {
   }
 }
 
+async fn get_preferences(
+  state: Data<AppState>,
+  session: Session,
+) -> Result<HttpResponse, ErrorResponse> {
+  let mut user_repository = di::user::repositories::UserRepository::new(&state);
+  let user = get_actor(&mut user_repository, &session).await?;
+
+  Ok(
+    HttpResponse::Ok().json(Into::<super::presenters::UserPreferencesPresenter>::into(
+      user,
+    )),
+  )
+}
+
+async fn update_preferences(
+  state: Data<AppState>,
+  Json(data): Json<super::dto::UserPreferencesInputDTO>,
+  session: Session,
+) -> Result<HttpResponse, ErrorResponse> {
+  let mut user_repository = di::user::repositories::UserRepository::new(&state);
+  let user = get_actor(&mut user_repository, &session).await?;
+
+  let user =
+    update_preferences::execute(&mut user_repository, data.into_input(user.id().clone())).await?;
+
+  Ok(
+    HttpResponse::Ok().json(Into::<super::presenters::UserPreferencesPresenter>::into(
+      user,
+    )),
+  )
+}
+
 pub fn configure(config: &mut actix_web::web::ServiceConfig) {
   config
     .route("login", post().to(user_login))
     .route("me", get().to(get_me))
+    .route("me/preferences", get().to(get_preferences))
+    .route("me/preferences", put().to(update_preferences))
     .route("register", post().to(register_user));
 }
@@ -1,4 +1,7 @@
-use crate::domain::user::stories::{user_login, user_register};
+use crate::{
+  domain::user::stories::{update_preferences, user_login, user_register},
+  shared::vo::UUID4,
+};
 
 #[derive(Debug, Clone, serde::Deserialize, validator::Validate)]
 pub struct UserRegisterInputDTO {
@@ -24,6 +27,22 @@ pub struct UserLoginInputDTO {
   pub password: String,
 }
 
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct UserPreferencesInputDTO {
+  pub max_parental_rating: Option<u8>,
+  pub birth_date: Option<chrono::NaiveDate>,
+}
+
+impl UserPreferencesInputDTO {
+  pub fn into_input(self, user_id: UUID4) -> update_preferences::Input {
+    update_preferences::Input {
+      user_id,
+      max_parental_rating: self.max_parental_rating,
+      birth_date: self.birth_date,
+    }
+  }
+}
+
 impl From<UserRegisterInputDTO> for user_register::Input {
   fn from(value: UserRegisterInputDTO) -> Self {
     Self {
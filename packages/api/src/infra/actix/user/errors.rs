@@ -1,5 +1,5 @@
 use crate::{
-  domain::user::stories::{user_login, user_register},
+  domain::user::stories::{promote, update_preferences, user_login, user_register},
   infra::actix::errors::ErrorResponse,
 };
 
@@ -36,7 +36,7 @@ impl From<user_register::CreateUserStoryError> for ErrorResponse {
               (
                 field.to_string(),
                 errors
-                  .into_iter()
+                  .iter()
                   .filter_map(|error| error.message.clone().map(String::from))
                   .collect(),
               )
@@ -53,3 +53,71 @@ impl From<user_register::CreateUserStoryError> for ErrorResponse {
     }
   }
 }
+
+impl From<update_preferences::UpdatePreferencesError> for ErrorResponse {
+  fn from(value: update_preferences::UpdatePreferencesError) -> Self {
+    match value {
+      update_preferences::UpdatePreferencesError::UserNotFound => {
+        ErrorResponse::Forbidden(String::from("user not authenticated"))
+      }
+
+      update_preferences::UpdatePreferencesError::BirthDateInFuture => ErrorResponse::BadRequest(
+        String::from("Invalid input"),
+        Some(
+          [(
+            String::from("birth_date"),
+            vec![String::from("birth date cannot be in the future")],
+          )]
+          .into(),
+        ),
+      ),
+
+      update_preferences::UpdatePreferencesError::InvalidInput(error) => ErrorResponse::BadRequest(
+        String::from("Invalid input"),
+        Some(
+          error
+            .field_errors()
+            .into_iter()
+            .map(|(field, errors)| {
+              (
+                field.to_string(),
+                errors
+                  .iter()
+                  .map(|error| {
+                    error
+                      .message
+                      .clone()
+                      .map(String::from)
+                      .unwrap_or(error.code.to_string())
+                  })
+                  .collect(),
+              )
+            })
+            .collect(),
+        ),
+      ),
+
+      update_preferences::UpdatePreferencesError::RepositoryError(error) => {
+        log::error!("Failed to update preferences: {:?}", error);
+
+        ErrorResponse::InternalServerError(String::from("Failed to update preferences"))
+      }
+    }
+  }
+}
+
+impl From<promote::PromoteError> for ErrorResponse {
+  fn from(value: promote::PromoteError) -> Self {
+    match value {
+      promote::PromoteError::UserNotFound => {
+        ErrorResponse::NotFound(String::from("User not found"))
+      }
+
+      promote::PromoteError::RepositoryError(error) => {
+        log::error!("Failed to promote user: {:?}", error);
+
+        ErrorResponse::InternalServerError(String::from("Failed to promote user"))
+      }
+    }
+  }
+}
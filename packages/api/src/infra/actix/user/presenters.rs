@@ -5,6 +5,7 @@ pub struct PublicUserPresenter {
   pub id: String,
   pub username: String,
   pub is_curator: bool,
+  pub is_admin: bool,
   pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
@@ -14,11 +15,33 @@ impl From<crate::domain::user::User> for PublicUserPresenter {
       id: user.id().to_string(),
       username: user.username().clone(),
       is_curator: *user.is_curator(),
+      is_admin: *user.is_admin(),
       created_at: (*user.created_at()).and_utc(),
     }
   }
 }
 
+#[derive(Debug, serde::Serialize)]
+pub struct UserPreferencesPresenter {
+  pub max_parental_rating: Option<u8>,
+  pub birth_date: Option<chrono::NaiveDate>,
+
+  /**
+   * Classificação indicativa efetivamente aplicada, considerando a idade.
+   */
+  pub parental_rating_limit: u8,
+}
+
+impl From<User> for UserPreferencesPresenter {
+  fn from(user: User) -> Self {
+    UserPreferencesPresenter {
+      max_parental_rating: *user.max_parental_rating(),
+      birth_date: *user.birth_date(),
+      parental_rating_limit: user.parental_rating_limit(crate::shared::util::naive_now().date()),
+    }
+  }
+}
+
 impl From<crate::shared::paged::Paged<User>> for crate::shared::paged::Paged<PublicUserPresenter> {
   fn from(value: crate::shared::paged::Paged<User>) -> Self {
     Self {
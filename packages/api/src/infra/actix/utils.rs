@@ -2,7 +2,7 @@ use actix_session::Session;
 
 use crate::{
   domain::user::{User, UserRepository},
-  shared::vo::UUID4,
+  shared::{util::naive_now, vo::UUID4},
 };
 
 use super::errors::ErrorResponse;
@@ -43,3 +43,37 @@ pub async fn get_actor(
     ))),
   }
 }
+
+/**
+ * Classificação indicativa máxima que quem fez a requisição pode ver.
+ * Visitantes não autenticados recebem o limite padrão configurado.
+ */
+pub async fn get_parental_rating_limit(
+  user_repository: &mut impl UserRepository,
+  session: &Session,
+  anonymous_limit: u8,
+) -> Result<u8, ErrorResponse> {
+  if get_actor_id(session).is_err() {
+    return Ok(anonymous_limit);
+  }
+
+  match get_actor(user_repository, session).await {
+    Ok(user) => Ok(user.parental_rating_limit(naive_now().date())),
+    Err(ErrorResponse::Forbidden(_)) => Ok(anonymous_limit),
+    Err(error) => Err(error),
+  }
+}
+
+pub async fn get_viewer_parental_rating_limit(
+  app_state: &crate::AppState,
+  session: &Session,
+) -> Result<u8, ErrorResponse> {
+  let mut user_repository = crate::di::user::repositories::UserRepository::new(app_state);
+
+  get_parental_rating_limit(
+    &mut user_repository,
+    session,
+    app_state.anonymous_max_parental_rating,
+  )
+  .await
+}
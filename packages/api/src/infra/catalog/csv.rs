@@ -0,0 +1,56 @@
+/**
+ * Células de uma linha, acompanhadas do número da linha do arquivo em que ela começa.
+ */
+pub type Row = (usize, Vec<String>);
+
+/**
+ * Separa o conteúdo CSV em linhas e células, seguindo a RFC 4180: células entre
+ * aspas podem conter vírgulas, quebras de linha e aspas duplicadas (`""`).
+ */
+pub fn parse(content: &str) -> Result<Vec<Row>, super::LineError> {
+  let mut rows = Vec::new();
+  let mut cells = Vec::new();
+  let mut cell = String::new();
+
+  let mut line = 1;
+  let mut row_line = 1;
+  let mut quoted = false;
+  let mut chars = content.chars().peekable();
+
+  while let Some(char) = chars.next() {
+    match (quoted, char) {
+      (true, '"') if chars.peek() == Some(&'"') => {
+        chars.next();
+        cell.push('"');
+      }
+      (true, '"') => quoted = false,
+      (true, char) => {
+        if char == '\n' {
+          line += 1;
+        }
+        cell.push(char);
+      }
+      (false, '"') if cell.is_empty() => quoted = true,
+      (false, ',') => cells.push(std::mem::take(&mut cell)),
+      (false, '\r') if chars.peek() == Some(&'\n') => {}
+      (false, '\n') => {
+        cells.push(std::mem::take(&mut cell));
+        rows.push((row_line, std::mem::take(&mut cells)));
+        line += 1;
+        row_line = line;
+      }
+      (false, char) => cell.push(char),
+    }
+  }
+
+  if quoted {
+    return Err((row_line, String::from("Unterminated quoted field")));
+  }
+
+  if !cell.is_empty() || !cells.is_empty() {
+    cells.push(cell);
+    rows.push((row_line, cells));
+  }
+
+  Ok(rows)
+}
@@ -0,0 +1,281 @@
+mod csv;
+pub mod musicbrainz;
+
+use crate::domain::catalog::ImportRecord;
+
+/**
+ * Formatos aceitos pela importação do catálogo.
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+  /**
+   * Um objeto JSON por linha, com o tipo do registro no campo `type`.
+   */
+  JsonLines,
+
+  /**
+   * Uma linha de cabeçalho com os nomes dos campos, incluindo `type`. Listas são
+   * separadas por `;` dentro da célula e células vazias são ignoradas.
+   */
+  Csv,
+}
+
+impl std::str::FromStr for Format {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "jsonl" => Ok(Self::JsonLines),
+      "csv" => Ok(Self::Csv),
+      _ => Err(format!("Unknown import format: {}", s)),
+    }
+  }
+}
+
+impl Format {
+  /**
+   * Deduz o formato pela extensão do arquivo.
+   */
+  pub fn from_path(path: &str) -> Option<Self> {
+    match std::path::Path::new(path)
+      .extension()?
+      .to_str()?
+      .to_lowercase()
+      .as_str()
+    {
+      "jsonl" | "ndjson" => Some(Self::JsonLines),
+      "csv" => Some(Self::Csv),
+      _ => None,
+    }
+  }
+}
+
+/**
+ * Quantidade de registros lidos do banco por vez durante a exportação.
+ */
+pub const EXPORT_BATCH_SIZE: usize = 500;
+
+/**
+ * Formato dos momentos aceitos e informados pela exportação incremental.
+ */
+pub const SINCE_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+/**
+ * Erro encontrado em uma linha do arquivo.
+ */
+pub type LineError = (usize, String);
+
+/**
+ * Linha lida do arquivo, ainda não convertida em registro.
+ */
+type ParsedLine = (usize, Result<serde_json::Value, String>);
+
+/**
+ * Campos que contêm listas de referências.
+ */
+const LIST_FIELDS: [&str; 6] = [
+  "artists",
+  "interpreters",
+  "composers",
+  "genres",
+  "albums",
+  "aliases",
+];
+
+const NUMBER_FIELDS: [&str; 4] = ["parental_rating", "duration", "bpm", "start_offset"];
+
+const BOOLEAN_FIELDS: [&str; 1] = ["is_single"];
+
+/**
+ * Lê os registros do conteúdo informado, acompanhados da linha em que começam.
+ * Retorna todos os erros encontrados caso algum registro seja inválido.
+ */
+pub fn parse(format: Format, content: &str) -> Result<Vec<(usize, ImportRecord)>, Vec<LineError>> {
+  let rows = match format {
+    Format::JsonLines => parse_json_lines(content),
+    Format::Csv => parse_csv(content)?,
+  };
+
+  let mut records = Vec::new();
+  let mut errors = Vec::new();
+
+  for (line, row) in rows {
+    match row.and_then(|value| serde_json::from_value(value).map_err(|error| error.to_string())) {
+      Ok(record) => records.push((line, record)),
+      Err(error) => errors.push((line, error)),
+    }
+  }
+
+  if errors.is_empty() {
+    Ok(records)
+  } else {
+    Err(errors)
+  }
+}
+
+/**
+ * Serializa os registros no formato JSON Lines, um por linha.
+ */
+pub fn to_json_lines(records: &[ImportRecord]) -> String {
+  records
+    .iter()
+    .filter_map(|record| serde_json::to_string(record).ok())
+    .map(|line| line + "\n")
+    .collect()
+}
+
+fn parse_json_lines(content: &str) -> Vec<ParsedLine> {
+  content
+    .lines()
+    .enumerate()
+    .filter(|(_, line)| !line.trim().is_empty())
+    .map(|(index, line)| {
+      (
+        index + 1,
+        serde_json::from_str(line).map_err(|error| error.to_string()),
+      )
+    })
+    .collect()
+}
+
+fn parse_csv(content: &str) -> Result<Vec<ParsedLine>, Vec<LineError>> {
+  let mut rows = csv::parse(content)
+    .map_err(|error| vec![error])?
+    .into_iter();
+
+  let Some((_, header)) = rows.next() else {
+    return Ok(Vec::new());
+  };
+
+  Ok(
+    rows
+      .filter(|(_, cells)| cells.iter().any(|cell| !cell.trim().is_empty()))
+      .map(|(line, cells)| {
+        if cells.len() != header.len() {
+          return (
+            line,
+            Err(format!(
+              "Expected {} columns, found {}",
+              header.len(),
+              cells.len()
+            )),
+          );
+        }
+
+        let fields = header
+          .iter()
+          .zip(cells)
+          .filter(|(_, cell)| !cell.trim().is_empty())
+          .map(|(field, cell)| (field.trim().to_string(), csv_value(field.trim(), cell)))
+          .collect::<serde_json::Map<_, _>>();
+
+        (line, Ok(serde_json::Value::Object(fields)))
+      })
+      .collect(),
+  )
+}
+
+/**
+ * Converte a célula no tipo esperado pelo campo. Valores que não puderem ser
+ * convertidos são mantidos como texto para que a desserialização aponte o erro.
+ */
+fn csv_value(field: &str, cell: String) -> serde_json::Value {
+  if LIST_FIELDS.contains(&field) {
+    return serde_json::Value::Array(
+      cell
+        .split(';')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(|item| serde_json::Value::String(item.to_string()))
+        .collect(),
+    );
+  }
+
+  if NUMBER_FIELDS.contains(&field) {
+    if let Ok(number) = cell.trim().parse::<u64>() {
+      return serde_json::Value::from(number);
+    }
+  }
+
+  if BOOLEAN_FIELDS.contains(&field) {
+    if let Ok(boolean) = cell.trim().parse::<bool>() {
+      return serde_json::Value::Bool(boolean);
+    }
+  }
+
+  serde_json::Value::String(cell)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::domain::catalog::ImportRecord;
+
+  #[test]
+  fn test_parse_csv_records() {
+    let content =
+      "type,name,album_type,artists,parental_rating,interpreters,media_type,is_single\n\
+      album,\"Tempo, Tempo\",Album,legiao-urbana;renato-russo,12,,,\n\
+      media,Tempo Perdido,,,,legiao-urbana,Song,true\n";
+
+    let records =
+      super::parse(super::Format::Csv, content).expect("Falha ao ler os registros em CSV");
+
+    assert_eq!(records.len(), 2, "Todos os registros devem ser lidos");
+
+    let (line, ImportRecord::Album(album)) = &records[0] else {
+      panic!("O primeiro registro deve ser um álbum");
+    };
+    assert_eq!(*line, 2);
+    assert_eq!(album.name, "Tempo, Tempo");
+    assert_eq!(album.artists, vec!["legiao-urbana", "renato-russo"]);
+    assert_eq!(album.parental_rating, Some(12));
+
+    let ImportRecord::Media(media) = &records[1].1 else {
+      panic!("O segundo registro deve ser uma media");
+    };
+    assert_eq!(media.interpreters, vec!["legiao-urbana"]);
+    assert_eq!(media.is_single, Some(true));
+    assert_eq!(media.genres, None, "Células vazias devem ser ignoradas");
+  }
+
+  #[test]
+  fn test_json_lines_round_trip() {
+    let content = "{\"type\": \"genre\", \"name\": \"Rock\", \"slug\": \"rock\"}\n\
+      {\"type\": \"source\", \"media\": \"legiao-urbana/tempo-perdido\", \"source_type\": \"Youtube\", \"src\": \"https://youtu.be/ZnA4xA3bJfM\"}\n";
+
+    let records = super::parse(super::Format::JsonLines, content)
+      .expect("Falha ao ler os registros em JSON Lines")
+      .into_iter()
+      .map(|(_, record)| record)
+      .collect::<Vec<_>>();
+
+    let exported = super::to_json_lines(&records);
+    let reimported = super::parse(super::Format::JsonLines, &exported)
+      .expect("Falha ao ler os registros exportados")
+      .into_iter()
+      .map(|(_, record)| record)
+      .collect::<Vec<_>>();
+
+    assert_eq!(
+      reimported, records,
+      "Os registros exportados devem ser importados sem alterações"
+    );
+  }
+
+  #[test]
+  fn test_parse_reports_every_invalid_line() {
+    let content = "{\"type\": \"genre\", \"name\": \"Rock\"}\n\
+      \n\
+      {\"type\": \"planet\", \"name\": \"Plutão\"}\n\
+      {\"type\": \"artist\"}\n";
+
+    let errors = super::parse(super::Format::JsonLines, content)
+      .expect_err("Registros inválidos devem ser rejeitados");
+
+    assert_eq!(
+      errors.iter().map(|(line, _)| *line).collect::<Vec<_>>(),
+      vec![3, 4],
+      "Os erros devem indicar as linhas inválidas"
+    );
+  }
+}
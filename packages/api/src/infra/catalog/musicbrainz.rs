@@ -0,0 +1,698 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::{
+  domain::{
+    album::AlbumType,
+    artist::ArtistType,
+    catalog::{AlbumRecord, ArtistRecord, ImportRecord, MediaRecord},
+    media::MediaType,
+  },
+  shared::vo::{Barcode, Isrc},
+};
+
+use super::LineError;
+
+/**
+ * Origem dos identificadores vinculados pela importação do MusicBrainz.
+ */
+pub const ORIGIN: &str = "musicbrainz";
+
+/**
+ * Arquivos lidos do dump, com ou sem as extensões `.json` e `.jsonl`.
+ */
+pub const DUMP_FILES: [&str; 5] = ["artist", "release-group", "release", "recording", "work"];
+
+/**
+ * Tipos de relacionamento entre obra e artista tratados como composição.
+ */
+const COMPOSER_RELATIONS: [&str; 2] = ["composer", "writer"];
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct Artist {
+  id: String,
+  name: String,
+  #[serde(rename = "type")]
+  artist_type: Option<String>,
+  country: Option<String>,
+  #[serde(default)]
+  aliases: Vec<Alias>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct Alias {
+  name: String,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct ArtistCredit {
+  artist: Artist,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct ReleaseGroup {
+  id: String,
+  title: String,
+  primary_type: Option<String>,
+  #[serde(default)]
+  secondary_types: Vec<String>,
+  first_release_date: Option<String>,
+  #[serde(default)]
+  artist_credit: Vec<ArtistCredit>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct Release {
+  status: Option<String>,
+  date: Option<String>,
+  country: Option<String>,
+  barcode: Option<String>,
+  #[serde(default)]
+  label_info: Vec<LabelInfo>,
+  release_group: Option<ReleaseGroup>,
+  #[serde(default)]
+  artist_credit: Vec<ArtistCredit>,
+  #[serde(default)]
+  media: Vec<Medium>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct LabelInfo {
+  catalog_number: Option<String>,
+  label: Option<Label>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct Label {
+  name: String,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct Medium {
+  #[serde(default)]
+  tracks: Vec<Track>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct Track {
+  recording: Option<Reference>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct Reference {
+  id: String,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct Recording {
+  id: String,
+  title: String,
+
+  /**
+   * Duração em milissegundos.
+   */
+  length: Option<u64>,
+  #[serde(default)]
+  isrcs: Vec<String>,
+  first_release_date: Option<String>,
+  #[serde(default)]
+  artist_credit: Vec<ArtistCredit>,
+  #[serde(default)]
+  relations: Vec<Relation>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct Work {
+  id: String,
+  #[serde(default)]
+  relations: Vec<Relation>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct Relation {
+  #[serde(rename = "type")]
+  relation_type: String,
+  artist: Option<Artist>,
+  work: Option<Work>,
+}
+
+/**
+ * Entidades lidas de um dump JSON do MusicBrainz, em que cada linha de cada arquivo
+ * é uma entidade no formato da API do MusicBrainz.
+ */
+#[derive(Debug, Default)]
+pub struct Dump {
+  artists: Vec<Artist>,
+  release_groups: Vec<ReleaseGroup>,
+  releases: Vec<Release>,
+  recordings: Vec<Recording>,
+  works: Vec<Work>,
+}
+
+/**
+ * Relações entre as entidades do dump, indexadas uma única vez para a conversão.
+ */
+struct Index<'a> {
+  works: HashMap<&'a str, &'a Work>,
+
+  /**
+   * Lançamentos de cada release group, na ordem do dump.
+   */
+  releases: HashMap<&'a str, Vec<&'a Release>>,
+
+  /**
+   * Release groups cujos lançamentos contêm cada gravação, sem repetições.
+   */
+  release_groups: HashMap<&'a str, Vec<&'a str>>,
+}
+
+/**
+ * Lê o arquivo linha a linha, sem carregá-lo inteiro na memória.
+ */
+fn parse_lines<T: serde::de::DeserializeOwned>(
+  content: impl std::io::BufRead,
+) -> Result<Vec<T>, Vec<LineError>> {
+  let mut entities = Vec::new();
+  let mut errors = Vec::new();
+
+  for (index, line) in content.lines().enumerate() {
+    let line = match line {
+      Ok(line) => line,
+      Err(error) => {
+        errors.push((index + 1, error.to_string()));
+        break;
+      }
+    };
+
+    if line.trim().is_empty() {
+      continue;
+    }
+
+    match serde_json::from_str(&line) {
+      Ok(entity) => entities.push(entity),
+      Err(error) => errors.push((index + 1, error.to_string())),
+    }
+  }
+
+  if errors.is_empty() {
+    Ok(entities)
+  } else {
+    Err(errors)
+  }
+}
+
+/**
+ * Datas parciais, como apenas o ano, não são importadas.
+ */
+fn full_date(date: &Option<String>) -> Option<chrono::NaiveDate> {
+  chrono::NaiveDate::parse_from_str(date.as_deref()?, "%Y-%m-%d").ok()
+}
+
+fn artist_ids(credits: &[ArtistCredit]) -> Vec<String> {
+  let mut ids = Vec::new();
+
+  for credit in credits {
+    if !ids.contains(&credit.artist.id) {
+      ids.push(credit.artist.id.clone());
+    }
+  }
+
+  ids
+}
+
+fn non_empty<T>(values: Vec<T>) -> Option<Vec<T>> {
+  if values.is_empty() {
+    None
+  } else {
+    Some(values)
+  }
+}
+
+impl Dump {
+  /**
+   * Lê um dos arquivos do dump, identificado pelo nome da entidade.
+   */
+  pub fn read(&mut self, file: &str, content: impl std::io::BufRead) -> Result<(), Vec<LineError>> {
+    match file {
+      "artist" => self.artists.extend(parse_lines(content)?),
+      "release-group" => self.release_groups.extend(parse_lines(content)?),
+      "release" => self.releases.extend(parse_lines(content)?),
+      "recording" => self.recordings.extend(parse_lines(content)?),
+      "work" => self.works.extend(parse_lines(content)?),
+      _ => {
+        return Err(vec![(
+          0,
+          format!("Unknown MusicBrainz dump file: {}", file),
+        )])
+      }
+    }
+
+    Ok(())
+  }
+
+  /**
+   * Lê os arquivos do dump encontrados na pasta. Os erros são informados com o
+   * caminho e a linha do arquivo.
+   */
+  pub fn read_dir(path: &std::path::Path) -> Result<Self, Vec<String>> {
+    let mut dump = Self::default();
+    let mut errors = Vec::new();
+    let mut found = false;
+
+    for file in DUMP_FILES {
+      let Some(file_path) = ["", ".json", ".jsonl"]
+        .iter()
+        .map(|extension| path.join(format!("{}{}", file, extension)))
+        .find(|file_path| file_path.is_file())
+      else {
+        continue;
+      };
+
+      found = true;
+
+      let result = std::fs::File::open(&file_path)
+        .map_err(|error| vec![(0, error.to_string())])
+        .and_then(|content| dump.read(file, std::io::BufReader::new(content)));
+
+      if let Err(line_errors) = result {
+        errors.extend(
+          line_errors
+            .into_iter()
+            .map(|(line, error)| format!("{}:{}: {}", file_path.display(), line, error)),
+        );
+      }
+    }
+
+    if !found {
+      errors.push(format!(
+        "{}: none of the MusicBrainz dump files ({}) was found",
+        path.display(),
+        DUMP_FILES.join(", ")
+      ));
+    }
+
+    if errors.is_empty() {
+      Ok(dump)
+    } else {
+      Err(errors)
+    }
+  }
+
+  /**
+   * Converte o dump em registros de importação, com o MBID como `external_id` e as
+   * referências feitas pelo MBID.
+   *
+   * Cada release group vira um álbum, completado pelos dados do primeiro lançamento
+   * oficial: selo, número de catálogo, código de barras e país. Cada gravação vira
+   * uma media, interpretada pelos artistas creditados, composta pelos compositores
+   * das obras que ela interpreta e presente nos álbuns cujos lançamentos a contêm.
+   * Artistas que aparecem apenas em créditos são importados com o que o crédito
+   * informa.
+   */
+  pub fn records(&self) -> Vec<ImportRecord> {
+    let index = self.index();
+
+    let mut release_groups = self.release_groups.iter().collect::<Vec<_>>();
+    let mut known_release_groups = release_groups
+      .iter()
+      .map(|release_group| release_group.id.as_str())
+      .collect::<HashSet<_>>();
+
+    for release_group in self
+      .releases
+      .iter()
+      .filter_map(|release| release.release_group.as_ref())
+    {
+      if known_release_groups.insert(&release_group.id) {
+        release_groups.push(release_group);
+      }
+    }
+
+    let mut artists = self.artists.iter().collect::<Vec<_>>();
+    let mut known_artists = artists
+      .iter()
+      .map(|artist| artist.id.as_str())
+      .collect::<HashSet<_>>();
+
+    let credits = release_groups
+      .iter()
+      .flat_map(|release_group| &release_group.artist_credit)
+      .chain(
+        self
+          .releases
+          .iter()
+          .flat_map(|release| &release.artist_credit),
+      )
+      .chain(
+        self
+          .recordings
+          .iter()
+          .flat_map(|recording| &recording.artist_credit),
+      )
+      .map(|credit| &credit.artist);
+
+    let composers = self
+      .recordings
+      .iter()
+      .flat_map(|recording| self.composers(&index.works, recording));
+
+    for artist in credits.chain(composers) {
+      if known_artists.insert(&artist.id) {
+        artists.push(artist);
+      }
+    }
+
+    let mut records = artists
+      .iter()
+      .map(|artist| ImportRecord::Artist(artist_record(artist)))
+      .collect::<Vec<_>>();
+
+    records.extend(
+      release_groups
+        .iter()
+        .map(|release_group| ImportRecord::Album(self.album_record(&index, release_group))),
+    );
+
+    records.extend(
+      self
+        .recordings
+        .iter()
+        .map(|recording| ImportRecord::Media(self.media_record(&index, recording))),
+    );
+
+    records
+  }
+
+  fn index(&self) -> Index<'_> {
+    let mut releases = HashMap::<_, Vec<_>>::new();
+    let mut release_groups = HashMap::<_, Vec<_>>::new();
+    let mut known = HashSet::new();
+
+    for release in &self.releases {
+      let Some(release_group) = &release.release_group else {
+        continue;
+      };
+
+      releases
+        .entry(release_group.id.as_str())
+        .or_default()
+        .push(release);
+
+      let recordings = release
+        .media
+        .iter()
+        .flat_map(|medium| &medium.tracks)
+        .filter_map(|track| track.recording.as_ref());
+
+      for recording in recordings {
+        if known.insert((recording.id.as_str(), release_group.id.as_str())) {
+          release_groups
+            .entry(recording.id.as_str())
+            .or_default()
+            .push(release_group.id.as_str());
+        }
+      }
+    }
+
+    Index {
+      works: self
+        .works
+        .iter()
+        .map(|work| (work.id.as_str(), work))
+        .collect(),
+      releases,
+      release_groups,
+    }
+  }
+
+  /**
+   * Compositores das obras interpretadas pela gravação, buscadas no arquivo de obras
+   * ou, na falta dele, nos próprios relacionamentos da gravação.
+   */
+  fn composers<'a>(
+    &'a self,
+    works: &HashMap<&str, &'a Work>,
+    recording: &'a Recording,
+  ) -> Vec<&'a Artist> {
+    recording
+      .relations
+      .iter()
+      .filter(|relation| relation.relation_type == "performance")
+      .filter_map(|relation| relation.work.as_ref())
+      .flat_map(|work| {
+        &works
+          .get(work.id.as_str())
+          .copied()
+          .unwrap_or(work)
+          .relations
+      })
+      .filter(|relation| COMPOSER_RELATIONS.contains(&relation.relation_type.as_str()))
+      .filter_map(|relation| relation.artist.as_ref())
+      .collect()
+  }
+
+  fn album_record(&self, index: &Index, release_group: &ReleaseGroup) -> AlbumRecord {
+    let mut releases = index
+      .releases
+      .get(release_group.id.as_str())
+      .cloned()
+      .unwrap_or_default();
+    releases.sort_by_key(|release| {
+      (
+        release.status.as_deref() != Some("Official"),
+        full_date(&release.date).is_none(),
+        full_date(&release.date),
+      )
+    });
+    let release = releases.first();
+
+    let mut artists = artist_ids(&release_group.artist_credit);
+    if artists.is_empty() {
+      artists = release
+        .map(|release| artist_ids(&release.artist_credit))
+        .unwrap_or_default();
+    }
+
+    let label_info = release
+      .map(|release| release.label_info.as_slice())
+      .unwrap_or_default();
+
+    let album_type = match release_group.primary_type.as_deref() {
+      _ if release_group
+        .secondary_types
+        .iter()
+        .any(|secondary_type| secondary_type == "Compilation") =>
+      {
+        AlbumType::Compilation
+      }
+      Some("Single") => AlbumType::Single,
+      Some("EP") => AlbumType::EP,
+      _ => AlbumType::Album,
+    };
+
+    AlbumRecord {
+      external_id: Some(release_group.id.clone()),
+      name: release_group.title.clone(),
+      slug: None,
+      album_type,
+      artists,
+      cover: None,
+      release_date: full_date(&release_group.first_release_date)
+        .or_else(|| release.and_then(|release| full_date(&release.date))),
+      parental_rating: None,
+      label: label_info
+        .iter()
+        .find_map(|label_info| Some(label_info.label.as_ref()?.name.clone())),
+      catalog_number: label_info
+        .iter()
+        .find_map(|label_info| label_info.catalog_number.clone()),
+      barcode: release
+        .and_then(|release| release.barcode.clone())
+        .and_then(|barcode| Barcode::new(barcode).ok()),
+      release_country: release.and_then(|release| release.country.clone()),
+      edition: None,
+      release_group: None,
+    }
+  }
+
+  fn media_record(&self, index: &Index, recording: &Recording) -> MediaRecord {
+    let mut composers = Vec::new();
+    for composer in self.composers(&index.works, recording) {
+      if !composers.contains(&composer.id) {
+        composers.push(composer.id.clone());
+      }
+    }
+
+    let albums = index
+      .release_groups
+      .get(recording.id.as_str())
+      .map(|release_groups| release_groups.iter().map(|id| id.to_string()).collect())
+      .unwrap_or_default();
+
+    MediaRecord {
+      external_id: Some(recording.id.clone()),
+      name: recording.title.clone(),
+      slug: None,
+      media_type: MediaType::Song,
+      interpreters: artist_ids(&recording.artist_credit),
+      composers: non_empty(composers),
+      genres: None,
+      albums: non_empty(albums),
+      release_date: full_date(&recording.first_release_date),
+      cover: None,
+      parental_rating: None,
+      duration: recording
+        .length
+        .and_then(|length| u32::try_from(length).ok()),
+      isrc: recording
+        .isrcs
+        .iter()
+        .find_map(|isrc| Isrc::new(isrc.clone()).ok()),
+      bpm: None,
+      key: None,
+      language: None,
+      is_single: None,
+      credits: None,
+    }
+  }
+}
+
+fn artist_record(artist: &Artist) -> ArtistRecord {
+  let aliases = artist
+    .aliases
+    .iter()
+    .map(|alias| alias.name.clone())
+    .filter(|alias| alias != &artist.name)
+    .collect::<BTreeSet<_>>();
+
+  ArtistRecord {
+    external_id: Some(artist.id.clone()),
+    name: artist.name.clone(),
+    slug: None,
+    country: artist.country.clone(),
+    artist_type: match artist.artist_type.as_deref() {
+      Some("Person" | "Character") => Some(ArtistType::Person),
+      Some("Group" | "Orchestra" | "Choir") => Some(ArtistType::Group),
+      _ => None,
+    },
+    aliases: (!aliases.is_empty()).then_some(aliases),
+    members: None,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const ARTISTS: &str = r#"{"id": "a-legiao", "name": "Legião Urbana", "type": "Group", "country": "BR", "aliases": [{"name": "Legiao Urbana"}, {"name": "Legião Urbana"}]}"#;
+
+  const RELEASE_GROUPS: &str = r#"{"id": "rg-dois", "title": "Dois", "primary-type": "Album", "secondary-types": [], "first-release-date": "1986-07-01", "artist-credit": [{"name": "Legião Urbana", "joinphrase": "", "artist": {"id": "a-legiao", "name": "Legião Urbana"}}]}"#;
+
+  const RELEASES: &str = r#"{"id": "r-dois-cd", "title": "Dois", "status": "Official", "date": "1990", "country": "BR", "barcode": "", "label-info": [], "release-group": {"id": "rg-dois", "title": "Dois"}, "media": [{"tracks": [{"recording": {"id": "rec-tempo"}}]}]}
+{"id": "r-dois-lp", "title": "Dois", "status": "Official", "date": "1986-07-01", "country": "BR", "barcode": null, "label-info": [{"catalog-number": "064 422855 1", "label": {"name": "EMI-Odeon"}}], "release-group": {"id": "rg-dois", "title": "Dois"}, "media": [{"tracks": [{"recording": {"id": "rec-tempo"}}, {"recording": {"id": "rec-outra"}}]}]}"#;
+
+  const RECORDINGS: &str = r#"{"id": "rec-tempo", "title": "Tempo Perdido", "length": 301466, "isrcs": ["BRBMG8600012"], "first-release-date": "1986-07-01", "artist-credit": [{"artist": {"id": "a-legiao", "name": "Legião Urbana"}}], "relations": [{"type": "performance", "target-type": "work", "work": {"id": "w-tempo"}}]}"#;
+
+  const WORKS: &str = r#"{"id": "w-tempo", "title": "Tempo Perdido", "relations": [{"type": "writer", "target-type": "artist", "artist": {"id": "a-renato", "name": "Renato Russo", "type": "Person"}}, {"type": "arranger", "target-type": "artist", "artist": {"id": "a-outro", "name": "Outro"}}]}"#;
+
+  #[test]
+  fn test_records() {
+    let mut dump = Dump::default();
+
+    for (file, content) in [
+      ("artist", ARTISTS),
+      ("release-group", RELEASE_GROUPS),
+      ("release", RELEASES),
+      ("recording", RECORDINGS),
+      ("work", WORKS),
+    ] {
+      dump
+        .read(file, content.as_bytes())
+        .expect("Falha ao ler o arquivo do dump");
+    }
+
+    let records = dump.records();
+
+    assert_eq!(
+      records,
+      vec![
+        ImportRecord::Artist(ArtistRecord {
+          external_id: Some(String::from("a-legiao")),
+          name: String::from("Legião Urbana"),
+          slug: None,
+          country: Some(String::from("BR")),
+          artist_type: Some(ArtistType::Group),
+          aliases: Some(BTreeSet::from([String::from("Legiao Urbana")])),
+          members: None,
+        }),
+        ImportRecord::Artist(ArtistRecord {
+          external_id: Some(String::from("a-renato")),
+          name: String::from("Renato Russo"),
+          slug: None,
+          country: None,
+          artist_type: Some(ArtistType::Person),
+          aliases: None,
+          members: None,
+        }),
+        ImportRecord::Album(AlbumRecord {
+          external_id: Some(String::from("rg-dois")),
+          name: String::from("Dois"),
+          slug: None,
+          album_type: AlbumType::Album,
+          artists: vec![String::from("a-legiao")],
+          cover: None,
+          release_date: chrono::NaiveDate::from_ymd_opt(1986, 7, 1),
+          parental_rating: None,
+          label: Some(String::from("EMI-Odeon")),
+          catalog_number: Some(String::from("064 422855 1")),
+          barcode: None,
+          release_country: Some(String::from("BR")),
+          edition: None,
+          release_group: None,
+        }),
+        ImportRecord::Media(MediaRecord {
+          external_id: Some(String::from("rec-tempo")),
+          name: String::from("Tempo Perdido"),
+          slug: None,
+          media_type: MediaType::Song,
+          interpreters: vec![String::from("a-legiao")],
+          composers: Some(vec![String::from("a-renato")]),
+          genres: None,
+          albums: Some(vec![String::from("rg-dois")]),
+          release_date: chrono::NaiveDate::from_ymd_opt(1986, 7, 1),
+          cover: None,
+          parental_rating: None,
+          duration: Some(301466),
+          isrc: Some(Isrc::new("BRBMG8600012").unwrap()),
+          bpm: None,
+          key: None,
+          language: None,
+          is_single: None,
+          credits: None,
+        }),
+      ],
+      "O dump deve ser convertido em artistas, álbuns e medias referenciados pelo MBID"
+    );
+  }
+
+  #[test]
+  fn test_read_reports_invalid_lines() {
+    let mut dump = Dump::default();
+
+    let errors = dump
+      .read(
+        "artist",
+        format!("{}\n{{\"id\": \"sem-nome\"}}\n", ARTISTS).as_bytes(),
+      )
+      .expect_err("O arquivo com uma linha inválida deve ser recusado");
+
+    assert_eq!(
+      errors.iter().map(|(line, _)| *line).collect::<Vec<_>>(),
+      vec![2],
+      "O erro deve apontar a linha inválida"
+    );
+  }
+}
@@ -0,0 +1,3 @@
+mod source_checker;
+
+pub use source_checker::HttpSourceChecker;
@@ -0,0 +1,134 @@
+use std::{sync::Arc, time::Duration};
+
+use crate::domain::source::{
+  checker::{Error, SourceChecker},
+  source_entity::{Source, SourceStatus, SourceType},
+};
+
+/**
+ * Verificador que consulta o provedor pela rede: os provedores com oEmbed são
+ * consultados pelo endpoint público, os demais recebem um HEAD na própria URL.
+ */
+#[derive(Debug, Clone)]
+pub struct HttpSourceChecker {
+  agent: ureq::Agent,
+}
+
+impl HttpSourceChecker {
+  pub fn new() -> Result<Self, Error> {
+    let tls_connector =
+      native_tls::TlsConnector::new().map_err(|error| Error::CheckFailed(error.to_string()))?;
+
+    Ok(Self {
+      agent: ureq::AgentBuilder::new()
+        .timeout(Duration::from_secs(10))
+        .tls_connector(Arc::new(tls_connector))
+        .build(),
+    })
+  }
+}
+
+/**
+ * URL consultada para verificar a fonte e se a consulta usa HEAD em vez de GET.
+ */
+fn check_url(source: &Source) -> Result<(String, bool), Error> {
+  let endpoint = match source.source_type() {
+    SourceType::Youtube => "https://www.youtube.com/oembed?format=json",
+    SourceType::Soundcloud => "https://soundcloud.com/oembed?format=json",
+    SourceType::Vimeo => "https://vimeo.com/api/oembed.json",
+    SourceType::Spotify => "https://open.spotify.com/oembed",
+    SourceType::Deezer
+    | SourceType::AppleMusic
+    | SourceType::Bandcamp
+    | SourceType::DirectAudio => return Ok((source.src().clone(), true)),
+  };
+
+  url::Url::parse_with_params(endpoint, &[("url", source.src())])
+    .map(|url| (url.to_string(), false))
+    .map_err(|error| Error::CheckFailed(error.to_string()))
+}
+
+/**
+ * Status da fonte a partir do código HTTP; códigos sem significado claro, como
+ * erros do servidor, não alteram o status.
+ */
+fn status_from_code(code: u16) -> Option<SourceStatus> {
+  match code {
+    200..=399 => Some(SourceStatus::Active),
+    401 | 404 | 410 => Some(SourceStatus::Unavailable),
+    403 | 451 => Some(SourceStatus::RegionRestricted),
+    _ => None,
+  }
+}
+
+impl SourceChecker for HttpSourceChecker {
+  async fn check(&mut self, source: &Source) -> Result<SourceStatus, Error> {
+    let (url, head) = check_url(source)?;
+    let agent = self.agent.clone();
+
+    let code = tokio::task::spawn_blocking(move || {
+      let request = if head {
+        agent.head(&url)
+      } else {
+        agent.get(&url)
+      };
+
+      match request.call() {
+        Ok(response) => Ok(response.status()),
+        Err(ureq::Error::Status(code, _)) => Ok(code),
+        Err(error) => Err(Error::CheckFailed(error.to_string())),
+      }
+    })
+    .await
+    .map_err(|error| Error::CheckFailed(error.to_string()))??;
+
+    status_from_code(code).ok_or_else(|| Error::CheckFailed(format!("Unexpected status {}", code)))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_check_url() {
+    let youtube = Source::builder()
+      .source_type(SourceType::Youtube)
+      .src(String::from("https://www.youtube.com/watch?v=VHA2ASr8rmY"))
+      .build();
+
+    assert_eq!(
+      check_url(&youtube).expect("Erro ao montar a URL"),
+      (
+        String::from(
+          "https://www.youtube.com/oembed?format=json&url=https%3A%2F%2Fwww.youtube.com%2Fwatch%3Fv%3DVHA2ASr8rmY"
+        ),
+        false
+      ),
+      "YouTube deveria ser verificado pelo oEmbed"
+    );
+
+    let direct = Source::builder()
+      .source_type(SourceType::DirectAudio)
+      .src(String::from("https://example.com/song.mp3"))
+      .build();
+
+    assert_eq!(
+      check_url(&direct).expect("Erro ao montar a URL"),
+      (String::from("https://example.com/song.mp3"), true),
+      "Áudio direto deveria ser verificado pela própria URL"
+    );
+  }
+
+  #[test]
+  fn test_status_from_code() {
+    assert_eq!(status_from_code(200), Some(SourceStatus::Active));
+    assert_eq!(status_from_code(404), Some(SourceStatus::Unavailable));
+    assert_eq!(status_from_code(451), Some(SourceStatus::RegionRestricted));
+    assert_eq!(
+      status_from_code(503),
+      None,
+      "Erro do servidor não deveria alterar o status"
+    );
+  }
+}
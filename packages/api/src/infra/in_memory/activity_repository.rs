@@ -40,4 +40,64 @@ impl domain::activity::repository::ActivityRepository for InMemoryActivityReposi
       .insert(activity.id().to_string(), activity.clone());
     Ok(activity.clone())
   }
+
+  async fn find_by_cursor(
+    &self,
+    query: &domain::activity::repository::FindAllQuery,
+    page: &shared::paged::CursorPageParams,
+  ) -> Result<
+    shared::paged::CursorPaged<domain::activity::Activity>,
+    crate::domain::activity::repository::Error,
+  > {
+    let key = |activity: &domain::activity::Activity| {
+      (
+        *activity.created_at(),
+        uuid::Uuid::from(activity.id().clone()),
+      )
+    };
+
+    let mut activities = self
+      .activities
+      .values()
+      .filter(|activity| {
+        query
+          .user_id
+          .as_ref()
+          .is_none_or(|user_id| activity.user_id() == user_id)
+      })
+      .cloned()
+      .collect::<Vec<_>>();
+
+    let total_items = page.include_total.then_some(activities.len());
+
+    let direction = page
+      .cursor
+      .as_ref()
+      .map(|cursor| cursor.direction)
+      .unwrap_or(shared::paged::CursorDirection::Next);
+
+    if let Some(cursor) = &page.cursor {
+      let position = (cursor.created_at, uuid::Uuid::from(cursor.id.clone()));
+
+      activities.retain(|activity| match direction {
+        shared::paged::CursorDirection::Next => key(activity) < position,
+        shared::paged::CursorDirection::Prev => key(activity) > position,
+      });
+    }
+
+    activities.sort_by_key(key);
+
+    if direction == shared::paged::CursorDirection::Next {
+      activities.reverse();
+    }
+
+    activities.truncate(page.limit + 1);
+
+    Ok(shared::paged::CursorPaged::from_keyset(
+      activities,
+      page,
+      total_items,
+      |activity| (*activity.created_at(), activity.id().clone()),
+    ))
+  }
 }
@@ -1,15 +1,166 @@
 use crate::*;
+use domain::album::repository::SortField;
 
 #[derive(Debug, Default)]
 pub struct InMemoryAlbumRepository {
   pub albums: HashMap<String, domain::album::Album>,
+  pub deleted: HashMap<String, domain::album::Album>,
+
+  /**
+   * Artistas considerados na busca pelo slug do artista.
+   */
+  pub artists: HashMap<String, domain::artist::Artist>,
+
+  /**
+   * Medias e fontes consideradas no cálculo do impacto de exclusão. As medias
+   * também definem a popularidade do álbum.
+   */
+  pub medias: HashMap<String, domain::media::Media>,
+  pub sources: HashMap<String, domain::source::source_entity::Source>,
 }
 
 impl InMemoryAlbumRepository {
   pub fn new(_: &AppState) -> Self {
     Self {
       albums: HashMap::new(),
+      deleted: HashMap::new(),
+      artists: HashMap::new(),
+      medias: HashMap::new(),
+      sources: HashMap::new(),
+    }
+  }
+
+  /**
+   * Número de medias do álbum, como na ordenação por popularidade do repositório
+   * sqlx.
+   */
+  fn popularity(&self, album: &domain::album::Album) -> usize {
+    self
+      .medias
+      .values()
+      .filter(|media| media.album_ids().contains(album.id()))
+      .count()
+  }
+
+  /**
+   * Retorna os álbuns que atendem aos filtros da consulta, sem ordenação.
+   */
+  fn filter(&self, query: &domain::album::repository::FindAllQuery) -> Vec<domain::album::Album> {
+    let search = query.search.as_ref().map(|search| search.to_lowercase());
+
+    let mut albums = self
+      .albums
+      .values()
+      .filter(|album| {
+        search.as_ref().is_none_or(|search| {
+          album.name().to_lowercase().contains(search) || album.slug().to_string().contains(search)
+        })
+      })
+      .filter(|album| {
+        query
+          .artist_ids
+          .as_ref()
+          .is_none_or(|artist_ids| artist_ids.matches(album.artist_ids()))
+      })
+      .filter(|album| query.name.as_ref().is_none_or(|name| album.name() == name))
+      .filter(|album| query.slug.as_ref().is_none_or(|slug| album.slug() == slug))
+      .filter(|album| {
+        query
+          .album_type
+          .as_ref()
+          .is_none_or(|album_type| album_type.matches([album.album_type()]))
+      })
+      .filter(|album| {
+        query
+          .release_date
+          .is_none_or(|date| album.release_date() == &Some(date))
+      })
+      .filter(|album| {
+        query
+          .min_release_date
+          .is_none_or(|date| album.release_date().is_some_and(|release| release >= date))
+      })
+      .filter(|album| {
+        query
+          .max_release_date
+          .is_none_or(|date| album.release_date().is_some_and(|release| release <= date))
+      })
+      .filter(|album| {
+        query
+          .parental_rating
+          .as_ref()
+          .is_none_or(|rating| rating.matches([album.parental_rating()]))
+      })
+      .filter(|album| {
+        query
+          .min_parental_rating
+          .is_none_or(|rating| *album.parental_rating() >= rating)
+      })
+      .filter(|album| {
+        query
+          .max_parental_rating
+          .is_none_or(|rating| *album.parental_rating() <= rating)
+      })
+      .filter(|album| {
+        query.label.as_ref().is_none_or(|label| {
+          album
+            .label()
+            .as_ref()
+            .is_some_and(|album_label| album_label.eq_ignore_ascii_case(label))
+        })
+      })
+      .filter(|album| {
+        query
+          .barcode
+          .as_ref()
+          .is_none_or(|barcode| album.barcode().as_ref() == Some(barcode))
+      })
+      .filter(|album| {
+        query.release_country.as_ref().is_none_or(|country| {
+          album
+            .release_country()
+            .as_ref()
+            .is_some_and(|release_country| release_country.eq_ignore_ascii_case(country))
+        })
+      })
+      .filter(|album| {
+        query
+          .release_group_id
+          .as_ref()
+          .is_none_or(|release_group_id| album.release_group_id() == release_group_id)
+      })
+      .cloned()
+      .collect::<Vec<_>>();
+
+    if query.group_editions {
+      // Mantém apenas a edição mais antiga de cada grupo de lançamento.
+      albums.sort_by(|a, b| {
+        compare_release_dates(a.release_date(), b.release_date())
+          .then_with(|| a.created_at().cmp(b.created_at()))
+          .then_with(|| a.id().0.cmp(&b.id().0))
+      });
+
+      let mut groups = std::collections::HashSet::new();
+
+      albums.retain(|album| groups.insert(album.release_group_id().clone()));
     }
+
+    albums
+  }
+}
+
+/**
+ * Compara datas de lançamento em ordem crescente, com os registros sem data por último.
+ */
+pub(super) fn compare_release_dates(
+  a: &Option<chrono::NaiveDate>,
+  b: &Option<chrono::NaiveDate>,
+) -> std::cmp::Ordering {
+  match (a, b) {
+    (Some(a), Some(b)) => a.cmp(b),
+    (Some(_), None) => std::cmp::Ordering::Less,
+    (None, Some(_)) => std::cmp::Ordering::Greater,
+    (None, None) => std::cmp::Ordering::Equal,
   }
 }
 
@@ -29,6 +180,20 @@ impl domain::album::repository::AlbumRepository for InMemoryAlbumRepository {
     Ok(self.albums.get(&id.0).cloned())
   }
 
+  async fn find_by_ids(
+    &mut self,
+    ids: &std::collections::HashSet<crate::shared::vo::UUID4>,
+  ) -> Result<Vec<domain::album::Album>, crate::domain::album::repository::Error> {
+    Ok(
+      self
+        .albums
+        .values()
+        .filter(|album| ids.contains(album.id()))
+        .cloned()
+        .collect(),
+    )
+  }
+
   async fn update(
     &mut self,
     album: &domain::album::Album,
@@ -40,28 +205,184 @@ impl domain::album::repository::AlbumRepository for InMemoryAlbumRepository {
     &mut self,
     id: &crate::shared::vo::UUID4,
   ) -> Result<(), crate::domain::album::repository::Error> {
-    self.albums.remove(&id.0);
+    if let Some(album) = self.albums.remove(&id.0) {
+      self.deleted.insert(id.0.clone(), album);
+    }
     Ok(())
   }
 
+  async fn find_deleted_by_id(
+    &mut self,
+    id: &crate::shared::vo::UUID4,
+  ) -> Result<Option<domain::album::Album>, crate::domain::album::repository::Error> {
+    Ok(self.deleted.get(&id.0).cloned())
+  }
+
+  async fn restore_by_id(
+    &mut self,
+    id: &crate::shared::vo::UUID4,
+  ) -> Result<(), crate::domain::album::repository::Error> {
+    if let Some(album) = self.deleted.remove(&id.0) {
+      self.albums.insert(id.0.clone(), album);
+    }
+    Ok(())
+  }
+
+  async fn deletion_impact(
+    &mut self,
+    id: &crate::shared::vo::UUID4,
+  ) -> Result<domain::activity::DeletionImpact, crate::domain::album::repository::Error> {
+    let media_ids = self
+      .medias
+      .values()
+      .filter(|media| media.album_ids().contains(id))
+      .map(|media| media.id().clone())
+      .collect::<std::collections::HashSet<_>>();
+
+    let sources = self
+      .sources
+      .values()
+      .filter(|source| media_ids.contains(source.media_id()))
+      .count() as u64;
+
+    let album_artist = self
+      .albums
+      .get(&id.0)
+      .or_else(|| self.deleted.get(&id.0))
+      .map_or(0, |album| album.artist_ids().len() as u64);
+
+    Ok(domain::activity::DeletionImpact {
+      medias: media_ids.len() as u64,
+      sources,
+      join_rows: std::collections::BTreeMap::from([
+        (String::from("media_album"), media_ids.len() as u64),
+        (String::from("album_artist"), album_artist),
+      ]),
+      ..Default::default()
+    })
+  }
+
   async fn find_by_slug(
     &mut self,
-    _slug: &shared::vo::Slug,
-    _artist_slug: &shared::vo::Slug,
+    slug: &shared::vo::Slug,
+    artist_slug: &shared::vo::Slug,
   ) -> Result<
     Option<domain::album::album_aggregate::AlbumAggregate>,
     domain::album::repository::Error,
   > {
-    todo!()
+    let artists = |album: &domain::album::Album| {
+      album
+        .artist_ids()
+        .iter()
+        .filter_map(|id| self.artists.get(&id.0).cloned())
+        .collect::<Vec<_>>()
+    };
+
+    Ok(
+      self
+        .albums
+        .values()
+        .filter(|album| album.slug() == slug)
+        .map(|album| (album, artists(album)))
+        .find(|(_, artists)| artists.iter().any(|artist| artist.slug() == artist_slug))
+        .map(|(album, artists)| {
+          domain::album::album_aggregate::AlbumAggregate::new(album.clone(), artists)
+        }),
+    )
+  }
+
+  async fn find_by_release_group_id(
+    &mut self,
+    release_group_id: &crate::shared::vo::UUID4,
+  ) -> Result<Vec<domain::album::Album>, domain::album::repository::Error> {
+    Ok(
+      self
+        .albums
+        .values()
+        .filter(|album| album.release_group_id() == release_group_id)
+        .cloned()
+        .collect(),
+    )
   }
 
   async fn find_by(
     &mut self,
-    _query: &domain::album::repository::FindAllQuery,
+    query: &domain::album::repository::FindAllQuery,
   ) -> Result<
     crate::shared::paged::Paged<domain::album::album_aggregate::AlbumAggregate>,
     domain::album::repository::Error,
   > {
-    todo!()
+    let mut albums = self.filter(query);
+
+    albums.sort_by(|a, b| {
+      let ordering = match &query.sort {
+        Some(sort) => match sort.field {
+          SortField::Name => sort.apply(a.name().cmp(b.name())),
+          SortField::ReleaseDate => match (a.release_date(), b.release_date()) {
+            (Some(a), Some(b)) => sort.apply(a.cmp(b)),
+            (a, b) => compare_release_dates(a, b),
+          },
+          SortField::CreatedAt => sort.apply(a.created_at().cmp(b.created_at())),
+          SortField::UpdatedAt => sort.apply(a.updated_at().cmp(b.updated_at())),
+          SortField::Popularity => sort.apply(self.popularity(a).cmp(&self.popularity(b))),
+        },
+        None => std::cmp::Ordering::Equal,
+      };
+
+      ordering
+        .then_with(|| a.name().cmp(b.name()))
+        .then_with(|| a.id().0.cmp(&b.id().0))
+    });
+
+    let albums = albums
+      .into_iter()
+      .map(|album| domain::album::album_aggregate::AlbumAggregate::new(album, Vec::new()))
+      .collect();
+
+    Ok(crate::shared::paged::Paged::from_items(
+      albums,
+      query.page.clone(),
+    ))
+  }
+
+  async fn facets(
+    &mut self,
+    query: &domain::album::repository::FindAllQuery,
+    fields: &[domain::album::repository::FacetField],
+  ) -> Result<
+    Vec<crate::shared::facet::Facet<domain::album::repository::FacetField>>,
+    domain::album::repository::Error,
+  > {
+    use domain::album::repository::FacetField;
+
+    let albums = self.filter(query);
+
+    Ok(
+      fields
+        .iter()
+        .map(|field| {
+          crate::shared::facet::Facet::from_values(
+            *field,
+            albums.iter().filter_map(|album| {
+              let year = album
+                .release_date()
+                .map(|date| chrono::Datelike::year(&date));
+
+              match field {
+                FacetField::AlbumType => Some((album.album_type().to_string(), None)),
+                FacetField::ParentalRating => Some((album.parental_rating().to_string(), None)),
+                FacetField::ReleaseYear => year.map(|year| (year.to_string(), None)),
+                FacetField::ReleaseDecade => year.map(|year| {
+                  let decade = year / 10 * 10;
+                  (decade.to_string(), Some(format!("{}s", decade)))
+                }),
+                // As mídias, e portanto os gêneros, não são conhecidas por este repositório.
+                FacetField::Genre => None,
+              }
+            }),
+          )
+        })
+        .collect(),
+    )
   }
 }
@@ -1,18 +1,44 @@
 use std::collections::HashMap;
 
 use crate::*;
+use domain::artist::repository::SortField;
 
 #[derive(Debug, Default)]
 pub struct InMemoryArtistRepository {
   pub artists: HashMap<String, domain::artist::Artist>,
+  pub deleted: HashMap<String, domain::artist::Artist>,
+
+  /**
+   * Álbuns, medias e fontes considerados no cálculo do impacto de exclusão. As
+   * medias também definem a popularidade do artista.
+   */
+  pub albums: HashMap<String, domain::album::Album>,
+  pub medias: HashMap<String, domain::media::Media>,
+  pub sources: HashMap<String, domain::source::source_entity::Source>,
 }
 
 impl InMemoryArtistRepository {
   pub fn new(_: &AppState) -> Self {
     Self {
       artists: HashMap::new(),
+      deleted: HashMap::new(),
+      albums: HashMap::new(),
+      medias: HashMap::new(),
+      sources: HashMap::new(),
     }
   }
+
+  /**
+   * Número de medias interpretadas pelo artista, como na ordenação por popularidade
+   * do repositório sqlx.
+   */
+  fn popularity(&self, artist: &domain::artist::Artist) -> usize {
+    self
+      .medias
+      .values()
+      .filter(|media| media.interpreter_ids().contains(artist.id()))
+      .count()
+  }
 }
 
 impl domain::artist::repository::ArtistRepository for InMemoryArtistRepository {
@@ -56,15 +82,188 @@ impl domain::artist::repository::ArtistRepository for InMemoryArtistRepository {
     &mut self,
     id: &shared::vo::UUID4,
   ) -> Result<(), domain::artist::repository::Error> {
-    self.artists.remove(&id.to_string());
+    if let Some(artist) = self.artists.remove(&id.to_string()) {
+      self.deleted.insert(id.to_string(), artist);
+    }
+    Ok(())
+  }
+
+  async fn find_deleted_by_id(
+    &mut self,
+    id: &shared::vo::UUID4,
+  ) -> Result<Option<domain::artist::Artist>, domain::artist::repository::Error> {
+    Ok(self.deleted.get(&id.to_string()).cloned())
+  }
+
+  async fn restore_by_id(
+    &mut self,
+    id: &shared::vo::UUID4,
+  ) -> Result<(), domain::artist::repository::Error> {
+    if let Some(artist) = self.deleted.remove(&id.to_string()) {
+      self.artists.insert(id.to_string(), artist);
+    }
     Ok(())
   }
 
+  async fn deletion_impact(
+    &mut self,
+    id: &shared::vo::UUID4,
+  ) -> Result<domain::activity::DeletionImpact, domain::artist::repository::Error> {
+    let media_ids = self
+      .medias
+      .values()
+      .filter(|media| {
+        media.interpreter_ids().contains(id)
+          || media.composer_ids().contains(id)
+          || media.credits().iter().any(|credit| &credit.artist_id == id)
+      })
+      .map(|media| media.id().clone())
+      .collect::<std::collections::HashSet<_>>();
+
+    let albums = self
+      .albums
+      .values()
+      .filter(|album| album.artist_ids().contains(id))
+      .count() as u64;
+
+    let sources = self
+      .sources
+      .values()
+      .filter(|source| media_ids.contains(source.media_id()))
+      .count() as u64;
+
+    let media_interpreter = self
+      .medias
+      .values()
+      .filter(|media| media.interpreter_ids().contains(id))
+      .count() as u64;
+
+    let media_composer = self
+      .medias
+      .values()
+      .filter(|media| media.composer_ids().contains(id))
+      .count() as u64;
+
+    let media_credit = self
+      .medias
+      .values()
+      .flat_map(|media| media.credits())
+      .filter(|credit| &credit.artist_id == id)
+      .count() as u64;
+
+    let artist = self.artists.get(&id.to_string());
+
+    let artist_alias = artist.map_or(0, |artist| artist.aliases().len() as u64);
+
+    let artist_membership = artist.map_or(0, |artist| artist.members().len() as u64)
+      + self
+        .artists
+        .values()
+        .flat_map(|artist| artist.members())
+        .filter(|member| &member.artist_id == id)
+        .count() as u64;
+
+    Ok(domain::activity::DeletionImpact {
+      medias: media_ids.len() as u64,
+      albums,
+      sources,
+      subgenres: 0,
+      join_rows: std::collections::BTreeMap::from([
+        (String::from("album_artist"), albums),
+        (String::from("media_interpreter"), media_interpreter),
+        (String::from("media_composer"), media_composer),
+        (String::from("media_credit"), media_credit),
+        (String::from("artist_alias"), artist_alias),
+        (String::from("artist_membership"), artist_membership),
+      ]),
+    })
+  }
+
+  async fn find_by_ids(
+    &mut self,
+    ids: &std::collections::HashSet<shared::vo::UUID4>,
+  ) -> Result<Vec<domain::artist::Artist>, domain::artist::repository::Error> {
+    let artists = self
+      .artists
+      .values()
+      .filter(|artist| ids.contains(artist.id()))
+      .cloned()
+      .collect();
+    Ok(artists)
+  }
+
+  async fn find_groups_by_member_id(
+    &mut self,
+    member_id: &shared::vo::UUID4,
+  ) -> Result<Vec<domain::artist::Artist>, domain::artist::repository::Error> {
+    let groups = self
+      .artists
+      .values()
+      .filter(|artist| {
+        artist
+          .members()
+          .iter()
+          .any(|member| &member.artist_id == member_id)
+      })
+      .cloned()
+      .collect();
+    Ok(groups)
+  }
+
   async fn find_all(
     &mut self,
-    _query: &domain::artist::repository::FindAllQuery,
+    query: &domain::artist::repository::FindAllQuery,
   ) -> Result<shared::paged::Paged<domain::artist::Artist>, domain::artist::repository::Error> {
-    todo!()
+    let search = query.search.as_ref().map(|search| search.to_lowercase());
+
+    let mut artists = self
+      .artists
+      .values()
+      .filter(|artist| {
+        search.as_ref().is_none_or(|search| {
+          artist.name().to_lowercase().contains(search)
+            || artist.slug().to_string().contains(search)
+            || artist
+              .aliases()
+              .iter()
+              .any(|alias| alias.to_lowercase().contains(search))
+        })
+      })
+      .filter(|artist| {
+        query
+          .country
+          .as_ref()
+          .is_none_or(|country| artist.country().as_ref() == Some(country))
+      })
+      .filter(|artist| {
+        query
+          .artist_type
+          .as_ref()
+          .is_none_or(|artist_type| artist.artist_type() == artist_type)
+      })
+      .cloned()
+      .collect::<Vec<_>>();
+
+    artists.sort_by(|a, b| {
+      let ordering = match &query.sort {
+        Some(sort) => sort.apply(match sort.field {
+          SortField::Name => a.name().cmp(b.name()),
+          SortField::CreatedAt => a.created_at().cmp(b.created_at()),
+          SortField::UpdatedAt => a.updated_at().cmp(b.updated_at()),
+          SortField::Popularity => self.popularity(a).cmp(&self.popularity(b)),
+        }),
+        None => std::cmp::Ordering::Equal,
+      };
+
+      ordering
+        .then_with(|| a.name().cmp(b.name()))
+        .then_with(|| a.id().0.cmp(&b.id().0))
+    });
+
+    Ok(shared::paged::Paged::from_items(
+      artists,
+      query.page.clone(),
+    ))
   }
 }
 
@@ -86,7 +285,10 @@ pub mod tests {
 
     artists.insert(artist.id().to_string(), artist.clone());
 
-    let mut repo = InMemoryArtistRepository { artists };
+    let mut repo = InMemoryArtistRepository {
+      artists,
+      ..Default::default()
+    };
 
     let result = repo.find_by_slug(artist.slug()).await.unwrap();
 
@@ -102,7 +304,10 @@ pub mod tests {
       .build();
 
     let artists = HashMap::new();
-    let mut repo = InMemoryArtistRepository { artists };
+    let mut repo = InMemoryArtistRepository {
+      artists,
+      ..Default::default()
+    };
 
     let result = repo
       .find_by_slug(artist.slug())
@@ -121,7 +326,10 @@ pub mod tests {
       .build();
 
     let artists = HashMap::new();
-    let mut repo = InMemoryArtistRepository { artists };
+    let mut repo = InMemoryArtistRepository {
+      artists,
+      ..Default::default()
+    };
 
     let result = repo.create(&artist).await.expect("Error creating artist");
     assert_eq!(result.id(), artist.id());
@@ -139,7 +347,10 @@ pub mod tests {
 
     artists.insert(artist.id().to_string(), artist.clone());
 
-    let mut repo = InMemoryArtistRepository { artists };
+    let mut repo = InMemoryArtistRepository {
+      artists,
+      ..Default::default()
+    };
 
     let result = repo.update(&artist).await.expect("Error updating artist");
 
@@ -158,7 +369,10 @@ pub mod tests {
 
     artists.insert(artist.id().to_string(), artist.clone());
 
-    let mut repo = InMemoryArtistRepository { artists };
+    let mut repo = InMemoryArtistRepository {
+      artists,
+      ..Default::default()
+    };
 
     let result = repo
       .find_by_id(artist.id())
@@ -167,4 +381,106 @@ pub mod tests {
 
     assert_eq!(result, Some(artist));
   }
+
+  #[tokio::test]
+  async fn test_find_all_sorted() {
+    let artists = ["Beta", "Alpha", "Gamma"]
+      .into_iter()
+      .map(|name| {
+        domain::artist::Artist::builder()
+          .name(String::from(name))
+          .slug(shared::vo::Slug::generate(name))
+          .build()
+      })
+      .map(|artist| (artist.id().to_string(), artist))
+      .collect::<HashMap<_, _>>();
+
+    let mut repo = InMemoryArtistRepository {
+      artists,
+      ..Default::default()
+    };
+
+    let result = repo
+      .find_all(&domain::artist::repository::FindAllQuery {
+        page: shared::paged::RequestPageParams {
+          page: 1,
+          per_page: 2,
+        },
+        sort: Some("-name".parse().unwrap()),
+        search: None,
+        country: None,
+        artist_type: None,
+      })
+      .await
+      .unwrap();
+
+    assert_eq!(result.total_items, 3);
+    assert_eq!(
+      result
+        .items
+        .iter()
+        .map(|artist| artist.name().as_str())
+        .collect::<Vec<_>>(),
+      vec!["Gamma", "Beta"],
+      "Os artistas deveriam estar ordenados pelo nome decrescente"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_find_all_sorted_by_popularity() {
+    let artists = ["Alpha", "Beta", "Gamma"]
+      .into_iter()
+      .map(|name| {
+        domain::artist::Artist::builder()
+          .name(String::from(name))
+          .slug(shared::vo::Slug::generate(name))
+          .build()
+      })
+      .collect::<Vec<_>>();
+
+    // Gamma interpreta duas medias, Alpha uma e Beta nenhuma.
+    let medias = [&artists[2], &artists[2], &artists[0]]
+      .into_iter()
+      .map(|artist| {
+        domain::media::Media::builder()
+          .name(String::from("media"))
+          .interpreter_ids([artist.id().clone()].into())
+          .build()
+      })
+      .map(|media| (media.id().to_string(), media))
+      .collect::<HashMap<_, _>>();
+
+    let mut repo = InMemoryArtistRepository {
+      artists: artists
+        .into_iter()
+        .map(|artist| (artist.id().to_string(), artist))
+        .collect(),
+      medias,
+      ..Default::default()
+    };
+
+    let result = repo
+      .find_all(&domain::artist::repository::FindAllQuery {
+        page: shared::paged::RequestPageParams {
+          page: 1,
+          per_page: 3,
+        },
+        sort: Some("-popularity".parse().unwrap()),
+        search: None,
+        country: None,
+        artist_type: None,
+      })
+      .await
+      .unwrap();
+
+    assert_eq!(
+      result
+        .items
+        .iter()
+        .map(|artist| artist.name().as_str())
+        .collect::<Vec<_>>(),
+      vec!["Gamma", "Alpha", "Beta"],
+      "Os artistas deveriam estar ordenados pelo número de medias interpretadas"
+    );
+  }
 }
@@ -0,0 +1,380 @@
+use std::collections::HashSet;
+
+use crate::*;
+
+#[derive(Debug, Default)]
+pub struct InMemoryCatalogRepository {
+  /**
+   * Entidades vinculadas, indexadas por `<origem>:<tipo>:<identificador externo>`.
+   */
+  pub external_ids: HashMap<String, shared::vo::UUID4>,
+
+  /**
+   * Entidades exportadas.
+   */
+  pub genres: HashMap<String, domain::genre::Genre>,
+  pub artists: HashMap<String, domain::artist::Artist>,
+  pub albums: HashMap<String, domain::album::Album>,
+  pub medias: HashMap<String, domain::media::Media>,
+  pub sources: HashMap<String, domain::source::source_entity::Source>,
+  pub lyrics: HashMap<String, domain::lyrics::lyrics_entity::Lyrics>,
+
+  /**
+   * Entidades excluídas, com o tipo e o momento da exclusão.
+   */
+  pub deleted: HashMap<String, (shared::vo::CollaborativeEntityName, chrono::NaiveDateTime)>,
+}
+
+impl InMemoryCatalogRepository {
+  pub fn new(_: &AppState) -> Self {
+    Self {
+      external_ids: HashMap::new(),
+      genres: HashMap::new(),
+      artists: HashMap::new(),
+      albums: HashMap::new(),
+      medias: HashMap::new(),
+      sources: HashMap::new(),
+      lyrics: HashMap::new(),
+      deleted: HashMap::new(),
+    }
+  }
+
+  fn key(
+    origin: &str,
+    entity_name: &shared::vo::CollaborativeEntityName,
+    external_id: &str,
+  ) -> String {
+    format!("{}:{}:{}", origin, entity_name, external_id)
+  }
+
+  /**
+   * Slugs ordenados dos artistas existentes.
+   */
+  fn artist_slugs(&self, ids: &HashSet<shared::vo::UUID4>) -> Vec<String> {
+    let mut slugs: Vec<String> = ids
+      .iter()
+      .filter_map(|id| self.artists.get(&id.to_string()))
+      .map(|artist| artist.slug().to_string())
+      .collect();
+    slugs.sort();
+    slugs
+  }
+
+  fn artist_slug(&self, id: &shared::vo::UUID4) -> Option<String> {
+    self
+      .artists
+      .get(&id.to_string())
+      .map(|artist| artist.slug().to_string())
+  }
+
+  /**
+   * Referência `<artista>/<slug>`, pelo menor slug entre os artistas, como no
+   * repositório sqlx. Sem artistas não há referência.
+   */
+  fn reference(
+    &self,
+    artist_ids: &HashSet<shared::vo::UUID4>,
+    slug: &shared::vo::Slug,
+  ) -> Option<String> {
+    self
+      .artist_slugs(artist_ids)
+      .first()
+      .map(|artist| format!("{}/{}", artist, slug))
+  }
+
+  fn media_reference(&self, media_id: &shared::vo::UUID4) -> Option<String> {
+    let media = self.medias.get(&media_id.to_string())?;
+    self.reference(media.interpreter_ids(), media.slug())
+  }
+
+  fn genre_record(&self, genre: &domain::genre::Genre) -> domain::catalog::ImportRecord {
+    domain::catalog::ImportRecord::Genre(domain::catalog::GenreRecord {
+      external_id: Some(genre.id().to_string()),
+      name: genre.name().clone(),
+      slug: Some(genre.slug().clone()),
+      parent: genre
+        .parent_id()
+        .as_ref()
+        .and_then(|parent_id| self.genres.get(&parent_id.to_string()))
+        .map(|parent| parent.slug().to_string()),
+    })
+  }
+
+  fn artist_record(&self, artist: &domain::artist::Artist) -> domain::catalog::ImportRecord {
+    let members = artist
+      .members()
+      .iter()
+      .filter_map(|member| {
+        Some(domain::catalog::MemberRecord {
+          artist: self.artist_slug(&member.artist_id)?,
+          started_at: member.started_at,
+          ended_at: member.ended_at,
+        })
+      })
+      .collect();
+
+    domain::catalog::ImportRecord::Artist(domain::catalog::ArtistRecord {
+      external_id: Some(artist.id().to_string()),
+      name: artist.name().clone(),
+      slug: Some(artist.slug().clone()),
+      country: artist.country().clone(),
+      artist_type: Some(artist.artist_type().clone()),
+      aliases: non_empty(artist.aliases().iter().cloned().collect())
+        .map(|aliases| aliases.into_iter().collect()),
+      members: non_empty(members),
+    })
+  }
+
+  /**
+   * O grupo de lançamento é referenciado pelo álbum que o inaugurou ou, se ele foi
+   * excluído, pelo primeiro álbum restante do grupo, como no repositório sqlx.
+   */
+  fn album_record(&self, album: &domain::album::Album) -> domain::catalog::ImportRecord {
+    let release_group = self
+      .albums
+      .values()
+      .filter(|other| other.release_group_id() == album.release_group_id())
+      .min_by_key(|other| {
+        (
+          other.id() != other.release_group_id(),
+          other.id().to_string(),
+        )
+      })
+      .filter(|leader| leader.id() != album.id())
+      .and_then(|leader| self.reference(leader.artist_ids(), leader.slug()));
+
+    domain::catalog::ImportRecord::Album(domain::catalog::AlbumRecord {
+      external_id: Some(album.id().to_string()),
+      name: album.name().clone(),
+      slug: Some(album.slug().clone()),
+      album_type: album.album_type().clone(),
+      artists: self.artist_slugs(album.artist_ids()),
+      cover: album.cover().clone(),
+      release_date: *album.release_date(),
+      parental_rating: Some(*album.parental_rating()),
+      label: album.label().clone(),
+      catalog_number: album.catalog_number().clone(),
+      barcode: album.barcode().clone(),
+      release_country: album.release_country().clone(),
+      edition: album.edition().clone(),
+      release_group,
+    })
+  }
+
+  fn media_record(&self, media: &domain::media::Media) -> domain::catalog::ImportRecord {
+    let mut genres: Vec<String> = media
+      .genre_ids()
+      .iter()
+      .filter_map(|id| self.genres.get(&id.to_string()))
+      .map(|genre| genre.slug().to_string())
+      .collect();
+    genres.sort();
+
+    let mut albums: Vec<String> = media
+      .album_ids()
+      .iter()
+      .filter_map(|id| self.albums.get(&id.to_string()))
+      .filter_map(|album| self.reference(album.artist_ids(), album.slug()))
+      .collect();
+    albums.sort();
+
+    let credits = media
+      .credits()
+      .iter()
+      .enumerate()
+      .filter_map(|(position, credit)| {
+        Some(domain::catalog::CreditRecord {
+          artist: self.artist_slug(&credit.artist_id)?,
+          role: credit.role.clone(),
+          position: position as u16,
+        })
+      })
+      .collect();
+
+    domain::catalog::ImportRecord::Media(domain::catalog::MediaRecord {
+      external_id: Some(media.id().to_string()),
+      name: media.name().clone(),
+      slug: Some(media.slug().clone()),
+      media_type: media.media_type().clone(),
+      interpreters: self.artist_slugs(media.interpreter_ids()),
+      composers: non_empty(self.artist_slugs(media.composer_ids())),
+      genres: non_empty(genres),
+      albums: non_empty(albums),
+      release_date: *media.release_date(),
+      cover: media.cover().clone(),
+      parental_rating: Some(*media.parental_rating()),
+      duration: *media.duration(),
+      isrc: media.isrc().clone(),
+      bpm: *media.bpm(),
+      key: media.key().clone(),
+      language: media.language().clone(),
+      is_single: Some(*media.is_single()),
+      credits: non_empty(credits),
+    })
+  }
+
+  /**
+   * Fontes de medias excluídas ou sem intérpretes não têm como referenciar a media.
+   */
+  fn source_record(
+    &self,
+    source: &domain::source::source_entity::Source,
+  ) -> Option<domain::catalog::ImportRecord> {
+    Some(domain::catalog::ImportRecord::Source(
+      domain::catalog::SourceRecord {
+        external_id: Some(source.id().to_string()),
+        media: self.media_reference(source.media_id())?,
+        source_type: source.source_type().clone(),
+        src: source.src().clone(),
+        start_offset: *source.start_offset(),
+        duration: *source.duration(),
+      },
+    ))
+  }
+
+  /**
+   * Apenas a versão mais recente da letra de cada media é exportada.
+   */
+  fn lyrics_record(
+    &self,
+    lyrics: &domain::lyrics::lyrics_entity::Lyrics,
+  ) -> Option<domain::catalog::ImportRecord> {
+    let is_latest = self
+      .lyrics
+      .values()
+      .filter(|other| other.media_id() == lyrics.media_id())
+      .all(|other| other.version() <= lyrics.version());
+
+    if !is_latest {
+      return None;
+    }
+
+    Some(domain::catalog::ImportRecord::Lyrics(
+      domain::catalog::LyricsRecord {
+        external_id: Some(lyrics.id().to_string()),
+        media: self.media_reference(lyrics.media_id())?,
+        language: lyrics.language().clone(),
+        content: lyrics.content().clone(),
+        lrc: lyrics
+          .is_synced()
+          .then(|| domain::lyrics::lyrics_entity::LyricLine::to_lrc(lyrics.lines())),
+      },
+    ))
+  }
+}
+
+fn non_empty<T>(values: Vec<T>) -> Option<Vec<T>> {
+  if values.is_empty() {
+    None
+  } else {
+    Some(values)
+  }
+}
+
+impl domain::catalog::CatalogRepository for InMemoryCatalogRepository {
+  /**
+   * Monta os registros de todas as entidades do tipo, incluindo as exclusões na
+   * exportação incremental, e devolve a página pedida em ordem de identificador.
+   */
+  async fn export(
+    &mut self,
+    query: &domain::catalog::ExportQuery,
+  ) -> Result<Vec<(shared::vo::UUID4, domain::catalog::ImportRecord)>, domain::catalog::Error> {
+    use shared::vo::CollaborativeEntityName;
+
+    let changed =
+      |updated_at: &chrono::NaiveDateTime| query.since.is_none_or(|since| *updated_at > since);
+
+    let mut records: Vec<(shared::vo::UUID4, domain::catalog::ImportRecord)> =
+      match query.entity_name {
+        CollaborativeEntityName::Genre => self
+          .genres
+          .values()
+          .filter(|genre| changed(genre.updated_at()))
+          .map(|genre| (genre.id().clone(), self.genre_record(genre)))
+          .collect(),
+        CollaborativeEntityName::Artist => self
+          .artists
+          .values()
+          .filter(|artist| changed(artist.updated_at()))
+          .map(|artist| (artist.id().clone(), self.artist_record(artist)))
+          .collect(),
+        CollaborativeEntityName::Album => self
+          .albums
+          .values()
+          .filter(|album| changed(album.updated_at()))
+          .map(|album| (album.id().clone(), self.album_record(album)))
+          .collect(),
+        CollaborativeEntityName::Media => self
+          .medias
+          .values()
+          .filter(|media| changed(media.updated_at()))
+          .map(|media| (media.id().clone(), self.media_record(media)))
+          .collect(),
+        CollaborativeEntityName::Source => self
+          .sources
+          .values()
+          .filter(|source| changed(source.updated_at()))
+          .filter_map(|source| Some((source.id().clone(), self.source_record(source)?)))
+          .collect(),
+        CollaborativeEntityName::Lyrics => self
+          .lyrics
+          .values()
+          .filter(|lyrics| changed(lyrics.updated_at()))
+          .filter_map(|lyrics| Some((lyrics.id().clone(), self.lyrics_record(lyrics)?)))
+          .collect(),
+      };
+
+    records.extend(
+      self
+        .deleted
+        .iter()
+        .filter(|(_, (entity_name, deleted_at))| {
+          *entity_name == query.entity_name && query.since.is_some_and(|since| *deleted_at > since)
+        })
+        .map(|(id, (entity_name, _))| {
+          (
+            shared::vo::UUID4(id.clone()),
+            domain::catalog::ImportRecord::Deleted(domain::catalog::DeletedRecord {
+              entity: entity_name.clone(),
+              external_id: id.clone(),
+            }),
+          )
+        }),
+    );
+
+    records.retain(|(id, _)| query.after.as_ref().is_none_or(|after| id.0 > after.0));
+    records.sort_by(|(a, _), (b, _)| a.0.cmp(&b.0));
+    records.truncate(query.limit);
+
+    Ok(records)
+  }
+
+  async fn find_by_external_id(
+    &mut self,
+    origin: &str,
+    entity_name: &shared::vo::CollaborativeEntityName,
+    external_id: &str,
+  ) -> Result<Option<shared::vo::UUID4>, domain::catalog::Error> {
+    Ok(
+      self
+        .external_ids
+        .get(&Self::key(origin, entity_name, external_id))
+        .cloned(),
+    )
+  }
+
+  async fn save_external_id(
+    &mut self,
+    origin: &str,
+    entity_name: &shared::vo::CollaborativeEntityName,
+    external_id: &str,
+    entity_id: &shared::vo::UUID4,
+  ) -> Result<(), domain::catalog::Error> {
+    self.external_ids.insert(
+      Self::key(origin, entity_name, external_id),
+      entity_id.clone(),
+    );
+    Ok(())
+  }
+}
@@ -1,20 +1,41 @@
 use std::collections::HashMap;
 
 use crate::{
-  domain::genre::{Genre, GenreRepository},
+  domain::genre::{Genre, GenreRepository, SortField},
   AppState,
 };
 
+#[derive(Debug, Default)]
 pub struct InMemoryGenreRepository {
   pub genres: HashMap<String, Genre>,
+  pub deleted: HashMap<String, Genre>,
+
+  /**
+   * Medias que definem a popularidade dos gêneros.
+   */
+  pub medias: HashMap<String, crate::domain::media::Media>,
 }
 
 impl InMemoryGenreRepository {
   pub fn new(_: &AppState) -> Self {
     Self {
       genres: HashMap::new(),
+      deleted: HashMap::new(),
+      medias: HashMap::new(),
     }
   }
+
+  /**
+   * Número de medias do gênero, como na ordenação por popularidade do repositório
+   * sqlx.
+   */
+  fn popularity(&self, genre: &Genre) -> usize {
+    self
+      .medias
+      .values()
+      .filter(|media| media.genre_ids().contains(genre.id()))
+      .count()
+  }
 }
 
 impl GenreRepository for InMemoryGenreRepository {
@@ -25,6 +46,20 @@ impl GenreRepository for InMemoryGenreRepository {
     Ok(self.genres.get(&id.to_string()).cloned())
   }
 
+  async fn find_by_ids(
+    &mut self,
+    ids: &std::collections::HashSet<crate::shared::vo::UUID4>,
+  ) -> Result<Vec<Genre>, crate::domain::genre::Error> {
+    Ok(
+      self
+        .genres
+        .values()
+        .filter(|genre| ids.contains(genre.id()))
+        .cloned()
+        .collect(),
+    )
+  }
+
   async fn create(&mut self, genre: &Genre) -> Result<(), crate::domain::genre::Error> {
     self.genres.insert(genre.id().to_string(), genre.clone());
     Ok(())
@@ -39,21 +74,114 @@ impl GenreRepository for InMemoryGenreRepository {
     &mut self,
     id: &crate::shared::vo::UUID4,
   ) -> Result<(), crate::domain::genre::Error> {
-    self.genres.remove(&id.to_string());
+    if let Some(genre) = self.genres.remove(&id.to_string()) {
+      self.deleted.insert(id.to_string(), genre);
+    }
+    Ok(())
+  }
+
+  async fn find_deleted_by_id(
+    &mut self,
+    id: &crate::shared::vo::UUID4,
+  ) -> Result<Option<Genre>, crate::domain::genre::Error> {
+    Ok(self.deleted.get(&id.to_string()).cloned())
+  }
+
+  async fn restore_by_id(
+    &mut self,
+    id: &crate::shared::vo::UUID4,
+  ) -> Result<(), crate::domain::genre::Error> {
+    if let Some(genre) = self.deleted.remove(&id.to_string()) {
+      self.genres.insert(id.to_string(), genre);
+    }
     Ok(())
   }
 
+  async fn deletion_impact(
+    &mut self,
+    id: &crate::shared::vo::UUID4,
+  ) -> Result<crate::domain::activity::DeletionImpact, crate::domain::genre::Error> {
+    let mut parents = vec![id.clone()];
+    let mut subgenres = 0;
+
+    while let Some(parent_id) = parents.pop() {
+      for genre in self.genres.values() {
+        if genre.parent_id().as_ref() == Some(&parent_id) {
+          subgenres += 1;
+          parents.push(genre.id().clone());
+        }
+      }
+    }
+
+    Ok(crate::domain::activity::DeletionImpact {
+      subgenres,
+      ..Default::default()
+    })
+  }
+
   async fn find_genre_and_subgenre_by_slug(
     &mut self,
-    _slug: &crate::shared::vo::Slug,
+    slug: &crate::shared::vo::Slug,
   ) -> Result<Option<crate::domain::genre::GenreAggregate>, crate::domain::genre::Error> {
-    todo!()
+    let Some(genre) = self.genres.values().find(|genre| genre.slug() == slug) else {
+      return Ok(None);
+    };
+
+    let mut parents = vec![genre.id().clone()];
+    let mut related = Vec::new();
+
+    while let Some(parent_id) = parents.pop() {
+      for subgenre in self.genres.values() {
+        if subgenre.parent_id().as_ref() == Some(&parent_id) {
+          parents.push(subgenre.id().clone());
+          related.push(subgenre.clone());
+        }
+      }
+    }
+
+    Ok(Some(crate::domain::genre::GenreAggregate::new(
+      genre.clone(),
+      related,
+    )))
   }
 
   async fn find_all(
     &mut self,
     query: &crate::domain::genre::FindAllQuery,
   ) -> Result<crate::shared::paged::Paged<Genre>, crate::domain::genre::Error> {
-    todo!()
+    let search = query.search.as_ref().map(|search| search.to_lowercase());
+
+    let mut genres = self
+      .genres
+      .values()
+      .filter(|genre| genre.parent_id() == &query.parent_id)
+      .filter(|genre| {
+        search.as_ref().is_none_or(|search| {
+          genre.name().to_lowercase().contains(search) || genre.slug().to_string().contains(search)
+        })
+      })
+      .cloned()
+      .collect::<Vec<_>>();
+
+    genres.sort_by(|a, b| {
+      let ordering = match &query.sort {
+        Some(sort) => sort.apply(match sort.field {
+          SortField::Name => a.name().cmp(b.name()),
+          SortField::CreatedAt => a.created_at().cmp(b.created_at()),
+          SortField::UpdatedAt => a.updated_at().cmp(b.updated_at()),
+          SortField::Popularity => self.popularity(a).cmp(&self.popularity(b)),
+        }),
+        None => std::cmp::Ordering::Equal,
+      };
+
+      ordering
+        .then_with(|| a.name().cmp(b.name()))
+        .then_with(|| a.id().0.cmp(&b.id().0))
+    });
+
+    Ok(crate::shared::paged::Paged::from_items(
+      genres,
+      query.page.clone(),
+    ))
   }
 }
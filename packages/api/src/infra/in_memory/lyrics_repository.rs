@@ -0,0 +1,70 @@
+use crate::*;
+
+#[derive(Debug, Default)]
+pub struct InMemoryLyricsRepository {
+  pub lyrics: HashMap<String, domain::lyrics::lyrics_entity::Lyrics>,
+}
+
+impl InMemoryLyricsRepository {
+  pub fn new(_: &AppState) -> Self {
+    Self {
+      lyrics: HashMap::new(),
+    }
+  }
+}
+
+impl domain::lyrics::repository::LyricsRepository for InMemoryLyricsRepository {
+  async fn create(
+    &mut self,
+    lyrics: &domain::lyrics::lyrics_entity::Lyrics,
+  ) -> Result<(), domain::lyrics::repository::Error> {
+    self.lyrics.insert(lyrics.id().to_string(), lyrics.clone());
+    Ok(())
+  }
+
+  async fn find_by_id(
+    &mut self,
+    id: &shared::vo::UUID4,
+  ) -> Result<Option<domain::lyrics::lyrics_entity::Lyrics>, domain::lyrics::repository::Error> {
+    Ok(self.lyrics.get(&id.to_string()).cloned())
+  }
+
+  async fn find_by_media_id(
+    &mut self,
+    media_id: &shared::vo::UUID4,
+    version: Option<u32>,
+  ) -> Result<Option<domain::lyrics::lyrics_entity::Lyrics>, domain::lyrics::repository::Error> {
+    Ok(
+      self
+        .lyrics
+        .values()
+        .filter(|lyrics| lyrics.media_id() == media_id)
+        .filter(|lyrics| version.is_none_or(|version| *lyrics.version() == version))
+        .max_by_key(|lyrics| *lyrics.version())
+        .cloned(),
+    )
+  }
+
+  async fn find_versions_by_media_id(
+    &mut self,
+    media_id: &shared::vo::UUID4,
+  ) -> Result<Vec<domain::lyrics::lyrics_entity::Lyrics>, domain::lyrics::repository::Error> {
+    let mut versions: Vec<_> = self
+      .lyrics
+      .values()
+      .filter(|lyrics| lyrics.media_id() == media_id)
+      .cloned()
+      .collect();
+    versions.sort_by_key(|lyrics| std::cmp::Reverse(*lyrics.version()));
+
+    Ok(versions)
+  }
+
+  async fn delete_by_id(
+    &mut self,
+    id: &shared::vo::UUID4,
+  ) -> Result<(), domain::lyrics::repository::Error> {
+    self.lyrics.remove(&id.to_string());
+    Ok(())
+  }
+}
@@ -0,0 +1,492 @@
+use crate::*;
+use domain::media::repository::SortField;
+
+#[derive(Debug, Default)]
+pub struct InMemoryMediaRepository {
+  pub medias: HashMap<String, domain::media::Media>,
+  pub deleted: HashMap<String, domain::media::Media>,
+
+  /**
+   * Artistas considerados na busca pelo slug do intérprete.
+   */
+  pub artists: HashMap<String, domain::artist::Artist>,
+
+  /**
+   * Álbuns considerados na popularidade das medias.
+   */
+  pub albums: HashMap<String, domain::album::Album>,
+
+  /**
+   * Gêneros que nomeiam os valores da faceta de gênero.
+   */
+  pub genres: HashMap<String, domain::genre::Genre>,
+}
+
+impl InMemoryMediaRepository {
+  pub fn new(_: &AppState) -> Self {
+    Self {
+      medias: HashMap::new(),
+      deleted: HashMap::new(),
+      artists: HashMap::new(),
+      albums: HashMap::new(),
+      genres: HashMap::new(),
+    }
+  }
+
+  /**
+   * Número de álbuns que contêm a media, como na ordenação por popularidade do
+   * repositório sqlx.
+   */
+  fn popularity(&self, media: &domain::media::Media) -> usize {
+    media
+      .album_ids()
+      .iter()
+      .filter(|album_id| self.albums.contains_key(&album_id.to_string()))
+      .count()
+  }
+
+  /**
+   * Retorna as medias que atendem aos filtros da consulta, sem ordenação.
+   */
+  fn filter(&self, query: &domain::media::repository::FindByQuery) -> Vec<domain::media::Media> {
+    let search = query.search.as_ref().map(|search| search.to_lowercase());
+
+    self
+      .medias
+      .values()
+      .filter(|media| {
+        search.as_ref().is_none_or(|search| {
+          media.name().to_lowercase().contains(search) || media.slug().to_string().contains(search)
+        })
+      })
+      .filter(|media| {
+        query
+          .release_date
+          .is_none_or(|date| media.release_date() == &Some(date))
+      })
+      .filter(|media| {
+        query
+          .min_release_date
+          .is_none_or(|date| media.release_date().is_some_and(|release| release >= date))
+      })
+      .filter(|media| {
+        query
+          .max_release_date
+          .is_none_or(|date| media.release_date().is_some_and(|release| release <= date))
+      })
+      .filter(|media| {
+        query
+          .parental_rating
+          .as_ref()
+          .is_none_or(|rating| rating.matches([media.parental_rating()]))
+      })
+      .filter(|media| {
+        query
+          .min_parental_rating
+          .is_none_or(|rating| *media.parental_rating() >= rating)
+      })
+      .filter(|media| {
+        query
+          .max_parental_rating
+          .is_none_or(|rating| *media.parental_rating() <= rating)
+      })
+      .filter(|media| {
+        query
+          .is_single
+          .is_none_or(|is_single| *media.is_single() == is_single)
+      })
+      .filter(|media| {
+        query
+          .media_type
+          .as_ref()
+          .is_none_or(|media_type| media_type.matches([media.media_type()]))
+      })
+      .filter(|media| query.slug.as_ref().is_none_or(|slug| media.slug() == slug))
+      .filter(|media| {
+        query
+          .artist_ids
+          .as_ref()
+          .is_none_or(|artist_ids| artist_ids.matches(media.interpreter_ids()))
+      })
+      .filter(|media| {
+        query
+          .composer_ids
+          .as_ref()
+          .is_none_or(|composer_ids| composer_ids.matches(media.composer_ids()))
+      })
+      .filter(|media| {
+        query
+          .genre_ids
+          .as_ref()
+          .is_none_or(|genre_ids| genre_ids.matches(media.genre_ids()))
+      })
+      .filter(|media| {
+        query
+          .album_ids
+          .as_ref()
+          .is_none_or(|album_ids| album_ids.matches(media.album_ids()))
+      })
+      .filter(|media| {
+        query
+          .credit_role
+          .as_ref()
+          .is_none_or(|role| media.credits().iter().any(|credit| &credit.role == role))
+      })
+      .cloned()
+      .collect()
+  }
+}
+
+/**
+ * As entidades relacionadas não são conhecidas por este repositório e voltam vazias.
+ */
+fn aggregate(media: domain::media::Media) -> domain::media::media_aggregate::MediaAggregate {
+  domain::media::media_aggregate::MediaAggregate {
+    media,
+    interpreters: Vec::new(),
+    composers: Vec::new(),
+    credits: Vec::new(),
+    sources: Vec::new(),
+    genres: Vec::new(),
+    albums: Vec::new(),
+  }
+}
+
+impl domain::media::repository::MediaRepository for InMemoryMediaRepository {
+  async fn create(
+    &mut self,
+    media: &domain::media::Media,
+  ) -> Result<(), domain::media::repository::Error> {
+    self.medias.insert(media.id().to_string(), media.clone());
+    Ok(())
+  }
+
+  async fn update(
+    &mut self,
+    media: &domain::media::Media,
+  ) -> Result<(), domain::media::repository::Error> {
+    self.medias.insert(media.id().to_string(), media.clone());
+    Ok(())
+  }
+
+  async fn find_by_id(
+    &mut self,
+    id: &shared::vo::UUID4,
+  ) -> Result<Option<domain::media::Media>, domain::media::repository::Error> {
+    Ok(self.medias.get(&id.to_string()).cloned())
+  }
+
+  /**
+   * As entidades relacionadas não são conhecidas por este repositório e voltam vazias.
+   */
+  async fn find_aggregate_by_id(
+    &mut self,
+    id: &shared::vo::UUID4,
+  ) -> Result<
+    Option<domain::media::media_aggregate::MediaAggregate>,
+    domain::media::repository::Error,
+  > {
+    Ok(self.medias.get(&id.to_string()).cloned().map(aggregate))
+  }
+
+  async fn find_by_slug(
+    &mut self,
+    slug: &shared::vo::Slug,
+    artist_slug: &shared::vo::Slug,
+  ) -> Result<
+    Option<domain::media::media_aggregate::MediaAggregate>,
+    domain::media::repository::Error,
+  > {
+    let interpreters = |media: &domain::media::Media| {
+      media
+        .interpreter_ids()
+        .iter()
+        .filter_map(|id| self.artists.get(&id.to_string()).cloned())
+        .collect::<Vec<_>>()
+    };
+
+    Ok(
+      self
+        .medias
+        .values()
+        .filter(|media| media.slug() == slug)
+        .map(|media| (media, interpreters(media)))
+        .find(|(_, interpreters)| {
+          interpreters
+            .iter()
+            .any(|artist| artist.slug() == artist_slug)
+        })
+        .map(
+          |(media, interpreters)| domain::media::media_aggregate::MediaAggregate {
+            media: media.clone(),
+            interpreters,
+            composers: Vec::new(),
+            credits: Vec::new(),
+            sources: Vec::new(),
+            genres: Vec::new(),
+            albums: Vec::new(),
+          },
+        ),
+    )
+  }
+
+  async fn delete_by_id(
+    &mut self,
+    id: &shared::vo::UUID4,
+  ) -> Result<(), domain::media::repository::Error> {
+    if let Some(media) = self.medias.remove(&id.to_string()) {
+      self.deleted.insert(id.to_string(), media);
+    }
+    Ok(())
+  }
+
+  async fn find_deleted_by_id(
+    &mut self,
+    id: &shared::vo::UUID4,
+  ) -> Result<Option<domain::media::Media>, domain::media::repository::Error> {
+    Ok(self.deleted.get(&id.to_string()).cloned())
+  }
+
+  async fn restore_by_id(
+    &mut self,
+    id: &shared::vo::UUID4,
+  ) -> Result<(), domain::media::repository::Error> {
+    if let Some(media) = self.deleted.remove(&id.to_string()) {
+      self.medias.insert(id.to_string(), media);
+    }
+    Ok(())
+  }
+
+  async fn find_by(
+    &mut self,
+    query: &domain::media::repository::FindByQuery,
+  ) -> Result<
+    shared::paged::Paged<domain::media::media_aggregate::MediaAggregate>,
+    domain::media::repository::Error,
+  > {
+    let mut medias = self.filter(query);
+
+    medias.sort_by(|a, b| {
+      let ordering = match &query.sort {
+        Some(sort) => match sort.field {
+          SortField::Name => sort.apply(a.name().cmp(b.name())),
+          SortField::ReleaseDate => match (a.release_date(), b.release_date()) {
+            (Some(a), Some(b)) => sort.apply(a.cmp(b)),
+            (a, b) => super::album_repository::compare_release_dates(a, b),
+          },
+          SortField::CreatedAt => sort.apply(a.created_at().cmp(b.created_at())),
+          SortField::UpdatedAt => sort.apply(a.updated_at().cmp(b.updated_at())),
+          SortField::Popularity => sort.apply(self.popularity(a).cmp(&self.popularity(b))),
+        },
+        None => std::cmp::Ordering::Equal,
+      };
+
+      ordering
+        .then_with(|| a.name().cmp(b.name()))
+        .then_with(|| a.id().0.cmp(&b.id().0))
+    });
+
+    Ok(shared::paged::Paged::from_items(
+      medias.into_iter().map(aggregate).collect(),
+      query.page.clone(),
+    ))
+  }
+
+  async fn find_by_cursor(
+    &mut self,
+    query: &domain::media::repository::FindByQuery,
+    page: &shared::paged::CursorPageParams,
+  ) -> Result<
+    shared::paged::CursorPaged<domain::media::media_aggregate::MediaAggregate>,
+    domain::media::repository::Error,
+  > {
+    let key =
+      |media: &domain::media::Media| (*media.created_at(), uuid::Uuid::from(media.id().clone()));
+
+    let mut medias = self.filter(query);
+
+    let total_items = page.include_total.then_some(medias.len());
+
+    let direction = page
+      .cursor
+      .as_ref()
+      .map(|cursor| cursor.direction)
+      .unwrap_or(shared::paged::CursorDirection::Next);
+
+    if let Some(cursor) = &page.cursor {
+      let position = (cursor.created_at, uuid::Uuid::from(cursor.id.clone()));
+
+      medias.retain(|media| match direction {
+        shared::paged::CursorDirection::Next => key(media) < position,
+        shared::paged::CursorDirection::Prev => key(media) > position,
+      });
+    }
+
+    medias.sort_by_key(key);
+
+    if direction == shared::paged::CursorDirection::Next {
+      medias.reverse();
+    }
+
+    medias.truncate(page.limit + 1);
+
+    Ok(shared::paged::CursorPaged::from_keyset(
+      medias.into_iter().map(aggregate).collect(),
+      page,
+      total_items,
+      |item| (*item.media.created_at(), item.media.id().clone()),
+    ))
+  }
+
+  async fn facets(
+    &mut self,
+    query: &domain::media::repository::FindByQuery,
+    fields: &[domain::media::repository::FacetField],
+  ) -> Result<
+    Vec<shared::facet::Facet<domain::media::repository::FacetField>>,
+    domain::media::repository::Error,
+  > {
+    use domain::media::repository::FacetField;
+
+    let medias = self.filter(query);
+
+    Ok(
+      fields
+        .iter()
+        .map(|field| {
+          shared::facet::Facet::from_values(
+            *field,
+            medias.iter().flat_map(|media| {
+              let year = media
+                .release_date()
+                .map(|date| chrono::Datelike::year(&date));
+
+              match field {
+                FacetField::MediaType => vec![(media.media_type().to_string(), None)],
+                FacetField::ParentalRating => vec![(media.parental_rating().to_string(), None)],
+                FacetField::ReleaseYear => year
+                  .map(|year| (year.to_string(), None))
+                  .into_iter()
+                  .collect(),
+                FacetField::ReleaseDecade => year
+                  .map(|year| {
+                    let decade = year / 10 * 10;
+                    (decade.to_string(), Some(format!("{}s", decade)))
+                  })
+                  .into_iter()
+                  .collect(),
+                FacetField::Genre => media
+                  .genre_ids()
+                  .iter()
+                  .filter_map(|genre_id| self.genres.get(&genre_id.to_string()))
+                  .map(|genre| (genre.id().to_string(), Some(genre.name().clone())))
+                  .collect(),
+              }
+            }),
+          )
+        })
+        .collect(),
+    )
+  }
+}
+
+#[cfg(test)]
+pub mod tests {
+  use domain::media::repository::{FacetField, FindByQuery, MediaRepository};
+
+  use super::*;
+
+  fn query(sort: &str, max_parental_rating: Option<u8>) -> FindByQuery {
+    FindByQuery {
+      page: shared::paged::RequestPageParams {
+        page: 1,
+        per_page: 10,
+      },
+      sort: Some(sort.parse().unwrap()),
+      search: None,
+      release_date: None,
+      min_release_date: None,
+      max_release_date: None,
+      parental_rating: None,
+      min_parental_rating: None,
+      max_parental_rating,
+      is_single: None,
+      media_type: None,
+      slug: None,
+      artist_ids: None,
+      composer_ids: None,
+      genre_ids: None,
+      album_ids: None,
+      credit_role: None,
+    }
+  }
+
+  fn repository() -> InMemoryMediaRepository {
+    let album = domain::album::Album::builder()
+      .name(String::from("Dois"))
+      .build();
+
+    let genre = domain::genre::Genre::builder()
+      .name(String::from("Rock"))
+      .build();
+
+    let medias = [("Alpha", 0, 0), ("Beta", 16, 1), ("Gamma", 10, 1)]
+      .into_iter()
+      .map(|(name, parental_rating, albums)| {
+        domain::media::Media::builder()
+          .name(String::from(name))
+          .slug(shared::vo::Slug::generate(name))
+          .parental_rating(parental_rating)
+          .album_ids([album.id().clone()].into_iter().take(albums).collect())
+          .genre_ids([genre.id().clone()].into())
+          .build()
+      })
+      .map(|media| (media.id().to_string(), media))
+      .collect();
+
+    InMemoryMediaRepository {
+      medias,
+      albums: [(album.id().to_string(), album)].into(),
+      genres: [(genre.id().to_string(), genre)].into(),
+      ..Default::default()
+    }
+  }
+
+  #[tokio::test]
+  async fn test_find_by_sorted_by_popularity_within_parental_rating() {
+    let mut repo = repository();
+
+    let result = repo.find_by(&query("-popularity", Some(14))).await.unwrap();
+
+    assert_eq!(
+      result
+        .items
+        .iter()
+        .map(|item| item.media.name().as_str())
+        .collect::<Vec<_>>(),
+      vec!["Gamma", "Alpha"],
+      "As medias deveriam respeitar a classificação e estar ordenadas pela popularidade"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_facets_by_genre() {
+    let mut repo = repository();
+
+    let facets = repo
+      .facets(&query("name", Some(14)), &[FacetField::Genre])
+      .await
+      .unwrap();
+
+    assert_eq!(facets[0].values.len(), 1);
+    assert_eq!(
+      facets[0].values[0].label.as_deref(),
+      Some("Rock"),
+      "A faceta deveria trazer o nome do gênero"
+    );
+    assert_eq!(
+      facets[0].values[0].count, 2,
+      "A faceta deveria contar apenas as medias dentro da classificação"
+    );
+  }
+}
@@ -1,11 +1,21 @@
 mod activity_repository;
 mod album_repository;
 mod artist_repository;
+mod catalog_repository;
 mod genre_repository;
+mod lyrics_repository;
+mod media_repository;
+mod source_checker;
+mod source_repository;
 mod user_repository;
 
 pub use activity_repository::InMemoryActivityRepository;
 pub use album_repository::InMemoryAlbumRepository;
 pub use artist_repository::InMemoryArtistRepository;
+pub use catalog_repository::InMemoryCatalogRepository;
 pub use genre_repository::InMemoryGenreRepository;
+pub use lyrics_repository::InMemoryLyricsRepository;
+pub use media_repository::InMemoryMediaRepository;
+pub use source_checker::InMemorySourceChecker;
+pub use source_repository::InMemorySourceRepository;
 pub use user_repository::InMemoryUserRepository;
@@ -0,0 +1,19 @@
+use crate::*;
+
+/**
+ * Verificador de fontes sem acesso à rede: o status é definido por URL e fontes
+ * desconhecidas são consideradas ativas.
+ */
+#[derive(Debug, Default)]
+pub struct InMemorySourceChecker {
+  pub statuses: HashMap<String, domain::source::source_entity::SourceStatus>,
+}
+
+impl domain::source::checker::SourceChecker for InMemorySourceChecker {
+  async fn check(
+    &mut self,
+    source: &domain::source::source_entity::Source,
+  ) -> Result<domain::source::source_entity::SourceStatus, domain::source::checker::Error> {
+    Ok(self.statuses.get(source.src()).cloned().unwrap_or_default())
+  }
+}
@@ -0,0 +1,112 @@
+use crate::*;
+
+#[derive(Debug, Default)]
+pub struct InMemorySourceRepository {
+  pub sources: HashMap<String, domain::source::source_entity::Source>,
+  pub deleted: HashMap<String, domain::source::source_entity::Source>,
+}
+
+impl InMemorySourceRepository {
+  pub fn new(_: &AppState) -> Self {
+    Self {
+      sources: HashMap::new(),
+      deleted: HashMap::new(),
+    }
+  }
+}
+
+impl domain::source::repository::SourceRepository for InMemorySourceRepository {
+  async fn create(
+    &mut self,
+    source: &domain::source::source_entity::Source,
+  ) -> Result<(), domain::source::repository::Error> {
+    self.sources.insert(source.id().to_string(), source.clone());
+    Ok(())
+  }
+
+  async fn update(
+    &mut self,
+    source: &domain::source::source_entity::Source,
+  ) -> Result<(), domain::source::repository::Error> {
+    self.sources.insert(source.id().to_string(), source.clone());
+    Ok(())
+  }
+
+  async fn find_by_id(
+    &mut self,
+    id: &shared::vo::UUID4,
+  ) -> Result<Option<domain::source::source_entity::Source>, domain::source::repository::Error> {
+    Ok(self.sources.get(&id.to_string()).cloned())
+  }
+
+  async fn find_by_media_id(
+    &mut self,
+    media_id: &shared::vo::UUID4,
+  ) -> Result<Vec<domain::source::source_entity::Source>, domain::source::repository::Error> {
+    Ok(
+      self
+        .sources
+        .values()
+        .filter(|source| source.media_id() == media_id)
+        .cloned()
+        .collect(),
+    )
+  }
+
+  async fn find_least_recently_checked(
+    &mut self,
+    limit: usize,
+  ) -> Result<Vec<domain::source::source_entity::Source>, domain::source::repository::Error> {
+    let mut sources: Vec<_> = self.sources.values().cloned().collect();
+    sources.sort_by_key(|source| (*source.last_checked_at(), *source.created_at()));
+    sources.truncate(limit);
+
+    Ok(sources)
+  }
+
+  async fn find_by_external_id(
+    &mut self,
+    media_id: &shared::vo::UUID4,
+    source_type: &domain::source::source_entity::SourceType,
+    external_id: &str,
+  ) -> Result<Option<domain::source::source_entity::Source>, domain::source::repository::Error> {
+    Ok(
+      self
+        .sources
+        .values()
+        .find(|source| {
+          source.media_id() == media_id
+            && source.source_type() == source_type
+            && source.external_id().as_deref() == Some(external_id)
+        })
+        .cloned(),
+    )
+  }
+
+  async fn delete_by_id(
+    &mut self,
+    id: &shared::vo::UUID4,
+  ) -> Result<(), domain::source::repository::Error> {
+    if let Some(source) = self.sources.remove(&id.to_string()) {
+      self.deleted.insert(id.to_string(), source);
+    }
+    Ok(())
+  }
+
+  async fn find_deleted_by_id(
+    &mut self,
+    id: &shared::vo::UUID4,
+  ) -> Result<Option<domain::source::source_entity::Source>, domain::source::repository::Error> {
+    Ok(self.deleted.get(&id.to_string()).cloned())
+  }
+
+  async fn restore_by_id(
+    &mut self,
+    id: &shared::vo::UUID4,
+  ) -> Result<(), domain::source::repository::Error> {
+    if let Some(source) = self.deleted.remove(&id.to_string()) {
+      self.sources.insert(id.to_string(), source);
+    }
+    Ok(())
+  }
+}
@@ -36,6 +36,9 @@ impl crate::domain::user::UserRepository for InMemoryUserRepository {
         "email": user.email().clone(),
         "password": user.password().clone(),
         "is_curator": user.is_curator().clone(),
+        "is_admin": user.is_admin().clone(),
+        "max_parental_rating": user.max_parental_rating().clone(),
+        "birth_date": user.birth_date().clone(),
         "created_at": user.created_at().clone(),
         "updated_at": user.updated_at().clone(),
       }),
@@ -44,6 +47,13 @@ impl crate::domain::user::UserRepository for InMemoryUserRepository {
     Ok(user.clone())
   }
 
+  async fn update(
+    &mut self,
+    user: &User,
+  ) -> Result<User, crate::domain::user::UserRepositoryError> {
+    self.create(user).await
+  }
+
   async fn find_by_username(
     &mut self,
     username: impl Into<String>,
@@ -61,6 +71,13 @@ impl crate::domain::user::UserRepository for InMemoryUserRepository {
           .display_name(value["display_name"].as_str().unwrap().to_string())
           .email(value["email"].as_str().unwrap().to_string())
           .is_curator(value["is_curator"].as_bool().unwrap())
+          .is_admin(value["is_admin"].as_bool().unwrap_or_default())
+          .max_parental_rating(
+            value["max_parental_rating"]
+              .as_u64()
+              .map(|rating| rating as u8),
+          )
+          .birth_date(serde_json::from_value(value["birth_date"].clone()).unwrap_or_default())
           .password(value["password"].as_str().unwrap().to_string())
           .created_at(
             serde_json::from_value::<chrono::NaiveDateTime>(value["created_at"].clone()).unwrap(),
@@ -96,6 +113,12 @@ impl crate::domain::user::UserRepository for InMemoryUserRepository {
         .display_name(value["display_name"].as_str().unwrap().to_string())
         .email(value["email"].as_str().unwrap().to_string())
         .is_curator(value["is_curator"].as_bool().unwrap())
+        .max_parental_rating(
+          value["max_parental_rating"]
+            .as_u64()
+            .map(|rating| rating as u8),
+        )
+        .birth_date(serde_json::from_value(value["birth_date"].clone()).unwrap_or_default())
         .password(value["password"].as_str().unwrap().to_string())
         .build()
     });
@@ -1,4 +1,6 @@
 pub mod actix;
 pub mod bcrypt;
+pub mod catalog;
+pub mod http;
 pub mod in_memory;
 pub mod sqlx;
@@ -1,5 +1,7 @@
 use crate::*;
 
+use super::keyset::push_keyset;
+
 pub struct SqlxActivityRepository {
   db: sqlx::Pool<sqlx::Postgres>,
 }
@@ -12,6 +14,49 @@ impl SqlxActivityRepository {
   }
 }
 
+#[derive(sqlx::FromRow)]
+struct ActivityRecord {
+  id: uuid::Uuid,
+  status: String,
+  user_id: uuid::Uuid,
+  curator_id: Option<uuid::Uuid>,
+  changes: Option<serde_json::Value>,
+  impact: Option<serde_json::Value>,
+  revision_date: Option<chrono::NaiveDateTime>,
+  reason: Option<String>,
+  created_at: chrono::NaiveDateTime,
+  updated_at: chrono::NaiveDateTime,
+}
+
+impl From<ActivityRecord> for domain::activity::Activity {
+  fn from(row: ActivityRecord) -> Self {
+    domain::activity::Activity::builder()
+      .id(shared::vo::UUID4::new(row.id).unwrap())
+      .status(match row.status.as_str() {
+        "Draft" => domain::activity::ActivityStatus::Draft,
+        "Pending" => domain::activity::ActivityStatus::Pending,
+        "Approved" => domain::activity::ActivityStatus::Approved,
+        "Rejected" => domain::activity::ActivityStatus::Rejected(row.reason.unwrap_or_default()),
+        value => panic!("status {:?} desconhecido", value),
+      })
+      .user_id(shared::vo::UUID4::new(row.user_id).unwrap())
+      .curator_id(row.curator_id.map(|id| shared::vo::UUID4::new(id).unwrap()))
+      .revision_date(row.revision_date)
+      .impact(
+        row
+          .impact
+          .map(|impact| serde_json::from_value(impact).expect("falha ao deserializar impacto")),
+      )
+      .contribution(
+        serde_json::from_value(row.changes.expect("contribuição não encontrada"))
+          .expect("falha ao deserializar contribuição"),
+      )
+      .created_at(row.created_at)
+      .updated_at(row.updated_at)
+      .build()
+  }
+}
+
 impl domain::activity::repository::ActivityRepository for SqlxActivityRepository {
   async fn create(
     &mut self,
@@ -23,9 +68,10 @@ impl domain::activity::repository::ActivityRepository for SqlxActivityRepository
         "status",
         "user_id",
         "changes",
+        "impact",
         "created_at",
         "updated_at"
-      ) VALUES ( $1, $2::activity_status, $3, $4, $5, $6)"#,
+      ) VALUES ( $1, $2::activity_status, $3, $4, $5, $6, $7)"#,
       Into::<uuid::Uuid>::into(activity.id().clone()),
       match activity.status() {
         domain::activity::ActivityStatus::Draft => "Draft",
@@ -35,6 +81,10 @@ impl domain::activity::repository::ActivityRepository for SqlxActivityRepository
       } as _,
       Into::<uuid::Uuid>::into(activity.user_id().clone()),
       serde_json::to_value(activity.contribution()).expect("falha ao serializar contribuição"),
+      activity
+        .impact()
+        .as_ref()
+        .map(|impact| serde_json::to_value(impact).expect("falha ao serializar impacto")),
       activity.created_at(),
       activity.updated_at()
     )
@@ -49,13 +99,15 @@ impl domain::activity::repository::ActivityRepository for SqlxActivityRepository
     &self,
     id: &shared::vo::UUID4,
   ) -> Result<Option<domain::activity::Activity>, domain::activity::repository::Error> {
-    let result = sqlx::query!(
+    let result = sqlx::query_as!(
+      ActivityRecord,
       r#"SELECT
         "id",
         "status" as "status: String",
         "user_id",
         "curator_id",
         "changes",
+        "impact",
         "revision_date",
         "reason",
         "created_at",
@@ -69,27 +121,7 @@ impl domain::activity::repository::ActivityRepository for SqlxActivityRepository
     .await
     .map_err(|err| domain::activity::repository::Error::InternalServerError(err.to_string()))?;
 
-    Ok(result.map(|row| {
-      domain::activity::Activity::builder()
-        .id(shared::vo::UUID4::new(row.id).unwrap())
-        .status(match row.status.as_str() {
-          "Draft" => domain::activity::ActivityStatus::Draft,
-          "Pending" => domain::activity::ActivityStatus::Pending,
-          "Approved" => domain::activity::ActivityStatus::Approved,
-          "Rejected" => domain::activity::ActivityStatus::Rejected(row.reason.unwrap_or_default()),
-          value => panic!("status {:?} desconhecido", value),
-        })
-        .user_id(shared::vo::UUID4::new(row.user_id).unwrap())
-        .curator_id(row.curator_id.map(|id| shared::vo::UUID4::new(id).unwrap()))
-        .revision_date(row.revision_date)
-        .contribution(
-          serde_json::from_value(row.changes.expect("contribuição não encontrada"))
-            .expect("falha ao deserializar contribuição"),
-        )
-        .created_at(row.created_at)
-        .updated_at(row.updated_at)
-        .build()
-    }))
+    Ok(result.map(domain::activity::Activity::from))
   }
 
   async fn update(
@@ -103,7 +135,8 @@ impl domain::activity::repository::ActivityRepository for SqlxActivityRepository
             "changes" = $4,
             "revision_date" = $5,
             "updated_at" = $6,
-            "reason" = $7
+            "reason" = $7,
+            "impact" = $8
 
           WHERE "id" = $1"#,
       Into::<uuid::Uuid>::into(activity.id().clone()),
@@ -123,13 +156,77 @@ impl domain::activity::repository::ActivityRepository for SqlxActivityRepository
       match activity.status() {
         domain::activity::ActivityStatus::Rejected(reason) => Some(reason.clone()),
         _ => None,
-      }
+      },
+      activity
+        .impact()
+        .as_ref()
+        .map(|impact| serde_json::to_value(impact).expect("falha ao serializar impacto"))
     )
     .execute(&self.db)
     .await
     .map_err(|err| domain::activity::repository::Error::InternalServerError(err.to_string()))?;
     Ok(activity.clone())
   }
+
+  async fn find_by_cursor(
+    &self,
+    query: &domain::activity::repository::FindAllQuery,
+    page: &shared::paged::CursorPageParams,
+  ) -> Result<
+    shared::paged::CursorPaged<domain::activity::Activity>,
+    domain::activity::repository::Error,
+  > {
+    let filter = create_filter!(domain::activity::repository::FindAllQuery, qb => {
+      user_id(value) => qb.push(r#""user_id" = "#).push_bind(uuid::Uuid::from(value.clone())),
+    });
+
+    let (count, items) = tokio::join!(
+      async {
+        if !page.include_total {
+          return Ok(None);
+        }
+
+        let mut query_builder = sqlx::QueryBuilder::new(r#"SELECT COUNT("id") FROM "activity""#);
+        filter(&mut query_builder, query);
+
+        query_builder
+          .build_query_scalar::<i64>()
+          .fetch_one(&self.db)
+          .await
+          .map(|count| Some(count as usize))
+      },
+      async {
+        let mut query_builder = sqlx::QueryBuilder::new(
+          r#"SELECT * FROM (
+            SELECT "id", "status"::TEXT, "user_id", "curator_id", "changes", "impact", "revision_date", "reason", "created_at", "updated_at"
+            FROM "activity"
+          "#,
+        );
+        filter(&mut query_builder, query);
+        query_builder.push(r#") "k""#);
+        push_keyset(&mut query_builder, page);
+
+        query_builder
+          .build_query_as::<ActivityRecord>()
+          .fetch_all(&self.db)
+          .await
+      }
+    );
+
+    let to_error =
+      |err: sqlx::Error| domain::activity::repository::Error::InternalServerError(err.to_string());
+
+    Ok(shared::paged::CursorPaged::from_keyset(
+      items
+        .map_err(to_error)?
+        .into_iter()
+        .map(domain::activity::Activity::from)
+        .collect(),
+      page,
+      count.map_err(to_error)?,
+      |activity| (*activity.created_at(), activity.id().clone()),
+    ))
+  }
 }
 
 #[cfg(test)]
@@ -285,4 +382,155 @@ pub mod tests {
       "A atividade retornada não foi a mesma que foi cadastrada"
     );
   }
+
+  #[tokio::test]
+  async fn test_paginar_atividades_por_cursor() {
+    // Load .env file
+    dotenvy::dotenv().ok();
+
+    const USER_ID: &str = "3f0c4d0e-8c56-4c4b-9a0f-5b8f1c6b2d11";
+    const ACTIVITY_IDS: [&str; 3] = [
+      "7a1f6b3e-2f0d-4a8e-9c55-0d3b6f1e9a01",
+      "7a1f6b3e-2f0d-4a8e-9c55-0d3b6f1e9a02",
+      "7a1f6b3e-2f0d-4a8e-9c55-0d3b6f1e9a03",
+    ];
+
+    async fn cleanup(db: &sqlx::PgPool) {
+      db.execute(
+        format!(
+          r#"
+          DELETE FROM "activity" WHERE "user_id" = '{}';
+          DELETE FROM "users" WHERE "id" = '{}';
+          "#,
+          USER_ID, USER_ID
+        )
+        .as_str(),
+      )
+      .await
+      .expect("falha ao deletar dados antigos");
+    }
+
+    let app_state = AppState::default().await;
+
+    cleanup(&app_state.db).await;
+
+    let user = domain::user::User::builder()
+      .id(shared::vo::UUID4::new(USER_ID).unwrap())
+      .username(String::from("test_paginar_atividades_user"))
+      .password(String::from("password"))
+      .build();
+
+    infra::sqlx::SqlxUserRepository::new(&app_state)
+      .create(&user)
+      .await
+      .expect("falha ao cadastrar usuário");
+
+    let mut activity_repository = infra::sqlx::SqlxActivityRepository::new(&app_state);
+
+    for (index, id) in ACTIVITY_IDS.iter().enumerate() {
+      let created_at = chrono::NaiveDate::from_ymd_opt(2024, 1, index as u32 + 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+
+      activity_repository
+        .create(
+          &domain::activity::Activity::builder()
+            .id(shared::vo::UUID4::new(*id).unwrap())
+            .user_id(user.id().clone())
+            .contribution(shared::vo::Contribution::Genre(
+              domain::genre::contribution::Contribution::Create(
+                domain::genre::Genre::builder()
+                  .name(format!("Gênero {}", index))
+                  .build(),
+              ),
+            ))
+            .created_at(created_at)
+            .updated_at(created_at)
+            .build(),
+        )
+        .await
+        .expect("falha ao cadastrar atividade");
+    }
+
+    let query = domain::activity::repository::FindAllQuery {
+      user_id: Some(user.id().clone()),
+    };
+
+    let ids = |page: &shared::paged::CursorPaged<domain::activity::Activity>| {
+      page
+        .items
+        .iter()
+        .map(|activity| activity.id().to_string())
+        .collect::<Vec<_>>()
+    };
+
+    let first_page = activity_repository
+      .find_by_cursor(
+        &query,
+        &shared::paged::CursorPageParams {
+          cursor: None,
+          limit: 2,
+          include_total: true,
+        },
+      )
+      .await
+      .expect("falha ao buscar a primeira página");
+
+    let second_page = activity_repository
+      .find_by_cursor(
+        &query,
+        &shared::paged::CursorPageParams {
+          cursor: first_page.next_cursor.as_ref().map(|cursor| {
+            cursor
+              .parse()
+              .expect("o cursor retornado deveria ser válido")
+          }),
+          limit: 2,
+          include_total: false,
+        },
+      )
+      .await
+      .expect("falha ao buscar a segunda página");
+
+    let previous_page = activity_repository
+      .find_by_cursor(
+        &query,
+        &shared::paged::CursorPageParams {
+          cursor: second_page.prev_cursor.as_ref().map(|cursor| {
+            cursor
+              .parse()
+              .expect("o cursor retornado deveria ser válido")
+          }),
+          limit: 2,
+          include_total: false,
+        },
+      )
+      .await
+      .expect("falha ao voltar para a primeira página");
+
+    cleanup(&app_state.db).await;
+
+    assert_eq!(
+      ids(&first_page),
+      vec![ACTIVITY_IDS[2], ACTIVITY_IDS[1]],
+      "A primeira página deveria trazer as atividades mais recentes"
+    );
+    assert_eq!(first_page.total_items, Some(3));
+    assert!(first_page.prev_cursor.is_none());
+
+    assert_eq!(
+      ids(&second_page),
+      vec![ACTIVITY_IDS[0]],
+      "A segunda página deveria continuar de onde a primeira parou"
+    );
+    assert!(second_page.next_cursor.is_none());
+    assert_eq!(second_page.total_items, None);
+
+    assert_eq!(
+      ids(&previous_page),
+      ids(&first_page),
+      "Voltar a partir da segunda página deveria trazer a primeira"
+    );
+  }
 }
@@ -7,11 +7,57 @@ use domain::{
 };
 use shared::{
   paged::Paged,
-  vo::{Slug, UUID4},
+  vo::{Barcode, Slug, UUID4},
 };
 use sqlx::Postgres;
 
-use super::many_to_many::ManyToManyBuilder;
+use super::{
+  facet::{self, FacetColumn},
+  list_filter::{push_relation_filter, push_value_filter},
+  many_to_many::ManyToManyBuilder,
+};
+
+fn sort_column(field: &domain::album::repository::SortField) -> &'static str {
+  use domain::album::repository::SortField;
+
+  match field {
+    SortField::Name => r#""a"."name""#,
+    SortField::ReleaseDate => r#""a"."release_date""#,
+    SortField::CreatedAt => r#""a"."created_at""#,
+    SortField::UpdatedAt => r#""a"."updated_at""#,
+    SortField::Popularity => {
+      r#"(SELECT COUNT(*) FROM "media_album" "ma" INNER JOIN "media" "m" ON "m"."id" = "ma"."media_id" WHERE "ma"."album_id" = "a"."id" AND "m"."deleted_at" IS NULL)"#
+    }
+  }
+}
+
+/**
+ * Aplica os filtros da listagem de álbuns sobre a tabela "album" "a".
+ */
+fn push_filters(
+  qb: &mut sqlx::QueryBuilder<'_, sqlx::Postgres>,
+  query: &domain::album::repository::FindAllQuery,
+) {
+  let filter = create_filter!(domain::album::repository::FindAllQuery, qb, r#""a"."deleted_at" IS NULL"# => {
+    search (value) => search_by!(qb, value.clone(), r#""a""#),
+    artist_ids (value) => push_relation_filter(qb, r#""a"."id""#, "album_artist", "album_id", "artist_id", value),
+    name (value) => qb.push(r#" "a"."name" = "#).push_bind(value.to_string()),
+    slug (value) => qb.push(r#" "a"."slug" = "#).push_bind(value.to_string()),
+    album_type (value) => push_value_filter(qb, r#""a"."album_type""#, &value.map(ToString::to_string), r#"::"album_type""#),
+    release_date (value) => qb.push(r#" "a"."release_date" = "#).push_bind(*value),
+    min_release_date (value) => qb.push(r#" "a"."release_date" >= "#).push_bind(*value),
+    max_release_date (value) => qb.push(r#" "a"."release_date" <= "#).push_bind(*value),
+    parental_rating (value) => push_value_filter(qb, r#""a"."parental_rating""#, &value.map(|rating| *rating as i16), ""),
+    min_parental_rating (value) => qb.push(r#" "a"."parental_rating" >= "#).push_bind(*value as i16),
+    max_parental_rating (value) => qb.push(r#" "a"."parental_rating" <= "#).push_bind(*value as i16),
+    label (value) => qb.push(r#" LOWER("a"."label") = LOWER("#).push_bind(value.clone()).push(")"),
+    barcode (value) => qb.push(r#" "a"."barcode" = "#).push_bind(value.to_string()),
+    release_country (value) => qb.push(r#" "a"."release_country" = "#).push_bind(value.to_uppercase()),
+    release_group_id (value) => qb.push(r#" "a"."release_group_id" = "#).push_bind(uuid::Uuid::from(value.clone())),
+  });
+
+  filter(qb, query);
+}
 
 pub struct SqlxAlbumRepository {
   db: sqlx::Pool<Postgres>,
@@ -23,6 +69,77 @@ impl SqlxAlbumRepository {
       db: app_state.db.clone(),
     }
   }
+
+  /**
+   * Busca um álbum pelo identificador entre os álbuns ativos ou, com `deleted`, entre os excluídos.
+   */
+  async fn fetch_by_id(
+    &self,
+    id: &crate::shared::vo::UUID4,
+    deleted: bool,
+  ) -> Result<Option<crate::domain::album::Album>, crate::domain::album::repository::Error> {
+    let result = sqlx::query!(
+      r#"
+      SELECT "id", "name", "album_type" as "album_type: String", "cover", "slug", "release_date", "parental_rating", "created_at", "updated_at",
+             "label", "catalog_number", "barcode", "release_country", "release_group_id", "edition"
+      FROM "album"
+      WHERE "id" = $1 AND ("deleted_at" IS NOT NULL) = $2
+      "#,
+      Into::<uuid::Uuid>::into(id.clone()),
+      deleted
+    )
+    .fetch_optional(&self.db)
+    .await
+    .map_err(|err| domain::album::repository::Error::DatabaseError(err.to_string()))?;
+
+    if result.is_none() {
+      return Ok(None);
+    }
+
+    let result = result.unwrap();
+
+    let artists_id = {
+      let result = sqlx::query!(
+        r#"
+        SELECT "artist_id" FROM "album_artist" WHERE "album_id" = $1 
+      "#,
+        Into::<uuid::Uuid>::into(id.clone())
+      )
+      .fetch_all(&self.db)
+      .await
+      .map_err(|err| domain::album::repository::Error::DatabaseError(err.to_string()))?;
+
+      result
+        .into_iter()
+        .map(|value| shared::vo::UUID4::new(value.artist_id).unwrap())
+        .collect::<HashSet<shared::vo::UUID4>>()
+    };
+
+    Ok(Some(
+      domain::album::Album::builder()
+        .id(shared::vo::UUID4::new(result.id).unwrap())
+        .name(result.name)
+        .album_type(result.album_type.parse().unwrap())
+        .cover(result.cover)
+        .slug(Slug::from(result.slug))
+        .parental_rating(result.parental_rating as u8)
+        .release_date(result.release_date)
+        .label(result.label)
+        .catalog_number(result.catalog_number)
+        .barcode(
+          result
+            .barcode
+            .and_then(|barcode| Barcode::new(barcode).ok()),
+        )
+        .release_country(result.release_country)
+        .release_group_id(UUID4::from(result.release_group_id))
+        .edition(result.edition)
+        .created_at(result.created_at)
+        .updated_at(result.updated_at)
+        .artist_ids(artists_id)
+        .build(),
+    ))
+  }
 }
 
 impl domain::album::repository::AlbumRepository for SqlxAlbumRepository {
@@ -37,17 +154,24 @@ impl domain::album::repository::AlbumRepository for SqlxAlbumRepository {
 
     sqlx::query!(
       r#"
-      INSERT INTO "album" ("id", "name", "album_type", "cover", "release_date", "parental_rating", "created_at", "updated_at")
-      VALUES ($1, $2, $3::album_type, $4, $5, $6, $7, $8)
+      INSERT INTO "album" ("id", "name", "album_type", "cover", "slug", "release_date", "parental_rating", "created_at", "updated_at", "label", "catalog_number", "barcode", "release_country", "release_group_id", "edition")
+      VALUES ($1, $2, $3::album_type, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
       "#,
       Into::<uuid::Uuid>::into(album.id().clone()),
       album.name(),
       album.album_type().to_string() as _,
       album.cover().clone(),
+      album.slug().to_string(),
       album.release_date().clone(),
       (*album.parental_rating()) as i16,
       album.created_at(),
-      album.updated_at()
+      album.updated_at(),
+      album.label().clone(),
+      album.catalog_number().clone(),
+      album.barcode().as_ref().map(|barcode| barcode.to_string()),
+      album.release_country().clone(),
+      Into::<uuid::Uuid>::into(album.release_group_id().clone()),
+      album.edition().clone()
     )
     .execute(&mut *trx)
     .await
@@ -83,7 +207,8 @@ impl domain::album::repository::AlbumRepository for SqlxAlbumRepository {
     sqlx::query!(
       r#"
       UPDATE "album"
-      SET "name" = $2, "cover" = $3, "release_date" = $4, "parental_rating" = $5, "updated_at" = $6
+      SET "name" = $2, "cover" = $3, "release_date" = $4, "parental_rating" = $5, "updated_at" = $6, "slug" = $7, "album_type" = $8::album_type,
+          "label" = $9, "catalog_number" = $10, "barcode" = $11, "release_country" = $12, "release_group_id" = $13, "edition" = $14
       WHERE "id" = $1
       "#,
       Into::<uuid::Uuid>::into(album.id().clone()),
@@ -91,7 +216,15 @@ impl domain::album::repository::AlbumRepository for SqlxAlbumRepository {
       album.cover().clone(),
       album.release_date().clone(),
       *album.parental_rating() as i16,
-      album.updated_at()
+      album.updated_at(),
+      album.slug().to_string(),
+      album.album_type().to_string() as _,
+      album.label().clone(),
+      album.catalog_number().clone(),
+      album.barcode().as_ref().map(|barcode| barcode.to_string()),
+      album.release_country().clone(),
+      Into::<uuid::Uuid>::into(album.release_group_id().clone()),
+      album.edition().clone()
     )
     .execute(&mut *trx)
     .await
@@ -118,94 +251,145 @@ impl domain::album::repository::AlbumRepository for SqlxAlbumRepository {
     &mut self,
     id: &crate::shared::vo::UUID4,
   ) -> Result<Option<crate::domain::album::Album>, crate::domain::album::repository::Error> {
+    self.fetch_by_id(id, false).await
+  }
+
+  async fn find_by_ids(
+    &mut self,
+    ids: &HashSet<UUID4>,
+  ) -> Result<Vec<Album>, domain::album::repository::Error> {
+    if ids.is_empty() {
+      return Ok(Vec::new());
+    }
+
     let result = sqlx::query!(
       r#"
-      SELECT "id", "name", "album_type" as "album_type: String", "cover", "release_date", "parental_rating", "created_at", "updated_at"
-      FROM "album"
-      WHERE "id" = $1
+      SELECT "a"."id", "a"."name", "a"."album_type" as "album_type: String", "a"."cover", "a"."slug", "a"."release_date", "a"."parental_rating",
+             "a"."label", "a"."catalog_number", "a"."barcode", "a"."release_country", "a"."release_group_id", "a"."edition",
+             "a"."created_at", "a"."updated_at",
+             COALESCE(ARRAY_AGG("aa"."artist_id") FILTER (WHERE "aa"."artist_id" IS NOT NULL), '{}') as "artist_ids!: Vec<uuid::Uuid>"
+      FROM "album" "a"
+      LEFT JOIN "album_artist" "aa" ON "aa"."album_id" = "a"."id"
+      WHERE "a"."id" = ANY($1) AND "a"."deleted_at" IS NULL
+      GROUP BY "a"."id"
       "#,
-      Into::<uuid::Uuid>::into(id.clone())
+      &ids
+        .iter()
+        .map(|id| uuid::Uuid::from(id.clone()))
+        .collect::<Vec<_>>(),
     )
-    .fetch_optional(&self.db)
+    .fetch_all(&self.db)
     .await
     .map_err(|err| domain::album::repository::Error::DatabaseError(err.to_string()))?;
 
-    if result.is_none() {
-      return Ok(None);
-    }
-
-    let result = result.unwrap();
-
-    let artists_id = {
-      let result = sqlx::query!(
-        r#"
-        SELECT "artist_id" FROM "album_artist" WHERE "album_id" = $1 
-      "#,
-        Into::<uuid::Uuid>::into(id.clone())
-      )
-      .fetch_all(&self.db)
-      .await
-      .map_err(|err| domain::album::repository::Error::DatabaseError(err.to_string()))?;
-
+    Ok(
       result
         .into_iter()
-        .map(|value| shared::vo::UUID4::new(value.artist_id).unwrap())
-        .collect::<HashSet<shared::vo::UUID4>>()
-    };
-
-    Ok(Some(
-      domain::album::Album::builder()
-        .id(shared::vo::UUID4::new(result.id).unwrap())
-        .name(result.name)
-        .album_type(result.album_type.parse().unwrap())
-        .cover(result.cover)
-        .parental_rating(result.parental_rating as u8)
-        .release_date(result.release_date)
-        .created_at(result.created_at)
-        .updated_at(result.updated_at)
-        .artist_ids(artists_id)
-        .build(),
-    ))
+        .map(|row| {
+          Album::builder()
+            .id(UUID4::from(row.id))
+            .name(row.name)
+            .album_type(row.album_type.parse().unwrap())
+            .cover(row.cover)
+            .slug(Slug::from(row.slug))
+            .release_date(row.release_date)
+            .parental_rating(row.parental_rating as u8)
+            .label(row.label)
+            .catalog_number(row.catalog_number)
+            .barcode(row.barcode.and_then(|barcode| Barcode::new(barcode).ok()))
+            .release_country(row.release_country)
+            .release_group_id(UUID4::from(row.release_group_id))
+            .edition(row.edition)
+            .artist_ids(row.artist_ids.into_iter().map(UUID4::from).collect())
+            .created_at(row.created_at)
+            .updated_at(row.updated_at)
+            .build()
+        })
+        .collect(),
+    )
   }
 
   async fn delete_by_id(
     &mut self,
     id: &crate::shared::vo::UUID4,
   ) -> Result<(), crate::domain::album::repository::Error> {
-    let mut trx = self
-      .db
-      .begin()
-      .await
-      .map_err(|err| domain::album::repository::Error::DatabaseError(err.to_string()))?;
-
     sqlx::query!(
       r#"
-      DELETE FROM album_artist WHERE album_id = $1
+      UPDATE "album" SET "deleted_at" = now() WHERE "id" = $1 AND "deleted_at" IS NULL
       "#,
       Into::<uuid::Uuid>::into(id.clone())
     )
-    .execute(&mut *trx)
+    .execute(&self.db)
     .await
     .map_err(|err| domain::album::repository::Error::DatabaseError(err.to_string()))?;
 
+    Ok(())
+  }
+
+  async fn find_deleted_by_id(
+    &mut self,
+    id: &crate::shared::vo::UUID4,
+  ) -> Result<Option<crate::domain::album::Album>, crate::domain::album::repository::Error> {
+    self.fetch_by_id(id, true).await
+  }
+
+  async fn restore_by_id(
+    &mut self,
+    id: &crate::shared::vo::UUID4,
+  ) -> Result<(), crate::domain::album::repository::Error> {
     sqlx::query!(
       r#"
-      DELETE FROM album WHERE id = $1
+      UPDATE "album" SET "deleted_at" = NULL WHERE "id" = $1
       "#,
       Into::<uuid::Uuid>::into(id.clone())
     )
-    .execute(&mut *trx)
+    .execute(&self.db)
     .await
     .map_err(|err| domain::album::repository::Error::DatabaseError(err.to_string()))?;
 
-    trx
-      .commit()
-      .await
-      .map_err(|err| domain::album::repository::Error::DatabaseError(err.to_string()))?;
-
     Ok(())
   }
 
+  async fn deletion_impact(
+    &mut self,
+    id: &crate::shared::vo::UUID4,
+  ) -> Result<domain::activity::DeletionImpact, crate::domain::album::repository::Error> {
+    let result = sqlx::query!(
+      r#"
+      WITH "media_ids" AS (
+        SELECT "m"."id"
+        FROM "media" "m"
+        INNER JOIN "media_album" "ma" ON "ma"."media_id" = "m"."id"
+        WHERE "ma"."album_id" = $1 AND "m"."deleted_at" IS NULL
+      )
+      SELECT
+        (SELECT COUNT(*) FROM "media_ids") AS "medias!",
+        (
+          SELECT COUNT(*)
+          FROM "source" "s"
+          INNER JOIN "media_ids" "mi" ON "mi"."id" = "s"."media_id"
+          WHERE "s"."deleted_at" IS NULL
+        ) AS "sources!",
+        (SELECT COUNT(*) FROM "media_album" WHERE "album_id" = $1) AS "media_album!",
+        (SELECT COUNT(*) FROM "album_artist" WHERE "album_id" = $1) AS "album_artist!"
+      "#,
+      Into::<uuid::Uuid>::into(id.clone())
+    )
+    .fetch_one(&self.db)
+    .await
+    .map_err(|err| domain::album::repository::Error::DatabaseError(err.to_string()))?;
+
+    Ok(domain::activity::DeletionImpact {
+      medias: result.medias as u64,
+      sources: result.sources as u64,
+      join_rows: std::collections::BTreeMap::from([
+        (String::from("media_album"), result.media_album as u64),
+        (String::from("album_artist"), result.album_artist as u64),
+      ]),
+      ..Default::default()
+    })
+  }
+
   async fn find_by_slug(
     &mut self,
     slug: &shared::vo::Slug,
@@ -223,17 +407,24 @@ impl domain::album::repository::AlbumRepository for SqlxAlbumRepository {
       "a"."slug", 
       "a"."release_date", 
       "a"."parental_rating", 
+      "a"."label",
+      "a"."catalog_number",
+      "a"."barcode",
+      "a"."release_country",
+      "a"."release_group_id",
+      "a"."edition",
       "a"."created_at", 
       "a"."updated_at",
       "a2"."id" as "artist_id: Option<uuid::Uuid>",
       "a2"."name" as "artist_name: Option<String>",
       "a2"."slug" as "artist_slug: Option<String>",
       "a2"."country" as "artist_country: Option<String>",
+      "a2"."artist_type"::TEXT as "artist_type: Option<String>",
       "a2"."created_at" as "artist_created_at: Option<chrono::NaiveDateTime>",
       "a2"."updated_at" as "artist_update_at: Option<chrono::NaiveDateTime>"
       from "album" "a"
       left join "album_artist" "aa" on "a"."id" = "aa"."album_id" 
-      left join "artist" "a2" on "a2"."id" = "aa"."artist_id"
+      left join "artist" "a2" on "a2"."id" = "aa"."artist_id" and "a2"."deleted_at" IS NULL
       WHERE 
           "a"."id" IN (
           SELECT "album"."id"
@@ -241,6 +432,7 @@ impl domain::album::repository::AlbumRepository for SqlxAlbumRepository {
           JOIN "album_artist" "aa" ON "album"."id" = "aa"."album_id"
           JOIN "artist" "a" ON "aa"."artist_id" = "a"."id"
           WHERE "album"."slug" = $1 AND "a"."slug" = $2
+            AND "album"."deleted_at" IS NULL AND "a"."deleted_at" IS NULL
       )
     "#,
       slug.to_string(),
@@ -263,6 +455,25 @@ impl domain::album::repository::AlbumRepository for SqlxAlbumRepository {
         .cover(album.cover.clone())
         .slug(Slug::from(album.slug.clone()))
         .album_type(album.album_type.parse().unwrap())
+        .release_date(album.release_date)
+        .parental_rating(album.parental_rating as u8)
+        .label(album.label.clone())
+        .catalog_number(album.catalog_number.clone())
+        .barcode(
+          album
+            .barcode
+            .as_ref()
+            .and_then(|barcode| Barcode::new(barcode.clone()).ok()),
+        )
+        .release_country(album.release_country.clone())
+        .release_group_id(UUID4::from(album.release_group_id))
+        .edition(album.edition.clone())
+        .artist_ids(
+          result
+            .iter()
+            .filter_map(|item| item.artist_id.map(UUID4::from))
+            .collect(),
+        )
         .created_at(album.created_at)
         .updated_at(album.updated_at)
         .build(),
@@ -275,6 +486,13 @@ impl domain::album::repository::AlbumRepository for SqlxAlbumRepository {
               .name(item.artist_name.unwrap_or_default())
               .slug(Slug::from(item.artist_slug.unwrap_or_default()))
               .country(item.artist_country.unwrap_or_default())
+              .artist_type(
+                item
+                  .artist_type
+                  .flatten()
+                  .and_then(|value| value.parse().ok())
+                  .unwrap_or_default(),
+              )
               .created_at(item.artist_created_at.unwrap_or_default())
               .updated_at(item.artist_update_at.unwrap_or_default())
               .build()
@@ -286,36 +504,67 @@ impl domain::album::repository::AlbumRepository for SqlxAlbumRepository {
     Ok(Some(album_aggregate))
   }
 
+  async fn find_by_release_group_id(
+    &mut self,
+    release_group_id: &UUID4,
+  ) -> Result<Vec<Album>, domain::album::repository::Error> {
+    let result = sqlx::query!(
+      r#"
+      SELECT "a"."id", "a"."name", "a"."album_type" as "album_type: String", "a"."cover", "a"."slug", "a"."release_date", "a"."parental_rating",
+             "a"."label", "a"."catalog_number", "a"."barcode", "a"."release_country", "a"."release_group_id", "a"."edition",
+             "a"."created_at", "a"."updated_at",
+             COALESCE(ARRAY_AGG("aa"."artist_id") FILTER (WHERE "aa"."artist_id" IS NOT NULL), '{}') as "artist_ids!: Vec<uuid::Uuid>"
+      FROM "album" "a"
+      LEFT JOIN "album_artist" "aa" ON "aa"."album_id" = "a"."id"
+      WHERE "a"."release_group_id" = $1 AND "a"."deleted_at" IS NULL
+      GROUP BY "a"."id"
+      ORDER BY "a"."release_date" ASC NULLS LAST, "a"."created_at"
+      "#,
+      uuid::Uuid::from(release_group_id.clone())
+    )
+    .fetch_all(&self.db)
+    .await
+    .map_err(|err| domain::album::repository::Error::DatabaseError(err.to_string()))?;
+
+    Ok(
+      result
+        .into_iter()
+        .map(|row| {
+          Album::builder()
+            .id(UUID4::from(row.id))
+            .name(row.name)
+            .album_type(row.album_type.parse().unwrap())
+            .cover(row.cover)
+            .slug(Slug::from(row.slug))
+            .release_date(row.release_date)
+            .parental_rating(row.parental_rating as u8)
+            .label(row.label)
+            .catalog_number(row.catalog_number)
+            .barcode(row.barcode.and_then(|barcode| Barcode::new(barcode).ok()))
+            .release_country(row.release_country)
+            .release_group_id(UUID4::from(row.release_group_id))
+            .edition(row.edition)
+            .artist_ids(row.artist_ids.into_iter().map(UUID4::from).collect())
+            .created_at(row.created_at)
+            .updated_at(row.updated_at)
+            .build()
+        })
+        .collect(),
+    )
+  }
+
   async fn find_by(
     &mut self,
     query: &domain::album::repository::FindAllQuery,
   ) -> Result<crate::shared::paged::Paged<AlbumAggregate>, domain::album::repository::Error> {
-    let filter = create_filter!(domain::album::repository::FindAllQuery, qb => {
-      search (value) => search_by!(qb, value.clone(), r#""a"."name""#, r#""a"."slug""#),
-      artist_ids (value) => {
-        qb.push(r#" "a"."id" in ( select "aa"."album_id" from album_artist aa where "aa"."artist_id" in ( "#);
-
-        let mut separated = qb.separated(", ");
-        for id in value {
-          separated.push_bind(uuid::Uuid::from(id.clone()));
-        }
-        separated.push_unseparated(")");
-      },
-      name (value) => qb.push(r#" "a"."name" = "#).push_bind(value.to_string()),
-      slug (value) => qb.push(r#" "a"."slug" = "#).push_bind(value.to_string()),
-      album_type (value) => qb.push(r#" "a"."album_type" = "#).push_bind(value.to_string()),
-      release_date (value) => qb.push(r#" "a"."release_date" = "#).push_bind(*value),
-      min_release_date (value) => qb.push(r#" "a"."release_date" >= "#).push_bind(*value),
-      max_release_date (value) => qb.push(r#" "a"."release_date" <= "#).push_bind(*value),
-      parental_rating (value) => qb.push(r#" "a"."parental_rating" = "#).push_bind(*value as i16),
-      min_parental_rating (value) => qb.push(r#" "a"."parental_rating" >= "#).push_bind(*value as i16),
-      max_parental_rating (value) => qb.push(r#" "a"."parental_rating" <= "#).push_bind(*value as i16),
-    });
-
     let (count, items) = tokio::join!(
       async {
-        let mut query_builder = sqlx::QueryBuilder::new(r#"select count(id) from "album" "a" "#);
-        filter(&mut query_builder, query);
+        let mut query_builder = sqlx::QueryBuilder::new(if query.group_editions {
+          r#"select count(distinct "a"."release_group_id") from "album" "a" "#
+        } else {
+          r#"select count("a"."id") from "album" "a" "#
+        });
+        push_filters(&mut query_builder, query);
 
         query_builder
           .build_query_scalar::<i64>()
@@ -334,14 +583,21 @@ impl domain::album::repository::AlbumRepository for SqlxAlbumRepository {
           slug: String,
           release_date: Option<chrono::NaiveDate>,
           parental_rating: i16,
+          label: Option<String>,
+          catalog_number: Option<String>,
+          barcode: Option<String>,
+          release_country: Option<String>,
+          release_group_id: uuid::Uuid,
+          edition: Option<String>,
           created_at: chrono::NaiveDateTime,
           updated_at: chrono::NaiveDateTime,
           artist_id: Option<uuid::Uuid>,
-          artist_name: String,
-          artist_slug: String,
+          artist_name: Option<String>,
+          artist_slug: Option<String>,
           artist_country: Option<String>,
-          artist_created_at: chrono::NaiveDateTime,
-          artist_updated_at: chrono::NaiveDateTime,
+          artist_type: Option<String>,
+          artist_created_at: Option<chrono::NaiveDateTime>,
+          artist_updated_at: Option<chrono::NaiveDateTime>,
         }
 
         let mut query_builder = sqlx::QueryBuilder::new(
@@ -354,29 +610,82 @@ impl domain::album::repository::AlbumRepository for SqlxAlbumRepository {
             "a"."slug",
             "a"."release_date",
             "a"."parental_rating",
+            "a"."label",
+            "a"."catalog_number",
+            "a"."barcode",
+            "a"."release_country",
+            "a"."release_group_id",
+            "a"."edition",
             "a"."created_at",
             "a"."updated_at",
             "ar"."id" as "artist_id",
             "ar"."name" as "artist_name",
             "ar"."slug" as "artist_slug",
             "ar"."country" as "artist_country",
+            "ar"."artist_type"::text as "artist_type",
             "ar"."created_at" as "artist_created_at",
             "ar"."updated_at" as "artist_updated_at"
-            from "album" "a"
-            left join "album_artist" "aa2" on "aa2"."album_id" = "a"."id"
-            left join "artist" "ar" on "ar"."id" = "aa2"."artist_id" 
+            from (
+              select * from (
         "#,
         );
 
-        filter(&mut query_builder, query);
+        // A paginação é aplicada sobre os álbuns, antes da junção com os artistas.
+        if query.group_editions {
+          query_builder.push(r#" select distinct on ("a"."release_group_id") "a".*, "#);
+        } else {
+          query_builder.push(r#" select "a".*, "#);
+        }
+
+        // Relevância da busca textual; sem busca, todos os álbuns empatam.
+        if let Some(search) = &query.search {
+          search_rank!(query_builder, search.clone(), r#""a""#);
+        } else {
+          query_builder.push("0::REAL");
+        }
+
+        query_builder.push(r#" as "search_rank", "#);
+
+        match &query.sort {
+          Some(sort) => query_builder.push(sort_column(&sort.field)),
+          None => query_builder.push("NULL"),
+        };
+
+        query_builder.push(r#" as "sort_key" from "album" "a" "#);
+
+        push_filters(&mut query_builder, query);
+
+        if query.group_editions {
+          query_builder.push(
+            r#" order by "a"."release_group_id", "a"."release_date" asc nulls last, "a"."created_at", "a"."id" "#,
+          );
+        }
 
         let page = crate::shared::paged::PageQueryParams::from(query.page.clone());
 
+        let order = match &query.sort {
+          Some(sort) => format!(
+            r#""a"."sort_key" {} NULLS LAST, "a"."search_rank" desc, "a"."name", "a"."id""#,
+            sort.direction.to_sql()
+          ),
+          None => String::from(r#""a"."search_rank" desc, "a"."name", "a"."id""#),
+        };
+
         query_builder
+          .push(r#" ) "a" order by "#)
+          .push(&order)
           .push(" LIMIT ")
-          .push_bind(page.take as i16)
+          .push_bind(i64::try_from(page.take).unwrap_or(i64::MAX))
           .push(" OFFSET ")
-          .push_bind(page.skip as i16);
+          .push_bind(i64::try_from(page.skip).unwrap_or(i64::MAX))
+          .push(
+            r#"
+            ) "a"
+            left join "album_artist" "aa2" on "aa2"."album_id" = "a"."id"
+            left join "artist" "ar" on "ar"."id" = "aa2"."artist_id" and "ar"."deleted_at" is null
+            order by "#,
+          )
+          .push(&order);
 
         query_builder
           .build_query_as::<Record>()
@@ -398,6 +707,17 @@ impl domain::album::repository::AlbumRepository for SqlxAlbumRepository {
                     .slug(Slug::from(album.slug.clone()))
                     .parental_rating(album.parental_rating as u8)
                     .release_date(album.release_date)
+                    .label(album.label.clone())
+                    .catalog_number(album.catalog_number.clone())
+                    .barcode(
+                      album
+                        .barcode
+                        .as_ref()
+                        .and_then(|barcode| Barcode::new(barcode.clone()).ok()),
+                    )
+                    .release_country(album.release_country.clone())
+                    .release_group_id(UUID4::from(album.release_group_id))
+                    .edition(album.edition.clone())
                     .created_at(album.created_at)
                     .updated_at(album.updated_at)
                     .artist_ids(
@@ -413,11 +733,18 @@ impl domain::album::repository::AlbumRepository for SqlxAlbumRepository {
                       row.artist_id.map(|id| {
                         Artist::builder()
                           .id(UUID4::from(id))
-                          .name(row.artist_name.clone())
-                          .slug(Slug::from(row.artist_slug.clone()))
+                          .name(row.artist_name.clone().unwrap_or_default())
+                          .slug(Slug::from(row.artist_slug.clone().unwrap_or_default()))
                           .country(row.artist_country.clone())
-                          .created_at(row.artist_created_at)
-                          .updated_at(row.artist_updated_at)
+                          .artist_type(
+                            row
+                              .artist_type
+                              .as_ref()
+                              .and_then(|value| value.parse().ok())
+                              .unwrap_or_default(),
+                          )
+                          .created_at(row.artist_created_at.unwrap_or_default())
+                          .updated_at(row.artist_updated_at.unwrap_or_default())
                           .build()
                       })
                     })
@@ -431,6 +758,61 @@ impl domain::album::repository::AlbumRepository for SqlxAlbumRepository {
 
     Ok(Paged::from_tuple((count?, items?), query.page.clone()))
   }
+
+  async fn facets(
+    &mut self,
+    query: &domain::album::repository::FindAllQuery,
+    fields: &[domain::album::repository::FacetField],
+  ) -> Result<
+    Vec<crate::shared::facet::Facet<domain::album::repository::FacetField>>,
+    domain::album::repository::Error,
+  > {
+    use domain::album::repository::FacetField;
+
+    // Com edições agrupadas, cada grupo de lançamento conta uma única vez.
+    let count = if query.group_editions {
+      r#""f"."release_group_id""#
+    } else {
+      r#""f"."id""#
+    };
+
+    let db = &self.db;
+
+    futures::future::try_join_all(fields.iter().map(|field| async move {
+      let column = match field {
+        FacetField::AlbumType => &FacetColumn {
+          value: r#""f"."album_type"::TEXT"#,
+          label: "NULL::TEXT",
+          join: "",
+        },
+        FacetField::ParentalRating => &facet::PARENTAL_RATING,
+        FacetField::ReleaseYear => &facet::RELEASE_YEAR,
+        FacetField::ReleaseDecade => &facet::RELEASE_DECADE,
+        FacetField::Genre => &FacetColumn {
+          value: r#""g"."id"::TEXT"#,
+          label: r#""g"."name""#,
+          join: r#"
+            INNER JOIN "media_album" "ma" ON "ma"."album_id" = "f"."id"
+            INNER JOIN "media" "m" ON "m"."id" = "ma"."media_id" AND "m"."deleted_at" IS NULL
+            INNER JOIN "media_genre" "mg" ON "mg"."media_id" = "ma"."media_id"
+            INNER JOIN "genre" "g" ON "g"."id" = "mg"."genre_id" AND "g"."deleted_at" IS NULL
+          "#,
+        },
+      };
+
+      facet::fetch_facet(db, column, count, |query_builder| {
+        query_builder.push(r#"SELECT "a".* FROM "album" "a" "#);
+        push_filters(query_builder, query);
+      })
+      .await
+      .map(|values| crate::shared::facet::Facet {
+        field: *field,
+        values,
+      })
+      .map_err(|err| domain::album::repository::Error::DatabaseError(err.to_string()))
+    }))
+    .await
+  }
 }
 
 #[cfg(test)]
@@ -1190,4 +1572,466 @@ mod tests {
       "Album retornado não é o mesmo que foi criado"
     );
   }
+
+  #[tokio::test]
+  async fn test_release_groups() {
+    // Load .env file
+    dotenvy::dotenv().ok();
+
+    const ORIGINAL_ID: &str = "5f0d8a3e-1c1b-4c55-9f3e-7d2a1b6c0a01";
+    const DELUXE_ID: &str = "5f0d8a3e-1c1b-4c55-9f3e-7d2a1b6c0a02";
+    const LABEL: &str = "Test Release Groups Records";
+
+    async fn cleanup(db: &sqlx::pool::Pool<Postgres>) {
+      sqlx::query!(
+        r#"
+        DELETE FROM "album" WHERE "id" IN ($1, $2)
+      "#,
+        uuid::Uuid::parse_str(ORIGINAL_ID).unwrap(),
+        uuid::Uuid::parse_str(DELUXE_ID).unwrap()
+      )
+      .execute(db)
+      .await
+      .expect("Erro ao excluir dados de teste da tabela album");
+    }
+
+    let app_state = AppState::default().await;
+
+    cleanup(&app_state.db).await;
+
+    let mut album_repository = SqlxAlbumRepository::new(&app_state);
+
+    let original = domain::album::Album::builder()
+      .id(UUID4::new(ORIGINAL_ID).unwrap())
+      .release_group_id(UUID4::new(ORIGINAL_ID).unwrap())
+      .name(String::from("test_release_group"))
+      .slug(Slug::new("test-release-group").unwrap())
+      .label(Some(String::from(LABEL)))
+      .catalog_number(Some(String::from("TRG-001")))
+      .barcode(Some(Barcode::new("4006381333931").unwrap()))
+      .release_country(Some(String::from("BR")))
+      .release_date(chrono::NaiveDate::from_ymd_opt(2001, 1, 1))
+      .build();
+
+    let deluxe = domain::album::AlbumBuilder::from(original.clone())
+      .id(UUID4::new(DELUXE_ID).unwrap())
+      .name(String::from("test_release_group (Deluxe)"))
+      .slug(Slug::new("test-release-group-deluxe").unwrap())
+      .edition(Some(String::from("Deluxe")))
+      .barcode(None)
+      .release_date(chrono::NaiveDate::from_ymd_opt(2011, 1, 1))
+      .build();
+
+    album_repository
+      .create(&original)
+      .await
+      .expect("Erro ao criar album");
+    album_repository
+      .create(&deluxe)
+      .await
+      .expect("Erro ao criar edição deluxe");
+
+    let editions = album_repository
+      .find_by_release_group_id(original.release_group_id())
+      .await
+      .expect("Erro ao buscar edições");
+
+    let query = |group_editions| domain::album::repository::FindAllQuery {
+      page: shared::paged::RequestPageParams {
+        page: 1,
+        per_page: 10,
+      },
+      name: None,
+      slug: None,
+      artist_ids: None,
+      album_type: None,
+      release_date: None,
+      min_release_date: None,
+      max_release_date: None,
+      parental_rating: None,
+      min_parental_rating: None,
+      max_parental_rating: None,
+      search: None,
+      sort: None,
+      label: Some(LABEL.to_lowercase()),
+      barcode: None,
+      release_country: None,
+      release_group_id: None,
+      group_editions,
+    };
+
+    let by_label = album_repository
+      .find_by(&query(false))
+      .await
+      .expect("Erro ao filtrar por gravadora");
+    let grouped = album_repository
+      .find_by(&query(true))
+      .await
+      .expect("Erro ao agrupar edições");
+
+    cleanup(&app_state.db).await;
+
+    assert_eq!(
+      editions,
+      vec![original.clone(), deluxe],
+      "Edições do grupo incorretas"
+    );
+    assert_eq!(by_label.total_items, 2, "Filtro por gravadora incorreto");
+    assert_eq!(grouped.total_items, 1, "Edições não foram agrupadas");
+    assert_eq!(
+      grouped.items[0].clone().into_parts().0.id(),
+      original.id(),
+      "O grupo deveria ser representado pela edição mais antiga"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_sort() {
+    // Load .env file
+    dotenvy::dotenv().ok();
+
+    const OLDER_ID: &str = "5f0d8a3e-1c1b-4c55-9f3e-7d2a1b6c0a03";
+    const NEWER_ID: &str = "5f0d8a3e-1c1b-4c55-9f3e-7d2a1b6c0a04";
+    const LABEL: &str = "Test Sort Records";
+
+    async fn cleanup(db: &sqlx::pool::Pool<Postgres>) {
+      sqlx::query!(
+        r#"DELETE FROM "album" WHERE "id" IN ($1, $2)"#,
+        uuid::Uuid::parse_str(OLDER_ID).unwrap(),
+        uuid::Uuid::parse_str(NEWER_ID).unwrap()
+      )
+      .execute(db)
+      .await
+      .expect("Erro ao excluir dados de teste da tabela album");
+    }
+
+    let app_state = AppState::default().await;
+
+    cleanup(&app_state.db).await;
+
+    let mut album_repository = SqlxAlbumRepository::new(&app_state);
+
+    let older = domain::album::Album::builder()
+      .id(UUID4::new(OLDER_ID).unwrap())
+      .release_group_id(UUID4::new(OLDER_ID).unwrap())
+      .name(String::from("B test_sort"))
+      .slug(Slug::new("test-sort-older").unwrap())
+      .label(Some(String::from(LABEL)))
+      .release_date(chrono::NaiveDate::from_ymd_opt(1990, 1, 1))
+      .build();
+
+    let newer = domain::album::Album::builder()
+      .id(UUID4::new(NEWER_ID).unwrap())
+      .release_group_id(UUID4::new(NEWER_ID).unwrap())
+      .name(String::from("A test_sort"))
+      .slug(Slug::new("test-sort-newer").unwrap())
+      .label(Some(String::from(LABEL)))
+      .release_date(chrono::NaiveDate::from_ymd_opt(2020, 1, 1))
+      .build();
+
+    for album in [&older, &newer] {
+      album_repository
+        .create(album)
+        .await
+        .expect("Erro ao criar album");
+    }
+
+    let mut find_sorted = async |sort: &str| {
+      album_repository
+        .find_by(&domain::album::repository::FindAllQuery {
+          page: shared::paged::RequestPageParams {
+            page: 1,
+            per_page: 10,
+          },
+          sort: Some(sort.parse().unwrap()),
+          name: None,
+          slug: None,
+          artist_ids: None,
+          album_type: None,
+          release_date: None,
+          min_release_date: None,
+          max_release_date: None,
+          parental_rating: None,
+          min_parental_rating: None,
+          max_parental_rating: None,
+          search: None,
+          label: Some(String::from(LABEL)),
+          barcode: None,
+          release_country: None,
+          release_group_id: None,
+          group_editions: false,
+        })
+        .await
+        .expect("Erro ao buscar albums ordenados")
+        .items
+        .into_iter()
+        .map(|album| album.into_parts().0.id().clone())
+        .collect::<Vec<_>>()
+    };
+
+    let by_name = find_sorted("name").await;
+    let by_release_date = find_sorted("-release_date").await;
+    let by_popularity = find_sorted("-popularity").await;
+
+    cleanup(&app_state.db).await;
+
+    assert_eq!(
+      by_name,
+      vec![newer.id().clone(), older.id().clone()],
+      "Albums deveriam estar ordenados pelo nome"
+    );
+    assert_eq!(
+      by_release_date,
+      vec![newer.id().clone(), older.id().clone()],
+      "Albums deveriam estar ordenados pela data de lançamento decrescente"
+    );
+    assert_eq!(
+      by_popularity.len(),
+      2,
+      "A ordenação por popularidade não deveria alterar os resultados"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_facets() {
+    // Load .env file
+    dotenvy::dotenv().ok();
+
+    const ALBUM_IDS: [&str; 3] = [
+      "c4a1e0f2-6d3b-4f7a-9b1e-2a5c8d0f1a01",
+      "c4a1e0f2-6d3b-4f7a-9b1e-2a5c8d0f1a02",
+      "c4a1e0f2-6d3b-4f7a-9b1e-2a5c8d0f1a03",
+    ];
+    const LABEL: &str = "Test Facets Records";
+
+    async fn cleanup(db: &sqlx::pool::Pool<Postgres>) {
+      sqlx::query!(r#"DELETE FROM "album" WHERE "label" = $1"#, LABEL)
+        .execute(db)
+        .await
+        .expect("Erro ao excluir dados de teste da tabela album");
+    }
+
+    let app_state = AppState::default().await;
+
+    cleanup(&app_state.db).await;
+
+    let mut album_repository = SqlxAlbumRepository::new(&app_state);
+
+    for (index, (album_type, year)) in [
+      (domain::album::AlbumType::Album, 1991),
+      (domain::album::AlbumType::Album, 1999),
+      (domain::album::AlbumType::EP, 2004),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+      album_repository
+        .create(
+          &domain::album::Album::builder()
+            .id(UUID4::new(ALBUM_IDS[index]).unwrap())
+            .release_group_id(UUID4::new(ALBUM_IDS[index]).unwrap())
+            .name(format!("test_facets {}", index))
+            .slug(Slug::new(format!("test-facets-{}", index)).unwrap())
+            .album_type(album_type)
+            .label(Some(String::from(LABEL)))
+            .release_date(chrono::NaiveDate::from_ymd_opt(year, 1, 1))
+            .build(),
+        )
+        .await
+        .expect("Erro ao criar album");
+    }
+
+    let facets = album_repository
+      .facets(
+        &domain::album::repository::FindAllQuery {
+          page: shared::paged::RequestPageParams {
+            page: 1,
+            per_page: 10,
+          },
+          sort: None,
+          name: None,
+          slug: None,
+          artist_ids: None,
+          album_type: None,
+          release_date: None,
+          min_release_date: None,
+          max_release_date: None,
+          parental_rating: None,
+          min_parental_rating: None,
+          max_parental_rating: None,
+          search: None,
+          label: Some(String::from(LABEL)),
+          barcode: None,
+          release_country: None,
+          release_group_id: None,
+          group_editions: false,
+        },
+        &[
+          domain::album::repository::FacetField::AlbumType,
+          domain::album::repository::FacetField::ReleaseDecade,
+        ],
+      )
+      .await
+      .expect("Erro ao buscar facetas dos albums");
+
+    cleanup(&app_state.db).await;
+
+    let counts = |index: usize| {
+      facets[index]
+        .values
+        .iter()
+        .map(|value| (value.value.as_str(), value.label.as_deref(), value.count))
+        .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+      counts(0),
+      vec![("Album", None, 2), ("EP", None, 1)],
+      "Os albums deveriam ser contados por tipo"
+    );
+    assert_eq!(
+      counts(1),
+      vec![("1990", Some("1990s"), 2), ("2000", Some("2000s"), 1)],
+      "Os albums deveriam ser contados por década de lançamento"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_list_filters() {
+    use crate::shared::filter::{ListFilter, MatchMode};
+
+    // Load .env file
+    dotenvy::dotenv().ok();
+
+    const ARTIST_IDS: [&str; 2] = [
+      "e1d2c3b4-0a1b-4c2d-8e3f-5a6b7c8d9e01",
+      "e1d2c3b4-0a1b-4c2d-8e3f-5a6b7c8d9e02",
+    ];
+    const SOLO_ID: &str = "e1d2c3b4-0a1b-4c2d-8e3f-5a6b7c8d9e11";
+    const DUET_ID: &str = "e1d2c3b4-0a1b-4c2d-8e3f-5a6b7c8d9e12";
+    const LABEL: &str = "Test List Filters Records";
+
+    async fn cleanup(db: &sqlx::pool::Pool<Postgres>) {
+      sqlx::query!(r#"DELETE FROM "album" WHERE "label" = $1"#, LABEL)
+        .execute(db)
+        .await
+        .expect("Erro ao excluir dados de teste da tabela album");
+
+      sqlx::query!(
+        r#"DELETE FROM "artist" WHERE "id" IN ($1, $2)"#,
+        uuid::Uuid::parse_str(ARTIST_IDS[0]).unwrap(),
+        uuid::Uuid::parse_str(ARTIST_IDS[1]).unwrap()
+      )
+      .execute(db)
+      .await
+      .expect("Erro ao excluir dados de teste da tabela artist");
+    }
+
+    let app_state = AppState::default().await;
+
+    cleanup(&app_state.db).await;
+
+    let mut artist_repository = infra::sqlx::SqlxArtistRepository::new(&app_state);
+    let mut album_repository = SqlxAlbumRepository::new(&app_state);
+
+    for (index, id) in ARTIST_IDS.iter().enumerate() {
+      artist_repository
+        .create(
+          &domain::artist::Artist::builder()
+            .id(UUID4::new(*id).unwrap())
+            .name(format!("test_list_filters {}", index))
+            .slug(Slug::new(format!("test-list-filters-{}", index)).unwrap())
+            .build(),
+        )
+        .await
+        .expect("Erro ao criar artista");
+    }
+
+    let artist_ids = ARTIST_IDS.map(|id| UUID4::new(id).unwrap());
+
+    for (id, artists, album_type) in [
+      (SOLO_ID, &artist_ids[..1], domain::album::AlbumType::Album),
+      (DUET_ID, &artist_ids[..], domain::album::AlbumType::EP),
+    ] {
+      album_repository
+        .create(
+          &domain::album::Album::builder()
+            .id(UUID4::new(id).unwrap())
+            .release_group_id(UUID4::new(id).unwrap())
+            .name(format!("test_list_filters {}", id))
+            .slug(Slug::new(format!("test-list-filters-{}", id)).unwrap())
+            .album_type(album_type)
+            .label(Some(String::from(LABEL)))
+            .artist_ids(artists.iter().cloned().collect())
+            .build(),
+        )
+        .await
+        .expect("Erro ao criar album");
+    }
+
+    let mut find = async |artist_ids: &str, mode: MatchMode, album_type: Option<&str>| {
+      let mut ids = album_repository
+        .find_by(&domain::album::repository::FindAllQuery {
+          page: shared::paged::RequestPageParams {
+            page: 1,
+            per_page: 10,
+          },
+          sort: None,
+          name: None,
+          slug: None,
+          artist_ids: Some(ListFilter::parse(artist_ids, mode).unwrap()),
+          album_type: album_type.map(|album_type| ListFilter::parse(album_type, mode).unwrap()),
+          release_date: None,
+          min_release_date: None,
+          max_release_date: None,
+          parental_rating: None,
+          min_parental_rating: None,
+          max_parental_rating: None,
+          search: None,
+          label: Some(String::from(LABEL)),
+          barcode: None,
+          release_country: None,
+          release_group_id: None,
+          group_editions: false,
+        })
+        .await
+        .expect("Erro ao buscar albums filtrados")
+        .items
+        .into_iter()
+        .map(|album| album.into_parts().0.id().to_string())
+        .collect::<Vec<_>>();
+
+      ids.sort();
+      ids
+    };
+
+    let both = format!("{},{}", ARTIST_IDS[0], ARTIST_IDS[1]);
+    let any = find(&both, MatchMode::Any, None).await;
+    let all = find(&both, MatchMode::All, None).await;
+    let excluded = find(&format!("!{}", ARTIST_IDS[1]), MatchMode::Any, None).await;
+    let not_ep = find(ARTIST_IDS[0], MatchMode::Any, Some("!EP")).await;
+
+    cleanup(&app_state.db).await;
+
+    assert_eq!(
+      any,
+      vec![SOLO_ID, DUET_ID],
+      "Deveriam ser retornados os albums de qualquer um dos artistas"
+    );
+    assert_eq!(
+      all,
+      vec![DUET_ID],
+      "Deveriam ser retornados apenas os albums com todos os artistas"
+    );
+    assert_eq!(
+      excluded,
+      vec![SOLO_ID],
+      "Albums do artista excluído não deveriam ser retornados"
+    );
+    assert_eq!(
+      not_ep,
+      vec![SOLO_ID],
+      "Albums do tipo excluído não deveriam ser retornados"
+    );
+  }
 }
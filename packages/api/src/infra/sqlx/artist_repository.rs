@@ -1,10 +1,15 @@
-use domain::artist::{repository::FindAllQuery, Artist};
+use std::collections::{HashMap, HashSet};
+
+use domain::artist::{
+  repository::{FindAllQuery, SortField},
+  Artist, ArtistMember,
+};
 use shared::{
   paged::{PageQueryParams, Paged},
   util::trim_datetime,
   vo::UUID4,
 };
-use sqlx::{query, Postgres};
+use sqlx::Postgres;
 
 use crate::*;
 
@@ -20,26 +25,189 @@ impl SqlxArtistRepository {
   }
 }
 
+fn to_database_error(error: sqlx::error::Error) -> crate::domain::artist::repository::Error {
+  crate::domain::artist::repository::Error::DatabaseError(error.to_string())
+}
+
+fn sort_column(field: &SortField) -> &'static str {
+  match field {
+    SortField::Name => r#""name""#,
+    SortField::CreatedAt => r#""created_at""#,
+    SortField::UpdatedAt => r#""updated_at""#,
+    SortField::Popularity => {
+      r#"(SELECT COUNT(*) FROM "media_interpreter" "mi" INNER JOIN "media" "m" ON "m"."id" = "mi"."media_id" WHERE "mi"."interpreter_id" = "artist"."id" AND "m"."deleted_at" IS NULL)"#
+    }
+  }
+}
+
+#[derive(sqlx::FromRow)]
+struct ArtistRecord {
+  id: uuid::Uuid,
+  name: String,
+  slug: String,
+  country: Option<String>,
+  artist_type: String,
+  created_at: chrono::NaiveDateTime,
+  updated_at: chrono::NaiveDateTime,
+}
+
+impl From<ArtistRecord> for Artist {
+  fn from(record: ArtistRecord) -> Self {
+    Artist::builder()
+      .id(UUID4::from(record.id))
+      .name(record.name)
+      .slug(shared::vo::Slug::from(record.slug))
+      .country(record.country)
+      .artist_type(record.artist_type.parse().unwrap_or_default())
+      .created_at(trim_datetime(record.created_at))
+      .updated_at(trim_datetime(record.updated_at))
+      .build()
+  }
+}
+
+impl SqlxArtistRepository {
+  /**
+   * Carrega os apelidos e membros dos artistas informados.
+   */
+  async fn load_relations(
+    &self,
+    artists: Vec<ArtistRecord>,
+  ) -> Result<Vec<Artist>, crate::domain::artist::repository::Error> {
+    let mut artists = artists.into_iter().map(Artist::from).collect::<Vec<_>>();
+
+    if artists.is_empty() {
+      return Ok(artists);
+    }
+
+    let ids = artists
+      .iter()
+      .map(|artist| uuid::Uuid::from(artist.id().clone()))
+      .collect::<Vec<_>>();
+
+    let (aliases, members) = tokio::try_join!(
+      sqlx::query!(
+        r#"SELECT "artist_id", "name" FROM "artist_alias" WHERE "artist_id" = ANY($1)"#,
+        &ids
+      )
+      .fetch_all(&self.db),
+      sqlx::query!(
+        r#"
+          SELECT "group_id", "member_id", "started_at", "ended_at"
+          FROM "artist_membership"
+          WHERE "group_id" = ANY($1)
+          ORDER BY "started_at" NULLS FIRST, "member_id"
+        "#,
+        &ids
+      )
+      .fetch_all(&self.db),
+    )
+    .map_err(to_database_error)?;
+
+    let mut aliases_by_artist: HashMap<uuid::Uuid, HashSet<String>> = HashMap::new();
+
+    for alias in aliases {
+      aliases_by_artist
+        .entry(alias.artist_id)
+        .or_default()
+        .insert(alias.name);
+    }
+
+    let mut members_by_group: HashMap<uuid::Uuid, Vec<ArtistMember>> = HashMap::new();
+
+    for member in members {
+      members_by_group
+        .entry(member.group_id)
+        .or_default()
+        .push(ArtistMember {
+          artist_id: UUID4::from(member.member_id),
+          started_at: member.started_at,
+          ended_at: member.ended_at,
+        });
+    }
+
+    for artist in artists.iter_mut() {
+      let id = uuid::Uuid::from(artist.id().clone());
+
+      *artist.aliases_mut() = aliases_by_artist.remove(&id).unwrap_or_default();
+      *artist.members_mut() = members_by_group.remove(&id).unwrap_or_default();
+    }
+
+    Ok(artists)
+  }
+
+  async fn save_relations(trx: &mut sqlx::PgConnection, input: &Artist) -> Result<(), sqlx::Error> {
+    let id = uuid::Uuid::from(input.id().clone());
+
+    sqlx::query!(r#"DELETE FROM "artist_alias" WHERE "artist_id" = $1"#, id)
+      .execute(&mut *trx)
+      .await?;
+
+    for alias in input.aliases() {
+      sqlx::query!(
+        r#"INSERT INTO "artist_alias" ("artist_id", "name") VALUES ($1, $2)"#,
+        id,
+        alias
+      )
+      .execute(&mut *trx)
+      .await?;
+    }
+
+    sqlx::query!(
+      r#"DELETE FROM "artist_membership" WHERE "group_id" = $1"#,
+      id
+    )
+    .execute(&mut *trx)
+    .await?;
+
+    for member in input.members() {
+      sqlx::query!(
+        r#"
+          INSERT INTO "artist_membership" ("group_id", "member_id", "started_at", "ended_at")
+          VALUES ($1, $2, $3, $4)
+        "#,
+        id,
+        uuid::Uuid::from(member.artist_id.clone()),
+        member.started_at,
+        member.ended_at,
+      )
+      .execute(&mut *trx)
+      .await?;
+    }
+
+    Ok(())
+  }
+}
+
 impl domain::artist::repository::ArtistRepository for SqlxArtistRepository {
   async fn create(
     &mut self,
     input: &crate::domain::artist::Artist,
   ) -> Result<crate::domain::artist::Artist, crate::domain::artist::repository::Error> {
+    let mut trx = self.db.begin().await.map_err(to_database_error)?;
+
     sqlx::query!(
       r#"
-        INSERT INTO "artist" ("id", "name", "slug", "country", "created_at", "updated_at")
-        VALUES ($1, $2, $3, $4, $5, $6)
+        INSERT INTO "artist" ("id", "name", "slug", "country", "artist_type", "created_at", "updated_at")
+        VALUES ($1, $2, $3, $4, $5::"artist_type", $6, $7)
       "#,
       Into::<uuid::Uuid>::into(input.id().clone()),
       input.name(),
       input.slug().to_string(),
       input.country().clone(),
+      input.artist_type().to_string() as _,
       trim_datetime(*input.created_at()),
       trim_datetime(*input.updated_at()),
     )
-    .execute(&self.db)
+    .execute(&mut *trx)
     .await
-    .map_err(|err| domain::artist::repository::Error::DatabaseError(err.to_string()))?;
+    .map_err(to_database_error)?;
+
+    Self::save_relations(&mut trx, input)
+      .await
+      .map_err(to_database_error)?;
+
+    trx.commit().await.map_err(to_database_error)?;
+
     Ok(input.clone())
   }
 
@@ -47,21 +215,31 @@ impl domain::artist::repository::ArtistRepository for SqlxArtistRepository {
     &mut self,
     input: &crate::domain::artist::Artist,
   ) -> Result<crate::domain::artist::Artist, crate::domain::artist::repository::Error> {
+    let mut trx = self.db.begin().await.map_err(to_database_error)?;
+
     sqlx::query!(
       r#"
         UPDATE "artist"
-        SET "name" = $2, "slug" = $3, "country" = $4, "updated_at" = $5
+        SET "name" = $2, "slug" = $3, "country" = $4, "artist_type" = $5::"artist_type", "updated_at" = $6
         WHERE "id" = $1
       "#,
       Into::<uuid::Uuid>::into(input.id().clone()),
       input.name(),
       input.slug().to_string(),
       input.country().clone(),
+      input.artist_type().to_string() as _,
       trim_datetime(*input.updated_at()),
     )
-    .execute(&self.db)
+    .execute(&mut *trx)
     .await
-    .map_err(|err| domain::artist::repository::Error::DatabaseError(err.to_string()))?;
+    .map_err(to_database_error)?;
+
+    Self::save_relations(&mut trx, input)
+      .await
+      .map_err(to_database_error)?;
+
+    trx.commit().await.map_err(to_database_error)?;
+
     Ok(input.clone())
   }
 
@@ -69,27 +247,25 @@ impl domain::artist::repository::ArtistRepository for SqlxArtistRepository {
     &mut self,
     id: &crate::shared::vo::UUID4,
   ) -> Result<Option<crate::domain::artist::Artist>, crate::domain::artist::repository::Error> {
-    let artist = query!(
+    let artist = sqlx::query_as!(
+      ArtistRecord,
       r#"
-        SELECT "id", "name", "slug", "country", "created_at", "updated_at"
+        SELECT "id", "name", "slug", "country", "artist_type" as "artist_type: String", "created_at", "updated_at"
         FROM "artist"
-        WHERE "id" = $1
+        WHERE "id" = $1 AND "deleted_at" IS NULL
       "#,
       Into::<uuid::Uuid>::into(id.clone()),
     )
     .fetch_optional(&self.db)
     .await
-    .map_err(|err| domain::artist::repository::Error::DatabaseError(err.to_string()))?;
-    Ok(artist.map(|artist| {
-      crate::domain::artist::Artist::builder()
-        .id(shared::vo::UUID4::new(artist.id).unwrap_or_default())
-        .name(artist.name)
-        .slug(shared::vo::Slug::new(&artist.slug).unwrap_or_default())
-        .country(artist.country)
-        .created_at(trim_datetime(artist.created_at))
-        .updated_at(trim_datetime(artist.updated_at))
-        .build()
-    }))
+    .map_err(to_database_error)?;
+
+    Ok(
+      self
+        .load_relations(artist.into_iter().collect())
+        .await?
+        .pop(),
+    )
   }
 
   async fn delete_by_id(
@@ -98,53 +274,215 @@ impl domain::artist::repository::ArtistRepository for SqlxArtistRepository {
   ) -> Result<(), crate::domain::artist::repository::Error> {
     sqlx::query!(
       r#"
-        DELETE FROM "artist"
+        UPDATE "artist"
+        SET "deleted_at" = now()
+        WHERE "id" = $1 AND "deleted_at" IS NULL
+      "#,
+      Into::<uuid::Uuid>::into(id.clone()),
+    )
+    .execute(&self.db)
+    .await
+    .map_err(to_database_error)?;
+    Ok(())
+  }
+
+  async fn find_deleted_by_id(
+    &mut self,
+    id: &UUID4,
+  ) -> Result<Option<Artist>, domain::artist::repository::Error> {
+    let artist = sqlx::query_as!(
+      ArtistRecord,
+      r#"
+        SELECT "id", "name", "slug", "country", "artist_type" as "artist_type: String", "created_at", "updated_at"
+        FROM "artist"
+        WHERE "id" = $1 AND "deleted_at" IS NOT NULL
+      "#,
+      Into::<uuid::Uuid>::into(id.clone()),
+    )
+    .fetch_optional(&self.db)
+    .await
+    .map_err(to_database_error)?;
+
+    Ok(
+      self
+        .load_relations(artist.into_iter().collect())
+        .await?
+        .pop(),
+    )
+  }
+
+  async fn restore_by_id(&mut self, id: &UUID4) -> Result<(), domain::artist::repository::Error> {
+    sqlx::query!(
+      r#"
+        UPDATE "artist"
+        SET "deleted_at" = NULL
         WHERE "id" = $1
       "#,
       Into::<uuid::Uuid>::into(id.clone()),
     )
     .execute(&self.db)
     .await
-    .map_err(|err| domain::artist::repository::Error::DatabaseError(err.to_string()))?;
+    .map_err(to_database_error)?;
     Ok(())
   }
 
+  async fn deletion_impact(
+    &mut self,
+    id: &UUID4,
+  ) -> Result<domain::activity::DeletionImpact, domain::artist::repository::Error> {
+    let result = sqlx::query!(
+      r#"
+        WITH "media_ids" AS (
+          SELECT "m"."id"
+          FROM "media" "m"
+          WHERE "m"."deleted_at" IS NULL
+            AND "m"."id" IN (
+              SELECT "media_id" FROM "media_interpreter" WHERE "interpreter_id" = $1
+              UNION
+              SELECT "media_id" FROM "media_composer" WHERE "composer_id" = $1
+              UNION
+              SELECT "media_id" FROM "media_credit" WHERE "artist_id" = $1
+            )
+        )
+        SELECT
+          (SELECT COUNT(*) FROM "media_ids") AS "medias!",
+          (
+            SELECT COUNT(*)
+            FROM "album_artist" "aa"
+            INNER JOIN "album" "a" ON "a"."id" = "aa"."album_id" AND "a"."deleted_at" IS NULL
+            WHERE "aa"."artist_id" = $1
+          ) AS "albums!",
+          (
+            SELECT COUNT(*)
+            FROM "source" "s"
+            INNER JOIN "media_ids" "mi" ON "mi"."id" = "s"."media_id"
+            WHERE "s"."deleted_at" IS NULL
+          ) AS "sources!",
+          (SELECT COUNT(*) FROM "album_artist" WHERE "artist_id" = $1) AS "album_artist!",
+          (SELECT COUNT(*) FROM "media_interpreter" WHERE "interpreter_id" = $1) AS "media_interpreter!",
+          (SELECT COUNT(*) FROM "media_composer" WHERE "composer_id" = $1) AS "media_composer!",
+          (SELECT COUNT(*) FROM "media_credit" WHERE "artist_id" = $1) AS "media_credit!",
+          (SELECT COUNT(*) FROM "artist_alias" WHERE "artist_id" = $1) AS "artist_alias!",
+          (
+            SELECT COUNT(*) FROM "artist_membership" WHERE "member_id" = $1 OR "group_id" = $1
+          ) AS "artist_membership!"
+      "#,
+      Into::<uuid::Uuid>::into(id.clone()),
+    )
+    .fetch_one(&self.db)
+    .await
+    .map_err(to_database_error)?;
+
+    Ok(domain::activity::DeletionImpact {
+      medias: result.medias as u64,
+      albums: result.albums as u64,
+      sources: result.sources as u64,
+      subgenres: 0,
+      join_rows: std::collections::BTreeMap::from([
+        (String::from("album_artist"), result.album_artist as u64),
+        (
+          String::from("media_interpreter"),
+          result.media_interpreter as u64,
+        ),
+        (String::from("media_composer"), result.media_composer as u64),
+        (String::from("media_credit"), result.media_credit as u64),
+        (String::from("artist_alias"), result.artist_alias as u64),
+        (
+          String::from("artist_membership"),
+          result.artist_membership as u64,
+        ),
+      ]),
+    })
+  }
+
   async fn find_by_slug(
     &mut self,
     slug: &shared::vo::Slug,
   ) -> Result<Option<crate::domain::artist::Artist>, crate::domain::artist::repository::Error> {
-    let artist = query!(
+    let artist = sqlx::query_as!(
+      ArtistRecord,
       r#"
-        SELECT "id", "name", "slug", "country", "created_at", "updated_at"
+        SELECT "id", "name", "slug", "country", "artist_type" as "artist_type: String", "created_at", "updated_at"
         FROM "artist"
-        WHERE "slug" = $1
+        WHERE "slug" = $1 AND "deleted_at" IS NULL
         LIMIT 1
       "#,
       slug.to_string(),
     )
     .fetch_optional(&self.db)
     .await
-    .map_err(|err| domain::artist::repository::Error::DatabaseError(err.to_string()))?;
-
-    Ok(artist.map(|artist| {
-      crate::domain::artist::Artist::builder()
-        .id(UUID4::from(artist.id))
-        .name(artist.name)
-        .slug(shared::vo::Slug::new(&artist.slug).unwrap_or_default())
-        .country(artist.country)
-        .created_at(trim_datetime(artist.created_at))
-        .updated_at(trim_datetime(artist.updated_at))
-        .build()
-    }))
+    .map_err(to_database_error)?;
+
+    Ok(
+      self
+        .load_relations(artist.into_iter().collect())
+        .await?
+        .pop(),
+    )
+  }
+
+  async fn find_by_ids(
+    &mut self,
+    ids: &HashSet<UUID4>,
+  ) -> Result<Vec<Artist>, domain::artist::repository::Error> {
+    if ids.is_empty() {
+      return Ok(Vec::new());
+    }
+
+    let artists = sqlx::query_as!(
+      ArtistRecord,
+      r#"
+        SELECT "id", "name", "slug", "country", "artist_type" as "artist_type: String", "created_at", "updated_at"
+        FROM "artist"
+        WHERE "id" = ANY($1) AND "deleted_at" IS NULL
+      "#,
+      &ids
+        .iter()
+        .map(|id| uuid::Uuid::from(id.clone()))
+        .collect::<Vec<_>>(),
+    )
+    .fetch_all(&self.db)
+    .await
+    .map_err(to_database_error)?;
+
+    self.load_relations(artists).await
+  }
+
+  async fn find_groups_by_member_id(
+    &mut self,
+    member_id: &UUID4,
+  ) -> Result<Vec<Artist>, domain::artist::repository::Error> {
+    let artists = sqlx::query_as!(
+      ArtistRecord,
+      r#"
+        SELECT "a"."id", "a"."name", "a"."slug", "a"."country", "a"."artist_type" as "artist_type: String", "a"."created_at", "a"."updated_at"
+        FROM "artist" "a"
+        INNER JOIN "artist_membership" "am" ON "am"."group_id" = "a"."id"
+        WHERE "am"."member_id" = $1 AND "a"."deleted_at" IS NULL
+        ORDER BY "am"."started_at" NULLS FIRST, "a"."name"
+      "#,
+      uuid::Uuid::from(member_id.clone()),
+    )
+    .fetch_all(&self.db)
+    .await
+    .map_err(to_database_error)?;
+
+    self.load_relations(artists).await
   }
 
   async fn find_all(
     &mut self,
     query: &FindAllQuery,
   ) -> Result<Paged<domain::artist::Artist>, domain::artist::repository::Error> {
-    let artist_filter = create_filter!(FindAllQuery, builder => {
+    let artist_filter = create_filter!(FindAllQuery, builder, r#""deleted_at" IS NULL"# => {
       country(value) => builder.push(r#""country" = "#).push_bind(value.clone()),
-      search(value) => search_by!(builder, value.clone(), "name", "slug"),
+      artist_type(value) => builder.push(r#""artist_type" = "#).push_bind(value.to_string()).push(r#"::"artist_type""#),
+      search(value) => {
+        search_by!(builder, value.clone(), r#""artist""#);
+        builder.push(r#" OR EXISTS (SELECT 1 FROM "artist_alias" WHERE "artist_alias"."artist_id" = "artist"."id" AND ("#);
+        search_by_name!(builder, value.clone(), r#""artist_alias""#);
+        builder.push("))");
+      },
     });
 
     let (count, items) = tokio::join!(
@@ -157,56 +495,43 @@ impl domain::artist::repository::ArtistRepository for SqlxArtistRepository {
           .build_query_scalar::<Option<i64>>()
           .fetch_one(&self.db)
           .await
-          .map_err(|err| domain::artist::repository::Error::DatabaseError(err.to_string()))
+          .map_err(to_database_error)
           .map(|result| result.unwrap_or(0) as usize)
       },
       async {
-        #[derive(sqlx::FromRow)]
-        struct Record {
-          id: uuid::Uuid,
-          name: String,
-          slug: String,
-          country: Option<String>,
-          created_at: chrono::NaiveDateTime,
-          updated_at: chrono::NaiveDateTime,
-        }
         let page_params = PageQueryParams::from(query.page.clone());
 
         let mut builder = sqlx::QueryBuilder::new(
-          r#"SELECT "id", "name", "slug", "country", "created_at", "updated_at" FROM "artist""#,
+          r#"SELECT "id", "name", "slug", "country", "artist_type"::TEXT, "created_at", "updated_at" FROM "artist""#,
         );
 
         artist_filter(&mut builder, query);
 
+        if let Some(sort) = &query.sort {
+          order_by!(builder, sort, sort_column(&sort.field), r#""name", "id""#);
+        } else if let Some(search) = &query.search {
+          builder.push(" ORDER BY ");
+          search_rank!(builder, search.clone(), r#""artist""#);
+          builder.push(r#" DESC, "name", "id""#);
+        }
+
         builder
           .push(" LIMIT ")
-          .push_bind(page_params.take as i32)
+          .push_bind(i64::try_from(page_params.take).unwrap_or(i64::MAX))
           .push(" OFFSET ")
-          .push_bind(page_params.skip as i32);
+          .push_bind(i64::try_from(page_params.skip).unwrap_or(i64::MAX));
 
         builder
-          .build_query_as::<Record>()
+          .build_query_as::<ArtistRecord>()
           .fetch_all(&self.db)
           .await
-          .map_err(|err| domain::artist::repository::Error::DatabaseError(err.to_string()))
-          .map(|result| {
-            result
-              .into_iter()
-              .map(|row| {
-                Artist::builder()
-                  .id(UUID4::from(row.id))
-                  .name(row.name)
-                  .slug(shared::vo::Slug::from(row.slug))
-                  .country(row.country)
-                  .created_at(row.created_at)
-                  .updated_at(row.updated_at)
-                  .build()
-              })
-              .collect::<Vec<Artist>>()
-          })
+          .map_err(to_database_error)
       }
     );
-    Ok(Paged::from_tuple((count?, items?), query.page.clone()))
+
+    let items = self.load_relations(items?).await?;
+
+    Ok(Paged::from_tuple((count?, items), query.page.clone()))
   }
 }
 
@@ -489,4 +814,218 @@ pub mod tests {
 
     assert_eq!(result, None, "O artista não foi encontrado");
   }
+
+  #[tokio::test]
+  async fn test_aliases_and_members() {
+    // Load .env file
+    dotenvy::dotenv().ok();
+
+    const PERSON_ID: &str = "5a0e3c1d-8f43-4b7e-9a52-3b1f6d2c7e10";
+    const GROUP_ID: &str = "9c7d2e4f-1b6a-4c3d-8e5f-7a2b9c0d1e21";
+
+    async fn cleanup(db: &sqlx::PgPool) {
+      sqlx::query!(
+        "DELETE FROM artist WHERE id = ANY($1)",
+        &[
+          uuid::Uuid::parse_str(GROUP_ID).unwrap(),
+          uuid::Uuid::parse_str(PERSON_ID).unwrap()
+        ]
+      )
+      .execute(db)
+      .await
+      .expect("Falha ao limpar dados de teste da tabela artist");
+    }
+
+    let app_state = AppState::default().await;
+
+    cleanup(&app_state.db).await;
+
+    let mut artist_repository = SqlxArtistRepository::new(&app_state);
+
+    let person = domain::artist::Artist::builder()
+      .id(shared::vo::UUID4::new(PERSON_ID).unwrap())
+      .name(String::from("Test Person"))
+      .slug(shared::vo::Slug::new("test_aliases_and_members_person").unwrap())
+      .build();
+
+    let mut group = domain::artist::Artist::builder()
+      .id(shared::vo::UUID4::new(GROUP_ID).unwrap())
+      .name(String::from("Test Group"))
+      .slug(shared::vo::Slug::new("test_aliases_and_members_group").unwrap())
+      .artist_type(domain::artist::ArtistType::Group)
+      .aliases(HashSet::from([String::from("Zyxwv Alias")]))
+      .members(vec![ArtistMember {
+        artist_id: person.id().clone(),
+        started_at: chrono::NaiveDate::from_ymd_opt(1990, 1, 1),
+        ended_at: None,
+      }])
+      .build();
+
+    artist_repository
+      .create(&person)
+      .await
+      .expect("Falha ao criar artista");
+
+    artist_repository
+      .create(&group)
+      .await
+      .expect("Falha ao criar grupo");
+
+    let created = artist_repository
+      .find_by_id(group.id())
+      .await
+      .expect("Falha ao buscar grupo")
+      .expect("Grupo não cadastrado");
+
+    let found = artist_repository
+      .find_all(&FindAllQuery {
+        page: shared::paged::RequestPageParams {
+          page: 1,
+          per_page: 10,
+        },
+        search: Some(String::from("zyxwv")),
+        sort: None,
+        country: None,
+        artist_type: None,
+      })
+      .await
+      .expect("Falha ao buscar artistas pelo apelido");
+
+    let groups = artist_repository
+      .find_groups_by_member_id(person.id())
+      .await
+      .expect("Falha ao buscar grupos do artista");
+
+    group.set_members(Vec::new());
+    group.set_aliases(HashSet::new());
+
+    artist_repository
+      .update(&group)
+      .await
+      .expect("Falha ao atualizar grupo");
+
+    let updated = artist_repository
+      .find_by_id(group.id())
+      .await
+      .expect("Falha ao buscar grupo")
+      .expect("Grupo não cadastrado");
+
+    cleanup(&app_state.db).await;
+
+    assert_eq!(
+      created.aliases(),
+      &HashSet::from([String::from("Zyxwv Alias")]),
+      "Os apelidos do grupo não conferem"
+    );
+    assert_eq!(
+      created.members().len(),
+      1,
+      "Os membros do grupo não conferem"
+    );
+    assert_eq!(
+      found
+        .items
+        .iter()
+        .map(|artist| artist.id())
+        .collect::<Vec<_>>(),
+      vec![group.id()],
+      "O grupo não foi encontrado pelo apelido"
+    );
+    assert_eq!(
+      groups.iter().map(|artist| artist.id()).collect::<Vec<_>>(),
+      vec![group.id()],
+      "Os grupos do artista não conferem"
+    );
+    assert_eq!(updated, group, "Os dados do grupo não conferem");
+  }
+
+  #[tokio::test]
+  async fn test_search() {
+    // Load .env file
+    dotenvy::dotenv().ok();
+
+    const FIRST_ID: &str = "0b3e6f34-9c5e-4bb1-9d0e-2f7c4d1f5a01";
+    const SECOND_ID: &str = "0b3e6f34-9c5e-4bb1-9d0e-2f7c4d1f5a02";
+
+    async fn cleanup(db: &sqlx::PgPool) {
+      sqlx::query!(
+        "DELETE FROM artist WHERE id = ANY($1)",
+        &[
+          uuid::Uuid::parse_str(FIRST_ID).unwrap(),
+          uuid::Uuid::parse_str(SECOND_ID).unwrap()
+        ]
+      )
+      .execute(db)
+      .await
+      .expect("Falha ao limpar dados de teste da tabela artist");
+    }
+
+    let app_state = AppState::default().await;
+
+    cleanup(&app_state.db).await;
+
+    let mut artist_repository = SqlxArtistRepository::new(&app_state);
+
+    let first = domain::artist::Artist::builder()
+      .id(shared::vo::UUID4::new(FIRST_ID).unwrap())
+      .name(String::from("Joãozinho Trovão Qwzx"))
+      .slug(shared::vo::Slug::new("test_search_first").unwrap())
+      .build();
+
+    let second = domain::artist::Artist::builder()
+      .id(shared::vo::UUID4::new(SECOND_ID).unwrap())
+      .name(String::from("Trovão Qwzx"))
+      .slug(shared::vo::Slug::new("test_search_second").unwrap())
+      .build();
+
+    for artist in [&first, &second] {
+      artist_repository
+        .create(artist)
+        .await
+        .expect("Falha ao criar artista");
+    }
+
+    let mut search = async |value: &str| {
+      artist_repository
+        .find_all(&FindAllQuery {
+          page: shared::paged::RequestPageParams {
+            page: 1,
+            per_page: 50,
+          },
+          search: Some(String::from(value)),
+          sort: None,
+          country: None,
+          artist_type: None,
+        })
+        .await
+        .expect("Falha ao buscar artistas")
+        .items
+        .into_iter()
+        .map(|artist| artist.id().clone())
+        .filter(|id| id == first.id() || id == second.id())
+        .collect::<Vec<_>>()
+    };
+
+    let without_accents = search("joaozinho trovao").await;
+    let with_typo = search("Joaozino Trovao Qwzx").await;
+    let ranked = search("trovão qwzx").await;
+
+    cleanup(&app_state.db).await;
+
+    assert_eq!(
+      without_accents.first(),
+      Some(first.id()),
+      "A busca deveria ignorar os acentos"
+    );
+    assert_eq!(
+      with_typo.first(),
+      Some(first.id()),
+      "A busca deveria tolerar erros de digitação"
+    );
+    assert_eq!(
+      ranked,
+      vec![second.id().clone(), first.id().clone()],
+      "Os resultados deveriam estar ordenados por relevância"
+    );
+  }
 }
@@ -0,0 +1,768 @@
+use crate::{
+  domain::{
+    catalog::{
+      AlbumRecord, ArtistRecord, CatalogRepository, CreditRecord, DeletedRecord, Error,
+      ExportQuery, GenreRecord, ImportRecord, LyricsRecord, MediaRecord, MemberRecord,
+      SourceRecord,
+    },
+    lyrics::lyrics_entity::LyricLine,
+  },
+  shared::vo::{Barcode, CollaborativeEntityName, Isrc, Slug, UUID4},
+  AppState,
+};
+
+pub struct SqlxCatalogRepository {
+  db: sqlx::Pool<sqlx::Postgres>,
+}
+
+impl SqlxCatalogRepository {
+  pub fn new(app_state: &AppState) -> Self {
+    Self {
+      db: app_state.db.clone(),
+    }
+  }
+}
+
+fn to_database_error(error: sqlx::error::Error) -> Error {
+  Error::DatabaseError(error.to_string())
+}
+
+/**
+ * Converte uma lista vinda do banco em uma lista opcional, omitida quando vazia.
+ */
+fn non_empty<T>(values: Vec<T>) -> Option<Vec<T>> {
+  if values.is_empty() {
+    None
+  } else {
+    Some(values)
+  }
+}
+
+/**
+ * Registro de exclusão de uma entidade excluída depois de `since`.
+ */
+fn deleted(entity: CollaborativeEntityName, id: uuid::Uuid) -> (UUID4, ImportRecord) {
+  (
+    UUID4::from(id),
+    ImportRecord::Deleted(DeletedRecord {
+      entity,
+      external_id: id.to_string(),
+    }),
+  )
+}
+
+// Na exportação incremental, as entidades excluídas depois de `since` são lidas junto
+// com as alteradas, na mesma ordem, e viram registros de exclusão.
+impl SqlxCatalogRepository {
+  async fn export_genres(&self, query: &ExportQuery) -> Result<Vec<(UUID4, ImportRecord)>, Error> {
+    let rows = sqlx::query!(
+      r#"
+        SELECT "g"."id", "g"."name", "g"."slug", "g"."deleted_at", "p"."slug" as "parent?"
+        FROM "genre" "g"
+        LEFT JOIN "genre" "p" ON "p"."id" = "g"."parent_id" AND "p"."deleted_at" IS NULL
+        WHERE (
+            ("g"."deleted_at" IS NULL AND ($1::TIMESTAMP IS NULL OR "g"."updated_at" > $1))
+            OR "g"."deleted_at" > $1
+          )
+          AND ($2::UUID IS NULL OR "g"."id" > $2)
+        ORDER BY "g"."id"
+        LIMIT $3
+      "#,
+      query.since,
+      query.after.clone().map(uuid::Uuid::from),
+      query.limit as i64
+    )
+    .fetch_all(&self.db)
+    .await
+    .map_err(to_database_error)?;
+
+    Ok(
+      rows
+        .into_iter()
+        .map(|row| {
+          if row.deleted_at.is_some() {
+            return deleted(CollaborativeEntityName::Genre, row.id);
+          }
+
+          (
+            UUID4::from(row.id),
+            ImportRecord::Genre(GenreRecord {
+              external_id: Some(row.id.to_string()),
+              name: row.name,
+              slug: Some(Slug::from(row.slug)),
+              parent: row.parent,
+            }),
+          )
+        })
+        .collect(),
+    )
+  }
+
+  async fn export_artists(&self, query: &ExportQuery) -> Result<Vec<(UUID4, ImportRecord)>, Error> {
+    let rows = sqlx::query!(
+      r#"
+        SELECT "a"."id", "a"."name", "a"."slug", "a"."country", "a"."artist_type"::TEXT as "artist_type!",
+          "a"."deleted_at",
+          ARRAY(
+            SELECT "aa"."name" FROM "artist_alias" "aa"
+            WHERE "aa"."artist_id" = "a"."id"
+            ORDER BY "aa"."name"
+          ) as "aliases!",
+          COALESCE((
+            SELECT json_agg(
+              json_build_object(
+                'artist', "m"."slug",
+                'started_at', "am"."started_at",
+                'ended_at', "am"."ended_at"
+              )
+              ORDER BY "am"."started_at" NULLS FIRST, "am"."member_id"
+            )
+            FROM "artist_membership" "am"
+            JOIN "artist" "m" ON "m"."id" = "am"."member_id" AND "m"."deleted_at" IS NULL
+            WHERE "am"."group_id" = "a"."id"
+          ), '[]') as "members!"
+        FROM "artist" "a"
+        WHERE (
+            ("a"."deleted_at" IS NULL AND ($1::TIMESTAMP IS NULL OR "a"."updated_at" > $1))
+            OR "a"."deleted_at" > $1
+          )
+          AND ($2::UUID IS NULL OR "a"."id" > $2)
+        ORDER BY "a"."id"
+        LIMIT $3
+      "#,
+      query.since,
+      query.after.clone().map(uuid::Uuid::from),
+      query.limit as i64
+    )
+    .fetch_all(&self.db)
+    .await
+    .map_err(to_database_error)?;
+
+    Ok(
+      rows
+        .into_iter()
+        .map(|row| {
+          if row.deleted_at.is_some() {
+            return deleted(CollaborativeEntityName::Artist, row.id);
+          }
+
+          (
+            UUID4::from(row.id),
+            ImportRecord::Artist(ArtistRecord {
+              external_id: Some(row.id.to_string()),
+              name: row.name,
+              slug: Some(Slug::from(row.slug)),
+              country: row.country,
+              artist_type: Some(row.artist_type.parse().unwrap_or_default()),
+              aliases: non_empty(row.aliases).map(|aliases| aliases.into_iter().collect()),
+              members: non_empty(
+                serde_json::from_value::<Vec<MemberRecord>>(row.members).unwrap_or_default(),
+              ),
+            }),
+          )
+        })
+        .collect(),
+    )
+  }
+
+  /**
+   * O grupo de lançamento é referenciado pelo álbum que o inaugurou ou, se ele foi
+   * excluído, pelo primeiro álbum restante do grupo, que então não referencia nenhum.
+   */
+  async fn export_albums(&self, query: &ExportQuery) -> Result<Vec<(UUID4, ImportRecord)>, Error> {
+    let rows = sqlx::query!(
+      r#"
+        SELECT "a"."id", "a"."name", "a"."slug", "a"."album_type"::TEXT as "album_type!", "a"."cover",
+          "a"."release_date", "a"."parental_rating", "a"."label", "a"."catalog_number", "a"."barcode",
+          "a"."release_country", "a"."edition", "a"."deleted_at", "rg"."reference" as "release_group?",
+          ARRAY(
+            SELECT "ar"."slug" FROM "album_artist" "aa"
+            JOIN "artist" "ar" ON "ar"."id" = "aa"."artist_id" AND "ar"."deleted_at" IS NULL
+            WHERE "aa"."album_id" = "a"."id"
+            ORDER BY "ar"."slug"
+          ) as "artists!"
+        FROM "album" "a"
+        LEFT JOIN LATERAL (
+          SELECT "g"."id", (
+            SELECT MIN("ar"."slug") FROM "album_artist" "aa"
+            JOIN "artist" "ar" ON "ar"."id" = "aa"."artist_id" AND "ar"."deleted_at" IS NULL
+            WHERE "aa"."album_id" = "g"."id"
+          ) || '/' || "g"."slug" as "reference"
+          FROM "album" "g"
+          WHERE "g"."release_group_id" = "a"."release_group_id" AND "g"."deleted_at" IS NULL
+          ORDER BY "g"."id" = "g"."release_group_id" DESC, "g"."id"
+          LIMIT 1
+        ) "rg" ON "rg"."id" <> "a"."id"
+        WHERE (
+            ("a"."deleted_at" IS NULL AND ($1::TIMESTAMP IS NULL OR "a"."updated_at" > $1))
+            OR "a"."deleted_at" > $1
+          )
+          AND ($2::UUID IS NULL OR "a"."id" > $2)
+        ORDER BY "a"."id"
+        LIMIT $3
+      "#,
+      query.since,
+      query.after.clone().map(uuid::Uuid::from),
+      query.limit as i64
+    )
+    .fetch_all(&self.db)
+    .await
+    .map_err(to_database_error)?;
+
+    Ok(
+      rows
+        .into_iter()
+        .map(|row| {
+          if row.deleted_at.is_some() {
+            return deleted(CollaborativeEntityName::Album, row.id);
+          }
+
+          (
+            UUID4::from(row.id),
+            ImportRecord::Album(AlbumRecord {
+              external_id: Some(row.id.to_string()),
+              name: row.name,
+              slug: Some(Slug::from(row.slug)),
+              album_type: row.album_type.parse().unwrap(),
+              artists: row.artists,
+              cover: row.cover,
+              release_date: row.release_date,
+              parental_rating: Some(row.parental_rating as u8),
+              label: row.label,
+              catalog_number: row.catalog_number,
+              barcode: row.barcode.and_then(|barcode| Barcode::new(barcode).ok()),
+              release_country: row.release_country,
+              edition: row.edition,
+              release_group: row.release_group,
+            }),
+          )
+        })
+        .collect(),
+    )
+  }
+
+  async fn export_medias(&self, query: &ExportQuery) -> Result<Vec<(UUID4, ImportRecord)>, Error> {
+    let rows = sqlx::query!(
+      r#"
+        SELECT "m"."id", "m"."name", "m"."slug", "m"."type"::TEXT as "media_type!", "m"."cover",
+          "m"."release_date", "m"."parental_rating", "m"."duration", "m"."isrc", "m"."bpm", "m"."key",
+          "m"."language", "m"."is_single", "m"."deleted_at",
+          ARRAY(
+            SELECT "a"."slug" FROM "media_interpreter" "mi"
+            JOIN "artist" "a" ON "a"."id" = "mi"."interpreter_id" AND "a"."deleted_at" IS NULL
+            WHERE "mi"."media_id" = "m"."id"
+            ORDER BY "a"."slug"
+          ) as "interpreters!",
+          ARRAY(
+            SELECT "a"."slug" FROM "media_composer" "mc"
+            JOIN "artist" "a" ON "a"."id" = "mc"."composer_id" AND "a"."deleted_at" IS NULL
+            WHERE "mc"."media_id" = "m"."id"
+            ORDER BY "a"."slug"
+          ) as "composers!",
+          ARRAY(
+            SELECT "g"."slug" FROM "media_genre" "mg"
+            JOIN "genre" "g" ON "g"."id" = "mg"."genre_id" AND "g"."deleted_at" IS NULL
+            WHERE "mg"."media_id" = "m"."id"
+            ORDER BY "g"."slug"
+          ) as "genres!",
+          ARRAY(
+            SELECT "k"."reference" FROM (
+              SELECT (
+                SELECT MIN("ar"."slug") FROM "album_artist" "aa"
+                JOIN "artist" "ar" ON "ar"."id" = "aa"."artist_id" AND "ar"."deleted_at" IS NULL
+                WHERE "aa"."album_id" = "al"."id"
+              ) || '/' || "al"."slug" as "reference"
+              FROM "media_album" "ma"
+              JOIN "album" "al" ON "al"."id" = "ma"."album_id" AND "al"."deleted_at" IS NULL
+              WHERE "ma"."media_id" = "m"."id"
+            ) "k"
+            WHERE "k"."reference" IS NOT NULL
+            ORDER BY "k"."reference"
+          ) as "albums!",
+          COALESCE((
+            SELECT json_agg(
+              json_build_object('artist', "a"."slug", 'role', "mc"."role", 'position', "mc"."position")
+              ORDER BY "mc"."position"
+            )
+            FROM "media_credit" "mc"
+            JOIN "artist" "a" ON "a"."id" = "mc"."artist_id" AND "a"."deleted_at" IS NULL
+            WHERE "mc"."media_id" = "m"."id"
+          ), '[]') as "credits!"
+        FROM "media" "m"
+        WHERE (
+            ("m"."deleted_at" IS NULL AND ($1::TIMESTAMP IS NULL OR "m"."updated_at" > $1))
+            OR "m"."deleted_at" > $1
+          )
+          AND ($2::UUID IS NULL OR "m"."id" > $2)
+        ORDER BY "m"."id"
+        LIMIT $3
+      "#,
+      query.since,
+      query.after.clone().map(uuid::Uuid::from),
+      query.limit as i64
+    )
+    .fetch_all(&self.db)
+    .await
+    .map_err(to_database_error)?;
+
+    Ok(
+      rows
+        .into_iter()
+        .map(|row| {
+          if row.deleted_at.is_some() {
+            return deleted(CollaborativeEntityName::Media, row.id);
+          }
+
+          (
+            UUID4::from(row.id),
+            ImportRecord::Media(MediaRecord {
+              external_id: Some(row.id.to_string()),
+              name: row.name,
+              slug: Some(Slug::from(row.slug)),
+              media_type: row.media_type.parse().unwrap(),
+              interpreters: row.interpreters,
+              composers: non_empty(row.composers),
+              genres: non_empty(row.genres),
+              albums: non_empty(row.albums),
+              release_date: row.release_date,
+              cover: row.cover,
+              parental_rating: Some(row.parental_rating as u8),
+              duration: row.duration.map(|duration| duration as u32),
+              isrc: row.isrc.and_then(|isrc| Isrc::new(isrc).ok()),
+              bpm: row.bpm.map(|bpm| bpm as u16),
+              key: row.key,
+              language: row.language,
+              is_single: Some(row.is_single),
+              credits: non_empty(
+                serde_json::from_value::<Vec<CreditRecord>>(row.credits).unwrap_or_default(),
+              ),
+            }),
+          )
+        })
+        .collect(),
+    )
+  }
+
+  async fn export_sources(&self, query: &ExportQuery) -> Result<Vec<(UUID4, ImportRecord)>, Error> {
+    let rows = sqlx::query!(
+      r#"
+        SELECT "s"."id", "s"."source_type", "s"."src", "s"."start_offset", "s"."duration",
+          "s"."deleted_at",
+          (
+            SELECT MIN("a"."slug") FROM "media_interpreter" "mi"
+            JOIN "artist" "a" ON "a"."id" = "mi"."interpreter_id" AND "a"."deleted_at" IS NULL
+            WHERE "mi"."media_id" = "m"."id"
+          ) || '/' || "m"."slug" as "media?"
+        FROM "source" "s"
+        JOIN "media" "m" ON "m"."id" = "s"."media_id"
+        WHERE (
+            (
+              "s"."deleted_at" IS NULL AND "m"."deleted_at" IS NULL
+              AND ($1::TIMESTAMP IS NULL OR "s"."updated_at" > $1)
+            )
+            OR "s"."deleted_at" > $1
+          )
+          AND ($2::UUID IS NULL OR "s"."id" > $2)
+        ORDER BY "s"."id"
+        LIMIT $3
+      "#,
+      query.since,
+      query.after.clone().map(uuid::Uuid::from),
+      query.limit as i64
+    )
+    .fetch_all(&self.db)
+    .await
+    .map_err(to_database_error)?;
+
+    Ok(
+      rows
+        .into_iter()
+        .map(|row| {
+          if row.deleted_at.is_some() {
+            return deleted(CollaborativeEntityName::Source, row.id);
+          }
+
+          (
+            UUID4::from(row.id),
+            ImportRecord::Source(SourceRecord {
+              external_id: Some(row.id.to_string()),
+              media: row.media.unwrap_or_default(),
+              source_type: row.source_type.parse().unwrap(),
+              src: row.src,
+              start_offset: row.start_offset.map(|start_offset| start_offset as u32),
+              duration: row.duration.map(|duration| duration as u32),
+            }),
+          )
+        })
+        .collect(),
+    )
+  }
+
+  /**
+   * Apenas a versão mais recente da letra de cada media é exportada. As versões são
+   * removidas de fato, e por isso não geram registros de exclusão.
+   */
+  async fn export_lyrics(&self, query: &ExportQuery) -> Result<Vec<(UUID4, ImportRecord)>, Error> {
+    let rows = sqlx::query!(
+      r#"
+        SELECT "l"."id", "l"."language", "l"."content", "l"."lines",
+          (
+            SELECT MIN("a"."slug") FROM "media_interpreter" "mi"
+            JOIN "artist" "a" ON "a"."id" = "mi"."interpreter_id" AND "a"."deleted_at" IS NULL
+            WHERE "mi"."media_id" = "m"."id"
+          ) || '/' || "m"."slug" as "media?"
+        FROM "lyrics" "l"
+        JOIN "media" "m" ON "m"."id" = "l"."media_id" AND "m"."deleted_at" IS NULL
+        WHERE "l"."version" = (
+            SELECT MAX("v"."version") FROM "lyrics" "v" WHERE "v"."media_id" = "l"."media_id"
+          )
+          AND ($1::TIMESTAMP IS NULL OR "l"."updated_at" > $1)
+          AND ($2::UUID IS NULL OR "l"."id" > $2)
+        ORDER BY "l"."id"
+        LIMIT $3
+      "#,
+      query.since,
+      query.after.clone().map(uuid::Uuid::from),
+      query.limit as i64
+    )
+    .fetch_all(&self.db)
+    .await
+    .map_err(to_database_error)?;
+
+    Ok(
+      rows
+        .into_iter()
+        .map(|row| {
+          let lines = serde_json::from_value::<Vec<LyricLine>>(row.lines).unwrap_or_default();
+
+          (
+            UUID4::from(row.id),
+            ImportRecord::Lyrics(LyricsRecord {
+              external_id: Some(row.id.to_string()),
+              media: row.media.unwrap_or_default(),
+              language: row.language,
+              content: row.content,
+              lrc: (!lines.is_empty()).then(|| LyricLine::to_lrc(&lines)),
+            }),
+          )
+        })
+        .collect(),
+    )
+  }
+}
+
+impl CatalogRepository for SqlxCatalogRepository {
+  async fn export(&mut self, query: &ExportQuery) -> Result<Vec<(UUID4, ImportRecord)>, Error> {
+    match query.entity_name {
+      CollaborativeEntityName::Genre => self.export_genres(query).await,
+      CollaborativeEntityName::Artist => self.export_artists(query).await,
+      CollaborativeEntityName::Album => self.export_albums(query).await,
+      CollaborativeEntityName::Media => self.export_medias(query).await,
+      CollaborativeEntityName::Source => self.export_sources(query).await,
+      CollaborativeEntityName::Lyrics => self.export_lyrics(query).await,
+    }
+  }
+
+  async fn find_by_external_id(
+    &mut self,
+    origin: &str,
+    entity_name: &CollaborativeEntityName,
+    external_id: &str,
+  ) -> Result<Option<UUID4>, Error> {
+    let row = sqlx::query!(
+      r#"
+        SELECT "entity_id" FROM "external_id"
+        WHERE "origin" = $1 AND "entity_name" = $2 AND "external_id" = $3
+      "#,
+      origin,
+      entity_name.to_string(),
+      external_id
+    )
+    .fetch_optional(&self.db)
+    .await
+    .map_err(to_database_error)?;
+
+    Ok(row.map(|row| UUID4::from(row.entity_id)))
+  }
+
+  async fn save_external_id(
+    &mut self,
+    origin: &str,
+    entity_name: &CollaborativeEntityName,
+    external_id: &str,
+    entity_id: &UUID4,
+  ) -> Result<(), Error> {
+    sqlx::query!(
+      r#"
+        INSERT INTO "external_id" ("origin", "entity_name", "external_id", "entity_id")
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT ("origin", "entity_name", "external_id")
+        DO UPDATE SET "entity_id" = EXCLUDED."entity_id", "updated_at" = CURRENT_TIMESTAMP
+      "#,
+      origin,
+      entity_name.to_string(),
+      external_id,
+      uuid::Uuid::from(entity_id.clone())
+    )
+    .execute(&self.db)
+    .await
+    .map_err(to_database_error)?;
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{
+    domain::{artist::repository::ArtistRepository, genre::GenreRepository},
+    infra::sqlx::{SqlxArtistRepository, SqlxGenreRepository},
+  };
+
+  #[tokio::test]
+  async fn test_export() {
+    // Load .env file
+    dotenvy::dotenv().ok();
+
+    const PARENT_ID: &str = "7d2e4c1a-3b5f-4a6e-8c9d-0e1f2a3b4c02";
+    const CHILD_ID: &str = "7d2e4c1a-3b5f-4a6e-8c9d-0e1f2a3b4c01";
+    const ARTIST_ID: &str = "7d2e4c1a-3b5f-4a6e-8c9d-0e1f2a3b4c03";
+    const MEMBER_ID: &str = "7d2e4c1a-3b5f-4a6e-8c9d-0e1f2a3b4c04";
+
+    async fn cleanup(db: &sqlx::PgPool) {
+      sqlx::query!(
+        r#"DELETE FROM "genre" WHERE "id" = ANY($1)"#,
+        &[
+          uuid::Uuid::parse_str(CHILD_ID).unwrap(),
+          uuid::Uuid::parse_str(PARENT_ID).unwrap()
+        ]
+      )
+      .execute(db)
+      .await
+      .expect("Falha ao limpar dados de teste da tabela genre");
+
+      sqlx::query!(
+        r#"DELETE FROM "artist" WHERE "id" = ANY($1)"#,
+        &[
+          uuid::Uuid::parse_str(ARTIST_ID).unwrap(),
+          uuid::Uuid::parse_str(MEMBER_ID).unwrap()
+        ]
+      )
+      .execute(db)
+      .await
+      .expect("Falha ao limpar dados de teste da tabela artist");
+    }
+
+    let app_state = AppState::default().await;
+
+    cleanup(&app_state.db).await;
+
+    let mut genre_repository = SqlxGenreRepository::new(&app_state);
+    let mut artist_repository = SqlxArtistRepository::new(&app_state);
+    let mut catalog_repository = SqlxCatalogRepository::new(&app_state);
+
+    let since = crate::shared::util::naive_now() - chrono::Duration::seconds(1);
+
+    let parent = crate::domain::genre::Genre::builder()
+      .id(UUID4::new(PARENT_ID).unwrap())
+      .name(String::from("Export Rock"))
+      .slug(Slug::new("test_export_parent_genre").unwrap())
+      .build();
+
+    let child = crate::domain::genre::Genre::builder()
+      .id(UUID4::new(CHILD_ID).unwrap())
+      .name(String::from("Export Punk"))
+      .slug(Slug::new("test_export_child_genre").unwrap())
+      .parent_id(Some(parent.id().clone()))
+      .build();
+
+    let member = crate::domain::artist::Artist::builder()
+      .id(UUID4::new(MEMBER_ID).unwrap())
+      .name(String::from("Export Singer"))
+      .slug(Slug::new("test_export_member").unwrap())
+      .build();
+
+    let artist = crate::domain::artist::Artist::builder()
+      .id(UUID4::new(ARTIST_ID).unwrap())
+      .name(String::from("Export Band"))
+      .slug(Slug::new("test_export_artist").unwrap())
+      .artist_type(crate::domain::artist::ArtistType::Group)
+      .aliases(["Zeta".to_string(), "Alfa".to_string()].into())
+      .members(vec![crate::domain::artist::ArtistMember {
+        artist_id: member.id().clone(),
+        started_at: chrono::NaiveDate::from_ymd_opt(1982, 8, 1),
+        ended_at: None,
+      }])
+      .build();
+
+    for genre in [&parent, &child] {
+      genre_repository
+        .create(genre)
+        .await
+        .expect("Falha ao criar gênero");
+    }
+
+    for artist in [&member, &artist] {
+      artist_repository
+        .create(artist)
+        .await
+        .expect("Falha ao criar artista");
+    }
+
+    let mut export = async |entity_name: CollaborativeEntityName,
+                            since: Option<chrono::NaiveDateTime>,
+                            after: Option<UUID4>| {
+      catalog_repository
+        .export(&ExportQuery {
+          entity_name,
+          since,
+          after,
+          limit: 1000,
+        })
+        .await
+        .expect("Falha ao exportar o catálogo")
+        .into_iter()
+        .filter(|(id, _)| {
+          [&parent, &child].iter().any(|genre| genre.id() == id) || id == artist.id()
+        })
+        .map(|(_, record)| record)
+        .collect::<Vec<_>>()
+    };
+
+    let genres = export(CollaborativeEntityName::Genre, Some(since), None).await;
+    let after_child = export(
+      CollaborativeEntityName::Genre,
+      Some(since),
+      Some(child.id().clone()),
+    )
+    .await;
+    let future = export(
+      CollaborativeEntityName::Genre,
+      Some(crate::shared::util::naive_now() + chrono::Duration::days(1)),
+      None,
+    )
+    .await;
+    let artists = export(CollaborativeEntityName::Artist, Some(since), None).await;
+
+    genre_repository
+      .delete_by_id(child.id())
+      .await
+      .expect("Falha ao excluir gênero");
+
+    let incremental = export(CollaborativeEntityName::Genre, Some(since), None).await;
+    let full = export(CollaborativeEntityName::Genre, None, None).await;
+
+    cleanup(&app_state.db).await;
+
+    assert_eq!(
+      genres,
+      vec![
+        ImportRecord::Genre(GenreRecord {
+          external_id: Some(String::from(CHILD_ID)),
+          name: String::from("Export Punk"),
+          slug: Some(Slug::new("test_export_child_genre").unwrap()),
+          parent: Some(String::from("test_export_parent_genre")),
+        }),
+        ImportRecord::Genre(GenreRecord {
+          external_id: Some(String::from(PARENT_ID)),
+          name: String::from("Export Rock"),
+          slug: Some(Slug::new("test_export_parent_genre").unwrap()),
+          parent: None,
+        }),
+      ],
+      "Os gêneros devem ser exportados em ordem de identificador, com o pai referenciado pelo slug"
+    );
+    assert_eq!(
+      after_child.len(),
+      1,
+      "A exportação deve continuar a partir do identificador informado"
+    );
+    assert!(
+      future.is_empty(),
+      "A exportação incremental não deve incluir entidades não alteradas"
+    );
+
+    let [ImportRecord::Artist(exported_artist)] = artists.as_slice() else {
+      panic!("O artista deve ser exportado");
+    };
+    assert_eq!(
+      exported_artist
+        .aliases
+        .as_ref()
+        .map(|aliases| aliases.iter().cloned().collect::<Vec<_>>()),
+      Some(vec![String::from("Alfa"), String::from("Zeta")]),
+      "Os nomes alternativos devem ser exportados"
+    );
+    assert_eq!(
+      exported_artist.members,
+      Some(vec![MemberRecord {
+        artist: String::from("test_export_member"),
+        started_at: chrono::NaiveDate::from_ymd_opt(1982, 8, 1),
+        ended_at: None,
+      }]),
+      "Os membros do grupo devem ser exportados pelo slug"
+    );
+
+    assert_eq!(
+      incremental.first(),
+      Some(&ImportRecord::Deleted(DeletedRecord {
+        entity: CollaborativeEntityName::Genre,
+        external_id: String::from(CHILD_ID),
+      })),
+      "A exportação incremental deve incluir a exclusão feita depois de since"
+    );
+    assert_eq!(
+      full.len(),
+      1,
+      "A exportação completa não deve incluir as entidades excluídas"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_external_id() {
+    // Load .env file
+    dotenvy::dotenv().ok();
+
+    const ORIGIN: &str = "test_external_id";
+
+    let app_state = AppState::default().await;
+    let mut catalog_repository = SqlxCatalogRepository::new(&app_state);
+
+    let first_id = UUID4::generate();
+    let second_id = UUID4::generate();
+
+    catalog_repository
+      .save_external_id(ORIGIN, &CollaborativeEntityName::Artist, "mbid", &first_id)
+      .await
+      .expect("Falha ao vincular o identificador externo");
+    catalog_repository
+      .save_external_id(ORIGIN, &CollaborativeEntityName::Artist, "mbid", &second_id)
+      .await
+      .expect("Falha ao substituir o vínculo do identificador externo");
+
+    let artist_id = catalog_repository
+      .find_by_external_id(ORIGIN, &CollaborativeEntityName::Artist, "mbid")
+      .await
+      .expect("Falha ao buscar o identificador externo");
+    let album_id = catalog_repository
+      .find_by_external_id(ORIGIN, &CollaborativeEntityName::Album, "mbid")
+      .await
+      .expect("Falha ao buscar o identificador externo");
+
+    sqlx::query!(r#"DELETE FROM "external_id" WHERE "origin" = $1"#, ORIGIN)
+      .execute(&app_state.db)
+      .await
+      .expect("Falha ao limpar dados de teste da tabela external_id");
+
+    assert_eq!(
+      artist_id,
+      Some(second_id),
+      "O último vínculo do identificador externo deve prevalecer"
+    );
+    assert_eq!(
+      album_id, None,
+      "O vínculo deve valer apenas para o tipo de entidade informado"
+    );
+  }
+}
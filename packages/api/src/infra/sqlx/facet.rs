@@ -0,0 +1,85 @@
+use crate::shared::facet::FacetValue;
+
+/**
+ * Expressões SQL de uma faceta, escritas sobre a subconsulta filtrada "f".
+ */
+pub struct FacetColumn {
+  pub value: &'static str,
+  pub label: &'static str,
+
+  /**
+   * Junções necessárias para chegar ao valor da faceta a partir de "f".
+   */
+  pub join: &'static str,
+}
+
+pub const RELEASE_YEAR: FacetColumn = FacetColumn {
+  value: r#"EXTRACT(YEAR FROM "f"."release_date")::INT::TEXT"#,
+  label: "NULL::TEXT",
+  join: "",
+};
+
+pub const RELEASE_DECADE: FacetColumn = FacetColumn {
+  value: r#"(EXTRACT(YEAR FROM "f"."release_date")::INT / 10 * 10)::TEXT"#,
+  label: r#"(EXTRACT(YEAR FROM "f"."release_date")::INT / 10 * 10)::TEXT || 's'"#,
+  join: "",
+};
+
+pub const PARENTAL_RATING: FacetColumn = FacetColumn {
+  value: r#""f"."parental_rating"::TEXT"#,
+  label: "NULL::TEXT",
+  join: "",
+};
+
+/**
+ * Conta os registros de `push_source` agrupados pelo valor da faceta, do mais frequente
+ * para o menos frequente. Registros sem valor para a faceta são ignorados.
+ *
+ * `count` é a expressão contada de forma distinta (normalmente `"f"."id"`).
+ */
+pub async fn fetch_facet(
+  db: &sqlx::Pool<sqlx::Postgres>,
+  column: &FacetColumn,
+  count: &str,
+  push_source: impl FnOnce(&mut sqlx::QueryBuilder<'_, sqlx::Postgres>),
+) -> Result<Vec<FacetValue>, sqlx::Error> {
+  #[derive(sqlx::FromRow)]
+  struct Record {
+    value: String,
+    label: Option<String>,
+    count: i64,
+  }
+
+  let mut query_builder = sqlx::QueryBuilder::new("SELECT ");
+
+  query_builder
+    .push(column.value)
+    .push(r#" AS "value", "#)
+    .push(column.label)
+    .push(r#" AS "label", COUNT(DISTINCT "#)
+    .push(count)
+    .push(r#") AS "count" FROM ("#);
+
+  push_source(&mut query_builder);
+
+  query_builder
+    .push(r#") "f" "#)
+    .push(column.join)
+    .push(" WHERE ")
+    .push(column.value)
+    .push(r#" IS NOT NULL GROUP BY 1, 2 ORDER BY 3 DESC, 1"#);
+
+  Ok(
+    query_builder
+      .build_query_as::<Record>()
+      .fetch_all(db)
+      .await?
+      .into_iter()
+      .map(|record| FacetValue {
+        value: record.value,
+        label: record.label,
+        count: record.count as usize,
+      })
+      .collect(),
+  )
+}
@@ -44,33 +44,111 @@ macro_rules! where_filter {
   };
 }
 
+/**
+ * Busca pelo nome, ignorando acentos e tolerando pequenos erros de digitação. `%` e `_`
+ * no termo são buscados literalmente.
+ *
+ * Usa o índice trigram sobre "f_unaccent"(lower("name")) da tabela informada.
+ */
 #[macro_export]
-macro_rules! count {
-    () => { 0 };
-    ($head:tt $($tail:tt)*) => { 1 + $crate::count!($($tail)*) };
+macro_rules! search_by_name {
+  ($qb:expr, $value:expr, $table:literal) => {
+    let value: String = $value;
+
+    $qb.push(concat!(
+      r#""f_unaccent"(lower("#,
+      $table,
+      r#"."name")) LIKE '%' || "f_unaccent"(lower("#
+    ));
+    $qb.push_bind($crate::infra::sqlx::escape_like(&value));
+    $qb.push(r#")) || '%' OR "f_unaccent"(lower("#);
+    $qb.push_bind(value.clone());
+    $qb.push(concat!(
+      r#")) <% "f_unaccent"(lower("#,
+      $table,
+      r#"."name")) OR "f_unaccent"(lower("#
+    ));
+    $qb.push_bind(value);
+    $qb.push(concat!(
+      r#")) % "f_unaccent"(lower("#,
+      $table,
+      r#"."name"))"#
+    ));
+  };
 }
 
+/**
+ * Busca textual sobre uma tabela que possui a coluna "search_vector" (ver migração
+ * full_text_search), combinando a busca full-text com [`search_by_name!`].
+ */
 #[macro_export]
 macro_rules! search_by {
-  ($qb:expr, $value:expr, $($field:literal$(,)?)*$(,)?) => {
-    let mut count = $crate::count!($($field)*);
+  ($qb:expr, $value:expr, $table:literal) => {
+    let value: String = $value;
 
-    $(
-      $qb.push($field);
-      $qb.push(" ILIKE '%' || ");
-      $qb.push_bind($value);
-      $qb.push(" || '%'");
+    $qb.push(concat!(
+      $table,
+      r#"."search_vector" @@ websearch_to_tsquery('simple', "f_unaccent"("#
+    ));
+    $qb.push_bind(value.clone());
+    $qb.push(")) OR ");
+    $crate::search_by_name!($qb, value, $table);
+  };
+}
 
-      if count > 1 {
-        $qb.push(" OR ");
-      }
+/**
+ * Expressão de relevância de um resultado de [`search_by!`], para ser usada no ORDER BY.
+ *
+ * Soma o rank full-text às similaridades trigram, de forma que nomes mais próximos
+ * do termo buscado fiquem à frente de nomes que apenas o contêm.
+ */
+#[macro_export]
+macro_rules! search_rank {
+  ($qb:expr, $value:expr, $table:literal) => {
+    let value: String = $value;
 
-      #[allow(unused_assignments)]
-      {count -= 1;}
-    )*
+    $qb.push(concat!(
+      "(ts_rank(",
+      $table,
+      r#"."search_vector", websearch_to_tsquery('simple', "f_unaccent"("#
+    ));
+    $qb.push_bind(value.clone());
+    $qb.push(r#"))) + word_similarity("f_unaccent"(lower("#);
+    $qb.push_bind(value.clone());
+    $qb.push(concat!(
+      r#")), "f_unaccent"(lower("#,
+      $table,
+      r#"."name"))) + similarity("f_unaccent"(lower("#
+    ));
+    $qb.push_bind(value);
+    $qb.push(concat!(
+      r#")), "f_unaccent"(lower("#,
+      $table,
+      r#"."name"))))"#
+    ));
+  };
+}
+
+/**
+ * Ordena pela coluna informada na direção de `$sort`, seguida dos critérios de desempate.
+ */
+#[macro_export]
+macro_rules! order_by {
+  ($qb:expr, $sort:expr, $column:expr, $tie_breaker:literal) => {
+    $qb.push(" ORDER BY ");
+    $qb.push($column);
+    $qb.push(" ");
+    $qb.push($sort.direction.to_sql());
+    $qb.push(concat!(" NULLS LAST, ", $tie_breaker));
   };
 }
 
+/**
+ * Cria uma closure que aplica os filtros da consulta informados.
+ *
+ * Quando uma condição base é informada (`create_filter!(Query, qb, "condição" => { ... })`),
+ * ela é sempre aplicada, antes dos demais filtros.
+ */
 #[macro_export]
 macro_rules! create_filter {
   ($query:ty, $qb: ident => {
@@ -86,4 +164,23 @@ macro_rules! create_filter {
       }
     }
   };
+  ($query:ty, $qb: ident, $base:literal => {
+    $($name:ident($value:ident) => $filter:expr,)*
+  }) => {
+    |$qb: &mut sqlx::QueryBuilder<'_, sqlx::Postgres>, query: &$query| {
+      $qb.push(concat!(" WHERE (", $base, ")"));
+
+      if $(query.$name.is_some() || )* false {
+        $qb.push(" AND ");
+      }
+
+      $crate::filter_params! {
+        $qb,
+        query,
+        {
+          $($name($value) => $filter,)*
+        }
+      }
+    }
+  };
 }
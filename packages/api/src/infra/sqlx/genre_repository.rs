@@ -1,7 +1,6 @@
-use std::vec;
-
 use crate::{
-  domain::genre::{Error, Genre, GenreAggregate, GenreRepository},
+  domain::genre::{Error, Genre, GenreAggregate, GenreRepository, SortField},
+  order_by, search_by, search_rank,
   shared::{
     paged::{PageQueryParams, Paged},
     vo::{Slug, UUID4},
@@ -9,6 +8,17 @@ use crate::{
   AppState,
 };
 
+fn sort_column(field: &SortField) -> &'static str {
+  match field {
+    SortField::Name => r#""name""#,
+    SortField::CreatedAt => r#""created_at""#,
+    SortField::UpdatedAt => r#""updated_at""#,
+    SortField::Popularity => {
+      r#"(SELECT COUNT(*) FROM "media_genre" "mg" INNER JOIN "media" "m" ON "m"."id" = "mg"."media_id" WHERE "mg"."genre_id" = "genre"."id" AND "m"."deleted_at" IS NULL)"#
+    }
+  }
+}
+
 pub struct SqlxGenreRepository {
   db: sqlx::Pool<sqlx::Postgres>,
 }
@@ -30,7 +40,7 @@ impl GenreRepository for SqlxGenreRepository {
       r#"
       SELECT "id", "name", "slug", "parent_id", "created_at", "updated_at"
       FROM "genre"
-      WHERE "id" = $1
+      WHERE "id" = $1 AND "deleted_at" IS NULL
       LIMIT 1
     "#,
       Into::<uuid::Uuid>::into(id.clone())
@@ -55,6 +65,46 @@ impl GenreRepository for SqlxGenreRepository {
     Ok(None)
   }
 
+  async fn find_by_ids(
+    &mut self,
+    ids: &std::collections::HashSet<crate::shared::vo::UUID4>,
+  ) -> Result<Vec<crate::domain::genre::Genre>, crate::domain::genre::Error> {
+    if ids.is_empty() {
+      return Ok(Vec::new());
+    }
+
+    let result = sqlx::query!(
+      r#"
+      SELECT "id", "name", "slug", "parent_id", "created_at", "updated_at"
+      FROM "genre"
+      WHERE "id" = ANY($1) AND "deleted_at" IS NULL
+    "#,
+      &ids
+        .iter()
+        .map(|id| uuid::Uuid::from(id.clone()))
+        .collect::<Vec<_>>(),
+    )
+    .fetch_all(&self.db)
+    .await
+    .map_err(|err| Error::DatabaseError(err.to_string()))?;
+
+    Ok(
+      result
+        .into_iter()
+        .map(|row| {
+          Genre::builder()
+            .id(UUID4::from(row.id))
+            .name(row.name)
+            .slug(Slug::from(row.slug))
+            .parent_id(row.parent_id.map(UUID4::from))
+            .created_at(row.created_at)
+            .updated_at(row.updated_at)
+            .build()
+        })
+        .collect(),
+    )
+  }
+
   async fn create(&mut self, genre: &Genre) -> Result<(), Error> {
     let input = genre.clone();
 
@@ -106,7 +156,45 @@ impl GenreRepository for SqlxGenreRepository {
 
   async fn delete_by_id(&mut self, id: &UUID4) -> Result<(), Error> {
     sqlx::query!(
-      r#"DELETE FROM "genre" WHERE "id" = $1"#,
+      r#"UPDATE "genre" SET "deleted_at" = now() WHERE "id" = $1 AND "deleted_at" IS NULL"#,
+      Into::<uuid::Uuid>::into(id.clone())
+    )
+    .execute(&self.db)
+    .await
+    .map_err(|err| Error::DatabaseError(err.to_string()))?;
+
+    Ok(())
+  }
+
+  async fn find_deleted_by_id(&mut self, id: &UUID4) -> Result<Option<Genre>, Error> {
+    let result = sqlx::query!(
+      r#"
+      SELECT "id", "name", "slug", "parent_id", "created_at", "updated_at"
+      FROM "genre"
+      WHERE "id" = $1 AND "deleted_at" IS NOT NULL
+      LIMIT 1
+    "#,
+      Into::<uuid::Uuid>::into(id.clone())
+    )
+    .fetch_optional(&self.db)
+    .await
+    .map_err(|err| Error::DatabaseError(err.to_string()))?;
+
+    Ok(result.map(|row| {
+      Genre::builder()
+        .id(UUID4::from(row.id))
+        .name(row.name)
+        .slug(Slug::from(row.slug))
+        .parent_id(row.parent_id.map(UUID4::from))
+        .created_at(row.created_at)
+        .updated_at(row.updated_at)
+        .build()
+    }))
+  }
+
+  async fn restore_by_id(&mut self, id: &UUID4) -> Result<(), Error> {
+    sqlx::query!(
+      r#"UPDATE "genre" SET "deleted_at" = NULL WHERE "id" = $1"#,
       Into::<uuid::Uuid>::into(id.clone())
     )
     .execute(&self.db)
@@ -116,6 +204,47 @@ impl GenreRepository for SqlxGenreRepository {
     Ok(())
   }
 
+  async fn deletion_impact(
+    &mut self,
+    id: &UUID4,
+  ) -> Result<crate::domain::activity::DeletionImpact, Error> {
+    let result = sqlx::query!(
+      r#"
+        WITH RECURSIVE "subgenre" AS (
+          SELECT "id" FROM "genre" WHERE "parent_id" = $1 AND "deleted_at" IS NULL
+          UNION
+          SELECT "g"."id"
+          FROM "genre" "g"
+          INNER JOIN "subgenre" "s" ON "g"."parent_id" = "s"."id"
+          WHERE "g"."deleted_at" IS NULL
+        )
+        SELECT
+          (SELECT COUNT(*) FROM "subgenre") AS "subgenres!",
+          (
+            SELECT COUNT(*)
+            FROM "media_genre" "mg"
+            INNER JOIN "media" "m" ON "m"."id" = "mg"."media_id" AND "m"."deleted_at" IS NULL
+            WHERE "mg"."genre_id" = $1
+          ) AS "medias!",
+          (SELECT COUNT(*) FROM "media_genre" WHERE "genre_id" = $1) AS "media_genre!"
+      "#,
+      Into::<uuid::Uuid>::into(id.clone())
+    )
+    .fetch_one(&self.db)
+    .await
+    .map_err(|err| Error::DatabaseError(err.to_string()))?;
+
+    Ok(crate::domain::activity::DeletionImpact {
+      medias: result.medias as u64,
+      subgenres: result.subgenres as u64,
+      join_rows: std::collections::BTreeMap::from([(
+        String::from("media_genre"),
+        result.media_genre as u64,
+      )]),
+      ..Default::default()
+    })
+  }
+
   async fn find_genre_and_subgenre_by_slug(
     &mut self,
     slug: &Slug,
@@ -133,7 +262,7 @@ impl GenreRepository for SqlxGenreRepository {
           FROM
               genre "g"
           WHERE
-              "g"."slug" = $1
+              "g"."slug" = $1 AND "g"."deleted_at" IS NULL
           UNION ALL
           SELECT
               "g"."id",
@@ -146,6 +275,8 @@ impl GenreRepository for SqlxGenreRepository {
               genre "g"
           JOIN "sub_genre" "sg" ON
               "g"."parent_id" = "sg"."id"
+          WHERE
+              "g"."deleted_at" IS NULL
         )
         SELECT * FROM "sub_genre";
     "#,
@@ -166,7 +297,7 @@ impl GenreRepository for SqlxGenreRepository {
           .updated_at(genre_row.updated_at.unwrap())
           .build(),
         related_rows
-          .into_iter()
+          .iter()
           .map(|row| {
             Genre::builder()
               .id(UUID4::from(row.id.unwrap()))
@@ -190,8 +321,9 @@ impl GenreRepository for SqlxGenreRepository {
   ) -> Result<crate::shared::paged::Paged<Genre>, Error> {
     let (count, items) = tokio::join!(
       async {
-        let mut builder =
-          sqlx::QueryBuilder::new(r#"SELECT COUNT("id") FROM "genre" WHERE "parent_id" "#);
+        let mut builder = sqlx::QueryBuilder::new(
+          r#"SELECT COUNT("id") FROM "genre" WHERE "deleted_at" IS NULL AND "parent_id" "#,
+        );
 
         if let Some(parent_id) = &query.parent_id {
           builder
@@ -201,12 +333,9 @@ impl GenreRepository for SqlxGenreRepository {
           builder.push(r#"IS NULL"#);
         }
         if let Some(search) = &query.search {
-          builder
-            .push(r#" AND ( "name" ILIKE '%' || "#)
-            .push_bind(search)
-            .push(r#" || '%' OR "slug" ILIKE '%' || "#)
-            .push_bind(search)
-            .push(r#" || '%')"#);
+          builder.push(" AND (");
+          search_by!(builder, search.clone(), r#""genre""#);
+          builder.push(")");
         }
 
         builder
@@ -229,7 +358,7 @@ impl GenreRepository for SqlxGenreRepository {
         let page_params = PageQueryParams::from(query.page.clone());
 
         let mut builder = sqlx::QueryBuilder::new(
-          r#"SELECT "id", "name", "slug", "parent_id", "created_at", "updated_at" FROM "genre" WHERE "parent_id" "#,
+          r#"SELECT "id", "name", "slug", "parent_id", "created_at", "updated_at" FROM "genre" WHERE "deleted_at" IS NULL AND "parent_id" "#,
         );
 
         if let Some(parent_id) = &query.parent_id {
@@ -241,19 +370,26 @@ impl GenreRepository for SqlxGenreRepository {
         }
 
         if let Some(search) = &query.search {
-          builder
-            .push(r#" AND ( "name" ILIKE '%' || "#)
-            .push_bind(search)
-            .push(r#" || '%' OR "slug" ILIKE '%' || "#)
-            .push_bind(search)
-            .push(r#" || '%')"#);
+          builder.push(" AND (");
+          search_by!(builder, search.clone(), r#""genre""#);
+          builder.push(")");
+        }
+
+        if let Some(sort) = &query.sort {
+          order_by!(builder, sort, sort_column(&sort.field), r#""name", "id""#);
+        } else if let Some(search) = &query.search {
+          builder.push(" ORDER BY ");
+          search_rank!(builder, search.clone(), r#""genre""#);
+          builder.push(r#" DESC, "name", "id""#);
+        } else {
+          builder.push(r#" ORDER BY "name", "id""#);
         }
 
         builder
           .push(" LIMIT ")
-          .push_bind(page_params.take as i32)
+          .push_bind(i64::try_from(page_params.take).unwrap_or(i64::MAX))
           .push(" OFFSET ")
-          .push_bind(page_params.skip as i32);
+          .push_bind(i64::try_from(page_params.skip).unwrap_or(i64::MAX));
 
         builder
           .build_query_as::<Record>()
@@ -505,4 +641,66 @@ mod tests {
       "Gênero e Subgêneros não são iguais ao esperado"
     );
   }
+
+  #[tokio::test]
+  async fn test_find_all_search_with_like_wildcards() {
+    // Load .env file
+    dotenvy::dotenv().ok();
+
+    const GENRE_ID: &str = "0b7d3c7e-2f4a-4c8e-9d1b-6a5e4f3c2b1a";
+
+    async fn clean(db: &sqlx::pool::Pool<sqlx::Postgres>) {
+      sqlx::query!(
+        r#"DELETE FROM "genre" WHERE "id" = $1"#,
+        uuid::Uuid::parse_str(GENRE_ID).unwrap()
+      )
+      .execute(db)
+      .await
+      .expect("Error cleaning database");
+    }
+
+    let app_state = AppState::default().await;
+
+    let mut repository_genre = SqlxGenreRepository::new(&app_state);
+
+    let genre = Genre::builder()
+      .id(UUID4::new(GENRE_ID).unwrap())
+      .name(String::from("Curinga"))
+      .slug(Slug::new("test_like_wildcards_genre").unwrap())
+      .build();
+
+    clean(&app_state.db).await;
+
+    repository_genre
+      .create(&genre)
+      .await
+      .expect("Falha ao criar Gênero");
+
+    let mut found = Vec::new();
+
+    for search in ["%", "_"] {
+      let result = repository_genre
+        .find_all(&crate::domain::genre::FindAllQuery {
+          page: crate::shared::paged::RequestPageParams {
+            page: 1,
+            per_page: 100,
+          },
+          sort: None,
+          parent_id: None,
+          search: Some(String::from(search)),
+        })
+        .await
+        .expect("Falha ao buscar Gêneros");
+
+      found.push(result.items.iter().any(|item| item.id() == genre.id()));
+    }
+
+    clean(&app_state.db).await;
+
+    assert_eq!(
+      found,
+      vec![false, false],
+      "% e _ deveriam ser buscados literalmente"
+    );
+  }
 }
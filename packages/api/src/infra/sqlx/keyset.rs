@@ -0,0 +1,33 @@
+use crate::shared::paged::{CursorDirection, CursorPageParams};
+
+/**
+ * Aplica a paginação por cursor sobre uma consulta envolvida como a subconsulta "k".
+ *
+ * Busca um item a mais do que o limite, para que `CursorPaged::from_keyset` saiba se
+ * ainda existem registros na direção do cursor.
+ */
+pub fn push_keyset(qb: &mut sqlx::QueryBuilder<'_, sqlx::Postgres>, params: &CursorPageParams) {
+  let direction = match &params.cursor {
+    Some(cursor) => {
+      qb.push(r#" WHERE ("k"."created_at", "k"."id") "#)
+        .push(match cursor.direction {
+          CursorDirection::Next => "<",
+          CursorDirection::Prev => ">",
+        })
+        .push(" (")
+        .push_bind(cursor.created_at)
+        .push(", ")
+        .push_bind(uuid::Uuid::from(cursor.id.clone()))
+        .push(")");
+
+      cursor.direction
+    }
+    None => CursorDirection::Next,
+  };
+
+  qb.push(match direction {
+    CursorDirection::Next => r#" ORDER BY "k"."created_at" DESC, "k"."id" DESC LIMIT "#,
+    CursorDirection::Prev => r#" ORDER BY "k"."created_at" ASC, "k"."id" ASC LIMIT "#,
+  })
+  .push_bind((params.limit + 1) as i64);
+}
@@ -0,0 +1,108 @@
+use crate::shared::{
+  filter::{ListFilter, MatchMode},
+  vo::UUID4,
+};
+
+/**
+ * Aplica um filtro de múltiplos valores sobre uma coluna do próprio registro.
+ *
+ * `cast` é concatenado a cada valor, ex: `::"media_type"` para enums do banco.
+ */
+pub fn push_value_filter<'args, V>(
+  qb: &mut sqlx::QueryBuilder<'args, sqlx::Postgres>,
+  column: &str,
+  filter: &ListFilter<V>,
+  cast: &str,
+) where
+  V: 'args
+    + sqlx::Encode<'args, sqlx::Postgres>
+    + sqlx::Type<sqlx::Postgres>
+    + Clone
+    + Eq
+    + std::hash::Hash,
+{
+  let mut has_conditions = false;
+
+  for (values, operator) in [(&filter.include, " IN ("), (&filter.exclude, " NOT IN (")] {
+    if values.is_empty() {
+      continue;
+    }
+
+    if has_conditions {
+      qb.push(" AND ");
+    }
+
+    qb.push(column).push(operator);
+
+    let mut separated = qb.separated(", ");
+
+    for value in values {
+      separated.push_bind(value.clone()).push_unseparated(cast);
+    }
+
+    separated.push_unseparated(")");
+
+    has_conditions = true;
+  }
+
+  if !has_conditions {
+    qb.push("TRUE");
+  }
+}
+
+/**
+ * Aplica um filtro de múltiplos valores sobre uma tabela de relacionamento, ex: os
+ * gêneros de uma mídia em "media_genre".
+ *
+ * No modo `All` o registro precisa estar relacionado a todos os valores incluídos.
+ */
+pub fn push_relation_filter(
+  qb: &mut sqlx::QueryBuilder<'_, sqlx::Postgres>,
+  owner: &str,
+  table: &str,
+  owner_column: &str,
+  value_column: &str,
+  filter: &ListFilter<UUID4>,
+) {
+  let mut has_conditions = false;
+
+  for (values, operator, all) in [
+    (&filter.include, " IN (", filter.mode == MatchMode::All),
+    (&filter.exclude, " NOT IN (", false),
+  ] {
+    if values.is_empty() {
+      continue;
+    }
+
+    if has_conditions {
+      qb.push(" AND ");
+    }
+
+    qb.push(owner).push(operator).push(format!(
+      r#"SELECT "{owner_column}" FROM "{table}" WHERE "{value_column}" IN ("#
+    ));
+
+    let mut separated = qb.separated(", ");
+
+    for value in values {
+      separated.push_bind(uuid::Uuid::from(value.clone()));
+    }
+
+    separated.push_unseparated(")");
+
+    if all {
+      qb.push(format!(
+        r#" GROUP BY "{owner_column}" HAVING COUNT(DISTINCT "{value_column}") = "#
+      ))
+      .push_bind(values.len() as i64);
+    }
+
+    qb.push(")");
+
+    has_conditions = true;
+  }
+
+  if !has_conditions {
+    qb.push("TRUE");
+  }
+}
@@ -0,0 +1,251 @@
+use crate::domain::lyrics::lyrics_entity::{LyricLine, Lyrics};
+use crate::shared::vo::UUID4;
+
+pub struct SqlxLyricsRepository {
+  db: sqlx::Pool<sqlx::Postgres>,
+}
+
+impl SqlxLyricsRepository {
+  pub fn new(app_state: &crate::AppState) -> Self {
+    Self {
+      db: app_state.db.clone(),
+    }
+  }
+}
+
+fn to_database_error(error: sqlx::error::Error) -> crate::domain::lyrics::repository::Error {
+  crate::domain::lyrics::repository::Error::DatabaseError(error.to_string())
+}
+
+#[derive(sqlx::FromRow)]
+struct LyricsRecord {
+  id: uuid::Uuid,
+  media_id: uuid::Uuid,
+  version: i32,
+  language: Option<String>,
+  content: String,
+  lines: serde_json::Value,
+  created_at: chrono::NaiveDateTime,
+  updated_at: chrono::NaiveDateTime,
+}
+
+impl From<LyricsRecord> for Lyrics {
+  fn from(record: LyricsRecord) -> Self {
+    Lyrics::builder()
+      .id(UUID4::from(record.id))
+      .media_id(UUID4::from(record.media_id))
+      .version(record.version as u32)
+      .language(record.language)
+      .content(record.content)
+      .lines(serde_json::from_value::<Vec<LyricLine>>(record.lines).unwrap_or_default())
+      .created_at(record.created_at)
+      .updated_at(record.updated_at)
+      .build()
+  }
+}
+
+impl crate::domain::lyrics::repository::LyricsRepository for SqlxLyricsRepository {
+  async fn create(
+    &mut self,
+    lyrics: &Lyrics,
+  ) -> Result<(), crate::domain::lyrics::repository::Error> {
+    sqlx::query!(
+      r#"
+        INSERT INTO "lyrics" ("id", "media_id", "version", "language", "content", "lines", "created_at", "updated_at")
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+      "#,
+      uuid::Uuid::from(lyrics.id().clone()),
+      uuid::Uuid::from(lyrics.media_id().clone()),
+      *lyrics.version() as i32,
+      lyrics.language().clone(),
+      lyrics.content(),
+      serde_json::to_value(lyrics.lines()).expect("falha ao serializar linhas da letra"),
+      lyrics.created_at(),
+      lyrics.updated_at(),
+    )
+    .execute(&self.db)
+    .await
+    .map_err(to_database_error)?;
+
+    Ok(())
+  }
+
+  async fn find_by_id(
+    &mut self,
+    id: &UUID4,
+  ) -> Result<Option<Lyrics>, crate::domain::lyrics::repository::Error> {
+    let lyrics = sqlx::query_as!(
+      LyricsRecord,
+      r#"
+        SELECT "id", "media_id", "version", "language", "content", "lines", "created_at", "updated_at"
+        FROM "lyrics"
+        WHERE "id" = $1
+      "#,
+      uuid::Uuid::from(id.clone())
+    )
+    .fetch_optional(&self.db)
+    .await
+    .map_err(to_database_error)?;
+
+    Ok(lyrics.map(Lyrics::from))
+  }
+
+  async fn find_by_media_id(
+    &mut self,
+    media_id: &UUID4,
+    version: Option<u32>,
+  ) -> Result<Option<Lyrics>, crate::domain::lyrics::repository::Error> {
+    let lyrics = sqlx::query_as!(
+      LyricsRecord,
+      r#"
+        SELECT "id", "media_id", "version", "language", "content", "lines", "created_at", "updated_at"
+        FROM "lyrics"
+        WHERE "media_id" = $1 AND ($2::INTEGER IS NULL OR "version" = $2)
+        ORDER BY "version" DESC
+        LIMIT 1
+      "#,
+      uuid::Uuid::from(media_id.clone()),
+      version.map(|version| version as i32)
+    )
+    .fetch_optional(&self.db)
+    .await
+    .map_err(to_database_error)?;
+
+    Ok(lyrics.map(Lyrics::from))
+  }
+
+  async fn find_versions_by_media_id(
+    &mut self,
+    media_id: &UUID4,
+  ) -> Result<Vec<Lyrics>, crate::domain::lyrics::repository::Error> {
+    let lyrics = sqlx::query_as!(
+      LyricsRecord,
+      r#"
+        SELECT "id", "media_id", "version", "language", "content", "lines", "created_at", "updated_at"
+        FROM "lyrics"
+        WHERE "media_id" = $1
+        ORDER BY "version" DESC
+      "#,
+      uuid::Uuid::from(media_id.clone())
+    )
+    .fetch_all(&self.db)
+    .await
+    .map_err(to_database_error)?;
+
+    Ok(lyrics.into_iter().map(Lyrics::from).collect())
+  }
+
+  async fn delete_by_id(
+    &mut self,
+    id: &UUID4,
+  ) -> Result<(), crate::domain::lyrics::repository::Error> {
+    sqlx::query!(
+      r#"DELETE FROM "lyrics" WHERE "id" = $1"#,
+      uuid::Uuid::from(id.clone())
+    )
+    .execute(&self.db)
+    .await
+    .map_err(to_database_error)?;
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    domain::{
+      lyrics::repository::LyricsRepository,
+      media::{repository::MediaRepository, Media},
+    },
+    infra::sqlx::SqlxMediaRepository,
+    shared::vo::Slug,
+    AppState,
+  };
+
+  use super::*;
+
+  #[tokio::test]
+  async fn test_lyrics_versions() {
+    const MEDIA_ID: &str = "c2d3e4f5-a6b7-4c8d-9e0f-1a2b3c4d5e6f";
+
+    // Load .env file
+    dotenvy::dotenv().ok();
+
+    async fn cleanup(db: &sqlx::PgPool) {
+      sqlx::query!(
+        r#"DELETE FROM "media" WHERE "id" = $1"#,
+        uuid::Uuid::parse_str(MEDIA_ID).unwrap()
+      )
+      .execute(db)
+      .await
+      .expect("Erro ao excluir dados de teste da tabela media");
+    }
+
+    let app_state = AppState::default().await;
+    let mut media_repository = SqlxMediaRepository::new(&app_state);
+    let mut lyrics_repository = SqlxLyricsRepository::new(&app_state);
+
+    cleanup(&app_state.db).await;
+
+    let media = Media::builder()
+      .id(UUID4::new(MEDIA_ID).unwrap())
+      .name(String::from("Teste"))
+      .slug(Slug::new("test_lyrics_versions_media").unwrap())
+      .build();
+
+    media_repository
+      .create(&media)
+      .await
+      .expect("Erro ao criar media");
+
+    let first = Lyrics::builder()
+      .media_id(media.id().clone())
+      .content(String::from("Primeira versão"))
+      .build();
+
+    let second = Lyrics::builder()
+      .media_id(media.id().clone())
+      .version(2)
+      .content(String::from("Segunda versão"))
+      .lines(vec![LyricLine {
+        time: 1_500,
+        text: String::from("Segunda versão"),
+      }])
+      .build();
+
+    lyrics_repository
+      .create(&first)
+      .await
+      .expect("Erro ao criar letra");
+    lyrics_repository
+      .create(&second)
+      .await
+      .expect("Erro ao criar letra");
+
+    let latest = lyrics_repository
+      .find_by_media_id(media.id(), None)
+      .await
+      .expect("Erro ao buscar letra");
+
+    let by_version = lyrics_repository
+      .find_by_media_id(media.id(), Some(1))
+      .await
+      .expect("Erro ao buscar letra");
+
+    let versions = lyrics_repository
+      .find_versions_by_media_id(media.id())
+      .await
+      .expect("Erro ao buscar versões da letra");
+
+    cleanup(&app_state.db).await;
+
+    assert_eq!(
+      latest,
+      Some(second.clone()),
+      "A versão mais recente não confere"
+    );
+    assert_eq!(by_version, Some(first.clone()), "A versão 1 não confere");
+    assert_eq!(versions, vec![second, first], "As versões não conferem");
+  }
+}
@@ -1,15 +1,89 @@
 use crate::*;
 
 use domain::{
+  album::Album,
   artist::Artist,
+  genre::Genre,
   media::{media_aggregate::MediaAggregate, Media},
   source::source_entity::Source,
 };
 use shared::{
-  paged::{PageQueryParams, Paged},
+  paged::{CursorPageParams, CursorPaged, PageQueryParams, Paged},
   vo::{Slug, UUID4},
 };
 
+use super::{
+  facet::{self, FacetColumn},
+  keyset::push_keyset,
+  list_filter::{push_relation_filter, push_value_filter},
+};
+
+fn sort_column(field: &crate::domain::media::repository::SortField) -> &'static str {
+  use crate::domain::media::repository::SortField;
+
+  match field {
+    SortField::Name => r#""m"."name""#,
+    SortField::ReleaseDate => r#""m"."release_date""#,
+    SortField::CreatedAt => r#""m"."created_at""#,
+    SortField::UpdatedAt => r#""m"."updated_at""#,
+    SortField::Popularity => {
+      r#"(SELECT COUNT(*) FROM "media_album" "ma" INNER JOIN "album" "a" ON "a"."id" = "ma"."album_id" WHERE "ma"."media_id" = "m"."id" AND "a"."deleted_at" IS NULL)"#
+    }
+  }
+}
+
+const SELECT_MEDIA: &str = r#"
+  select
+  "m"."id",
+  "m"."name",
+  "m"."type"::text as "media_type",
+  "m"."slug",
+  "m"."release_date",
+  "m"."cover",
+  "m"."parental_rating",
+  "m"."duration",
+  "m"."isrc",
+  "m"."bpm",
+  "m"."key",
+  "m"."language",
+  "m"."is_single",
+  "m"."created_at",
+  "m"."updated_at"
+  from "media" "m"
+"#;
+
+/**
+ * Aplica os filtros da listagem de mídias sobre a tabela "media" "m".
+ */
+fn push_filters(
+  qb: &mut sqlx::QueryBuilder<'_, sqlx::Postgres>,
+  query: &crate::domain::media::repository::FindByQuery,
+) {
+  let filter = create_filter!(crate::domain::media::repository::FindByQuery, qb, r#""m"."deleted_at" IS NULL"# => {
+    search(value) => search_by!(qb, value.clone(), r#""m""#),
+    release_date(value) => qb.push(r#""m"."release_date" = "#).push_bind(*value),
+    min_release_date(value) => qb.push(r#""m"."release_date" >= "#).push_bind(*value),
+    max_release_date(value) => qb.push(r#""m"."release_date" <= "#).push_bind(*value),
+    parental_rating(value) => push_value_filter(qb, r#""m"."parental_rating""#, &value.map(|rating| *rating as i16), ""),
+    min_parental_rating(value) => qb.push(r#""m"."parental_rating" >= "#).push_bind(*value as i16),
+    max_parental_rating(value) => qb.push(r#""m"."parental_rating" <= "#).push_bind(*value as i16),
+    is_single(value) => qb.push(r#""m"."is_single" = "#).push_bind(*value),
+    media_type(value) => push_value_filter(qb, r#""m"."type""#, &value.map(ToString::to_string), r#"::"media_type""#),
+    slug(value) => qb.push(r#""m"."slug" = "#).push_bind(value.to_string()),
+    artist_ids(value) => push_relation_filter(qb, r#""m"."id""#, "media_interpreter", "media_id", "interpreter_id", value),
+    composer_ids(value) => push_relation_filter(qb, r#""m"."id""#, "media_composer", "media_id", "composer_id", value),
+    genre_ids(value) => push_relation_filter(qb, r#""m"."id""#, "media_genre", "media_id", "genre_id", value),
+    album_ids(value) => push_relation_filter(qb, r#""m"."id""#, "media_album", "media_id", "album_id", value),
+    credit_role(value) => {
+      qb.push(r#""m"."id" IN (SELECT "mcr"."media_id" FROM "media_credit" "mcr" WHERE "mcr"."role" = "#)
+        .push_bind(value.to_string())
+        .push(r#"::"credit_role")"#);
+    },
+  });
+
+  filter(qb, query);
+}
+
 pub struct SqlxMediaRepository {
   db: sqlx::Pool<sqlx::Postgres>,
 }
@@ -20,23 +94,537 @@ impl SqlxMediaRepository {
       db: app_state.db.clone(),
     }
   }
+
+  /**
+   * Busca uma media pelo identificador entre as medias ativas ou, com `deleted`, entre as excluídas.
+   */
+  async fn fetch_by_id(
+    &self,
+    id: &crate::shared::vo::UUID4,
+    deleted: bool,
+  ) -> Result<Option<crate::domain::media::Media>, crate::domain::media::repository::Error> {
+    let media = sqlx::query!(
+      r#"
+        SELECT "id", "name", "type" as "media_type: String","slug", "cover", "release_date", "is_single", "parental_rating", "duration", "isrc", "bpm", "key", "language", "created_at", "updated_at" FROM "media" WHERE "id" = $1 AND ("deleted_at" IS NOT NULL) = $2
+      "#,
+      Into::<uuid::Uuid>::into(id.clone()),
+      deleted
+    )
+    .fetch_optional(&self.db)
+    .await
+    .map_err(to_database_error)?
+    .map(|value| crate::domain::media::Media::builder()
+    .id(crate::shared::vo::UUID4::from(value.id))
+    .name(value.name)
+    .media_type(value.media_type.parse().unwrap())
+    .slug(crate::shared::vo::Slug::new(value.slug).unwrap())
+    .cover(value.cover)
+    .is_single(value.is_single)
+    .release_date(value.release_date)
+    .parental_rating(value.parental_rating as u8)
+    .duration(value.duration.and_then(|value| u32::try_from(value).ok()))
+    .isrc(value.isrc.and_then(|value| crate::shared::vo::Isrc::new(value).ok()))
+    .bpm(value.bpm.map(|value| value as u16))
+    .key(value.key)
+    .language(value.language)
+    .created_at(value.created_at)
+    .updated_at(value.updated_at)
+    .build());
+
+    if media.is_none() {
+      return Ok(None);
+    }
+
+    let mut media = media.unwrap();
+
+    let (album_ids, genre_ids, composer_ids, interpreter_ids, credits) = tokio::join!(
+      async {
+        sqlx::query_scalar!(
+          r#" SELECT "album_id" FROM "media_album" WHERE "media_id" = $1 "#,
+          uuid::Uuid::from(id.clone())
+        )
+        .fetch_all(&self.db)
+        .await
+        .map_err(to_database_error)
+        .map(|result| {
+          result
+            .into_iter()
+            .map(crate::shared::vo::UUID4::from)
+            .collect::<std::collections::HashSet<crate::shared::vo::UUID4>>()
+        })
+      },
+      async {
+        sqlx::query_scalar!(
+          r#" SELECT "genre_id" FROM "media_genre" WHERE "media_id" = $1 "#,
+          uuid::Uuid::from(id.clone())
+        )
+        .fetch_all(&self.db)
+        .await
+        .map_err(to_database_error)
+        .map(|result| {
+          result
+            .into_iter()
+            .map(crate::shared::vo::UUID4::from)
+            .collect::<std::collections::HashSet<crate::shared::vo::UUID4>>()
+        })
+      },
+      async {
+        sqlx::query_scalar!(
+          r#" SELECT "composer_id" FROM "media_composer" WHERE "media_id" = $1 "#,
+          uuid::Uuid::from(id.clone())
+        )
+        .fetch_all(&self.db)
+        .await
+        .map_err(to_database_error)
+        .map(|result| {
+          result
+            .into_iter()
+            .map(crate::shared::vo::UUID4::from)
+            .collect::<std::collections::HashSet<crate::shared::vo::UUID4>>()
+        })
+      },
+      async {
+        sqlx::query_scalar!(
+          r#" SELECT "interpreter_id" FROM "media_interpreter" WHERE "media_id" = $1 "#,
+          uuid::Uuid::from(id.clone())
+        )
+        .fetch_all(&self.db)
+        .await
+        .map_err(to_database_error)
+        .map(|result| {
+          result
+            .into_iter()
+            .map(crate::shared::vo::UUID4::from)
+            .collect::<std::collections::HashSet<crate::shared::vo::UUID4>>()
+        })
+      },
+      async {
+        sqlx::query!(
+          r#"
+            SELECT "artist_id", "role"::TEXT as "role!"
+            FROM "media_credit"
+            WHERE "media_id" = $1
+            ORDER BY "position"
+          "#,
+          uuid::Uuid::from(id.clone())
+        )
+        .fetch_all(&self.db)
+        .await
+        .map_err(to_database_error)
+        .map(|result| {
+          result
+            .into_iter()
+            .map(|credit| crate::domain::media::credit::Credit {
+              artist_id: crate::shared::vo::UUID4::from(credit.artist_id),
+              role: credit.role.parse().unwrap(),
+            })
+            .collect::<Vec<_>>()
+        })
+      }
+    );
+
+    media = crate::domain::media::MediaBuilder::from(media)
+      .credits(credits?)
+      .album_ids(album_ids?)
+      .composer_ids(composer_ids?)
+      .genre_ids(genre_ids?)
+      .interpreter_ids(interpreter_ids?)
+      .build();
+
+    Ok(Some(media))
+  }
 }
 
 fn to_database_error(error: sqlx::error::Error) -> crate::domain::media::repository::Error {
   crate::domain::media::repository::Error::DatabaseError(error.to_string())
 }
 
+fn to_conversion_error(
+  error: std::num::TryFromIntError,
+) -> crate::domain::media::repository::Error {
+  crate::domain::media::repository::Error::DatabaseError(error.to_string())
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct MediaRecord {
+  id: uuid::Uuid,
+  name: String,
+  media_type: String,
+  slug: String,
+  release_date: Option<chrono::NaiveDate>,
+  cover: Option<String>,
+  parental_rating: i16,
+  duration: Option<i32>,
+  isrc: Option<String>,
+  bpm: Option<i16>,
+  key: Option<String>,
+  language: Option<String>,
+  is_single: bool,
+  created_at: chrono::NaiveDateTime,
+  updated_at: chrono::NaiveDateTime,
+}
+
+impl From<MediaRecord> for Media {
+  fn from(media: MediaRecord) -> Self {
+    Media::builder()
+      .id(UUID4::from(media.id))
+      .name(media.name)
+      .media_type(media.media_type.parse().unwrap())
+      .slug(Slug::from(media.slug))
+      .release_date(media.release_date)
+      .cover(media.cover)
+      .parental_rating(media.parental_rating as u8)
+      .duration(media.duration.and_then(|value| u32::try_from(value).ok()))
+      .isrc(
+        media
+          .isrc
+          .and_then(|value| crate::shared::vo::Isrc::new(value).ok()),
+      )
+      .bpm(media.bpm.map(|value| value as u16))
+      .key(media.key)
+      .language(media.language)
+      .is_single(media.is_single)
+      .created_at(media.created_at)
+      .updated_at(media.updated_at)
+      .build()
+  }
+}
+
+/**
+ * Carrega as entidades relacionadas à media (artistas, créditos, fontes, gêneros e álbuns).
+ */
+async fn load_aggregate(
+  db: &sqlx::Pool<sqlx::Postgres>,
+  media: Media,
+) -> Result<MediaAggregate, crate::domain::media::repository::Error> {
+  let media_id = uuid::Uuid::from(media.id().clone());
+
+  let (composers, interpreters, credits, sources, genres, albums) = tokio::join!(
+    async {
+      sqlx::query!(
+        r#"
+        SELECT
+        "a"."id",
+        "a"."name",
+        "a"."slug",
+        "a"."country",
+        "a"."artist_type"::TEXT as "artist_type!",
+        "a"."created_at",
+        "a"."updated_at"
+        FROM "media_composer" "mc"
+        INNER JOIN "artist" "a" ON "a"."id" = "mc"."composer_id"
+        WHERE mc."media_id" = $1 AND "a"."deleted_at" IS NULL
+        ORDER BY "a"."name"
+        "#,
+        media_id
+      )
+      .fetch_all(db)
+      .await
+      .map_err(to_database_error)
+      .map(|result| {
+        result
+          .into_iter()
+          .map(|result| {
+            Artist::builder()
+              .id(UUID4::from(result.id))
+              .name(result.name)
+              .slug(Slug::from(result.slug))
+              .country(result.country)
+              .artist_type(result.artist_type.parse().unwrap_or_default())
+              .created_at(result.created_at)
+              .updated_at(result.updated_at)
+              .build()
+          })
+          .collect::<Vec<_>>()
+      })
+    },
+    async {
+      sqlx::query!(
+        r#"
+        SELECT
+        "a"."id",
+        "a"."name",
+        "a"."slug",
+        "a"."country",
+        "a"."artist_type"::TEXT as "artist_type!",
+        "a"."created_at",
+        "a"."updated_at"
+        FROM "media_interpreter" "mi"
+        INNER JOIN "artist" "a" ON "a"."id" = "mi"."interpreter_id"
+        WHERE mi."media_id" = $1 AND "a"."deleted_at" IS NULL
+        ORDER BY "a"."name"
+        "#,
+        media_id
+      )
+      .fetch_all(db)
+      .await
+      .map_err(to_database_error)
+      .map(|result| {
+        result
+          .into_iter()
+          .map(|result| {
+            Artist::builder()
+              .id(UUID4::from(result.id))
+              .name(result.name)
+              .slug(Slug::from(result.slug))
+              .country(result.country)
+              .artist_type(result.artist_type.parse().unwrap_or_default())
+              .created_at(result.created_at)
+              .updated_at(result.updated_at)
+              .build()
+          })
+          .collect::<Vec<_>>()
+      })
+    },
+    async {
+      sqlx::query!(
+        r#"
+        SELECT
+        "mc"."role"::TEXT as "role!",
+        "a"."id",
+        "a"."name",
+        "a"."slug",
+        "a"."country",
+        "a"."artist_type"::TEXT as "artist_type!",
+        "a"."created_at",
+        "a"."updated_at"
+        FROM "media_credit" "mc"
+        INNER JOIN "artist" "a" ON "a"."id" = "mc"."artist_id"
+        WHERE "mc"."media_id" = $1 AND "a"."deleted_at" IS NULL
+        ORDER BY "mc"."position"
+        "#,
+        media_id
+      )
+      .fetch_all(db)
+      .await
+      .map_err(to_database_error)
+      .map(|result| {
+        result
+          .into_iter()
+          .map(|result| crate::domain::media::credit::ArtistCredit {
+            role: result.role.parse().unwrap(),
+            artist: Artist::builder()
+              .id(UUID4::from(result.id))
+              .name(result.name)
+              .slug(Slug::from(result.slug))
+              .country(result.country)
+              .artist_type(result.artist_type.parse().unwrap_or_default())
+              .created_at(result.created_at)
+              .updated_at(result.updated_at)
+              .build(),
+          })
+          .collect::<Vec<_>>()
+      })
+    },
+    async {
+      sqlx::query!(
+        r#"
+          SELECT
+          "s"."id",
+          "s"."source_type"::TEXT,
+          "s"."src",
+          "s"."external_id",
+          "s"."media_id",
+          "s"."status",
+          "s"."last_checked_at",
+          "s"."start_offset",
+          "s"."duration",
+          "s"."created_at",
+          "s"."updated_at"
+          FROM "source" "s"
+          WHERE "s"."media_id" = $1 AND "s"."deleted_at" IS NULL
+          ORDER BY "s"."created_at", "s"."id"
+          "#,
+        media_id
+      )
+      .fetch_all(db)
+      .await
+      .map_err(to_database_error)
+      .map(|result| {
+        result
+          .into_iter()
+          .map(|result| {
+            Source::builder()
+              .id(UUID4::from(result.id))
+              .src(result.src)
+              .external_id(result.external_id)
+              .media_id(UUID4::from(result.media_id))
+              .status(result.status.parse().unwrap_or_default())
+              .last_checked_at(result.last_checked_at)
+              .start_offset(
+                result
+                  .start_offset
+                  .and_then(|value| u32::try_from(value).ok()),
+              )
+              .duration(result.duration.and_then(|value| u32::try_from(value).ok()))
+              .source_type(result.source_type.unwrap_or_default().parse().unwrap())
+              .created_at(result.created_at)
+              .updated_at(result.updated_at)
+              .build()
+          })
+          .collect::<Vec<_>>()
+      })
+    },
+    async {
+      sqlx::query!(
+        r#"
+          SELECT "g"."id", "g"."name", "g"."slug", "g"."parent_id", "g"."created_at", "g"."updated_at"
+          FROM "media_genre" "mg"
+          INNER JOIN "genre" "g" ON "g"."id" = "mg"."genre_id"
+          WHERE "mg"."media_id" = $1 AND "g"."deleted_at" IS NULL
+          ORDER BY "g"."name"
+        "#,
+        media_id
+      )
+      .fetch_all(db)
+      .await
+      .map_err(to_database_error)
+      .map(|result| {
+        result
+          .into_iter()
+          .map(|result| {
+            Genre::builder()
+              .id(UUID4::from(result.id))
+              .name(result.name)
+              .slug(Slug::from(result.slug))
+              .parent_id(result.parent_id.map(UUID4::from))
+              .created_at(result.created_at)
+              .updated_at(result.updated_at)
+              .build()
+          })
+          .collect::<Vec<_>>()
+      })
+    },
+    async {
+      sqlx::query!(
+        r#"
+          SELECT "a"."id", "a"."name", "a"."album_type"::TEXT as "album_type!", "a"."cover", "a"."slug",
+                 "a"."release_date", "a"."parental_rating", "a"."label", "a"."catalog_number", "a"."barcode",
+                 "a"."release_country", "a"."release_group_id", "a"."edition", "a"."created_at", "a"."updated_at"
+          FROM "media_album" "ma"
+          INNER JOIN "album" "a" ON "a"."id" = "ma"."album_id"
+          WHERE "ma"."media_id" = $1 AND "a"."deleted_at" IS NULL
+          ORDER BY "a"."release_date" ASC NULLS LAST, "a"."name"
+        "#,
+        media_id
+      )
+      .fetch_all(db)
+      .await
+      .map_err(to_database_error)
+      .map(|result| {
+        result
+          .into_iter()
+          .map(|result| {
+            Album::builder()
+              .id(UUID4::from(result.id))
+              .name(result.name)
+              .album_type(result.album_type.parse().unwrap())
+              .cover(result.cover)
+              .slug(Slug::from(result.slug))
+              .release_date(result.release_date)
+              .parental_rating(result.parental_rating as u8)
+              .label(result.label)
+              .catalog_number(result.catalog_number)
+              .barcode(
+                result
+                  .barcode
+                  .and_then(|barcode| crate::shared::vo::Barcode::new(barcode).ok()),
+              )
+              .release_country(result.release_country)
+              .release_group_id(UUID4::from(result.release_group_id))
+              .edition(result.edition)
+              .created_at(result.created_at)
+              .updated_at(result.updated_at)
+              .build()
+          })
+          .collect::<Vec<_>>()
+      })
+    }
+  );
+
+  let (composers, interpreters, credits, sources, genres, albums) = (
+    composers?,
+    interpreters?,
+    credits?,
+    sources?,
+    genres?,
+    albums?,
+  );
+
+  let media = crate::domain::media::MediaBuilder::from(media)
+    .composer_ids(composers.iter().map(|artist| artist.id().clone()).collect())
+    .interpreter_ids(
+      interpreters
+        .iter()
+        .map(|artist| artist.id().clone())
+        .collect(),
+    )
+    .credits(
+      credits
+        .iter()
+        .map(|credit| crate::domain::media::credit::Credit {
+          artist_id: credit.artist.id().clone(),
+          role: credit.role.clone(),
+        })
+        .collect(),
+    )
+    .genre_ids(genres.iter().map(|genre| genre.id().clone()).collect())
+    .album_ids(albums.iter().map(|album| album.id().clone()).collect())
+    .build();
+
+  Ok(MediaAggregate {
+    media,
+    composers,
+    interpreters,
+    credits,
+    sources,
+    genres,
+    albums,
+  })
+}
+
+async fn save_credits(
+  trx: &mut sqlx::PgConnection,
+  media: &crate::domain::media::Media,
+) -> Result<(), sqlx::Error> {
+  sqlx::query!(
+    r#"DELETE FROM "media_credit" WHERE "media_id" = $1"#,
+    uuid::Uuid::from(media.id().clone())
+  )
+  .execute(&mut *trx)
+  .await?;
+
+  for (position, credit) in media.credits().iter().enumerate() {
+    sqlx::query!(
+      r#"
+        INSERT INTO "media_credit" ("media_id", "artist_id", "role", "position")
+        VALUES ($1, $2, $3::"credit_role", $4)
+      "#,
+      uuid::Uuid::from(media.id().clone()),
+      uuid::Uuid::from(credit.artist_id.clone()),
+      credit.role.to_string() as _,
+      position as i16,
+    )
+    .execute(&mut *trx)
+    .await?;
+  }
+
+  Ok(())
+}
+
 impl crate::domain::media::repository::MediaRepository for SqlxMediaRepository {
   async fn create(
     &mut self,
     media: &crate::domain::media::Media,
   ) -> Result<(), crate::domain::media::repository::Error> {
+    let duration = super::to_integer(*media.duration()).map_err(to_conversion_error)?;
+
     let mut trx = self.db.begin().await.map_err(to_database_error)?;
 
     sqlx::query!(
       r#"
-      INSERT INTO "media" ("id", "name", "type", "slug", "cover", "release_date", "is_single", "parental_rating", "created_at", "updated_at")
-      VALUES($1,$2, $3::"media_type", $4, $5, $6, $7, $8, $9, $10)
+      INSERT INTO "media" ("id", "name", "type", "slug", "cover", "release_date", "is_single", "parental_rating", "duration", "isrc", "bpm", "key", "language", "created_at", "updated_at")
+      VALUES($1,$2, $3::"media_type", $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
     "#,
     Into::<uuid::Uuid>::into(media.id().clone()),
     media.name().clone(),
@@ -46,6 +634,11 @@ impl crate::domain::media::repository::MediaRepository for SqlxMediaRepository {
     media.release_date().clone(),
     *media.is_single(),
     *media.parental_rating() as i16,
+    duration,
+    media.isrc().as_ref().map(|value| value.to_string()),
+    media.bpm().map(|value| value as i16),
+    media.key().clone(),
+    media.language().clone(),
     media.created_at().clone(),
     media.updated_at().clone()
 
@@ -89,6 +682,10 @@ impl crate::domain::media::repository::MediaRepository for SqlxMediaRepository {
       .await
       .map_err(to_database_error)?;
 
+    save_credits(&mut trx, media)
+      .await
+      .map_err(to_database_error)?;
+
     trx.commit().await.map_err(to_database_error)?;
 
     Ok(())
@@ -98,12 +695,14 @@ impl crate::domain::media::repository::MediaRepository for SqlxMediaRepository {
     &mut self,
     media: &crate::domain::media::Media,
   ) -> Result<(), crate::domain::media::repository::Error> {
+    let duration = super::to_integer(*media.duration()).map_err(to_conversion_error)?;
+
     let mut trx = self.db.begin().await.map_err(to_database_error)?;
 
     sqlx::query!(
       r#"
         UPDATE "media"
-        SET "name" = $2, "type" = $3::"media_type", "slug" = $4, "cover" = $5, "release_date" = $6, "is_single" = $7, "parental_rating" = $8, "updated_at" = $9
+        SET "name" = $2, "type" = $3::"media_type", "slug" = $4, "cover" = $5, "release_date" = $6, "is_single" = $7, "parental_rating" = $8, "duration" = $9, "isrc" = $10, "bpm" = $11, "key" = $12, "language" = $13, "updated_at" = $14
         WHERE "id" = $1
       "#,
       Into::<uuid::Uuid>::into(media.id().clone()),
@@ -114,6 +713,11 @@ impl crate::domain::media::repository::MediaRepository for SqlxMediaRepository {
       media.release_date().clone(),
       *media.is_single(),
       *media.parental_rating() as i16,
+      duration,
+      media.isrc().as_ref().map(|value| value.to_string()),
+      media.bpm().map(|value| value as i16),
+      media.key().clone(),
+      media.language().clone(),
       media.updated_at().clone()
     )
     .execute(&mut *trx)
@@ -156,6 +760,10 @@ impl crate::domain::media::repository::MediaRepository for SqlxMediaRepository {
       .await
       .map_err(to_database_error)?;
 
+    save_credits(&mut trx, media)
+      .await
+      .map_err(to_database_error)?;
+
     trx.commit().await.map_err(to_database_error)?;
 
     Ok(())
@@ -165,113 +773,93 @@ impl crate::domain::media::repository::MediaRepository for SqlxMediaRepository {
     &mut self,
     id: &crate::shared::vo::UUID4,
   ) -> Result<Option<crate::domain::media::Media>, crate::domain::media::repository::Error> {
-    let media = sqlx::query!(
+    self.fetch_by_id(id, false).await
+  }
+
+  async fn find_aggregate_by_id(
+    &mut self,
+    id: &UUID4,
+  ) -> Result<Option<MediaAggregate>, crate::domain::media::repository::Error> {
+    let media = sqlx::query_as!(
+      MediaRecord,
       r#"
-        SELECT "id", "name", "type" as "media_type: String","slug", "cover", "release_date", "is_single", "parental_rating", "created_at", "updated_at" FROM "media" WHERE "id" = $1
+        SELECT "id", "name", "type"::TEXT as "media_type!", "slug", "cover", "release_date", "is_single", "parental_rating",
+               "duration", "isrc", "bpm", "key", "language", "created_at", "updated_at"
+        FROM "media"
+        WHERE "id" = $1 AND "deleted_at" IS NULL
       "#,
-      Into::<uuid::Uuid>::into(id.clone())
+      uuid::Uuid::from(id.clone())
     )
     .fetch_optional(&self.db)
     .await
-    .map_err(to_database_error)?
-    .map(|value| crate::domain::media::Media::builder()
-    .id(crate::shared::vo::UUID4::from(value.id))
-    .name(value.name)
-    .media_type(value.media_type.parse().unwrap())
-    .slug(crate::shared::vo::Slug::new(value.slug).unwrap())
-    .cover(value.cover)
-    .is_single(value.is_single)
-    .release_date(value.release_date)
-    .parental_rating(value.parental_rating as u8)
-    .created_at(value.created_at)
-    .updated_at(value.updated_at)
-    .build());
+    .map_err(to_database_error)?;
 
-    if media.is_none() {
-      return Ok(None);
+    match media {
+      Some(media) => Ok(Some(load_aggregate(&self.db, Media::from(media)).await?)),
+      None => Ok(None),
     }
+  }
 
-    let mut media = media.unwrap();
+  async fn find_by_slug(
+    &mut self,
+    slug: &Slug,
+    artist_slug: &Slug,
+  ) -> Result<Option<MediaAggregate>, crate::domain::media::repository::Error> {
+    let media = sqlx::query_as!(
+      MediaRecord,
+      r#"
+        SELECT "m"."id", "m"."name", "m"."type"::TEXT as "media_type!", "m"."slug", "m"."cover", "m"."release_date", "m"."is_single",
+               "m"."parental_rating", "m"."duration", "m"."isrc", "m"."bpm", "m"."key", "m"."language", "m"."created_at", "m"."updated_at"
+        FROM "media" "m"
+        WHERE "m"."slug" = $1 AND "m"."deleted_at" IS NULL
+          AND EXISTS (
+            SELECT 1
+            FROM "media_interpreter" "mi"
+            INNER JOIN "artist" "a" ON "a"."id" = "mi"."interpreter_id"
+            WHERE "mi"."media_id" = "m"."id" AND "a"."slug" = $2 AND "a"."deleted_at" IS NULL
+          )
+        LIMIT 1
+      "#,
+      slug.to_string(),
+      artist_slug.to_string()
+    )
+    .fetch_optional(&self.db)
+    .await
+    .map_err(to_database_error)?;
 
-    let (album_ids, genre_ids, composer_ids, interpreter_ids) = tokio::join!(
-      async {
-        sqlx::query_scalar!(
-          r#" SELECT "album_id" FROM "media_album" WHERE "media_id" = $1 "#,
-          uuid::Uuid::from(id.clone())
-        )
-        .fetch_all(&self.db)
-        .await
-        .map_err(to_database_error)
-        .map(|result| {
-          result
-            .into_iter()
-            .map(crate::shared::vo::UUID4::from)
-            .collect::<std::collections::HashSet<crate::shared::vo::UUID4>>()
-        })
-      },
-      async {
-        sqlx::query_scalar!(
-          r#" SELECT "genre_id" FROM "media_genre" WHERE "media_id" = $1 "#,
-          uuid::Uuid::from(id.clone())
-        )
-        .fetch_all(&self.db)
-        .await
-        .map_err(to_database_error)
-        .map(|result| {
-          result
-            .into_iter()
-            .map(crate::shared::vo::UUID4::from)
-            .collect::<std::collections::HashSet<crate::shared::vo::UUID4>>()
-        })
-      },
-      async {
-        sqlx::query_scalar!(
-          r#" SELECT "composer_id" FROM "media_composer" WHERE "media_id" = $1 "#,
-          uuid::Uuid::from(id.clone())
-        )
-        .fetch_all(&self.db)
-        .await
-        .map_err(to_database_error)
-        .map(|result| {
-          result
-            .into_iter()
-            .map(crate::shared::vo::UUID4::from)
-            .collect::<std::collections::HashSet<crate::shared::vo::UUID4>>()
-        })
-      },
-      async {
-        sqlx::query_scalar!(
-          r#" SELECT "interpreter_id" FROM "media_interpreter" WHERE "media_id" = $1 "#,
-          uuid::Uuid::from(id.clone())
-        )
-        .fetch_all(&self.db)
-        .await
-        .map_err(to_database_error)
-        .map(|result| {
-          result
-            .into_iter()
-            .map(crate::shared::vo::UUID4::from)
-            .collect::<std::collections::HashSet<crate::shared::vo::UUID4>>()
-        })
-      }
-    );
+    match media {
+      Some(media) => Ok(Some(load_aggregate(&self.db, Media::from(media)).await?)),
+      None => Ok(None),
+    }
+  }
 
-    media = crate::domain::media::MediaBuilder::from(media)
-      .album_ids(album_ids?)
-      .composer_ids(composer_ids?)
-      .genre_ids(genre_ids?)
-      .interpreter_ids(interpreter_ids?)
-      .build();
+  async fn delete_by_id(
+    &mut self,
+    id: &crate::shared::vo::UUID4,
+  ) -> Result<(), crate::domain::media::repository::Error> {
+    sqlx::query!(
+      r#"UPDATE "media" SET "deleted_at" = now() WHERE "id" = $1 AND "deleted_at" IS NULL"#,
+      uuid::Uuid::from(id.clone())
+    )
+    .execute(&self.db)
+    .await
+    .map_err(to_database_error)?;
+    Ok(())
+  }
 
-    Ok(Some(media))
+  async fn find_deleted_by_id(
+    &mut self,
+    id: &crate::shared::vo::UUID4,
+  ) -> Result<Option<crate::domain::media::Media>, crate::domain::media::repository::Error> {
+    self.fetch_by_id(id, true).await
   }
 
-  async fn delete_by_id(
+  async fn restore_by_id(
     &mut self,
     id: &crate::shared::vo::UUID4,
   ) -> Result<(), crate::domain::media::repository::Error> {
     sqlx::query!(
-      r#"DELETE FROM "media" WHERE "id" = $1"#,
+      r#"UPDATE "media" SET "deleted_at" = NULL WHERE "id" = $1"#,
       uuid::Uuid::from(id.clone())
     )
     .execute(&self.db)
@@ -279,6 +867,7 @@ impl crate::domain::media::repository::MediaRepository for SqlxMediaRepository {
     .map_err(to_database_error)?;
     Ok(())
   }
+
   async fn find_by(
     &mut self,
     query: &crate::domain::media::repository::FindByQuery,
@@ -286,253 +875,103 @@ impl crate::domain::media::repository::MediaRepository for SqlxMediaRepository {
     crate::shared::paged::Paged<crate::domain::media::media_aggregate::MediaAggregate>,
     crate::domain::media::repository::Error,
   > {
-    let filter = create_filter!(crate::domain::media::repository::FindByQuery, qb => {
-      search(value) => search_by!(qb, value.clone(), r#""m"."name""#, r#""m"."slug""#),
-      release_date(value) => qb.push(r#""m"."release_date" = "#).push_bind(*value),
-      min_release_date(value) => qb.push(r#""m"."release_date" >= "#).push_bind(*value),
-      max_release_date(value) => qb.push(r#""m"."release_date" <= "#).push_bind(*value),
-      parental_rating(value) => qb.push(r#""m"."parental_rating" = "#).push_bind(*value as i16),
-      min_parental_rating(value) => qb.push(r#""m"."parental_rating" >= "#).push_bind(*value as i16),
-      max_parental_rating(value) => qb.push(r#""m"."parental_rating" <= "#).push_bind(*value as i16),
-      is_single(value) => qb.push(r#""m"."is_single" = "#).push_bind(*value),
-      media_type(value) => qb.push(r#""m"."type" = "#).push_bind(value.to_string()),
-      slug(value) => qb.push(r#""m"."slug" = "#).push_bind(value.to_string()),
-      artist_ids(value) => {
-        qb.push(r#""m"."id" IN (SELECT "mi"."media_id" FROM "media_interpreter" "mi" WHERE "mi"."interpreter_id" IN ("#);
-
-        let mut separated = qb.separated(", ");
-
-        for id in value {
-          separated.push_bind(uuid::Uuid::from(id.clone()));
-        }
-
-        separated.push_unseparated("))");
-      },
-      composer_ids(value) => {
-        qb.push(r#""m"."id" IN (SELECT "mc"."media_id" FROM "media_composer" "mc" WHERE "mc"."composer_id" IN ("#);
-
-        let mut separated = qb.separated(", ");
-
-        for id in value {
-          separated.push_bind(uuid::Uuid::from(id.clone()));
-        }
+    let (count, items) = tokio::join!(
+      async {
+        let mut query_builder =
+          sqlx::QueryBuilder::new(r#"SELECT COUNT("m"."id") FROM "media" "m""#);
+        push_filters(&mut query_builder, query);
 
-        separated.push_unseparated("))");
+        query_builder
+          .build_query_scalar::<i64>()
+          .fetch_one(&self.db)
+          .await
+          .map_err(to_database_error)
+          .map(|count| count as usize)
       },
-      genre_ids(value) => {
-        qb.push(r#""m"."id" IN (SELECT "mg"."media_id" FROM "media_genre" "mg" WHERE "mg"."genre_id" IN ("#);
-
-        let mut separated = qb.separated(", ");
-
-        for id in value {
-          separated.push_bind(uuid::Uuid::from(id.clone()));
+      async {
+        let mut query_builder = sqlx::QueryBuilder::new(SELECT_MEDIA);
+
+        push_filters(&mut query_builder, query);
+
+        if let Some(sort) = &query.sort {
+          order_by!(
+            query_builder,
+            sort,
+            sort_column(&sort.field),
+            r#""m"."name", "m"."id""#
+          );
+        } else if let Some(search) = &query.search {
+          query_builder.push(" ORDER BY ");
+          search_rank!(query_builder, search.clone(), r#""m""#);
+          query_builder.push(r#" DESC, "m"."name", "m"."id""#);
         }
 
-        separated.push_unseparated("))");
-      },
-      album_ids(value) => {
-        qb.push(r#""m"."id" IN (SELECT "ma"."media_id" FROM "media_album" "ma" WHERE "ma"."album_id" IN ("#);
-
-        let mut separated = qb.separated(", ");
+        let page = PageQueryParams::from(query.page.clone());
 
-        for id in value {
-          separated.push_bind(uuid::Uuid::from(id.clone()));
-        }
+        query_builder
+          .push(" LIMIT ")
+          .push_bind(i64::try_from(page.take).unwrap_or(i64::MAX))
+          .push(" OFFSET ")
+          .push_bind(i64::try_from(page.skip).unwrap_or(i64::MAX))
+          .build_query_as::<MediaRecord>()
+          .fetch_all(&self.db)
+          .await
+          .map_err(to_database_error)
+          .map(|result| {
+            result
+              .into_iter()
+              .map(|media| load_aggregate(&self.db, Media::from(media)))
+              .collect::<Vec<_>>()
+          })
+      }
+    );
 
-        separated.push_unseparated("))");
-      },
-    });
+    let items = futures::future::try_join_all(items?).await?;
 
+    Ok(Paged::from_tuple((count?, items), query.page.clone()))
+  }
+  async fn find_by_cursor(
+    &mut self,
+    query: &crate::domain::media::repository::FindByQuery,
+    page: &CursorPageParams,
+  ) -> Result<
+    CursorPaged<crate::domain::media::media_aggregate::MediaAggregate>,
+    crate::domain::media::repository::Error,
+  > {
     let (count, items) = tokio::join!(
       async {
-        let mut query_builder = sqlx::QueryBuilder::new(r#"SELECT COUNT("id") FROM "media""#);
-        filter(&mut query_builder, query);
+        if !page.include_total {
+          return Ok(None);
+        }
+
+        let mut query_builder =
+          sqlx::QueryBuilder::new(r#"SELECT COUNT("m"."id") FROM "media" "m""#);
+        push_filters(&mut query_builder, query);
 
         query_builder
           .build_query_scalar::<i64>()
           .fetch_one(&self.db)
           .await
           .map_err(to_database_error)
-          .map(|count| count as usize)
+          .map(|count| Some(count as usize))
       },
       async {
-        #[derive(Debug, Clone, sqlx::FromRow)]
-        struct Record {
-          id: uuid::Uuid,
-          name: String,
-          media_type: String,
-          slug: String,
-          release_date: Option<chrono::NaiveDate>,
-          cover: Option<String>,
-          parental_rating: i16,
-          is_single: bool,
-          created_at: chrono::NaiveDateTime,
-          updated_at: chrono::NaiveDateTime,
-        }
-        let mut query_builder = sqlx::QueryBuilder::new(
-          r#"
-            select 
-            "m"."id",
-            "m"."name", 
-            "m"."type"::text as "media_type", 
-            "m"."slug", 
-            "m"."release_date",
-            "m"."cover",
-            "m"."parental_rating",
-            "m"."is_single",
-            "m"."created_at",
-            "m"."updated_at"
-            from "media" "m" 
-          "#,
-        );
+        let mut query_builder = sqlx::QueryBuilder::new(r#"SELECT * FROM ("#);
 
-        filter(&mut query_builder, query);
-
-        let page = PageQueryParams::from(query.page.clone());
+        query_builder.push(SELECT_MEDIA);
+        push_filters(&mut query_builder, query);
+        query_builder.push(r#") "k""#);
+        push_keyset(&mut query_builder, page);
 
         query_builder
-          .push(" LIMIT ")
-          .push_bind(page.take as i64)
-          .push(" OFFSET ")
-          .push_bind(page.skip as i64)
-          .build_query_as::<Record>()
+          .build_query_as::<MediaRecord>()
           .fetch_all(&self.db)
           .await
           .map_err(to_database_error)
           .map(|result| {
             result
               .into_iter()
-              .map(|media| {
-                let db = &self.db;
-
-                async move {
-                  let (composers, interpreters, sources) = tokio::join!(
-                    async {
-                      sqlx::query!(
-                        r#"
-                        SELECT
-                        "a"."id",
-                        "a"."name",
-                        "a"."slug",
-                        "a"."country",
-                        "a"."created_at",
-                        "a"."updated_at"
-                        FROM "media_composer" "mc"
-                        LEFT JOIN "artist" "a" ON "a"."id" = "mc"."composer_id"
-                        WHERE mc."media_id" = $1
-                        "#,
-                        media.id.clone()
-                      )
-                      .fetch_all(db)
-                      .await
-                      .map_err(to_database_error)
-                      .map(|result| {
-                        result
-                          .into_iter()
-                          .map(|result| {
-                            Artist::builder()
-                              .id(UUID4::from(result.id))
-                              .name(result.name)
-                              .slug(Slug::from(result.slug))
-                              .country(result.country)
-                              .created_at(result.created_at)
-                              .updated_at(result.updated_at)
-                              .build()
-                          })
-                          .collect::<Vec<_>>()
-                      })
-                    },
-                    async {
-                      sqlx::query!(
-                        r#"
-                        SELECT
-                        "a"."id",
-                        "a"."name",
-                        "a"."slug",
-                        "a"."country",
-                        "a"."created_at",
-                        "a"."updated_at"
-                        FROM "media_interpreter" "mi"
-                        LEFT JOIN "artist" "a" ON "a"."id" = "mi"."interpreter_id"
-                        WHERE mi."media_id" = $1
-                        "#,
-                        media.id.clone()
-                      )
-                      .fetch_all(db)
-                      .await
-                      .map_err(to_database_error)
-                      .map(|result| {
-                        result
-                          .into_iter()
-                          .map(|result| {
-                            Artist::builder()
-                              .id(UUID4::from(result.id))
-                              .name(result.name)
-                              .slug(Slug::from(result.slug))
-                              .country(result.country)
-                              .created_at(result.created_at)
-                              .updated_at(result.updated_at)
-                              .build()
-                          })
-                          .collect::<Vec<_>>()
-                      })
-                    },
-                    async {
-                      sqlx::query!(
-                        r#"
-                          SELECT
-                          "s"."id",
-                          "s"."source_type"::TEXT,
-                          "s"."src",
-                          "s"."media_id",
-                          "s"."created_at",
-                          "s"."updated_at"
-                          FROM "source" "s"
-                          WHERE "s"."media_id" = $1
-                          "#,
-                        media.id.clone()
-                      )
-                      .fetch_all(db)
-                      .await
-                      .map_err(to_database_error)
-                      .map(|result| {
-                        result
-                          .into_iter()
-                          .map(|result| {
-                            Source::builder()
-                              .id(UUID4::from(result.id))
-                              .src(result.src)
-                              .source_type(result.source_type.unwrap_or_default().parse().unwrap())
-                              .created_at(result.created_at)
-                              .updated_at(result.updated_at)
-                              .build()
-                          })
-                          .collect::<Vec<_>>()
-                      })
-                    }
-                  );
-
-                  let media = Media::builder()
-                    .id(UUID4::from(media.id))
-                    .name(media.name.clone())
-                    .media_type(media.media_type.parse().unwrap())
-                    .slug(Slug::from(media.slug.clone()))
-                    .release_date(media.release_date)
-                    .cover(media.cover.clone())
-                    .parental_rating(media.parental_rating as u8)
-                    .is_single(media.is_single)
-                    .created_at(media.created_at)
-                    .updated_at(media.updated_at)
-                    .build();
-                  Ok(MediaAggregate {
-                    media,
-                    composers: composers?,
-                    interpreters: interpreters?,
-                    sources: sources?,
-                  })
-                    as Result<MediaAggregate, crate::domain::media::repository::Error>
-                }
-              })
+              .map(|media| load_aggregate(&self.db, Media::from(media)))
               .collect::<Vec<_>>()
           })
       }
@@ -540,7 +979,55 @@ impl crate::domain::media::repository::MediaRepository for SqlxMediaRepository {
 
     let items = futures::future::try_join_all(items?).await?;
 
-    Ok(Paged::from_tuple((count?, items), query.page.clone()))
+    Ok(CursorPaged::from_keyset(items, page, count?, |item| {
+      (*item.media.created_at(), item.media.id().clone())
+    }))
+  }
+
+  async fn facets(
+    &mut self,
+    query: &crate::domain::media::repository::FindByQuery,
+    fields: &[crate::domain::media::repository::FacetField],
+  ) -> Result<
+    Vec<crate::shared::facet::Facet<crate::domain::media::repository::FacetField>>,
+    crate::domain::media::repository::Error,
+  > {
+    use crate::domain::media::repository::FacetField;
+
+    let db = &self.db;
+
+    futures::future::try_join_all(fields.iter().map(|field| async move {
+      let column = match field {
+        FacetField::MediaType => &FacetColumn {
+          value: r#""f"."type"::TEXT"#,
+          label: "NULL::TEXT",
+          join: "",
+        },
+        FacetField::ParentalRating => &facet::PARENTAL_RATING,
+        FacetField::ReleaseYear => &facet::RELEASE_YEAR,
+        FacetField::ReleaseDecade => &facet::RELEASE_DECADE,
+        FacetField::Genre => &FacetColumn {
+          value: r#""g"."id"::TEXT"#,
+          label: r#""g"."name""#,
+          join: r#"
+            INNER JOIN "media_genre" "mg" ON "mg"."media_id" = "f"."id"
+            INNER JOIN "genre" "g" ON "g"."id" = "mg"."genre_id" AND "g"."deleted_at" IS NULL
+          "#,
+        },
+      };
+
+      facet::fetch_facet(db, column, r#""f"."id""#, |query_builder| {
+        query_builder.push(r#"SELECT "m".* FROM "media" "m""#);
+        push_filters(query_builder, query);
+      })
+      .await
+      .map(|values| crate::shared::facet::Facet {
+        field: *field,
+        values,
+      })
+      .map_err(to_database_error)
+    }))
+    .await
   }
 }
 
@@ -555,7 +1042,11 @@ mod tests {
       album::{repository::AlbumRepository, Album},
       artist::{repository::ArtistRepository, Artist},
       genre::{Genre, GenreRepository},
-      media::{repository::MediaRepository, Media},
+      media::{
+        credit::{Credit, CreditRole},
+        repository::{FindByQuery, MediaRepository},
+        Media,
+      },
     },
     infra::sqlx::{album_repository, artist_repository, genre_repository},
     shared::vo::{Slug, UUID4},
@@ -574,13 +1065,147 @@ mod tests {
 
     // Load .env file
     dotenvy::dotenv().ok();
-
+
+    let app_state = AppState::default().await;
+    let mut artist_repository = artist_repository::SqlxArtistRepository::new(&app_state);
+    let mut album_repository = album_repository::SqlxAlbumRepository::new(&app_state);
+    let mut media_repository = SqlxMediaRepository::new(&app_state);
+    let mut genre_repository = genre_repository::SqlxGenreRepository::new(&app_state);
+
+    async fn cleanup(db: &sqlx::pool::Pool<Postgres>) {
+      sqlx::query!(
+        r#"
+        DELETE FROM "media" WHERE "id" = $1
+      "#,
+        uuid::Uuid::parse_str(MEDIA_ID).unwrap()
+      )
+      .execute(db)
+      .await
+      .expect("Erro ao excluir dados de teste da tabela media");
+
+      sqlx::query!(
+        r#"
+        DELETE FROM "artist" WHERE "id" in ($1, $2)
+      "#,
+        uuid::Uuid::parse_str(COMPOSER_ID).unwrap(),
+        uuid::Uuid::parse_str(INTERPRETER_ID).unwrap()
+      )
+      .execute(db)
+      .await
+      .expect("Erro ao excluir dados de teste da tabela artist");
+
+      sqlx::query!(
+        r#"
+        DELETE FROM "album" WHERE "id" = $1
+      "#,
+        uuid::Uuid::parse_str(ALBUM_ID).unwrap()
+      )
+      .execute(db)
+      .await
+      .expect("Erro ao excluir dados de teste da tabela album");
+
+      sqlx::query!(
+        r#"
+        DELETE FROM "genre" WHERE "id" = $1
+      "#,
+        uuid::Uuid::parse_str(GENRE_ID).unwrap()
+      )
+      .execute(db)
+      .await
+      .expect("Erro ao excluir dados de teste da tabela genre");
+    }
+
+    let composer = Artist::builder()
+      .id(UUID4::new(COMPOSER_ID).unwrap())
+      .name(String::from("Teste"))
+      .slug(Slug::new("test_create_media_composer").unwrap())
+      .build();
+
+    let interpreter = Artist::builder()
+      .id(UUID4::new(INTERPRETER_ID).unwrap())
+      .name(String::from("Teste"))
+      .slug(Slug::new("test_create_media_interpreter").unwrap())
+      .build();
+
+    let genre = Genre::builder()
+      .id(UUID4::new(GENRE_ID).unwrap())
+      .name(String::from("Teste"))
+      .slug(Slug::new("test_create_media_genre").unwrap())
+      .build();
+
+    let album = Album::builder()
+      .id(UUID4::new(ALBUM_ID).unwrap())
+      .name(String::from("Teste"))
+      .build();
+
+    let media = Media::builder()
+      .id(UUID4::new(MEDIA_ID).unwrap())
+      .name(String::from("Teste"))
+      .slug(Slug::new("test_create_media_media").unwrap())
+      .duration(Some(215_000))
+      .isrc(Some(
+        crate::shared::vo::Isrc::new("BR-ABC-24-00001").unwrap(),
+      ))
+      .bpm(Some(120))
+      .key(Some(String::from("A minor")))
+      .language(Some(String::from("pt")))
+      .album_ids(HashSet::from([album.id().clone()]))
+      .composer_ids(HashSet::from([composer.id().clone()]))
+      .interpreter_ids(HashSet::from([interpreter.id().clone()]))
+      .genre_ids(HashSet::from([genre.id().clone()]))
+      .build();
+    cleanup(&app_state.db).await;
+    artist_repository
+      .create(&composer)
+      .await
+      .expect("Erro ao criar artista compisitor");
+    artist_repository
+      .create(&interpreter)
+      .await
+      .expect("Erro ao criar artista interpreter");
+    genre_repository
+      .create(&genre)
+      .await
+      .expect("Erro ao criar genero");
+    album_repository
+      .create(&album)
+      .await
+      .expect("Erro ao criar album");
+
+    media_repository
+      .create(&media)
+      .await
+      .expect("Erro ao criar media");
+
+    let result = media_repository
+      .find_by_id(media.id())
+      .await
+      .expect("Erro ao buscar media")
+      .expect("Media não encontrada");
+
+    cleanup(&app_state.db).await;
+
+    assert_eq!(
+      result, media,
+      "A media retornada é diferente da media criada"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_update_media() {
+    const MEDIA_ID: &str = "bd5d8140-a897-4577-8eb1-d8e6bc8b31bb";
+    const COMPOSER_ID: &str = "29be307b-6b7d-487e-a649-d314b408e818";
+    const INTERPRETER_ID: &str = "bb79f430-8ed0-4086-b9e7-77bebd0cd3c8";
+    const GENRE_ID: &str = "a8029aed-f392-41e6-9aa1-baa531df245a";
+    const ALBUM_ID: &str = "68749179-30d0-43c9-baff-870b9ff8255d";
+
+    // Load .env file
+    dotenvy::dotenv().ok();
     let app_state = AppState::default().await;
     let mut artist_repository = artist_repository::SqlxArtistRepository::new(&app_state);
     let mut album_repository = album_repository::SqlxAlbumRepository::new(&app_state);
     let mut media_repository = SqlxMediaRepository::new(&app_state);
     let mut genre_repository = genre_repository::SqlxGenreRepository::new(&app_state);
-
     async fn cleanup(db: &sqlx::pool::Pool<Postgres>) {
       sqlx::query!(
         r#"
@@ -627,19 +1252,19 @@ mod tests {
     let composer = Artist::builder()
       .id(UUID4::new(COMPOSER_ID).unwrap())
       .name(String::from("Teste"))
-      .slug(Slug::new("test_create_media_composer").unwrap())
+      .slug(Slug::new("test_update_media_composer").unwrap())
       .build();
 
     let interpreter = Artist::builder()
       .id(UUID4::new(INTERPRETER_ID).unwrap())
       .name(String::from("Teste"))
-      .slug(Slug::new("test_create_media_interpreter").unwrap())
+      .slug(Slug::new("test_update_media_interpreter").unwrap())
       .build();
 
     let genre = Genre::builder()
       .id(UUID4::new(GENRE_ID).unwrap())
       .name(String::from("Teste"))
-      .slug(Slug::new("test_create_media_genre").unwrap())
+      .slug(Slug::new("test_update_media_genre").unwrap())
       .build();
 
     let album = Album::builder()
@@ -647,20 +1272,23 @@ mod tests {
       .name(String::from("Teste"))
       .build();
 
-    let media = Media::builder()
+    let mut media = Media::builder()
       .id(UUID4::new(MEDIA_ID).unwrap())
       .name(String::from("Teste"))
-      .slug(Slug::new("test_create_media_media").unwrap())
+      .slug(Slug::new("test_update_media_media").unwrap())
       .album_ids(HashSet::from([album.id().clone()]))
       .composer_ids(HashSet::from([composer.id().clone()]))
       .interpreter_ids(HashSet::from([interpreter.id().clone()]))
       .genre_ids(HashSet::from([genre.id().clone()]))
       .build();
+
     cleanup(&app_state.db).await;
+
     artist_repository
       .create(&composer)
       .await
       .expect("Erro ao criar artista compisitor");
+
     artist_repository
       .create(&interpreter)
       .await
@@ -679,6 +1307,14 @@ mod tests {
       .await
       .expect("Erro ao criar media");
 
+    media.set_name(String::from("Teste 2"));
+    media.set_slug(Slug::new("test_update_media_media_2").unwrap());
+
+    media_repository
+      .update(&media)
+      .await
+      .expect("Erro ao atualizar media");
+
     let result = media_repository
       .find_by_id(media.id())
       .await
@@ -689,30 +1325,26 @@ mod tests {
 
     assert_eq!(
       result, media,
-      "A media retornada é diferente da media criada"
+      "A media retornada é diferente da media atualizada"
     );
   }
 
   #[tokio::test]
-  async fn test_update_media() {
-    const MEDIA_ID: &str = "bd5d8140-a897-4577-8eb1-d8e6bc8b31bb";
-    const COMPOSER_ID: &str = "29be307b-6b7d-487e-a649-d314b408e818";
-    const INTERPRETER_ID: &str = "bb79f430-8ed0-4086-b9e7-77bebd0cd3c8";
-    const GENRE_ID: &str = "a8029aed-f392-41e6-9aa1-baa531df245a";
-    const ALBUM_ID: &str = "68749179-30d0-43c9-baff-870b9ff8255d";
+  async fn test_credits() {
+    const MEDIA_ID: &str = "3f8b6c2a-7d41-4e9b-a0c5-1d2e3f4a5b6c";
+    const PRODUCER_ID: &str = "6e1f2a3b-4c5d-4e6f-8a7b-9c0d1e2f3a4b";
+    const LYRICIST_ID: &str = "8a9b0c1d-2e3f-4a5b-9c6d-7e8f9a0b1c2d";
 
     // Load .env file
     dotenvy::dotenv().ok();
+
     let app_state = AppState::default().await;
     let mut artist_repository = artist_repository::SqlxArtistRepository::new(&app_state);
-    let mut album_repository = album_repository::SqlxAlbumRepository::new(&app_state);
     let mut media_repository = SqlxMediaRepository::new(&app_state);
-    let mut genre_repository = genre_repository::SqlxGenreRepository::new(&app_state);
+
     async fn cleanup(db: &sqlx::pool::Pool<Postgres>) {
       sqlx::query!(
-        r#"
-        DELETE FROM "media" WHERE "id" = $1
-      "#,
+        r#"DELETE FROM "media" WHERE "id" = $1"#,
         uuid::Uuid::parse_str(MEDIA_ID).unwrap()
       )
       .execute(db)
@@ -720,20 +1352,155 @@ mod tests {
       .expect("Erro ao excluir dados de teste da tabela media");
 
       sqlx::query!(
-        r#"
-        DELETE FROM "artist" WHERE "id" in ($1, $2)
-      "#,
-        uuid::Uuid::parse_str(COMPOSER_ID).unwrap(),
-        uuid::Uuid::parse_str(INTERPRETER_ID).unwrap()
+        r#"DELETE FROM "artist" WHERE "id" in ($1, $2)"#,
+        uuid::Uuid::parse_str(PRODUCER_ID).unwrap(),
+        uuid::Uuid::parse_str(LYRICIST_ID).unwrap()
       )
       .execute(db)
       .await
       .expect("Erro ao excluir dados de teste da tabela artist");
+    }
+
+    let producer = Artist::builder()
+      .id(UUID4::new(PRODUCER_ID).unwrap())
+      .name(String::from("Teste"))
+      .slug(Slug::new("test_credits_producer").unwrap())
+      .build();
+
+    let lyricist = Artist::builder()
+      .id(UUID4::new(LYRICIST_ID).unwrap())
+      .name(String::from("Teste"))
+      .slug(Slug::new("test_credits_lyricist").unwrap())
+      .build();
+
+    let credits = vec![
+      Credit {
+        artist_id: lyricist.id().clone(),
+        role: CreditRole::Lyricist,
+      },
+      Credit {
+        artist_id: producer.id().clone(),
+        role: CreditRole::Producer,
+      },
+      Credit {
+        artist_id: lyricist.id().clone(),
+        role: CreditRole::Producer,
+      },
+    ];
+
+    let media = Media::builder()
+      .id(UUID4::new(MEDIA_ID).unwrap())
+      .name(String::from("Teste"))
+      .slug(Slug::new("test_credits_media").unwrap())
+      .credits(credits.clone())
+      .build();
+
+    cleanup(&app_state.db).await;
+
+    artist_repository
+      .create(&producer)
+      .await
+      .expect("Erro ao criar artista produtor");
+    artist_repository
+      .create(&lyricist)
+      .await
+      .expect("Erro ao criar artista letrista");
+
+    media_repository
+      .create(&media)
+      .await
+      .expect("Erro ao criar media");
+
+    let result = media_repository
+      .find_by_id(media.id())
+      .await
+      .expect("Erro ao buscar media")
+      .expect("Media não encontrada");
+
+    let query = |credit_role| FindByQuery {
+      page: crate::shared::paged::RequestPageParams {
+        page: 1,
+        per_page: 10,
+      },
+      search: None,
+      sort: None,
+      release_date: None,
+      min_release_date: None,
+      max_release_date: None,
+      parental_rating: None,
+      min_parental_rating: None,
+      max_parental_rating: None,
+      is_single: None,
+      media_type: None,
+      slug: Some(media.slug().clone()),
+      artist_ids: None,
+      composer_ids: None,
+      genre_ids: None,
+      album_ids: None,
+      credit_role: Some(credit_role),
+    };
+
+    let with_producer = media_repository
+      .find_by(&query(CreditRole::Producer))
+      .await
+      .expect("Erro ao buscar medias por papel");
+
+    let with_mastering = media_repository
+      .find_by(&query(CreditRole::Mastering))
+      .await
+      .expect("Erro ao buscar medias por papel");
+
+    cleanup(&app_state.db).await;
+
+    assert_eq!(result.credits(), &credits, "Os créditos não conferem");
+    assert_eq!(with_producer.total_items, 1, "A media não foi encontrada");
+    assert_eq!(
+      with_producer.items[0]
+        .credits
+        .iter()
+        .map(|credit| (credit.artist.id(), &credit.role))
+        .collect::<Vec<_>>(),
+      credits
+        .iter()
+        .map(|credit| (&credit.artist_id, &credit.role))
+        .collect::<Vec<_>>(),
+      "A ordem dos créditos não confere"
+    );
+    assert_eq!(
+      with_mastering.total_items, 0,
+      "A media não deveria ser encontrada"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_find_aggregate() {
+    const MEDIA_ID: &str = "b1c2d3e4-f5a6-4b7c-8d9e-0f1a2b3c4d01";
+    const ARTIST_ID: &str = "b1c2d3e4-f5a6-4b7c-8d9e-0f1a2b3c4d02";
+    const GENRE_ID: &str = "b1c2d3e4-f5a6-4b7c-8d9e-0f1a2b3c4d03";
+    const ALBUM_ID: &str = "b1c2d3e4-f5a6-4b7c-8d9e-0f1a2b3c4d04";
+    const SOURCE_ID: &str = "b1c2d3e4-f5a6-4b7c-8d9e-0f1a2b3c4d05";
+
+    // Load .env file
+    dotenvy::dotenv().ok();
+
+    let app_state = AppState::default().await;
+    let mut artist_repository = artist_repository::SqlxArtistRepository::new(&app_state);
+    let mut genre_repository = genre_repository::SqlxGenreRepository::new(&app_state);
+    let mut album_repository = album_repository::SqlxAlbumRepository::new(&app_state);
+    let mut source_repository = crate::infra::sqlx::SqlxSourceRepository::new(&app_state);
+    let mut media_repository = SqlxMediaRepository::new(&app_state);
 
+    async fn cleanup(db: &sqlx::pool::Pool<Postgres>) {
       sqlx::query!(
-        r#"
-        DELETE FROM "album" WHERE "id" = $1
-      "#,
+        r#"DELETE FROM "media" WHERE "id" = $1"#,
+        uuid::Uuid::parse_str(MEDIA_ID).unwrap()
+      )
+      .execute(db)
+      .await
+      .expect("Erro ao excluir dados de teste da tabela media");
+
+      sqlx::query!(
+        r#"DELETE FROM "album" WHERE "id" = $1"#,
         uuid::Uuid::parse_str(ALBUM_ID).unwrap()
       )
       .execute(db)
@@ -741,93 +1508,282 @@ mod tests {
       .expect("Erro ao excluir dados de teste da tabela album");
 
       sqlx::query!(
-        r#"
-        DELETE FROM "genre" WHERE "id" = $1
-      "#,
+        r#"DELETE FROM "genre" WHERE "id" = $1"#,
         uuid::Uuid::parse_str(GENRE_ID).unwrap()
       )
       .execute(db)
       .await
       .expect("Erro ao excluir dados de teste da tabela genre");
-    }
 
-    let composer = Artist::builder()
-      .id(UUID4::new(COMPOSER_ID).unwrap())
-      .name(String::from("Teste"))
-      .slug(Slug::new("test_update_media_composer").unwrap())
-      .build();
+      sqlx::query!(
+        r#"DELETE FROM "artist" WHERE "id" = $1"#,
+        uuid::Uuid::parse_str(ARTIST_ID).unwrap()
+      )
+      .execute(db)
+      .await
+      .expect("Erro ao excluir dados de teste da tabela artist");
+    }
 
-    let interpreter = Artist::builder()
-      .id(UUID4::new(INTERPRETER_ID).unwrap())
+    let artist = Artist::builder()
+      .id(UUID4::new(ARTIST_ID).unwrap())
       .name(String::from("Teste"))
-      .slug(Slug::new("test_update_media_interpreter").unwrap())
+      .slug(Slug::new("test-find-aggregate-artist").unwrap())
       .build();
 
     let genre = Genre::builder()
       .id(UUID4::new(GENRE_ID).unwrap())
       .name(String::from("Teste"))
-      .slug(Slug::new("test_update_media_genre").unwrap())
+      .slug(Slug::new("test-find-aggregate-genre").unwrap())
       .build();
 
     let album = Album::builder()
       .id(UUID4::new(ALBUM_ID).unwrap())
       .name(String::from("Teste"))
+      .slug(Slug::new("test-find-aggregate-album").unwrap())
       .build();
 
-    let mut media = Media::builder()
+    let media = Media::builder()
       .id(UUID4::new(MEDIA_ID).unwrap())
       .name(String::from("Teste"))
-      .slug(Slug::new("test_update_media_media").unwrap())
-      .album_ids(HashSet::from([album.id().clone()]))
-      .composer_ids(HashSet::from([composer.id().clone()]))
-      .interpreter_ids(HashSet::from([interpreter.id().clone()]))
+      .slug(Slug::new("test-find-aggregate-media").unwrap())
+      .interpreter_ids(HashSet::from([artist.id().clone()]))
       .genre_ids(HashSet::from([genre.id().clone()]))
+      .album_ids(HashSet::from([album.id().clone()]))
       .build();
 
-    cleanup(&app_state.db).await;
+    let source = Source::builder()
+      .id(UUID4::new(SOURCE_ID).unwrap())
+      .src(String::from("https://www.youtube.com/watch?v=VHA2ASr8rmY"))
+      .media_id(media.id().clone())
+      .build();
 
-    artist_repository
-      .create(&composer)
-      .await
-      .expect("Erro ao criar artista compisitor");
+    cleanup(&app_state.db).await;
 
     artist_repository
-      .create(&interpreter)
+      .create(&artist)
       .await
-      .expect("Erro ao criar artista interpreter");
+      .expect("Erro ao criar artista");
     genre_repository
       .create(&genre)
       .await
-      .expect("Erro ao criar genero");
+      .expect("Erro ao criar gênero");
     album_repository
       .create(&album)
       .await
-      .expect("Erro ao criar album");
-
+      .expect("Erro ao criar álbum");
     media_repository
       .create(&media)
       .await
       .expect("Erro ao criar media");
+    crate::domain::source::repository::SourceRepository::create(&mut source_repository, &source)
+      .await
+      .expect("Erro ao criar fonte");
 
-    media.set_name(String::from("Teste 2"));
-    media.set_slug(Slug::new("test_update_media_media_2").unwrap());
+    let by_slug = media_repository
+      .find_by_slug(media.slug(), artist.slug())
+      .await
+      .expect("Erro ao buscar media pelo slug");
+    let by_other_artist = media_repository
+      .find_by_slug(media.slug(), genre.slug())
+      .await
+      .expect("Erro ao buscar media pelo slug");
+    let by_id = media_repository
+      .find_aggregate_by_id(media.id())
+      .await
+      .expect("Erro ao buscar media pelo id")
+      .expect("Media não encontrada pelo id");
+
+    cleanup(&app_state.db).await;
+
+    let by_slug = by_slug.expect("Media não encontrada pelo slug");
+
+    assert_eq!(
+      by_slug.media, media,
+      "Media retornada é diferente da criada"
+    );
+    assert_eq!(by_slug.interpreters, vec![artist], "Intérpretes incorretos");
+    assert_eq!(by_slug.genres, vec![genre], "Gêneros incorretos");
+    assert_eq!(by_slug.albums, vec![album], "Álbuns incorretos");
+    assert_eq!(by_slug.sources, vec![source], "Fontes incorretas");
+    assert!(
+      by_other_artist.is_none(),
+      "Media não deveria ser encontrada por outro artista"
+    );
+    assert_eq!(by_id.media, by_slug.media, "Busca por id diverge do slug");
+  }
+
+  #[tokio::test]
+  async fn test_soft_delete() {
+    const MEDIA_ID: &str = "5b0e7c1d-2a3f-4b6c-8d9e-0f1a2b3c4d5e";
+    const ARTIST_ID: &str = "9c8d7e6f-5a4b-4c3d-8e2f-1a0b9c8d7e6f";
+
+    // Load .env file
+    dotenvy::dotenv().ok();
+
+    let app_state = AppState::default().await;
+    let mut artist_repository = artist_repository::SqlxArtistRepository::new(&app_state);
+    let mut media_repository = SqlxMediaRepository::new(&app_state);
+
+    async fn cleanup(db: &sqlx::pool::Pool<Postgres>) {
+      sqlx::query!(
+        r#"DELETE FROM "media" WHERE "id" = $1"#,
+        uuid::Uuid::parse_str(MEDIA_ID).unwrap()
+      )
+      .execute(db)
+      .await
+      .expect("Erro ao excluir dados de teste da tabela media");
+
+      sqlx::query!(
+        r#"DELETE FROM "artist" WHERE "id" = $1"#,
+        uuid::Uuid::parse_str(ARTIST_ID).unwrap()
+      )
+      .execute(db)
+      .await
+      .expect("Erro ao excluir dados de teste da tabela artist");
+    }
+
+    let artist = Artist::builder()
+      .id(UUID4::new(ARTIST_ID).unwrap())
+      .name(String::from("Teste"))
+      .slug(Slug::new("test_soft_delete_artist").unwrap())
+      .build();
+
+    let media = Media::builder()
+      .id(UUID4::new(MEDIA_ID).unwrap())
+      .name(String::from("Teste"))
+      .slug(Slug::new("test_soft_delete_media").unwrap())
+      .interpreter_ids(HashSet::from([artist.id().clone()]))
+      .build();
+
+    let query = FindByQuery {
+      page: crate::shared::paged::RequestPageParams {
+        page: 1,
+        per_page: 10,
+      },
+      search: None,
+      sort: None,
+      release_date: None,
+      min_release_date: None,
+      max_release_date: None,
+      parental_rating: None,
+      min_parental_rating: None,
+      max_parental_rating: None,
+      is_single: None,
+      media_type: None,
+      slug: Some(media.slug().clone()),
+      artist_ids: None,
+      composer_ids: None,
+      genre_ids: None,
+      album_ids: None,
+      credit_role: None,
+    };
+
+    cleanup(&app_state.db).await;
+
+    artist_repository
+      .create(&artist)
+      .await
+      .expect("Erro ao criar artista");
 
     media_repository
-      .update(&media)
+      .create(&media)
       .await
-      .expect("Erro ao atualizar media");
+      .expect("Erro ao criar media");
 
-    let result = media_repository
-      .find_by_id(media.id())
+    artist_repository
+      .delete_by_id(artist.id())
+      .await
+      .expect("Erro ao excluir artista");
+
+    let without_artist = media_repository
+      .find_aggregate_by_id(media.id())
       .await
       .expect("Erro ao buscar media")
       .expect("Media não encontrada");
 
+    media_repository
+      .delete_by_id(media.id())
+      .await
+      .expect("Erro ao excluir media");
+
+    let deleted = media_repository
+      .find_by_id(media.id())
+      .await
+      .expect("Erro ao buscar media excluída");
+    let listed = media_repository
+      .find_by(&query)
+      .await
+      .expect("Erro ao listar medias");
+    let found_deleted = media_repository
+      .find_deleted_by_id(media.id())
+      .await
+      .expect("Erro ao buscar media excluída");
+
+    media_repository
+      .restore_by_id(media.id())
+      .await
+      .expect("Erro ao restaurar media");
+    artist_repository
+      .restore_by_id(artist.id())
+      .await
+      .expect("Erro ao restaurar artista");
+
+    let restored = media_repository
+      .find_aggregate_by_id(media.id())
+      .await
+      .expect("Erro ao buscar media restaurada");
+
     cleanup(&app_state.db).await;
 
+    assert!(
+      without_artist.interpreters.is_empty(),
+      "Artista excluído não deveria aparecer entre os intérpretes"
+    );
+    assert!(deleted.is_none(), "Media excluída foi encontrada");
+    assert_eq!(listed.total_items, 0, "Media excluída foi listada");
     assert_eq!(
-      result, media,
-      "A media retornada é diferente da media atualizada"
+      found_deleted.map(|media| media.id().clone()),
+      Some(media.id().clone()),
+      "Media excluída não foi encontrada entre as excluídas"
+    );
+
+    let restored = restored.expect("Media restaurada não foi encontrada");
+
+    assert_eq!(
+      restored.interpreters,
+      vec![artist],
+      "Artista restaurado deveria voltar aos intérpretes"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_create_media_with_overflowing_duration() {
+    const MEDIA_ID: &str = "1f6c3f0e-5d4b-4a39-9c0e-7a2b8d9e4f51";
+
+    // Load .env file
+    dotenvy::dotenv().ok();
+
+    let app_state = AppState::default().await;
+    let mut media_repository = SqlxMediaRepository::new(&app_state);
+
+    let media = Media::builder()
+      .id(UUID4::new(MEDIA_ID).unwrap())
+      .name(String::from("Teste"))
+      .slug(Slug::new("test_overflowing_duration_media").unwrap())
+      .duration(Some(u32::MAX))
+      .build();
+
+    let result = media_repository.create(&media).await;
+
+    let found = media_repository
+      .find_by_id(media.id())
+      .await
+      .expect("Erro ao buscar media");
+
+    assert!(
+      result.is_err(),
+      "Duração acima de i32::MAX deveria ser rejeitada"
     );
+    assert!(found.is_none(), "Media com duração inválida foi criada");
   }
 }
@@ -0,0 +1,102 @@
+use sqlx::migrate::{Migrate, MigrateError, Migration};
+
+/**
+ * Migrações da pasta `migrations/`, embutidas no binário em tempo de compilação.
+ */
+pub static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("../../migrations");
+
+/**
+ * Aplica todas as migrações pendentes.
+ */
+pub async fn run(db: &sqlx::Pool<sqlx::Postgres>) -> Result<(), MigrateError> {
+  MIGRATOR.run(db).await
+}
+
+/**
+ * Reverte as `steps` últimas migrações aplicadas usando os arquivos `.down.sql` e
+ * retorna as versões revertidas, da mais recente para a mais antiga.
+ */
+pub async fn undo(db: &sqlx::Pool<sqlx::Postgres>, steps: usize) -> Result<Vec<i64>, MigrateError> {
+  let mut conn = db.acquire().await?;
+  conn.ensure_migrations_table().await?;
+
+  let mut applied = conn
+    .list_applied_migrations()
+    .await?
+    .into_iter()
+    .map(|migration| migration.version)
+    .collect::<Vec<_>>();
+  applied.sort_unstable_by(|a, b| b.cmp(a));
+
+  // Reverte tudo o que for mais recente que a primeira migração mantida.
+  let target = applied.get(steps).copied().unwrap_or(0);
+  MIGRATOR.undo(&mut *conn, target).await?;
+
+  Ok(applied.into_iter().take(steps).collect())
+}
+
+/**
+ * Lista as migrações embutidas que ainda não foram aplicadas ao banco, sem alterá-lo.
+ *
+ * Falha caso alguma migração tenha sido aplicada pela metade ou tenha sido alterada
+ * depois de aplicada.
+ */
+pub async fn pending(
+  db: &sqlx::Pool<sqlx::Postgres>,
+) -> Result<Vec<&'static Migration>, MigrateError> {
+  let mut conn = db.acquire().await?;
+
+  let has_migrations_table =
+    sqlx::query_scalar::<_, bool>("SELECT to_regclass('_sqlx_migrations') IS NOT NULL")
+      .fetch_one(&mut *conn)
+      .await?;
+
+  let applied = if has_migrations_table {
+    if let Some(version) = conn.dirty_version().await? {
+      return Err(MigrateError::Dirty(version));
+    }
+
+    conn.list_applied_migrations().await?
+  } else {
+    Vec::new()
+  };
+
+  let mut pending = Vec::new();
+
+  for migration in MIGRATOR
+    .iter()
+    .filter(|migration| migration.migration_type.is_up_migration())
+  {
+    match applied
+      .iter()
+      .find(|applied| applied.version == migration.version)
+    {
+      Some(applied) if applied.checksum != migration.checksum => {
+        return Err(MigrateError::VersionMismatch(migration.version));
+      }
+      Some(_) => {}
+      None => pending.push(migration),
+    }
+  }
+
+  Ok(pending)
+}
+
+#[cfg(test)]
+mod tests {
+  #[test]
+  fn test_every_migration_is_reversible() {
+    for migration in super::MIGRATOR
+      .iter()
+      .filter(|migration| migration.migration_type.is_up_migration())
+    {
+      assert!(
+        super::MIGRATOR
+          .iter()
+          .any(|down| down.version == migration.version && down.migration_type.is_down_migration()),
+        "A migração {} não possui um arquivo .down.sql",
+        migration.version
+      );
+    }
+  }
+}
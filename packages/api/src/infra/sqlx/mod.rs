@@ -1,18 +1,46 @@
 mod activity_repository;
 mod album_repository;
 mod artist_repository;
+mod catalog_repository;
+mod facet;
 mod genre_repository;
+mod keyset;
+mod list_filter;
+mod lyrics_repository;
 mod media_repository;
+mod search_repository;
 mod source_repository;
 mod user_repository;
 
 pub use activity_repository::SqlxActivityRepository;
 pub use album_repository::*;
 pub use artist_repository::*;
+pub use catalog_repository::*;
 pub use genre_repository::*;
+pub use lyrics_repository::*;
 pub use media_repository::*;
+pub use search_repository::*;
 pub use source_repository::*;
 pub use user_repository::*;
 pub mod many_to_many;
+pub mod migrations;
 
 pub mod filter_macro;
+
+/**
+ * Converte um valor sem sinal para uma coluna INTEGER, que não comporta valores acima
+ * de `i32::MAX`.
+ */
+pub(crate) fn to_integer(value: Option<u32>) -> Result<Option<i32>, std::num::TryFromIntError> {
+  value.map(i32::try_from).transpose()
+}
+
+/**
+ * Escapa os caracteres especiais do LIKE, para que o termo seja buscado literalmente.
+ */
+pub fn escape_like(term: &str) -> String {
+  term
+    .replace('\\', "\\\\")
+    .replace('%', "\\%")
+    .replace('_', "\\_")
+}
@@ -0,0 +1,528 @@
+use crate::{
+  domain::search::{
+    AutocompleteQuery, Error, SearchQuery, SearchRepository, SearchResult, Suggestion,
+  },
+  search_by, search_by_name, search_rank,
+  shared::{
+    paged::{PageQueryParams, Paged},
+    vo::{CollaborativeEntityName, Slug, UUID4},
+  },
+  AppState,
+};
+
+pub struct SqlxSearchRepository {
+  db: sqlx::Pool<sqlx::Postgres>,
+}
+
+impl SqlxSearchRepository {
+  pub fn new(app_state: &AppState) -> Self {
+    Self {
+      db: app_state.db.clone(),
+    }
+  }
+}
+
+fn to_database_error(error: sqlx::error::Error) -> Error {
+  Error::DatabaseError(error.to_string())
+}
+
+#[derive(sqlx::FromRow)]
+struct SearchResultRecord {
+  entity_name: String,
+  id: uuid::Uuid,
+  name: String,
+  slug: String,
+  rank: f32,
+}
+
+impl From<SearchResultRecord> for SearchResult {
+  fn from(value: SearchResultRecord) -> Self {
+    Self {
+      entity_name: value.entity_name.parse().unwrap(),
+      id: UUID4::from(value.id),
+      name: value.name,
+      slug: Slug::from(value.slug),
+      rank: value.rank,
+    }
+  }
+}
+
+#[derive(sqlx::FromRow)]
+struct SuggestionRecord {
+  id: uuid::Uuid,
+  name: String,
+  slug: String,
+  hint: Option<String>,
+}
+
+impl From<SuggestionRecord> for Suggestion {
+  fn from(value: SuggestionRecord) -> Self {
+    Self {
+      id: UUID4::from(value.id),
+      name: value.name,
+      slug: Slug::from(value.slug),
+      hint: value.hint,
+    }
+  }
+}
+
+/**
+ * Seleciona os resultados mais relevantes de uma tabela, limitados a `limit_per_type`.
+ */
+macro_rules! push_results {
+  ($qb:expr, $query:expr, $entity_name:literal, $table:literal $(, $extra:block)? $(; $filter:block)?) => {{
+    $qb.push(concat!(
+      "(SELECT '", $entity_name, r#"' AS "entity_name", "#,
+      $table, r#"."id", "#, $table, r#"."name", "#, $table, r#"."slug", "#
+    ));
+    search_rank!($qb, $query.term.clone(), $table);
+    $qb.push(concat!(r#" AS "rank" FROM "#, $table, " WHERE ("));
+    search_by!($qb, $query.term.clone(), $table);
+    $($extra)?
+    $qb.push(concat!(") AND ", $table, r#"."deleted_at" IS NULL"#));
+    $($filter)?
+    $qb.push(r#" ORDER BY "rank" DESC, "name" LIMIT "#);
+    $qb.push_bind($query.limit_per_type as i64);
+    $qb.push(")");
+  }};
+}
+
+/**
+ * Oculta os resultados acima da classificação indicativa máxima, quando informada.
+ */
+fn push_parental_rating(
+  qb: &mut sqlx::QueryBuilder<'_, sqlx::Postgres>,
+  table: &str,
+  max_parental_rating: Option<u8>,
+) {
+  if let Some(max_parental_rating) = max_parental_rating {
+    qb.push(format!(r#" AND {table}."parental_rating" <= "#))
+      .push_bind(max_parental_rating as i16);
+  }
+}
+
+/**
+ * Monta a união dos resultados de cada tipo de entidade buscado.
+ */
+fn push_union(qb: &mut sqlx::QueryBuilder<'_, sqlx::Postgres>, query: &SearchQuery) {
+  let mut is_first = true;
+
+  for entity_name in &query.entity_names {
+    if !matches!(
+      entity_name,
+      CollaborativeEntityName::Artist
+        | CollaborativeEntityName::Album
+        | CollaborativeEntityName::Media
+        | CollaborativeEntityName::Genre
+    ) {
+      continue;
+    }
+
+    if !is_first {
+      qb.push(" UNION ALL ");
+    }
+
+    is_first = false;
+
+    match entity_name {
+      CollaborativeEntityName::Artist => {
+        push_results!(qb, query, "Artist", r#""artist""#, {
+          qb.push(r#" OR EXISTS (SELECT 1 FROM "artist_alias" WHERE "artist_alias"."artist_id" = "artist"."id" AND ("#);
+          search_by_name!(qb, query.term.clone(), r#""artist_alias""#);
+          qb.push("))");
+        });
+      }
+      CollaborativeEntityName::Album => push_results!(qb, query, "Album", r#""album""#; {
+        push_parental_rating(qb, r#""album""#, query.max_parental_rating);
+      }),
+      CollaborativeEntityName::Media => push_results!(qb, query, "Media", r#""media""#; {
+        push_parental_rating(qb, r#""media""#, query.max_parental_rating);
+      }),
+      CollaborativeEntityName::Genre => push_results!(qb, query, "Genre", r#""genre""#),
+      _ => unreachable!(),
+    }
+  }
+
+  // Nenhuma entidade pesquisável: a união não deve retornar resultados.
+  if is_first {
+    qb.push(
+      r#"SELECT NULL::TEXT AS "entity_name", NULL::UUID AS "id", NULL::TEXT AS "name", NULL::TEXT AS "slug", NULL::REAL AS "rank" WHERE FALSE"#,
+    );
+  }
+}
+
+impl SearchRepository for SqlxSearchRepository {
+  async fn search(&mut self, query: &SearchQuery) -> Result<Paged<SearchResult>, Error> {
+    let (count, items) = tokio::join!(
+      async {
+        let mut builder = sqlx::QueryBuilder::new(r#"SELECT COUNT(*) FROM ("#);
+
+        push_union(&mut builder, query);
+
+        builder.push(r#") "r""#);
+
+        builder
+          .build_query_scalar::<i64>()
+          .fetch_one(&self.db)
+          .await
+          .map_err(to_database_error)
+          .map(|count| count as usize)
+      },
+      async {
+        let page_params = PageQueryParams::from(query.page.clone());
+
+        let mut builder = sqlx::QueryBuilder::new(r#"SELECT * FROM ("#);
+
+        push_union(&mut builder, query);
+
+        builder
+          .push(r#") "r" ORDER BY "r"."rank" DESC, "r"."name", "r"."id" LIMIT "#)
+          .push_bind(i64::try_from(page_params.take).unwrap_or(i64::MAX))
+          .push(" OFFSET ")
+          .push_bind(i64::try_from(page_params.skip).unwrap_or(i64::MAX));
+
+        builder
+          .build_query_as::<SearchResultRecord>()
+          .fetch_all(&self.db)
+          .await
+          .map_err(to_database_error)
+      }
+    );
+
+    Ok(Paged::from_tuple(
+      (count?, items?.into_iter().map(SearchResult::from).collect()),
+      query.page.clone(),
+    ))
+  }
+
+  async fn autocomplete(&mut self, query: &AutocompleteQuery) -> Result<Vec<Suggestion>, Error> {
+    let rated = matches!(
+      query.entity_name,
+      CollaborativeEntityName::Album | CollaborativeEntityName::Media
+    );
+    let (table, hint) = match query.entity_name {
+      CollaborativeEntityName::Artist => (r#""artist""#, r#""e"."country""#),
+      CollaborativeEntityName::Album => (
+        r#""album""#,
+        r#"concat_ws(' · ', EXTRACT(YEAR FROM "e"."release_date")::TEXT, (
+          SELECT string_agg("ar"."name", ', ' ORDER BY "ar"."name")
+          FROM "album_artist" "aa"
+          INNER JOIN "artist" "ar" ON "ar"."id" = "aa"."artist_id"
+          WHERE "aa"."album_id" = "e"."id" AND "ar"."deleted_at" IS NULL
+        ))"#,
+      ),
+      CollaborativeEntityName::Media => (
+        r#""media""#,
+        r#"concat_ws(' · ', (
+          SELECT string_agg("ar"."name", ', ' ORDER BY "ar"."name")
+          FROM "media_interpreter" "mi"
+          INNER JOIN "artist" "ar" ON "ar"."id" = "mi"."interpreter_id"
+          WHERE "mi"."media_id" = "e"."id" AND "ar"."deleted_at" IS NULL
+        ), EXTRACT(YEAR FROM "e"."release_date")::TEXT)"#,
+      ),
+      CollaborativeEntityName::Genre => (
+        r#""genre""#,
+        r#"(SELECT "p"."name" FROM "genre" "p" WHERE "p"."id" = "e"."parent_id" AND "p"."deleted_at" IS NULL)"#,
+      ),
+      _ => return Ok(Vec::new()),
+    };
+
+    // Apenas álbuns e mídias possuem classificação indicativa; o valor é um
+    // inteiro, então pode ser inserido diretamente na consulta.
+    let parental_rating = match query.max_parental_rating {
+      Some(max_parental_rating) if rated => {
+        format!(r#"AND "e"."parental_rating" <= {max_parental_rating}"#)
+      }
+      _ => String::new(),
+    };
+
+    // O nome deve começar com o termo (índice text_pattern_ops) ou conter uma
+    // palavra que comece com ele (índice trigram).
+    let sql = format!(
+      r#"
+        SELECT "e"."id", "e"."name", "e"."slug", NULLIF({hint}, '') AS "hint"
+        FROM {table} "e"
+        WHERE (
+          "f_unaccent"(lower("e"."name")) LIKE "f_unaccent"(lower($1)) || '%'
+          OR "f_unaccent"(lower("e"."name")) LIKE '% ' || "f_unaccent"(lower($1)) || '%'
+        ) AND "e"."deleted_at" IS NULL {parental_rating}
+        ORDER BY
+          "f_unaccent"(lower("e"."name")) LIKE "f_unaccent"(lower($1)) || '%' DESC,
+          length("e"."name"),
+          "e"."name",
+          "e"."id"
+        LIMIT $2
+      "#
+    );
+
+    sqlx::query_as::<_, SuggestionRecord>(&sql)
+      .bind(super::escape_like(&query.term))
+      .bind(query.limit as i64)
+      .fetch_all(&self.db)
+      .await
+      .map_err(to_database_error)
+      .map(|items| items.into_iter().map(Suggestion::from).collect())
+  }
+
+  async fn reindex(&mut self) -> Result<(), Error> {
+    sqlx::raw_sql(
+      r#"
+        REINDEX INDEX "idx_artist_search_vector";
+        REINDEX INDEX "idx_album_search_vector";
+        REINDEX INDEX "idx_media_search_vector";
+        REINDEX INDEX "idx_genre_search_vector";
+        REINDEX INDEX "idx_artist_name_trgm";
+        REINDEX INDEX "idx_artist_alias_name_trgm";
+        REINDEX INDEX "idx_album_name_trgm";
+        REINDEX INDEX "idx_media_name_trgm";
+        REINDEX INDEX "idx_genre_name_trgm";
+        REINDEX INDEX "idx_artist_name_prefix";
+        REINDEX INDEX "idx_album_name_prefix";
+        REINDEX INDEX "idx_media_name_prefix";
+        REINDEX INDEX "idx_genre_name_prefix";
+        ANALYZE "artist", "artist_alias", "album", "media", "genre";
+      "#,
+    )
+    .execute(&self.db)
+    .await
+    .map_err(to_database_error)?;
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{
+    domain::{artist::repository::ArtistRepository, genre::GenreRepository},
+    infra::sqlx::{SqlxArtistRepository, SqlxGenreRepository},
+    shared::paged::RequestPageParams,
+  };
+
+  #[tokio::test]
+  async fn test_search() {
+    // Load .env file
+    dotenvy::dotenv().ok();
+
+    const ARTIST_ID: &str = "5c1f0b7e-4a1d-4e8e-9d2a-7e3b9c0d1a01";
+    const OTHER_ARTIST_ID: &str = "5c1f0b7e-4a1d-4e8e-9d2a-7e3b9c0d1a02";
+    const GENRE_ID: &str = "5c1f0b7e-4a1d-4e8e-9d2a-7e3b9c0d1a03";
+
+    async fn cleanup(db: &sqlx::PgPool) {
+      sqlx::query!(
+        r#"DELETE FROM "artist" WHERE "id" = ANY($1)"#,
+        &[
+          uuid::Uuid::parse_str(ARTIST_ID).unwrap(),
+          uuid::Uuid::parse_str(OTHER_ARTIST_ID).unwrap()
+        ]
+      )
+      .execute(db)
+      .await
+      .expect("Falha ao limpar dados de teste da tabela artist");
+
+      sqlx::query!(
+        r#"DELETE FROM "genre" WHERE "id" = $1"#,
+        uuid::Uuid::parse_str(GENRE_ID).unwrap()
+      )
+      .execute(db)
+      .await
+      .expect("Falha ao limpar dados de teste da tabela genre");
+    }
+
+    let app_state = AppState::default().await;
+
+    cleanup(&app_state.db).await;
+
+    let mut artist_repository = SqlxArtistRepository::new(&app_state);
+    let mut genre_repository = SqlxGenreRepository::new(&app_state);
+    let mut search_repository = SqlxSearchRepository::new(&app_state);
+
+    let artist = crate::domain::artist::Artist::builder()
+      .id(UUID4::new(ARTIST_ID).unwrap())
+      .name(String::from("Xablauzinho"))
+      .slug(Slug::new("test_global_search_artist").unwrap())
+      .build();
+
+    let other_artist = crate::domain::artist::Artist::builder()
+      .id(UUID4::new(OTHER_ARTIST_ID).unwrap())
+      .name(String::from("Xablauzinho Cover"))
+      .slug(Slug::new("test_global_search_other_artist").unwrap())
+      .build();
+
+    let genre = crate::domain::genre::Genre::builder()
+      .id(UUID4::new(GENRE_ID).unwrap())
+      .name(String::from("Xablauzinho Rock"))
+      .slug(Slug::new("test_global_search_genre").unwrap())
+      .build();
+
+    for artist in [&artist, &other_artist] {
+      artist_repository
+        .create(artist)
+        .await
+        .expect("Falha ao criar artista");
+    }
+
+    genre_repository
+      .create(&genre)
+      .await
+      .expect("Falha ao criar gênero");
+
+    let mut search = async |entity_names: Vec<CollaborativeEntityName>, limit_per_type: usize| {
+      search_repository
+        .search(&SearchQuery {
+          page: RequestPageParams {
+            page: 1,
+            per_page: 10,
+          },
+          term: String::from("xablauzinho"),
+          entity_names,
+          limit_per_type,
+          max_parental_rating: None,
+        })
+        .await
+        .expect("Falha ao realizar a busca")
+        .items
+        .into_iter()
+        .map(|result| (result.entity_name, result.id))
+        .collect::<Vec<_>>()
+    };
+
+    let all = search(
+      vec![
+        CollaborativeEntityName::Artist,
+        CollaborativeEntityName::Genre,
+      ],
+      10,
+    )
+    .await;
+    let limited = search(
+      vec![
+        CollaborativeEntityName::Artist,
+        CollaborativeEntityName::Genre,
+      ],
+      1,
+    )
+    .await;
+    let only_genres = search(vec![CollaborativeEntityName::Genre], 10).await;
+
+    cleanup(&app_state.db).await;
+
+    assert_eq!(
+      all.first(),
+      Some(&(CollaborativeEntityName::Artist, artist.id().clone())),
+      "O resultado mais relevante deveria ser o artista com o nome exato"
+    );
+    assert!(
+      all.contains(&(CollaborativeEntityName::Artist, other_artist.id().clone()))
+        && all.contains(&(CollaborativeEntityName::Genre, genre.id().clone())),
+      "A busca deveria retornar artistas e gêneros"
+    );
+    assert_eq!(
+      limited
+        .iter()
+        .filter(|(entity_name, _)| entity_name == &CollaborativeEntityName::Artist)
+        .count(),
+      1,
+      "A busca deveria respeitar o limite por tipo"
+    );
+    assert!(
+      only_genres
+        .iter()
+        .all(|(entity_name, _)| entity_name == &CollaborativeEntityName::Genre)
+        && only_genres.contains(&(CollaborativeEntityName::Genre, genre.id().clone())),
+      "A busca deveria respeitar o filtro de tipos"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_autocomplete() {
+    // Load .env file
+    dotenvy::dotenv().ok();
+
+    const ARTIST_ID: &str = "5c1f0b7e-4a1d-4e8e-9d2a-7e3b9c0d1a04";
+    const OTHER_ARTIST_ID: &str = "5c1f0b7e-4a1d-4e8e-9d2a-7e3b9c0d1a05";
+
+    async fn cleanup(db: &sqlx::PgPool) {
+      sqlx::query!(
+        r#"DELETE FROM "artist" WHERE "id" = ANY($1)"#,
+        &[
+          uuid::Uuid::parse_str(ARTIST_ID).unwrap(),
+          uuid::Uuid::parse_str(OTHER_ARTIST_ID).unwrap()
+        ]
+      )
+      .execute(db)
+      .await
+      .expect("Falha ao limpar dados de teste da tabela artist");
+    }
+
+    let app_state = AppState::default().await;
+
+    cleanup(&app_state.db).await;
+
+    let mut artist_repository = SqlxArtistRepository::new(&app_state);
+    let mut search_repository = SqlxSearchRepository::new(&app_state);
+
+    let artist = crate::domain::artist::Artist::builder()
+      .id(UUID4::new(ARTIST_ID).unwrap())
+      .name(String::from("Zorbáquim Silva"))
+      .slug(Slug::new("test_autocomplete_artist").unwrap())
+      .country(Some(String::from("BR")))
+      .build();
+
+    let other_artist = crate::domain::artist::Artist::builder()
+      .id(UUID4::new(OTHER_ARTIST_ID).unwrap())
+      .name(String::from("Ana Zorbaquim"))
+      .slug(Slug::new("test_autocomplete_other_artist").unwrap())
+      .build();
+
+    for artist in [&artist, &other_artist] {
+      artist_repository
+        .create(artist)
+        .await
+        .expect("Falha ao criar artista");
+    }
+
+    let mut autocomplete = async |term: &str| {
+      search_repository
+        .autocomplete(&AutocompleteQuery {
+          entity_name: CollaborativeEntityName::Artist,
+          term: String::from(term),
+          limit: 10,
+          max_parental_rating: None,
+        })
+        .await
+        .expect("Falha ao buscar sugestões")
+    };
+
+    let by_prefix = autocomplete("zorbaq").await;
+    let by_word = autocomplete("silv").await;
+    let by_substring = autocomplete("orbaq").await;
+
+    cleanup(&app_state.db).await;
+
+    assert_eq!(
+      by_prefix
+        .iter()
+        .map(|suggestion| (suggestion.id.clone(), suggestion.hint.clone()))
+        .collect::<Vec<_>>(),
+      vec![
+        (artist.id().clone(), Some(String::from("BR"))),
+        (other_artist.id().clone(), None)
+      ],
+      "As sugestões que começam com o termo deveriam vir primeiro"
+    );
+    assert_eq!(
+      by_word
+        .iter()
+        .map(|suggestion| suggestion.id.clone())
+        .collect::<Vec<_>>(),
+      vec![artist.id().clone()],
+      "O autocomplete deveria encontrar palavras que começam com o termo"
+    );
+    assert!(
+      by_substring.is_empty(),
+      "O autocomplete não deveria encontrar trechos no meio das palavras"
+    );
+  }
+}
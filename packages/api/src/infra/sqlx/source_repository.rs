@@ -1,3 +1,5 @@
+use crate::domain::source::source_entity::Source;
+
 pub struct SqlxSourceRepository {
   db: sqlx::Pool<sqlx::Postgres>,
 }
@@ -14,22 +16,72 @@ fn to_database_error(error: sqlx::error::Error) -> crate::domain::source::reposi
   crate::domain::source::repository::Error::DatabaseError(error.to_string())
 }
 
+fn to_conversion_error(
+  error: std::num::TryFromIntError,
+) -> crate::domain::source::repository::Error {
+  crate::domain::source::repository::Error::DatabaseError(error.to_string())
+}
+
+struct SourceRecord {
+  id: uuid::Uuid,
+  source_type: String,
+  src: String,
+  external_id: Option<String>,
+  media_id: uuid::Uuid,
+  status: String,
+  last_checked_at: Option<chrono::NaiveDateTime>,
+  start_offset: Option<i32>,
+  duration: Option<i32>,
+  created_at: chrono::NaiveDateTime,
+  updated_at: chrono::NaiveDateTime,
+}
+
+impl From<SourceRecord> for Source {
+  fn from(value: SourceRecord) -> Self {
+    Source::builder()
+      .id(crate::shared::vo::UUID4::from(value.id))
+      .source_type(value.source_type.parse().unwrap())
+      .src(value.src)
+      .external_id(value.external_id)
+      .media_id(crate::shared::vo::UUID4::from(value.media_id))
+      .status(value.status.parse().unwrap_or_default())
+      .last_checked_at(value.last_checked_at)
+      .start_offset(
+        value
+          .start_offset
+          .and_then(|value| u32::try_from(value).ok()),
+      )
+      .duration(value.duration.and_then(|value| u32::try_from(value).ok()))
+      .created_at(value.created_at)
+      .updated_at(value.updated_at)
+      .build()
+  }
+}
+
 impl crate::domain::source::repository::SourceRepository for SqlxSourceRepository {
   async fn create(
     &mut self,
-    source: &crate::domain::source::source_entity::Source,
+    source: &Source,
   ) -> Result<(), crate::domain::source::repository::Error> {
+    let start_offset = super::to_integer(*source.start_offset()).map_err(to_conversion_error)?;
+    let duration = super::to_integer(*source.duration()).map_err(to_conversion_error)?;
+
     sqlx::query!(
       r#"
-      INSERT INTO "source" ("id", "source_type", "src", "media_id", "created_at" ,"updated_at")
-      VALUES($1,$2,$3,$4,$5,$6)
+      INSERT INTO "source" ("id", "source_type", "src", "media_id", "created_at" ,"updated_at", "external_id", "status", "last_checked_at", "start_offset", "duration")
+      VALUES($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11)
     "#,
       Into::<uuid::Uuid>::into(source.id().clone()),
       source.source_type().to_string(),
       source.src(),
       Into::<uuid::Uuid>::into(source.media_id().clone()),
       source.created_at(),
-      source.updated_at()
+      source.updated_at(),
+      source.external_id().clone(),
+      source.status().to_string(),
+      *source.last_checked_at(),
+      start_offset,
+      duration
     )
     .execute(&self.db)
     .await
@@ -37,36 +89,121 @@ impl crate::domain::source::repository::SourceRepository for SqlxSourceRepositor
     Ok(())
   }
 
+  async fn update(
+    &mut self,
+    source: &Source,
+  ) -> Result<(), crate::domain::source::repository::Error> {
+    let start_offset = super::to_integer(*source.start_offset()).map_err(to_conversion_error)?;
+    let duration = super::to_integer(*source.duration()).map_err(to_conversion_error)?;
+
+    sqlx::query!(
+      r#"
+      UPDATE "source"
+      SET "source_type" = $2, "src" = $3, "external_id" = $4, "updated_at" = $5,
+          "status" = $6, "last_checked_at" = $7, "start_offset" = $8, "duration" = $9
+      WHERE "id" = $1
+    "#,
+      uuid::Uuid::from(source.id().clone()),
+      source.source_type().to_string(),
+      source.src(),
+      source.external_id().clone(),
+      source.updated_at(),
+      source.status().to_string(),
+      *source.last_checked_at(),
+      start_offset,
+      duration
+    )
+    .execute(&self.db)
+    .await
+    .map_err(to_database_error)?;
+    Ok(())
+  }
+
+  async fn find_by_media_id(
+    &mut self,
+    media_id: &crate::shared::vo::UUID4,
+  ) -> Result<Vec<Source>, crate::domain::source::repository::Error> {
+    let sources = sqlx::query_as!(
+      SourceRecord,
+      r#"
+        SELECT "id", "source_type", "src", "external_id", "media_id", "status", "last_checked_at", "start_offset", "duration", "created_at", "updated_at"
+        FROM "source"
+        WHERE "media_id" = $1 AND "deleted_at" IS NULL
+        ORDER BY "created_at", "id"
+      "#,
+      uuid::Uuid::from(media_id.clone())
+    )
+    .fetch_all(&self.db)
+    .await
+    .map_err(to_database_error)?;
+
+    Ok(sources.into_iter().map(Source::from).collect())
+  }
+
+  async fn find_least_recently_checked(
+    &mut self,
+    limit: usize,
+  ) -> Result<Vec<Source>, crate::domain::source::repository::Error> {
+    let sources = sqlx::query_as!(
+      SourceRecord,
+      r#"
+        SELECT "id", "source_type", "src", "external_id", "media_id", "status", "last_checked_at", "start_offset", "duration", "created_at", "updated_at"
+        FROM "source"
+        WHERE "deleted_at" IS NULL
+        ORDER BY "last_checked_at" ASC NULLS FIRST, "created_at", "id"
+        LIMIT $1
+      "#,
+      i64::try_from(limit).unwrap_or(i64::MAX)
+    )
+    .fetch_all(&self.db)
+    .await
+    .map_err(to_database_error)?;
+
+    Ok(sources.into_iter().map(Source::from).collect())
+  }
+
   async fn find_by_id(
     &mut self,
     id: &crate::shared::vo::UUID4,
-  ) -> Result<
-    Option<crate::domain::source::source_entity::Source>,
-    crate::domain::source::repository::Error,
-  > {
-    let source = sqlx::query!(
+  ) -> Result<Option<Source>, crate::domain::source::repository::Error> {
+    let source = sqlx::query_as!(
+      SourceRecord,
       r#"
-        SELECT "id", "source_type","src", "media_id","created_at", "updated_at" FROM "source" WHERE "id" = $1
+        SELECT "id", "source_type", "src", "external_id", "media_id", "status", "last_checked_at", "start_offset", "duration", "created_at", "updated_at"
+        FROM "source"
+        WHERE "id" = $1 AND "deleted_at" IS NULL
       "#,
       Into::<uuid::Uuid>::into(id.clone())
     )
     .fetch_optional(&self.db)
     .await
-    .map_err(to_database_error)?
-    .map(|value| crate::domain::source::source_entity::Source::builder()
-    .id(crate::shared::vo::UUID4::from(value.id))
-    .source_type(value.source_type.parse().unwrap())
-    .src(value.src)
-    .media_id(crate::shared::vo::UUID4::from(value.media_id))
-    .created_at(value.created_at)
-    .updated_at(value.updated_at)
-    .build());
-
-    if source.is_none() {
-      return Ok(None);
-    }
+    .map_err(to_database_error)?;
+
+    Ok(source.map(Source::from))
+  }
+
+  async fn find_by_external_id(
+    &mut self,
+    media_id: &crate::shared::vo::UUID4,
+    source_type: &crate::domain::source::source_entity::SourceType,
+    external_id: &str,
+  ) -> Result<Option<Source>, crate::domain::source::repository::Error> {
+    let source = sqlx::query_as!(
+      SourceRecord,
+      r#"
+        SELECT "id", "source_type", "src", "external_id", "media_id", "status", "last_checked_at", "start_offset", "duration", "created_at", "updated_at"
+        FROM "source"
+        WHERE "media_id" = $1 AND "source_type" = $2 AND "external_id" = $3
+      "#,
+      uuid::Uuid::from(media_id.clone()),
+      source_type.to_string(),
+      external_id
+    )
+    .fetch_optional(&self.db)
+    .await
+    .map_err(to_database_error)?;
 
-    Ok(Some(source.unwrap()))
+    Ok(source.map(Source::from))
   }
 
   async fn delete_by_id(
@@ -74,7 +211,41 @@ impl crate::domain::source::repository::SourceRepository for SqlxSourceRepositor
     id: &crate::shared::vo::UUID4,
   ) -> Result<(), crate::domain::source::repository::Error> {
     sqlx::query!(
-      r#"DELETE FROM "source" WHERE "id" = $1"#,
+      r#"UPDATE "source" SET "deleted_at" = now() WHERE "id" = $1 AND "deleted_at" IS NULL"#,
+      uuid::Uuid::from(id.clone())
+    )
+    .execute(&self.db)
+    .await
+    .map_err(to_database_error)?;
+    Ok(())
+  }
+
+  async fn find_deleted_by_id(
+    &mut self,
+    id: &crate::shared::vo::UUID4,
+  ) -> Result<Option<Source>, crate::domain::source::repository::Error> {
+    let source = sqlx::query_as!(
+      SourceRecord,
+      r#"
+        SELECT "id", "source_type", "src", "external_id", "media_id", "status", "last_checked_at", "start_offset", "duration", "created_at", "updated_at"
+        FROM "source"
+        WHERE "id" = $1 AND "deleted_at" IS NOT NULL
+      "#,
+      uuid::Uuid::from(id.clone())
+    )
+    .fetch_optional(&self.db)
+    .await
+    .map_err(to_database_error)?;
+
+    Ok(source.map(Source::from))
+  }
+
+  async fn restore_by_id(
+    &mut self,
+    id: &crate::shared::vo::UUID4,
+  ) -> Result<(), crate::domain::source::repository::Error> {
+    sqlx::query!(
+      r#"UPDATE "source" SET "deleted_at" = NULL WHERE "id" = $1"#,
       uuid::Uuid::from(id.clone())
     )
     .execute(&self.db)
@@ -158,4 +329,135 @@ mod tests {
       "O source retornado é diferente do source criada"
     );
   }
+
+  #[tokio::test]
+  async fn test_update_and_find_sources() {
+    const SOURCE_ID: &str = "3c1a6f0e-5e0b-4f7a-9a55-0e1f6c2b7d11";
+    const MEDIA_ID: &str = "3c1a6f0e-5e0b-4f7a-9a55-0e1f6c2b7d12";
+
+    // Load .env file
+    dotenvy::dotenv().ok();
+    let app_state = AppState::default().await;
+    let mut media_repository = crate::infra::sqlx::SqlxMediaRepository::new(&app_state);
+    let mut source_repository = crate::infra::sqlx::SqlxSourceRepository::new(&app_state);
+    async fn cleanup(db: &sqlx::pool::Pool<Postgres>) {
+      sqlx::query!(
+        r#"
+        DELETE FROM "media" WHERE "id" = $1
+      "#,
+        uuid::Uuid::parse_str(MEDIA_ID).unwrap()
+      )
+      .execute(db)
+      .await
+      .expect("Erro ao excluir dados de teste da tabela media");
+    }
+
+    let media = Media::builder()
+      .id(UUID4::new(MEDIA_ID).unwrap())
+      .name(String::from("Teste"))
+      .slug(Slug::new("test_find_by_external_id_media").unwrap())
+      .build();
+
+    let canonical = crate::domain::source::source_url::parse(
+      &crate::domain::source::source_entity::SourceType::Youtube,
+      "https://youtu.be/VHA2ASr8rmY",
+    )
+    .expect("URL do Youtube rejeitada");
+
+    let source = crate::domain::source::source_entity::Source::builder()
+      .id(UUID4::new(SOURCE_ID).unwrap())
+      .source_type(crate::domain::source::source_entity::SourceType::Youtube)
+      .src(canonical.src)
+      .external_id(Some(canonical.external_id.clone()))
+      .media_id(media.id().clone())
+      .build();
+
+    cleanup(&app_state.db).await;
+
+    media_repository
+      .create(&media)
+      .await
+      .expect("Erro ao criar media");
+
+    source_repository
+      .create(&source)
+      .await
+      .expect("Erro ao criar source");
+
+    let found = source_repository
+      .find_by_external_id(
+        media.id(),
+        &crate::domain::source::source_entity::SourceType::Youtube,
+        &canonical.external_id,
+      )
+      .await
+      .expect("Erro ao buscar o source");
+
+    let updated = crate::domain::source::source_entity::SourceBuilder::from(source.clone())
+      .src(String::from("https://www.youtube.com/watch?v=O3tnOVideSo"))
+      .external_id(Some(String::from("O3tnOVideSo")))
+      .status(crate::domain::source::source_entity::SourceStatus::RegionRestricted)
+      .last_checked_at(Some(crate::shared::util::naive_now()))
+      .start_offset(Some(12))
+      .duration(Some(215))
+      .build();
+
+    source_repository
+      .update(&updated)
+      .await
+      .expect("Erro ao atualizar source");
+
+    let sources = source_repository
+      .find_by_media_id(media.id())
+      .await
+      .expect("Erro ao listar sources da media");
+
+    let other_provider = source_repository
+      .find_by_external_id(
+        media.id(),
+        &crate::domain::source::source_entity::SourceType::Soundcloud,
+        &canonical.external_id,
+      )
+      .await
+      .expect("Erro ao buscar o source");
+
+    cleanup(&app_state.db).await;
+
+    assert_eq!(found, Some(source), "Fonte duplicada não foi encontrada");
+    assert_eq!(
+      other_provider, None,
+      "Fonte de outro provedor foi encontrada"
+    );
+    assert_eq!(sources, vec![updated], "Fontes da media incorretas");
+  }
+
+  #[tokio::test]
+  async fn test_create_source_with_overflowing_offset() {
+    const SOURCE_ID: &str = "5b8e2f4a-6c1d-4e7f-8a9b-0c1d2e3f4a5b";
+
+    // Load .env file
+    dotenvy::dotenv().ok();
+
+    let app_state = AppState::default().await;
+    let mut source_repository = super::SqlxSourceRepository::new(&app_state);
+
+    let source = crate::domain::source::source_entity::Source::builder()
+      .id(UUID4::new(SOURCE_ID).unwrap())
+      .src(String::from("https://www.youtube.com/watch?v=VHA2ASr8rmY"))
+      .start_offset(Some(u32::MAX))
+      .build();
+
+    let result = source_repository.create(&source).await;
+
+    let found = source_repository
+      .find_by_id(source.id())
+      .await
+      .expect("Erro ao buscar fonte");
+
+    assert!(
+      result.is_err(),
+      "Início acima de i32::MAX deveria ser rejeitado"
+    );
+    assert!(found.is_none(), "Fonte com início inválido foi criada");
+  }
 }
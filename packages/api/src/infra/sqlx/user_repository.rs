@@ -26,6 +26,9 @@ struct UserRecord {
   email: String,
   display_name: String,
   is_curator: bool,
+  is_admin: bool,
+  max_parental_rating: Option<i16>,
+  birth_date: Option<chrono::NaiveDate>,
   created_at: chrono::NaiveDateTime,
   updated_at: chrono::NaiveDateTime,
 }
@@ -38,7 +41,10 @@ impl From<User> for UserRecord {
       password: val.password().clone(),
       email: val.email().clone(),
       is_curator: *val.is_curator(),
+      is_admin: *val.is_admin(),
       display_name: val.display_name().clone(),
+      max_parental_rating: val.max_parental_rating().map(i16::from),
+      birth_date: *val.birth_date(),
       created_at: trim_datetime(*val.created_at()),
       updated_at: trim_datetime(*val.updated_at()),
     }
@@ -54,6 +60,9 @@ impl From<UserRecord> for User {
       .email(val.email)
       .display_name(val.display_name)
       .is_curator(val.is_curator)
+      .is_admin(val.is_admin)
+      .max_parental_rating(val.max_parental_rating.map(|rating| rating as u8))
+      .birth_date(val.birth_date)
       .created_at(trim_datetime(val.created_at))
       .updated_at(trim_datetime(val.updated_at))
       .build()
@@ -85,6 +94,38 @@ impl UserRepository for SqlxUserRepository {
     Ok(user.clone())
   }
 
+  async fn update(&mut self, user: &User) -> Result<User, UserRepositoryError> {
+    let user_record: UserRecord = user.clone().into();
+
+    sqlx::query!(
+      r#"
+      UPDATE "users"
+      SET
+        "display_name" = $2,
+        "email" = $3,
+        "max_parental_rating" = $4,
+        "birth_date" = $5,
+        "is_curator" = $6,
+        "is_admin" = $7,
+        "updated_at" = $8
+      WHERE "id" = $1
+    "#r,
+      user_record.id,
+      user_record.display_name,
+      user_record.email,
+      user_record.max_parental_rating,
+      user_record.birth_date,
+      user_record.is_curator,
+      user_record.is_admin,
+      user_record.updated_at
+    )
+    .execute(&self.pool)
+    .await
+    .map_err(|e| UserRepositoryError::InternalServerError(e.to_string()))?;
+
+    Ok(user.clone())
+  }
+
   async fn find_by_username(
     &mut self,
     username: impl Into<String>,
@@ -264,4 +305,66 @@ mod tests {
 
     assert!(user.is_none());
   }
+
+  #[tokio::test]
+  async fn should_update_user_preferences() {
+    // Load .env file
+    dotenvy::dotenv().ok();
+
+    async fn delete_old_data() {
+      let app_state = AppState::default().await;
+
+      app_state
+        .db
+        .execute(
+          r#"
+          DELETE FROM "users" WHERE "id" = 'c0d1b6c2-9f0e-4a5e-8f38-3f4e2d1c7a10';
+          "#,
+        )
+        .await
+        .ok();
+    }
+
+    let state = AppState::default().await;
+
+    delete_old_data().await;
+
+    let mut user_repository = SqlxUserRepository::new(&state);
+
+    let mut user = User::builder()
+      .id(UUID4::new("c0d1b6c2-9f0e-4a5e-8f38-3f4e2d1c7a10").unwrap())
+      .username(String::from("should_update_user_preferences"))
+      .password(String::from("test"))
+      .email(String::from(TEST_EMAIL))
+      .build();
+
+    user_repository.create(&user).await.unwrap();
+
+    user.set_max_parental_rating(Some(14));
+    user.set_birth_date(chrono::NaiveDate::from_ymd_opt(2010, 5, 20));
+
+    user_repository
+      .update(&user)
+      .await
+      .expect("Erro ao atualizar usuário");
+
+    let result = user_repository
+      .find_by_id(user.id().clone())
+      .await
+      .expect("Erro ao buscar usuário por id")
+      .expect("Usuário não encontrado");
+
+    delete_old_data().await;
+
+    assert_eq!(
+      result.max_parental_rating(),
+      &Some(14),
+      "A classificação indicativa máxima não foi atualizada"
+    );
+    assert_eq!(
+      result.birth_date(),
+      user.birth_date(),
+      "A data de nascimento não foi atualizada"
+    );
+  }
 }
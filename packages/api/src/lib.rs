@@ -14,6 +14,21 @@ pub mod shared;
 pub struct AppState {
   pub db: sqlx::Pool<Postgres>,
   pub in_memory: Arc<RwLock<HashMap<String, serde_json::Value>>>,
+
+  /**
+   * Maior quantidade de itens que uma listagem paginada pode retornar por página.
+   */
+  pub max_per_page: usize,
+
+  /**
+   * Classificação indicativa máxima exibida para visitantes não autenticados.
+   */
+  pub anonymous_max_parental_rating: u8,
+
+  /**
+   * Quantidade de itens dependentes a partir da qual uma exclusão só pode ser aprovada por um administrador.
+   */
+  pub deletion_impact_threshold: u64,
 }
 
 impl AppState {
@@ -35,7 +50,8 @@ impl AppState {
       .await
     {
       Ok(pool) => {
-        println!("✅ Database connected successfully!");
+        // Na saída de erros, para não se misturar à saída dos comandos do admin.
+        eprintln!("✅ Database connected successfully!");
         pool
       }
       Err(e) => {
@@ -44,9 +60,45 @@ impl AppState {
       }
     };
 
+    let max_per_page = match std::env::var("PAGINATION_MAX_PER_PAGE")
+      .unwrap_or("100".to_string())
+      .parse::<usize>()
+    {
+      Ok(max_per_page) if max_per_page > 0 => max_per_page,
+      _ => {
+        eprintln!("🔥 PAGINATION_MAX_PER_PAGE deve ser um número inteiro maior que zero");
+        std::process::exit(1);
+      }
+    };
+
+    let anonymous_max_parental_rating = match std::env::var("ANONYMOUS_MAX_PARENTAL_RATING")
+      .unwrap_or("12".to_string())
+      .parse::<u8>()
+    {
+      Ok(rating) if rating <= domain::user::MAX_PARENTAL_RATING => rating,
+      _ => {
+        eprintln!("🔥 ANONYMOUS_MAX_PARENTAL_RATING deve ser um número inteiro entre 0 e 18");
+        std::process::exit(1);
+      }
+    };
+
+    let deletion_impact_threshold = match std::env::var("DELETION_IMPACT_THRESHOLD")
+      .unwrap_or("50".to_string())
+      .parse::<u64>()
+    {
+      Ok(threshold) => threshold,
+      _ => {
+        eprintln!("🔥 DELETION_IMPACT_THRESHOLD deve ser um número inteiro não negativo");
+        std::process::exit(1);
+      }
+    };
+
     Self {
       db: pool,
       in_memory: Arc::new(RwLock::new(HashMap::new())),
+      max_per_page,
+      anonymous_max_parental_rating,
+      deletion_impact_threshold,
     }
   }
 }
@@ -17,6 +17,51 @@ use serde_json::json;
 
 use actix_web::middleware::Logger;
 
+/**
+ * Modo de execução escolhido pelos argumentos da linha de comando.
+ */
+enum Mode {
+  /**
+   * Inicia o servidor, desde que o banco esteja com todas as migrações aplicadas.
+   */
+  Serve,
+
+  /**
+   * Aplica as migrações pendentes e encerra.
+   */
+  Migrate,
+
+  /**
+   * Reverte as N últimas migrações aplicadas e encerra.
+   */
+  MigrateDown(usize),
+}
+
+fn parse_mode() -> Mode {
+  let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+  match args
+    .iter()
+    .map(String::as_str)
+    .collect::<Vec<_>>()
+    .as_slice()
+  {
+    [] => Mode::Serve,
+    ["--migrate"] => Mode::Migrate,
+    ["--migrate-down", steps] => match steps.parse::<usize>() {
+      Ok(steps) if steps > 0 => Mode::MigrateDown(steps),
+      _ => {
+        eprintln!("🔥 --migrate-down espera um número inteiro maior que zero");
+        std::process::exit(1);
+      }
+    },
+    _ => {
+      eprintln!("Uso: api [--migrate | --migrate-down N]");
+      std::process::exit(1);
+    }
+  }
+}
+
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
   // Load .env file
@@ -38,7 +83,53 @@ async fn main() -> std::io::Result<()> {
     }
   };
 
+  let mode = parse_mode();
   let app_state = AppState::default().await;
+
+  match mode {
+    Mode::Migrate => {
+      match infra::sqlx::migrations::run(&app_state.db).await {
+        Ok(()) => println!("✅ Migrations applied successfully!"),
+        Err(e) => {
+          eprintln!("🔥 Failed to apply migrations: {}", e);
+          std::process::exit(1);
+        }
+      }
+
+      return Ok(());
+    }
+    Mode::MigrateDown(steps) => {
+      match infra::sqlx::migrations::undo(&app_state.db, steps).await {
+        Ok(versions) => {
+          for version in versions {
+            println!("↩️  Reverted migration {}", version);
+          }
+        }
+        Err(e) => {
+          eprintln!("🔥 Failed to revert migrations: {}", e);
+          std::process::exit(1);
+        }
+      }
+
+      return Ok(());
+    }
+    Mode::Serve => match infra::sqlx::migrations::pending(&app_state.db).await {
+      Ok(pending) if pending.is_empty() => {}
+      Ok(pending) => {
+        eprintln!("🔥 Database schema is behind, pending migrations:");
+        for migration in pending {
+          eprintln!("   {} {}", migration.version, migration.description);
+        }
+        eprintln!("   Run with --migrate to apply them");
+        std::process::exit(1);
+      }
+      Err(e) => {
+        eprintln!("🔥 Failed to check database schema: {}", e);
+        std::process::exit(1);
+      }
+    },
+  }
+
   let key = Key::from(
     base64::prelude::BASE64_STANDARD
       .decode(
@@ -120,6 +211,9 @@ async fn main() -> std::io::Result<()> {
         infra::actix::album::controller::configure(config);
         infra::actix::media::controller::configure(config);
         infra::actix::source::controller::configure(config);
+        infra::actix::lyrics::controller::configure(config);
+        infra::actix::search::controller::configure(config);
+        infra::actix::catalog::controller::configure(config);
       })
   })
   .bind((api_host.as_str(), api_port))
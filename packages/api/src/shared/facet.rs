@@ -0,0 +1,62 @@
+use serde::Serialize;
+
+/**
+ * Valor de uma faceta e a quantidade de registros da listagem que o possuem.
+ */
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FacetValue {
+  pub value: String,
+
+  /**
+   * Nome legível do valor, quando ele não é autoexplicativo (ex.: o nome de um gênero).
+   */
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub label: Option<String>,
+
+  pub count: usize,
+}
+
+/**
+ * Contagens de uma listagem agrupadas pelos valores de um campo.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Facet<F> {
+  pub field: F,
+  pub values: Vec<FacetValue>,
+}
+
+impl<F> Facet<F> {
+  /**
+   * Agrupa os valores informados, ordenando-os da maior para a menor contagem.
+   */
+  pub fn from_values(field: F, values: impl IntoIterator<Item = (String, Option<String>)>) -> Self {
+    let mut counts = std::collections::HashMap::<(String, Option<String>), usize>::new();
+
+    for value in values {
+      *counts.entry(value).or_default() += 1;
+    }
+
+    let mut values = counts
+      .into_iter()
+      .map(|((value, label), count)| FacetValue {
+        value,
+        label,
+        count,
+      })
+      .collect::<Vec<_>>();
+
+    values.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.value.cmp(&b.value)));
+
+    Self { field, values }
+  }
+}
+
+/**
+ * Declara a lista de campos pelos quais uma entidade pode ser agrupada em facetas.
+ */
+#[macro_export]
+macro_rules! facet_fields {
+  ($($tokens:tt)*) => {
+    $crate::field_enum!("facet", $($tokens)*);
+  };
+}
@@ -0,0 +1,130 @@
+use std::{collections::HashSet, hash::Hash, str::FromStr};
+
+/**
+ * Como os valores incluídos de um filtro são combinados.
+ */
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MatchMode {
+  /**
+   * O registro deve possuir ao menos um dos valores.
+   */
+  #[default]
+  Any,
+
+  /**
+   * O registro deve possuir todos os valores; só faz sentido em relações com vários valores.
+   */
+  All,
+}
+
+/**
+ * Filtro com múltiplos valores: o registro deve possuir os valores incluídos, conforme o
+ * `mode`, e nenhum dos valores excluídos.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListFilter<T: Eq + Hash> {
+  pub include: HashSet<T>,
+  pub exclude: HashSet<T>,
+  pub mode: MatchMode,
+}
+
+impl<T: Eq + Hash> ListFilter<T> {
+  /**
+   * Filtro que aceita registros com qualquer um dos valores.
+   */
+  pub fn any(values: impl IntoIterator<Item = T>) -> Self {
+    Self {
+      include: values.into_iter().collect(),
+      exclude: HashSet::new(),
+      mode: MatchMode::Any,
+    }
+  }
+
+  /**
+   * Interpreta uma lista de valores separados por vírgula, onde os valores precedidos
+   * por `!` são excluídos, ex: `rock,pop,!funk`.
+   */
+  pub fn parse(value: &str, mode: MatchMode) -> Result<Self, String>
+  where
+    T: FromStr,
+  {
+    let mut filter = Self {
+      include: HashSet::new(),
+      exclude: HashSet::new(),
+      mode,
+    };
+
+    for item in value
+      .split(',')
+      .map(str::trim)
+      .filter(|item| !item.is_empty())
+    {
+      let (values, item) = match item.strip_prefix('!') {
+        Some(item) => (&mut filter.exclude, item.trim()),
+        None => (&mut filter.include, item),
+      };
+
+      values.insert(
+        item
+          .parse()
+          .map_err(|_| format!("Invalid filter value: {}", item))?,
+      );
+    }
+
+    Ok(filter)
+  }
+
+  /**
+   * Verifica se um registro com os valores informados atende ao filtro.
+   */
+  pub fn matches<'a>(&self, values: impl IntoIterator<Item = &'a T>) -> bool
+  where
+    T: 'a,
+  {
+    let values = values.into_iter().collect::<HashSet<_>>();
+
+    let included = self.include.is_empty()
+      || match self.mode {
+        MatchMode::Any => self.include.iter().any(|value| values.contains(value)),
+        MatchMode::All => self.include.iter().all(|value| values.contains(value)),
+      };
+
+    included && !self.exclude.iter().any(|value| values.contains(value))
+  }
+
+  /**
+   * Converte os valores do filtro, mantendo o modo de combinação.
+   */
+  pub fn map<U: Eq + Hash>(&self, f: impl Fn(&T) -> U) -> ListFilter<U> {
+    ListFilter {
+      include: self.include.iter().map(&f).collect(),
+      exclude: self.exclude.iter().map(&f).collect(),
+      mode: self.mode,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_list_filter() {
+    let filter = ListFilter::<u8>::parse("1, 2,!3", MatchMode::All).unwrap();
+
+    assert_eq!(filter.include, HashSet::from([1, 2]));
+    assert_eq!(filter.exclude, HashSet::from([3]));
+    assert!(filter.matches(&[1, 2, 4]));
+    assert!(
+      !filter.matches(&[1, 4]),
+      "Todos os valores incluídos deveriam ser exigidos"
+    );
+    assert!(
+      !filter.matches(&[1, 2, 3]),
+      "Valores excluídos deveriam rejeitar o registro"
+    );
+    assert!(ListFilter::<u8>::any([1, 2]).matches(&[2]));
+    assert!(ListFilter::<u8>::parse("1,x", MatchMode::Any).is_err());
+  }
+}
@@ -1,5 +1,9 @@
+pub mod facet;
+pub mod filter;
 pub mod paged;
 pub mod password_hash;
+pub mod reference;
+pub mod sort;
 pub mod util;
 pub mod validator;
 pub mod vo;
@@ -48,19 +48,301 @@ pub struct RequestPageParams {
 impl From<RequestPageParams> for PageQueryParams {
   fn from(params: RequestPageParams) -> Self {
     Self {
-      skip: params.page.checked_sub(1).unwrap_or_default() * params.per_page,
+      skip: params
+        .page
+        .saturating_sub(1)
+        .saturating_mul(params.per_page),
       take: params.per_page,
     }
   }
 }
 
 impl<T> Paged<T> {
+  /**
+   * Pagina uma lista com todos os itens encontrados.
+   */
+  pub fn from_items(items: Vec<T>, params: RequestPageParams) -> Self {
+    let page_params = PageQueryParams::from(params.clone());
+    let total_items = items.len();
+
+    Self::from_tuple(
+      (
+        total_items,
+        items
+          .into_iter()
+          .skip(page_params.skip)
+          .take(page_params.take)
+          .collect(),
+      ),
+      params,
+    )
+  }
+
   pub fn from_tuple((total_items, items): PageTuple<T>, params: RequestPageParams) -> Self {
     Self {
       items,
       total_items,
-      total_pages: (total_items as f64 / params.per_page as f64).ceil() as usize,
+      total_pages: total_items.div_ceil(params.per_page.max(1)),
       page: params.page,
     }
   }
 }
+
+/**
+ * Direção de navegação a partir de um cursor.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorDirection {
+  Next,
+  Prev,
+}
+
+/**
+ * Posição na paginação por cursor (keyset), que ordena os registros do mais recente
+ * para o mais antigo por ("created_at", "id").
+ *
+ * É exposto aos clientes como uma string opaca.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cursor {
+  pub created_at: chrono::NaiveDateTime,
+  pub id: crate::shared::vo::UUID4,
+  pub direction: CursorDirection,
+}
+
+impl std::fmt::Display for Cursor {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    use base64::Engine;
+
+    let direction = match self.direction {
+      CursorDirection::Next => "n",
+      CursorDirection::Prev => "p",
+    };
+
+    write!(
+      f,
+      "{}",
+      base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(format!(
+        "{}:{}:{}",
+        direction,
+        self.created_at.and_utc().timestamp_micros(),
+        self.id
+      ))
+    )
+  }
+}
+
+impl std::str::FromStr for Cursor {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    use base64::Engine;
+
+    let invalid = || format!("Invalid cursor: {}", s);
+
+    let decoded = base64::engine::general_purpose::URL_SAFE_NO_PAD
+      .decode(s)
+      .ok()
+      .and_then(|decoded| String::from_utf8(decoded).ok())
+      .ok_or_else(invalid)?;
+
+    let mut parts = decoded.splitn(3, ':');
+
+    let direction = match parts.next() {
+      Some("n") => CursorDirection::Next,
+      Some("p") => CursorDirection::Prev,
+      _ => return Err(invalid()),
+    };
+
+    let created_at = parts
+      .next()
+      .and_then(|micros| micros.parse().ok())
+      .and_then(chrono::DateTime::from_timestamp_micros)
+      .ok_or_else(invalid)?
+      .naive_utc();
+
+    let id = parts
+      .next()
+      .and_then(|id| crate::shared::vo::UUID4::new(id).ok())
+      .ok_or_else(invalid)?;
+
+    Ok(Self {
+      created_at,
+      id,
+      direction,
+    })
+  }
+}
+
+/**
+ * Parâmetros da paginação por cursor.
+ */
+#[derive(Debug, Clone)]
+pub struct CursorPageParams {
+  pub cursor: Option<Cursor>,
+  pub limit: usize,
+
+  /**
+   * Quando verdadeiro, também conta o total de registros (consulta mais lenta).
+   */
+  pub include_total: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CursorPaged<T> {
+  pub items: Vec<T>,
+  pub next_cursor: Option<String>,
+  pub prev_cursor: Option<String>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub total_items: Option<usize>,
+}
+
+impl<T> CursorPaged<T> {
+  /**
+   * Monta a página a partir de até `limit + 1` itens buscados na direção do cursor;
+   * o item excedente indica que ainda existem registros nessa direção.
+   */
+  pub fn from_keyset(
+    mut items: Vec<T>,
+    params: &CursorPageParams,
+    total_items: Option<usize>,
+    key: impl Fn(&T) -> (chrono::NaiveDateTime, crate::shared::vo::UUID4),
+  ) -> Self {
+    let direction = params
+      .cursor
+      .as_ref()
+      .map(|cursor| cursor.direction)
+      .unwrap_or(CursorDirection::Next);
+
+    let has_more = items.len() > params.limit;
+
+    items.truncate(params.limit);
+
+    let (has_next, has_prev) = match direction {
+      CursorDirection::Next => (has_more, params.cursor.is_some()),
+      CursorDirection::Prev => {
+        items.reverse();
+        (true, has_more)
+      }
+    };
+
+    let cursor = |item: &T, direction| {
+      let (created_at, id) = key(item);
+
+      Cursor {
+        created_at,
+        id,
+        direction,
+      }
+      .to_string()
+    };
+
+    Self {
+      next_cursor: items
+        .last()
+        .filter(|_| has_next)
+        .map(|item| cursor(item, CursorDirection::Next)),
+      prev_cursor: items
+        .first()
+        .filter(|_| has_prev)
+        .map(|item| cursor(item, CursorDirection::Prev)),
+      items,
+      total_items,
+    }
+  }
+
+  pub fn map<U>(self, f: impl FnMut(T) -> U) -> CursorPaged<U> {
+    CursorPaged {
+      items: self.items.into_iter().map(f).collect(),
+      next_cursor: self.next_cursor,
+      prev_cursor: self.prev_cursor,
+      total_items: self.total_items,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_cursor_round_trip() {
+    let cursor = Cursor {
+      created_at: chrono::NaiveDate::from_ymd_opt(2024, 12, 1)
+        .unwrap()
+        .and_hms_micro_opt(10, 30, 15, 123456)
+        .unwrap(),
+      id: crate::shared::vo::UUID4::new("7f2a8ffe-b276-4fb9-b119-fbd7aac8b4c8").unwrap(),
+      direction: CursorDirection::Prev,
+    };
+
+    assert_eq!(cursor.to_string().parse::<Cursor>(), Ok(cursor));
+    assert!(
+      "not-a-cursor".parse::<Cursor>().is_err(),
+      "Cursores inválidos deveriam ser rejeitados"
+    );
+  }
+
+  #[test]
+  fn test_from_keyset() {
+    let key = |item: &i64| {
+      (
+        chrono::DateTime::from_timestamp(*item, 0)
+          .unwrap()
+          .naive_utc(),
+        crate::shared::vo::UUID4::default(),
+      )
+    };
+
+    let first_page = CursorPaged::from_keyset(
+      vec![3, 2, 1],
+      &CursorPageParams {
+        cursor: None,
+        limit: 2,
+        include_total: false,
+      },
+      None,
+      key,
+    );
+
+    assert_eq!(first_page.items, vec![3, 2]);
+    assert!(
+      first_page.next_cursor.is_some(),
+      "Deveria haver próxima página"
+    );
+    assert!(
+      first_page.prev_cursor.is_none(),
+      "Não deveria haver página anterior"
+    );
+
+    let previous_page = CursorPaged::from_keyset(
+      vec![4, 5],
+      &CursorPageParams {
+        cursor: Some(Cursor {
+          created_at: key(&3).0,
+          id: key(&3).1,
+          direction: CursorDirection::Prev,
+        }),
+        limit: 2,
+        include_total: false,
+      },
+      None,
+      key,
+    );
+
+    assert_eq!(
+      previous_page.items,
+      vec![5, 4],
+      "A página anterior deveria manter a ordem"
+    );
+    assert!(
+      previous_page.next_cursor.is_some(),
+      "Deveria haver próxima página"
+    );
+    assert!(
+      previous_page.prev_cursor.is_none(),
+      "Não deveria haver página anterior"
+    );
+  }
+}
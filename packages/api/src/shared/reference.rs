@@ -0,0 +1,49 @@
+/**
+ * Referências informadas em uma contribuição que não correspondem a nenhuma
+ * entidade existente, agrupadas pelo campo em que foram informadas.
+ */
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MissingReferences(std::collections::BTreeMap<String, Vec<crate::shared::vo::UUID4>>);
+
+impl MissingReferences {
+  /**
+   * Registra, no campo informado, os ids que não foram encontrados entre os existentes.
+   */
+  pub fn check<'a>(
+    &mut self,
+    field: &str,
+    ids: impl IntoIterator<Item = &'a crate::shared::vo::UUID4>,
+    existing: &std::collections::HashSet<crate::shared::vo::UUID4>,
+  ) {
+    let mut missing = ids
+      .into_iter()
+      .filter(|id| !existing.contains(id))
+      .cloned()
+      .collect::<Vec<_>>();
+
+    if missing.is_empty() {
+      return;
+    }
+
+    missing.sort_by_key(|id| id.to_string());
+    missing.dedup();
+
+    self.0.entry(field.to_string()).or_default().extend(missing);
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+
+  pub fn fields(&self) -> &std::collections::BTreeMap<String, Vec<crate::shared::vo::UUID4>> {
+    &self.0
+  }
+
+  pub fn into_result(self) -> Result<(), Self> {
+    if self.is_empty() {
+      Ok(())
+    } else {
+      Err(self)
+    }
+  }
+}
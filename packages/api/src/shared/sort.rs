@@ -0,0 +1,177 @@
+use std::{cmp::Ordering, str::FromStr};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortDirection {
+  #[default]
+  Asc,
+  Desc,
+}
+
+impl SortDirection {
+  pub fn to_sql(&self) -> &'static str {
+    match self {
+      Self::Asc => "ASC",
+      Self::Desc => "DESC",
+    }
+  }
+}
+
+/**
+ * Ordenação de uma listagem paginada.
+ *
+ * Informada como `campo` para ordem crescente ou `-campo` para ordem decrescente,
+ * onde `campo` deve pertencer à lista de campos permitidos da entidade (`F`).
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sort<F> {
+  pub field: F,
+  pub direction: SortDirection,
+}
+
+impl<F> Sort<F> {
+  /**
+   * Aplica a direção da ordenação a uma comparação feita em ordem crescente.
+   */
+  pub fn apply(&self, ordering: Ordering) -> Ordering {
+    match self.direction {
+      SortDirection::Asc => ordering,
+      SortDirection::Desc => ordering.reverse(),
+    }
+  }
+}
+
+impl<F: FromStr<Err = String>> FromStr for Sort<F> {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let s = s.trim();
+
+    let (field, direction) = match s.strip_prefix('-') {
+      Some(field) => (field, SortDirection::Desc),
+      None => (s.strip_prefix('+').unwrap_or(s), SortDirection::Asc),
+    };
+
+    Ok(Self {
+      field: field.parse()?,
+      direction,
+    })
+  }
+}
+
+impl<F: std::fmt::Display> std::fmt::Display for Sort<F> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self.direction {
+      SortDirection::Asc => write!(f, "{}", self.field),
+      SortDirection::Desc => write!(f, "-{}", self.field),
+    }
+  }
+}
+
+impl<'de, F: FromStr<Err = String>> serde::Deserialize<'de> for Sort<F> {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    String::deserialize(deserializer)?
+      .parse()
+      .map_err(serde::de::Error::custom)
+  }
+}
+
+/**
+ * Declara um enum de campos aceitos em uma listagem, com conversão de e para o nome
+ * usado na query string. `$kind` identifica o uso do campo nas mensagens de erro.
+ */
+#[macro_export]
+macro_rules! field_enum {
+  ($kind:literal, $(#[$meta:meta])* $vis:vis enum $name:ident { $($variant:ident => $key:literal,)* }) => {
+    $(#[$meta])*
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    $vis enum $name {
+      $($variant,)*
+    }
+
+    impl $name {
+      pub const ALL: &[Self] = &[$(Self::$variant,)*];
+    }
+
+    impl std::fmt::Display for $name {
+      fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+          $(Self::$variant => write!(f, $key),)*
+        }
+      }
+    }
+
+    impl std::str::FromStr for $name {
+      type Err = String;
+
+      fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+          .iter()
+          .find(|field| field.to_string() == s)
+          .copied()
+          .ok_or_else(|| {
+            format!(
+              "Invalid {} field: {}. Expected one of: {}",
+              $kind,
+              s,
+              Self::ALL
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+            )
+          })
+      }
+    }
+  };
+}
+
+/**
+ * Declara a lista de campos pelos quais uma entidade pode ser ordenada.
+ */
+#[macro_export]
+macro_rules! sort_fields {
+  ($($tokens:tt)*) => {
+    $crate::field_enum!("sort", $($tokens)*);
+  };
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  crate::sort_fields! {
+    enum TestField {
+      Name => "name",
+      ReleaseDate => "release_date",
+    }
+  }
+
+  #[test]
+  fn test_parse_sort() {
+    assert_eq!(
+      "name".parse::<Sort<TestField>>(),
+      Ok(Sort {
+        field: TestField::Name,
+        direction: SortDirection::Asc
+      })
+    );
+    assert_eq!(
+      "-release_date".parse::<Sort<TestField>>(),
+      Ok(Sort {
+        field: TestField::ReleaseDate,
+        direction: SortDirection::Desc
+      })
+    );
+    assert!(
+      "popularity".parse::<Sort<TestField>>().is_err(),
+      "Campos fora da lista deveriam ser rejeitados"
+    );
+    assert_eq!(
+      "-release_date"
+        .parse::<Sort<TestField>>()
+        .unwrap()
+        .to_string(),
+      "-release_date"
+    );
+  }
+}
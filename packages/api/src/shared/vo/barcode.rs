@@ -0,0 +1,120 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BarcodeError {
+  InvalidFormat,
+  InvalidChecksum,
+}
+
+impl Display for BarcodeError {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    match self {
+      Self::InvalidFormat => write!(f, "Invalid barcode: expected 8, 12 or 13 digits"),
+      Self::InvalidChecksum => write!(f, "Invalid barcode checksum"),
+    }
+  }
+}
+
+/**
+ * Código de barras de um lançamento (EAN-8, UPC-A ou EAN-13), armazenado somente com dígitos.
+ *
+ * O último dígito é o verificador GS1 e precisa conferir com os demais.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Barcode(String);
+
+impl Barcode {
+  pub fn new(value: impl Into<String>) -> Result<Self, BarcodeError> {
+    let value: String = value
+      .into()
+      .chars()
+      .filter(|c| !c.is_whitespace() && *c != '-')
+      .collect();
+
+    if !matches!(value.len(), 8 | 12 | 13) || !value.bytes().all(|b| b.is_ascii_digit()) {
+      return Err(BarcodeError::InvalidFormat);
+    }
+
+    let digits: Vec<u32> = value.bytes().map(|b| (b - b'0') as u32).collect();
+    let (check, payload) = digits.split_last().unwrap();
+
+    // Da direita para a esquerda, os dígitos alternam peso 3 e 1.
+    let sum: u32 = payload
+      .iter()
+      .rev()
+      .enumerate()
+      .map(|(i, digit)| if i % 2 == 0 { digit * 3 } else { *digit })
+      .sum();
+
+    if (10 - sum % 10) % 10 != *check {
+      return Err(BarcodeError::InvalidChecksum);
+    }
+
+    Ok(Self(value))
+  }
+}
+
+impl Display for Barcode {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
+
+impl Serialize for Barcode {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    serializer.serialize_str(&self.0)
+  }
+}
+
+impl<'de> Deserialize<'de> for Barcode {
+  fn deserialize<D>(deserializer: D) -> Result<Barcode, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    let s = String::deserialize(deserializer)?;
+    Barcode::new(s).map_err(serde::de::Error::custom)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_barcode_accepts_valid_checksums() {
+    assert!(
+      Barcode::new("4006381333931").is_ok(),
+      "EAN-13 válido foi rejeitado"
+    );
+    assert!(
+      Barcode::new("036000291452").is_ok(),
+      "UPC-A válido foi rejeitado"
+    );
+    assert!(
+      Barcode::new("96385074").is_ok(),
+      "EAN-8 válido foi rejeitado"
+    );
+    assert_eq!(
+      Barcode::new("4 006381-333931").unwrap().to_string(),
+      "4006381333931"
+    );
+  }
+
+  #[test]
+  fn test_barcode_rejects_invalid_input() {
+    assert_eq!(
+      Barcode::new("4006381333932"),
+      Err(BarcodeError::InvalidChecksum)
+    );
+    assert_eq!(Barcode::new("12345"), Err(BarcodeError::InvalidFormat));
+    assert_eq!(
+      Barcode::new("40063813339A1"),
+      Err(BarcodeError::InvalidFormat)
+    );
+  }
+}
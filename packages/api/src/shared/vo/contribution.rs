@@ -1,14 +1,51 @@
 use crate::domain;
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum CollaborativeEntityName {
   Genre,
   Artist,
   Album,
   Media,
   Source,
+  Lyrics,
 }
 
+impl CollaborativeEntityName {
+  pub const ALL: [Self; 6] = [
+    Self::Genre,
+    Self::Artist,
+    Self::Album,
+    Self::Media,
+    Self::Source,
+    Self::Lyrics,
+  ];
+}
+
+impl std::fmt::Display for CollaborativeEntityName {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Genre => write!(f, "Genre"),
+      Self::Artist => write!(f, "Artist"),
+      Self::Album => write!(f, "Album"),
+      Self::Media => write!(f, "Media"),
+      Self::Source => write!(f, "Source"),
+      Self::Lyrics => write!(f, "Lyrics"),
+    }
+  }
+}
+
+impl std::str::FromStr for CollaborativeEntityName {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::ALL
+      .into_iter()
+      .find(|name| name.to_string().eq_ignore_ascii_case(s))
+      .ok_or_else(|| format!("Invalid entity name: {}", s))
+  }
+}
+
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum Contribution {
   Genre(domain::genre::contribution::Contribution),
@@ -16,6 +53,7 @@ pub enum Contribution {
   Album(domain::album::contribution::Contribution),
   Media(domain::media::contribution::Contribution),
   Source(domain::source::contribution::Contribution),
+  Lyrics(domain::lyrics::contribution::Contribution),
 }
 
 impl Default for Contribution {
@@ -0,0 +1,86 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IsrcError {
+  InvalidIsrc,
+}
+
+impl Display for IsrcError {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    match self {
+      Self::InvalidIsrc => write!(f, "Invalid ISRC"),
+    }
+  }
+}
+
+/**
+ * International Standard Recording Code (ISO 3901), armazenado sem hífens e em caixa alta.
+ *
+ * Formato: país (2 letras), registrante (3 alfanuméricos), ano (2 dígitos) e designação (5 dígitos).
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Isrc(String);
+
+impl Isrc {
+  pub fn new(value: impl Into<String>) -> Result<Self, IsrcError> {
+    let value = value.into().replace('-', "").to_uppercase();
+    let bytes = value.as_bytes();
+
+    let is_valid = bytes.len() == 12
+      && bytes[0..2].iter().all(u8::is_ascii_alphabetic)
+      && bytes[2..5].iter().all(u8::is_ascii_alphanumeric)
+      && bytes[5..12].iter().all(u8::is_ascii_digit);
+
+    if is_valid {
+      Ok(Self(value))
+    } else {
+      Err(IsrcError::InvalidIsrc)
+    }
+  }
+}
+
+impl Display for Isrc {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
+
+impl Serialize for Isrc {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    serializer.serialize_str(&self.0)
+  }
+}
+
+impl<'de> Deserialize<'de> for Isrc {
+  fn deserialize<D>(deserializer: D) -> Result<Isrc, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    let s = String::deserialize(deserializer)?;
+    Isrc::new(s).map_err(serde::de::Error::custom)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_isrc_normalizes_hyphens_and_case() {
+    let isrc = Isrc::new("br-abc-24-00001").expect("ISRC válido foi rejeitado");
+
+    assert_eq!(isrc.to_string(), "BRABC2400001");
+  }
+
+  #[test]
+  fn test_isrc_rejects_invalid_format() {
+    assert_eq!(Isrc::new("BRABC24000"), Err(IsrcError::InvalidIsrc));
+    assert_eq!(Isrc::new("12ABC2400001"), Err(IsrcError::InvalidIsrc));
+    assert_eq!(Isrc::new("BRABC24A0001"), Err(IsrcError::InvalidIsrc));
+  }
+}
@@ -1,7 +1,11 @@
+mod barcode;
 mod contribution;
+mod isrc;
 mod slug;
 mod uuid;
 
+pub use barcode::*;
 pub use contribution::*;
+pub use isrc::*;
 pub use slug::*;
 pub use uuid::*;
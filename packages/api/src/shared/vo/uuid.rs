@@ -67,3 +67,11 @@ impl<'de> Deserialize<'de> for UUID4 {
     Ok(UUID4(s))
   }
 }
+
+impl std::str::FromStr for UUID4 {
+  type Err = UUIDError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::new(s)
+  }
+}
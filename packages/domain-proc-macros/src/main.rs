@@ -1,6 +1,6 @@
 use domain_proc_macros::Entity;
-use syn::token::Use;
 
+#[allow(dead_code)]
 #[derive(Entity, Debug)]
 struct User {
   id: usize,
@@ -21,5 +21,5 @@ impl Default for User {
 }
 
 fn main() {
-  let u = UserBuilder::new().id(1).build();
+  let _u = UserBuilder::new().id(1).build();
 }